@@ -0,0 +1,171 @@
+//! Programmatic access to the functionality of cargo-msrv.
+//!
+//! The functions in this module take a [`Config`], which can be created with a [`ConfigBuilder`],
+//! and a callback which receives each [`Event`] reported while the function runs, such as the
+//! installation and check of a toolchain. This allows other tools to embed MSRV discovery and
+//! show its progress, without invoking the `cargo-msrv` binary.
+//!
+//! ```no_run
+//! use cargo_msrv::api;
+//! use cargo_msrv::config::{Action, ConfigBuilder};
+//!
+//! let target = api::default_target().unwrap();
+//! let config = ConfigBuilder::new(Action::Find, &target)
+//!     .manifest_path(Some("path/to/Cargo.toml"))
+//!     .build();
+//!
+//! let msrv = api::find_msrv(&config, |event| println!("{:?}", event)).unwrap();
+//! println!("MSRV: {}", msrv);
+//! ```
+//!
+//! [`Config`]: crate::config::Config
+//! [`ConfigBuilder`]: crate::config::ConfigBuilder
+
+use storyteller::ReporterError;
+
+use crate::check::{CachedCheck, RustupToolchainCheck};
+use crate::config::verify::{FailOn, VerifyCmdConfig};
+use crate::config::{Config, ConfigBuilder, SubCommandConfig};
+use crate::dependency_graph::resolver::{CargoMetadataResolver, DependencyResolver};
+use crate::error::{CargoMSRVError, TResult};
+use crate::reporter::Event;
+use crate::sub_command::verify;
+use crate::{fetch_index, semver, Find, SubCommand, Verify};
+
+/// Determine the default target of the installed Rust toolchains, which is used as target when
+/// no target is configured.
+pub fn default_target() -> TResult<String> {
+    crate::default_target::default_target()
+}
+
+/// Find the MSRV of the crate, or of each member of the workspace if the config enables
+/// the workspace mode.
+///
+/// Returns the MSRV, or the greatest MSRV of the workspace members.
+pub fn find_msrv(config: &Config, progress: impl Fn(&Event)) -> TResult<semver::Version> {
+    let sink = ProgressSink::new(progress);
+
+    let index = fetch_index(config, &sink)?;
+    let runner = CachedCheck::new(&sink, RustupToolchainCheck::new(&sink));
+
+    Find::new(&index, runner).run(config, &sink)
+}
+
+/// Verify whether the crate is compatible with its MSRV.
+///
+/// The MSRV is read from the Cargo manifest, unless the config specifies a Rust version to
+/// verify against. Returns `false` if verification fails, which by default is the case if the
+/// crate is not compatible.
+pub fn verify_msrv(config: &Config, progress: impl Fn(&Event)) -> TResult<bool> {
+    let sink = ProgressSink::new(progress);
+
+    let config = match config.sub_command_config() {
+        SubCommandConfig::VerifyConfig(_) => config.clone(),
+        _ => ConfigBuilder::from_config(config)
+            .sub_command_config(SubCommandConfig::VerifyConfig(VerifyCmdConfig {
+                rust_version: None,
+                fail_on: FailOn::default(),
+                find_lower: false,
+                published: None,
+            }))
+            .build(),
+    };
+
+    let index = fetch_index(&config, &sink)?;
+    let runner = CachedCheck::new(&sink, RustupToolchainCheck::new(&sink));
+
+    match Verify::new(&index, runner).run(&config, &sink) {
+        Ok(()) => Ok(true),
+        Err(CargoMSRVError::SubCommandVerify(verify::Error::VerifyFailed(_)))
+        | Err(CargoMSRVError::SubCommandVerify(verify::Error::MsrvTooConservative(_)))
+        | Err(CargoMSRVError::SubCommandVerify(verify::Error::WorkspaceVerifyFailed(_))) => {
+            Ok(false)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// The MSRV of a dependency, as specified by its author.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyMsrv {
+    pub name: String,
+    pub version: semver::Version,
+    /// The `package.rust-version` or `package.metadata.msrv` of the dependency, or `None` if the
+    /// dependency doesn't specify an MSRV.
+    pub msrv: Option<semver::Version>,
+}
+
+/// List the MSRV of each dependency of the crate, including transitive dependencies, as
+/// specified by the authors of the dependencies.
+///
+/// The dependencies are ordered by name.
+pub fn list_dependency_msrvs(config: &Config) -> TResult<Vec<DependencyMsrv>> {
+    let resolver = CargoMetadataResolver::try_from_config(config)?;
+    let graph = resolver.resolve()?;
+
+    let mut dependencies = graph
+        .packages()
+        .node_weights()
+        .filter(|package| &package.id != graph.root_crate())
+        .map(|package| DependencyMsrv {
+            name: package.name.clone(),
+            version: package.version.clone(),
+            msrv: config.msrv_resolver().package_msrv(package),
+        })
+        .collect::<Vec<_>>();
+
+    dependencies.sort_by(|lhs, rhs| {
+        lhs.name
+            .cmp(&rhs.name)
+            .then_with(|| lhs.version.cmp(&rhs.version))
+    });
+
+    Ok(dependencies)
+}
+
+/// A reporter which passes each reported event to a callback.
+pub struct ProgressSink<F> {
+    callback: F,
+}
+
+impl<F: Fn(&Event)> ProgressSink<F> {
+    pub fn new(callback: F) -> Self {
+        Self { callback }
+    }
+}
+
+impl<F: Fn(&Event)> storyteller::Reporter for ProgressSink<F> {
+    type Event = Event;
+    type Err = ReporterError<Event>;
+
+    fn report_event(&self, event: impl Into<Self::Event>) -> Result<(), Self::Err> {
+        (self.callback)(&event.into());
+        Ok(())
+    }
+
+    fn disconnect(self) -> Result<(), Self::Err> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Meta;
+    use std::cell::RefCell;
+    use storyteller::Reporter;
+
+    #[test]
+    fn progress_sink_passes_events_to_callback() {
+        let events = RefCell::new(Vec::new());
+        let sink = ProgressSink::new(|event: &Event| events.borrow_mut().push(event.clone()));
+
+        sink.report_event(Meta::default()).unwrap();
+        sink.report_event(Meta::default()).unwrap();
+
+        assert_eq!(
+            events.into_inner(),
+            vec![Event::from(Meta::default()), Event::from(Meta::default())]
+        );
+    }
+}
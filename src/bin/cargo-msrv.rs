@@ -1,18 +1,26 @@
 use std::convert::TryFrom;
 use std::ffi::OsString;
+use std::fs::File;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use storyteller::{EventHandler, EventListener, FinishProcessing};
+use tracing_appender::non_blocking::WorkerGuard;
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
 
 use cargo_msrv::cli::CargoCli;
-use cargo_msrv::config::{Config, OutputFormat, TracingOptions, TracingTargetOption};
+use cargo_msrv::config::{Config, OutputFormat, TracingOptions, TracingTargetOption, Verbosity};
 use cargo_msrv::error::CargoMSRVError;
 use cargo_msrv::exit_code::ExitCode;
+#[cfg(feature = "tui")]
+use cargo_msrv::reporter::TuiHandler;
 use cargo_msrv::reporter::{
-    DiscardOutputHandler, HumanProgressHandler, JsonHandler, ReporterSetup,
+    DiscardOutputHandler, DotHandler, GithubHandler, HtmlHandler, HumanProgressHandler,
+    JsonHandler, JunitHandler, MarkdownHandler, QuietHandler, ReportHandler, ReporterSetup,
+    SarifHandler, TomlHandler,
 };
 use cargo_msrv::reporter::{Event, Reporter, TerminateWithFailure};
 use cargo_msrv::run_app;
@@ -61,8 +69,11 @@ fn init_and_run(config: &Config) -> Result<ExitCode, InstanceError> {
 
     tracing::info!("storyteller channel created");
 
-    let handler = WrappingHandler::from(config.output_format());
-    let finalizer = listener.run_handler(Arc::new(handler));
+    let handler = WrappingHandler::from(config);
+    let finalizer = match config.write_report() {
+        Some(path) => listener.run_handler(Arc::new(ReportHandler::new(path, handler))),
+        None => listener.run_handler(Arc::new(handler)),
+    };
     tracing::info!("storyteller started handler");
     tracing::info!("start run_app");
 
@@ -99,6 +110,16 @@ fn get_exit_code(
 enum WrappingHandler {
     HumanProgress(HumanProgressHandler),
     Json(JsonHandler<io::Stderr>),
+    Github(GithubHandler<io::Stdout>),
+    Junit(JunitHandler),
+    Toml(TomlHandler<io::Stdout>),
+    Sarif(SarifHandler),
+    Markdown(MarkdownHandler<io::Stdout>),
+    Html(HtmlHandler),
+    Dot(DotHandler<io::Stdout>),
+    Quiet(QuietHandler<io::Stdout>),
+    #[cfg(feature = "tui")]
+    Tui(TuiHandler),
     DiscardOutput(DiscardOutputHandler),
 }
 
@@ -106,9 +127,21 @@ impl EventHandler for WrappingHandler {
     type Event = Event;
 
     fn handle(&self, event: Self::Event) {
+        log_event(&event);
+
         match self {
             WrappingHandler::HumanProgress(inner) => inner.handle(event),
             WrappingHandler::Json(inner) => inner.handle(event),
+            WrappingHandler::Github(inner) => inner.handle(event),
+            WrappingHandler::Junit(inner) => inner.handle(event),
+            WrappingHandler::Toml(inner) => inner.handle(event),
+            WrappingHandler::Sarif(inner) => inner.handle(event),
+            WrappingHandler::Markdown(inner) => inner.handle(event),
+            WrappingHandler::Html(inner) => inner.handle(event),
+            WrappingHandler::Dot(inner) => inner.handle(event),
+            WrappingHandler::Quiet(inner) => inner.handle(event),
+            #[cfg(feature = "tui")]
+            WrappingHandler::Tui(inner) => inner.handle(event),
             WrappingHandler::DiscardOutput(inner) => inner.handle(event),
         }
     }
@@ -117,16 +150,59 @@ impl EventHandler for WrappingHandler {
         match self {
             WrappingHandler::HumanProgress(inner) => inner.finish(),
             WrappingHandler::Json(inner) => inner.finish(),
+            WrappingHandler::Github(inner) => inner.finish(),
+            WrappingHandler::Junit(inner) => inner.finish(),
+            WrappingHandler::Toml(inner) => inner.finish(),
+            WrappingHandler::Sarif(inner) => inner.finish(),
+            WrappingHandler::Markdown(inner) => inner.finish(),
+            WrappingHandler::Html(inner) => inner.finish(),
+            WrappingHandler::Dot(inner) => inner.finish(),
+            WrappingHandler::Quiet(inner) => inner.finish(),
+            #[cfg(feature = "tui")]
+            WrappingHandler::Tui(inner) => inner.finish(),
             WrappingHandler::DiscardOutput(inner) => inner.finish(),
         }
     }
 }
 
-impl From<OutputFormat> for WrappingHandler {
-    fn from(output_format: OutputFormat) -> Self {
-        match output_format {
-            OutputFormat::Human => Self::HumanProgress(HumanProgressHandler::default()),
+impl From<&Config<'_>> for WrappingHandler {
+    fn from(config: &Config) -> Self {
+        #[cfg(feature = "tui")]
+        if config.tui() {
+            return Self::Tui(TuiHandler::stderr());
+        }
+
+        match config.output_format() {
+            OutputFormat::Human if config.verbosity() == Verbosity::Quiet => {
+                Self::Quiet(QuietHandler::stdout())
+            }
+            OutputFormat::Human => {
+                Self::HumanProgress(HumanProgressHandler::new(config.verbosity()))
+            }
             OutputFormat::Json => Self::Json(JsonHandler::stderr()),
+            OutputFormat::JsonLines => Self::Json(JsonHandler::stderr_lines()),
+            OutputFormat::Github => Self::Github(GithubHandler::stdout()),
+            OutputFormat::Junit => Self::Junit(JunitHandler::new(
+                config
+                    .output_file()
+                    .unwrap_or_else(|| Path::new("junit.xml")),
+            )),
+            OutputFormat::Toml => {
+                Self::Toml(TomlHandler::stdout(config.context().manifest_path().ok()))
+            }
+            OutputFormat::Sarif => Self::Sarif(SarifHandler::new(
+                config
+                    .output_file()
+                    .unwrap_or_else(|| Path::new("cargo-msrv.sarif")),
+            )),
+            OutputFormat::Markdown => Self::Markdown(MarkdownHandler::stdout()),
+            OutputFormat::Html => Self::Html(HtmlHandler::new(
+                config
+                    .output_file()
+                    .unwrap_or_else(|| Path::new("cargo-msrv.html")),
+            )),
+            OutputFormat::Dot => Self::Dot(DotHandler::stdout()),
+            OutputFormat::Minimal => Self::Quiet(QuietHandler::stdout()),
             OutputFormat::None => {
                 // To disable regular output. Useful when outputting logs to stdout, as the
                 //   regular output and the log output may otherwise interfere with each other.
@@ -136,6 +212,14 @@ impl From<OutputFormat> for WrappingHandler {
     }
 }
 
+/// Log each event which is reported, so the logs contain the user output as well.
+fn log_event(event: &Event) {
+    match serde_json::to_string(event) {
+        Ok(event) => tracing::info!(event = event.as_str(), "reported event"),
+        Err(err) => tracing::warn!(error = %err, "unable to serialize reported event"),
+    }
+}
+
 /// Disconnect the reporter, signalling that the program is finished, and we can now finish
 /// up processing the last user output events.
 fn disconnect_reporter(reporter: impl Reporter) -> Result<(), InstanceError> {
@@ -160,54 +244,64 @@ fn wait_for_user_output(finalizer: impl FinishProcessing) -> Result<(), Instance
 }
 
 fn init_tracing(tracing_config: &TracingConfig) -> Result<TracingGuard, InstanceError> {
-    let level = tracing_config.level;
+    let mut guards = Vec::with_capacity(2);
 
-    match &tracing_config.target {
+    let (to_disk, to_stdout) = match &tracing_config.target {
         // Log (non-blocking) to disk
         TracingTarget::ToDisk(path) => {
-            let guard = init_tracing_to_file(path, level);
+            let file_appender = RollingFileAppender::new(Rotation::DAILY, path, "cargo-msrv-log");
+            let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+            guards.push(guard);
 
-            let folder = format!("{}", path.display());
-            tracing::debug!(log_folder = folder.as_str());
+            let layer = tracing_subscriber::fmt::layer()
+                .json()
+                .with_writer(non_blocking);
 
-            guard
+            (Some(layer), None)
         }
         // Log to stdout
-        TracingTarget::Stdout => init_tracing_to_stdout(level),
-    }
-}
-
-fn init_tracing_to_file(
-    log_folder: impl AsRef<Path>,
-    level: tracing::Level,
-) -> Result<TracingGuard, InstanceError> {
-    let file_appender = RollingFileAppender::new(Rotation::DAILY, log_folder, "cargo-msrv-log");
-    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+        TracingTarget::Stdout => (None, Some(tracing_subscriber::fmt::layer())),
+    };
+
+    // Log (non-blocking) to the given log file, in addition to the log target
+    let to_log_file = match &tracing_config.log_file {
+        Some(path) => {
+            let file = File::create(path)
+                .map_err(|error| InstanceError::UnableToCreateLogFile(path.clone(), error))?;
+            let (non_blocking, guard) = tracing_appender::non_blocking(file);
+            guards.push(guard);
+
+            let layer = tracing_subscriber::fmt::layer()
+                .json()
+                .with_ansi(false)
+                .with_writer(non_blocking);
+
+            Some(layer)
+        }
+        None => None,
+    };
 
-    let subscriber = tracing_subscriber::fmt()
-        .json()
-        .with_max_level(level)
-        .with_writer(non_blocking)
-        .finish();
+    let subscriber = tracing_subscriber::registry()
+        .with(LevelFilter::from_level(tracing_config.level))
+        .with(to_disk)
+        .with(to_stdout)
+        .with(to_log_file);
 
     tracing::subscriber::set_global_default(subscriber)
         .map_err(|_| InstanceError::UnableToInitTracing)?;
 
-    Ok(TracingGuard::NonBlockingGuard(guard))
-}
-
-fn init_tracing_to_stdout(level: tracing::Level) -> Result<TracingGuard, InstanceError> {
-    let subscriber = tracing_subscriber::fmt().with_max_level(level).finish();
-
-    tracing::subscriber::set_global_default(subscriber)
-        .map_err(|_| InstanceError::UnableToInitTracing)?;
+    if let TracingTarget::ToDisk(path) = &tracing_config.target {
+        let folder = format!("{}", path.display());
+        tracing::debug!(log_folder = folder.as_str());
+    }
 
-    Ok(TracingGuard::None)
+    Ok(TracingGuard { _guards: guards })
 }
 
 struct TracingConfig {
     level: tracing::Level,
     target: TracingTarget,
+    log_file: Option<PathBuf>,
 }
 
 impl TracingConfig {
@@ -217,6 +311,7 @@ impl TracingConfig {
         Ok(Self {
             level: (*config.level()).into(),
             target,
+            log_file: config.log_file().map(Path::to_path_buf),
         })
     }
 }
@@ -238,9 +333,9 @@ impl TracingTarget {
     }
 }
 
-enum TracingGuard {
-    NonBlockingGuard(tracing_appender::non_blocking::WorkerGuard),
-    None,
+/// Keeps the non-blocking log writers alive; the remaining logs are flushed when dropped.
+struct TracingGuard {
+    _guards: Vec<WorkerGuard>,
 }
 
 fn log_folder() -> Result<PathBuf, InstanceError> {
@@ -264,6 +359,9 @@ enum InstanceError {
     #[error("Unable to access log folder, run with --no-log to try again without logging.")]
     UnableToAccessLogFolder,
 
+    #[error("Unable to create log file '{}': {1}", .0.display())]
+    UnableToCreateLogFile(PathBuf, io::Error),
+
     #[error("Failed to disconnect user output channel (storyteller)")]
     StorytellerDisconnect,
 
@@ -0,0 +1,204 @@
+//! The inputs of the checks by which the MSRV was found, recorded in the
+//! `[package.metadata.msrv-verified]` table of the Cargo manifest by `find --certify`.
+//!
+//! When the MSRV is verified later on, the recorded inputs are compared to the current ones, so
+//! a certification which may no longer hold can be reported.
+
+use toml_edit::{value, Document, Item, Table};
+
+use crate::date::today;
+use crate::error::{IoErrorSource, TResult};
+use crate::lockfile::find_lockfile;
+use crate::reporter::event::CertifiedInput;
+use crate::{semver, CargoMSRVError, Config};
+
+/// The key of the certification table, in the `package.metadata` table.
+pub(crate) const CERTIFICATION_KEY: &str = "msrv-verified";
+
+const TOOLCHAIN_KEY: &str = "toolchain";
+const DATE_KEY: &str = "date";
+const CHECK_COMMAND_KEY: &str = "check-command";
+const LOCKFILE_HASH_KEY: &str = "lockfile-hash";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Certification {
+    toolchain: String,
+    date: String,
+    check_command: String,
+    lockfile_hash: Option<String>,
+}
+
+impl Certification {
+    /// Certify the given toolchain with the current inputs of the check.
+    pub(crate) fn new(config: &Config, toolchain: &semver::Version) -> TResult<Self> {
+        Ok(Self {
+            toolchain: toolchain.to_string(),
+            date: today(),
+            check_command: config.check_command_string(),
+            lockfile_hash: lockfile_hash(config)?,
+        })
+    }
+
+    /// Read the certification from the `package.metadata.msrv-verified` table of the manifest,
+    /// if it exists and is complete.
+    pub(crate) fn read(manifest: &Document) -> Option<Self> {
+        let certification = manifest
+            .get("package")
+            .and_then(|package| package.get("metadata"))
+            .and_then(|metadata| metadata.get(CERTIFICATION_KEY))?;
+
+        let get = |key: &str| {
+            certification
+                .get(key)
+                .and_then(Item::as_str)
+                .map(ToOwned::to_owned)
+        };
+
+        Some(Self {
+            toolchain: get(TOOLCHAIN_KEY)?,
+            date: get(DATE_KEY)?,
+            check_command: get(CHECK_COMMAND_KEY)?,
+            lockfile_hash: get(LOCKFILE_HASH_KEY),
+        })
+    }
+
+    /// The certification as table, to be written to `package.metadata.msrv-verified`.
+    pub(crate) fn to_table(&self) -> Table {
+        let mut table = Table::new();
+        table.insert(TOOLCHAIN_KEY, value(self.toolchain.as_str()));
+        table.insert(DATE_KEY, value(self.date.as_str()));
+        table.insert(CHECK_COMMAND_KEY, value(self.check_command.as_str()));
+
+        if let Some(hash) = &self.lockfile_hash {
+            table.insert(LOCKFILE_HASH_KEY, value(hash.as_str()));
+        }
+
+        table
+    }
+
+    /// The inputs which differ between this certification and the `current` one.
+    ///
+    /// The date is not an input of the check, and is not compared.
+    pub(crate) fn changed_inputs(&self, current: &Self) -> Vec<CertifiedInput> {
+        let mut changed = Vec::new();
+
+        if self.toolchain != current.toolchain {
+            changed.push(CertifiedInput::Toolchain);
+        }
+
+        if self.check_command != current.check_command {
+            changed.push(CertifiedInput::CheckCommand);
+        }
+
+        if self.lockfile_hash != current.lockfile_hash {
+            changed.push(CertifiedInput::Lockfile);
+        }
+
+        changed
+    }
+
+    pub(crate) fn toolchain(&self) -> &str {
+        &self.toolchain
+    }
+
+    pub(crate) fn date(&self) -> &str {
+        &self.date
+    }
+}
+
+/// Hash the contents of the lockfile of the crate, if it has one.
+fn lockfile_hash(config: &Config) -> TResult<Option<String>> {
+    let crate_root = config.context().crate_root_path()?;
+
+    match find_lockfile(crate_root) {
+        Some(lockfile) => {
+            let contents = std::fs::read(&lockfile).map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::ReadFile(lockfile.clone()),
+            })?;
+
+            Ok(Some(format!("{:016x}", fnv1a(&contents))))
+        }
+        None => Ok(None),
+    }
+}
+
+/// The 64 bit FNV-1a hash of the bytes.
+///
+/// Unlike the hasher of the standard library, its output is stable across Rust versions and
+/// platforms, so it can be stored in the manifest.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::{CargoManifestParser, TomlParser};
+
+    fn certification(toolchain: &str, check_command: &str, hash: Option<&str>) -> Certification {
+        Certification {
+            toolchain: toolchain.to_string(),
+            date: "2022-08-11".to_string(),
+            check_command: check_command.to_string(),
+            lockfile_hash: hash.map(ToOwned::to_owned),
+        }
+    }
+
+    #[test]
+    fn fnv1a_is_stable() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn read_certification() {
+        let manifest = r#"[package]
+name = "a"
+version = "0.1.0"
+rust-version = "1.56"
+
+[package.metadata.msrv-verified]
+toolchain = "1.56.1"
+date = "2022-08-11"
+check-command = "cargo check"
+lockfile-hash = "af63dc4c8601ec8c"
+"#;
+
+        let document = CargoManifestParser::default()
+            .parse::<Document>(manifest)
+            .unwrap();
+
+        assert_eq!(
+            Certification::read(&document).unwrap(),
+            certification("1.56.1", "cargo check", Some("af63dc4c8601ec8c"))
+        );
+    }
+
+    #[test]
+    fn read_missing_certification() {
+        let document = CargoManifestParser::default()
+            .parse::<Document>("[package]\nname = \"a\"\n")
+            .unwrap();
+
+        assert!(Certification::read(&document).is_none());
+    }
+
+    #[yare::parameterized(
+        unchanged = { certification("1.56.1", "cargo check", Some("1")), vec![] },
+        toolchain = { certification("1.57.0", "cargo check", Some("1")), vec![CertifiedInput::Toolchain] },
+        check_command = { certification("1.56.1", "cargo test", Some("1")), vec![CertifiedInput::CheckCommand] },
+        lockfile = { certification("1.56.1", "cargo check", Some("2")), vec![CertifiedInput::Lockfile] },
+        lockfile_removed = { certification("1.56.1", "cargo check", None), vec![CertifiedInput::Lockfile] },
+    )]
+    fn changed_inputs(current: Certification, expected: Vec<CertifiedInput>) {
+        let certified = certification("1.56.1", "cargo check", Some("1"));
+
+        assert_eq!(certified.changed_inputs(&current), expected);
+    }
+}
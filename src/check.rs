@@ -1,15 +1,38 @@
 use crate::config::Config;
+use crate::semver;
 use crate::toolchain::ToolchainSpec;
 
+mod cached_check;
+pub mod cancel;
+mod lockfile_fallback;
 mod rustup_toolchain_check;
+mod search_state;
+pub mod skip;
 #[cfg(test)]
 mod testing;
 
 use crate::{Outcome, TResult};
+pub use cached_check::CachedCheck;
 pub use rustup_toolchain_check::RustupToolchainCheck;
 #[cfg(test)]
 pub use testing::TestRunner;
 
 pub trait Check {
     fn check(&self, config: &Config, toolchain: &ToolchainSpec) -> TResult<Outcome>;
+
+    /// Check multiple toolchains, which are independent of each other.
+    ///
+    /// Implementations may run these checks concurrently. The outcomes are returned in the same
+    /// order as the given toolchains.
+    fn check_many(&self, config: &Config, toolchains: &[ToolchainSpec]) -> TResult<Vec<Outcome>> {
+        toolchains
+            .iter()
+            .map(|toolchain| self.check(config, toolchain))
+            .collect()
+    }
+
+    /// Called once the search space of a search for the MSRV is known, before its checks are run.
+    fn start_search(&self, _config: &Config, _search_space: &[semver::Version]) -> TResult<()> {
+        Ok(())
+    }
 }
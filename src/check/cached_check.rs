@@ -0,0 +1,546 @@
+use crate::check::search_state::SearchState;
+use crate::check::{cancel, Check};
+use crate::error::IoErrorSource;
+use crate::lockfile::CARGO_LOCK;
+use crate::reporter::event::{CheckToolchain, Compatibility, SearchCancelled};
+use crate::toolchain::ToolchainSpec;
+use crate::{semver, CargoMSRVError, Config, Outcome, Reporter, TResult};
+use cargo_metadata::MetadataCommand;
+use once_cell::unsync::OnceCell;
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Name of the file, within the cache directory, which stores the keys of the compatible checks.
+const CACHE_FILE: &str = "check-cache";
+
+/// Name of the file, within the cache directory, which stores the state of the last search.
+const STATE_FILE: &str = "state.json";
+
+/// A [`Check`] which remembers which toolchains were found to be compatible with a crate, and
+/// skips running the inner check if the same check was successful in an earlier run.
+///
+/// A check is only considered to be the same if the toolchain (version and target), the check
+/// command, and the sources of the crate, including its manifest and lockfile, are unchanged.
+/// Incompatible outcomes are not reused by default, since these are not guaranteed to be caused by
+/// the crate itself (e.g. a network failure while fetching dependencies).
+///
+/// The search space and the outcome of every check are also persisted as the state of the search,
+/// after each check. With `--resume`, the outcomes of an earlier, interrupted search are reused.
+///
+/// The cache and the state are stored in the `cargo-msrv` folder of the Cargo target directory, and may be
+/// disabled with `--no-cache`.
+///
+/// When the run is cancelled, the outcomes of the checks which were completed are reported.
+pub struct CachedCheck<'reporter, R: Reporter, C: Check> {
+    reporter: &'reporter R,
+    inner: C,
+    cache_dir: OnceCell<PathBuf>,
+    outcomes: Mutex<Vec<Outcome>>,
+    state: Mutex<Option<SearchState>>,
+}
+
+impl<'reporter, R: Reporter, C: Check> Check for CachedCheck<'reporter, R, C> {
+    fn check(&self, config: &Config, toolchain: &ToolchainSpec) -> TResult<Outcome> {
+        let outcome = self
+            .cached_check(config, toolchain)
+            .map_err(|error| self.cancelled(config, error))?;
+
+        self.track(std::slice::from_ref(&outcome));
+
+        Ok(outcome)
+    }
+
+    fn check_many(&self, config: &Config, toolchains: &[ToolchainSpec]) -> TResult<Vec<Outcome>> {
+        let outcomes = self
+            .cached_check_many(config, toolchains)
+            .map_err(|error| self.cancelled(config, error))?;
+
+        self.track(&outcomes);
+
+        Ok(outcomes)
+    }
+
+    fn start_search(&self, config: &Config, search_space: &[semver::Version]) -> TResult<()> {
+        if !config.no_cache() {
+            let path = self.state_path(config)?;
+
+            self.with_state(config, |state| {
+                state.set_search_space(search_space);
+                state.save(&path)
+            })??;
+        }
+
+        self.inner.start_search(config, search_space)
+    }
+}
+
+impl<'reporter, R: Reporter, C: Check> CachedCheck<'reporter, R, C> {
+    pub fn new(reporter: &'reporter R, inner: C) -> Self {
+        Self {
+            reporter,
+            inner,
+            cache_dir: OnceCell::new(),
+            outcomes: Mutex::new(Vec::new()),
+            state: Mutex::new(None),
+        }
+    }
+
+    fn cached_check(&self, config: &Config, toolchain: &ToolchainSpec) -> TResult<Outcome> {
+        // A linked toolchain may have been rebuilt since it was checked
+        if config.no_cache() || toolchain.is_linked() {
+            return self.inner.check(config, toolchain);
+        }
+
+        let cache = CheckCache::new(self.cache_dir(config)?);
+        let key = cache_key(config, toolchain)?;
+
+        if cache.contains(key)? {
+            return self.cached_outcome(toolchain);
+        }
+
+        match self.decided(config, key)? {
+            Some(true) => return self.cached_outcome(toolchain),
+            Some(false) => return self.resumed_outcome(toolchain),
+            None => {}
+        }
+
+        let outcome = self.inner.check(config, toolchain)?;
+
+        if outcome.is_success() {
+            cache.insert(key)?;
+        }
+
+        self.record(config, toolchain, key, outcome.is_success())?;
+
+        Ok(outcome)
+    }
+
+    fn cached_check_many(
+        &self,
+        config: &Config,
+        toolchains: &[ToolchainSpec],
+    ) -> TResult<Vec<Outcome>> {
+        if config.no_cache() || toolchains.iter().any(ToolchainSpec::is_linked) {
+            return self.inner.check_many(config, toolchains);
+        }
+
+        let cache = CheckCache::new(self.cache_dir(config)?);
+
+        let mut cached = Vec::with_capacity(toolchains.len());
+        let mut uncached = Vec::new();
+        let mut uncached_keys = Vec::new();
+
+        for toolchain in toolchains {
+            let key = cache_key(config, toolchain)?;
+
+            if cache.contains(key)? {
+                cached.push(Some(self.cached_outcome(toolchain)?));
+                continue;
+            }
+
+            match self.decided(config, key)? {
+                Some(true) => cached.push(Some(self.cached_outcome(toolchain)?)),
+                Some(false) => cached.push(Some(self.resumed_outcome(toolchain)?)),
+                None => {
+                    cached.push(None);
+                    uncached.push(toolchain.clone());
+                    uncached_keys.push(key);
+                }
+            }
+        }
+
+        let mut checked = self
+            .inner
+            .check_many(config, &uncached)?
+            .into_iter()
+            .zip(uncached.iter().zip(uncached_keys));
+
+        // Merge the cached and checked outcomes, in the order of the given toolchains
+        cached
+            .into_iter()
+            .map(|outcome| match outcome {
+                Some(outcome) => Ok(outcome),
+                None => {
+                    let (outcome, (toolchain, key)) = checked
+                        .next()
+                        .expect("Expected an outcome for each uncached toolchain");
+
+                    if outcome.is_success() {
+                        cache.insert(key)?;
+                    }
+
+                    self.record(config, toolchain, key, outcome.is_success())?;
+
+                    Ok(outcome)
+                }
+            })
+            .collect()
+    }
+
+    fn cached_outcome(&self, toolchain: &ToolchainSpec) -> TResult<Outcome> {
+        info!(toolchain = toolchain.spec(), "using cached check result");
+
+        self.reporter
+            .run_scoped_event(CheckToolchain::new(toolchain.to_owned()), || {
+                self.reporter
+                    .report_event(Compatibility::compatible(toolchain.to_owned()))?;
+
+                Ok(Outcome::new_success(toolchain.to_owned()))
+            })
+    }
+
+    fn resumed_outcome(&self, toolchain: &ToolchainSpec) -> TResult<Outcome> {
+        info!(
+            toolchain = toolchain.spec(),
+            "resuming incompatible check result"
+        );
+
+        let error = "Incompatible in the interrupted search which was resumed".to_string();
+
+        self.reporter
+            .run_scoped_event(CheckToolchain::new(toolchain.to_owned()), || {
+                self.reporter.report_event(Compatibility::incompatible(
+                    toolchain.to_owned(),
+                    Some(error.clone()),
+                ))?;
+
+                Ok(Outcome::new_failure(toolchain.to_owned(), error.clone()))
+            })
+    }
+
+    fn state_path(&self, config: &Config) -> TResult<PathBuf> {
+        Ok(self.cache_dir(config)?.join(STATE_FILE))
+    }
+
+    /// Access the search state of this run. When resuming, the state starts from the persisted
+    /// state of the earlier run.
+    fn with_state<T>(&self, config: &Config, f: impl FnOnce(&mut SearchState) -> T) -> TResult<T> {
+        let path = self.state_path(config)?;
+        let mut state = self
+            .state
+            .lock()
+            .expect("Expected the search state to be accessible");
+
+        let state = match &mut *state {
+            Some(state) => state,
+            empty => empty.insert(if config.resume() {
+                SearchState::load(&path)?
+            } else {
+                SearchState::default()
+            }),
+        };
+
+        Ok(f(state))
+    }
+
+    /// Whether the check was decided in the search which is resumed, if any.
+    fn decided(&self, config: &Config, key: u64) -> TResult<Option<bool>> {
+        if !config.resume() {
+            return Ok(None);
+        }
+
+        self.with_state(config, |state| state.decided(key))
+    }
+
+    /// Record the outcome of a check in the search state, and persist it.
+    fn record(
+        &self,
+        config: &Config,
+        toolchain: &ToolchainSpec,
+        key: u64,
+        compatible: bool,
+    ) -> TResult<()> {
+        let path = self.state_path(config)?;
+
+        self.with_state(config, |state| {
+            state.record(toolchain, key, compatible);
+            state.save(&path)
+        })?
+    }
+
+    /// Remember the outcomes of this run, so they can be reported when the run is cancelled.
+    fn track(&self, outcomes: &[Outcome]) {
+        if let Ok(mut tracked) = self.outcomes.lock() {
+            tracked.extend_from_slice(outcomes);
+        }
+    }
+
+    /// Report the outcomes of this run if the check failed because the run was cancelled.
+    fn cancelled(&self, config: &Config, error: CargoMSRVError) -> CargoMSRVError {
+        if !matches!(error, CargoMSRVError::Cancelled) && !cancel::is_cancelled() {
+            return error;
+        }
+
+        match self.report_cancelled(config) {
+            Ok(()) => CargoMSRVError::Cancelled,
+            Err(error) => error,
+        }
+    }
+
+    fn report_cancelled(&self, config: &Config) -> TResult<()> {
+        let (compatible, incompatible): (Vec<_>, Vec<_>) = self
+            .outcomes
+            .lock()
+            .map(|outcomes| outcomes.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .partition(Outcome::is_success);
+
+        let toolchains = |outcomes: Vec<Outcome>| {
+            outcomes
+                .iter()
+                .map(|outcome| outcome.toolchain_spec().clone())
+                .collect::<Vec<_>>()
+        };
+
+        self.reporter.report_event(SearchCancelled::new(
+            toolchains(compatible),
+            toolchains(incompatible),
+            !config.no_cache(),
+        ))?;
+
+        Ok(())
+    }
+
+    fn cache_dir(&self, config: &Config) -> TResult<&Path> {
+        let path = self.cache_dir.get_or_try_init(|| {
+            let target_dir = match std::env::var_os("CARGO_TARGET_DIR") {
+                Some(dir) => PathBuf::from(dir),
+                None => MetadataCommand::new()
+                    .manifest_path(config.context().manifest_path()?)
+                    .no_deps()
+                    .exec()?
+                    .target_directory
+                    .into_std_path_buf(),
+            };
+
+            Ok::<_, CargoMSRVError>(target_dir.join("cargo-msrv"))
+        })?;
+
+        Ok(path)
+    }
+}
+
+/// Persistent set of check keys, for which the check was found to be successful.
+struct CheckCache {
+    dir: PathBuf,
+}
+
+impl CheckCache {
+    fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn file(&self) -> PathBuf {
+        self.dir.join(CACHE_FILE)
+    }
+
+    fn contains(&self, key: u64) -> TResult<bool> {
+        let path = self.file();
+
+        if !path.is_file() {
+            return Ok(false);
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.clone()),
+        })?;
+
+        let key = format!("{:016x}", key);
+
+        Ok(contents.lines().any(|line| line == key))
+    }
+
+    fn insert(&self, key: u64) -> TResult<()> {
+        let path = self.file();
+
+        fs::create_dir_all(&self.dir).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::WriteFile(self.dir.clone()),
+        })?;
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::OpenFile(path.clone()),
+            })?;
+
+        writeln!(file, "{:016x}", key).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::WriteFile(path.clone()),
+        })
+    }
+}
+
+/// Compute the key which identifies a check: the combination of the toolchain, the check command
+/// and its environment, and the contents of the crate.
+fn cache_key(config: &Config, toolchain: &ToolchainSpec) -> TResult<u64> {
+    let mut hasher = DefaultHasher::new();
+
+    toolchain.spec().hash(&mut hasher);
+    config.check_command().hash(&mut hasher);
+    config.check_env().hash(&mut hasher);
+    config.check_targets().hash(&mut hasher);
+    config.ignore_lockfile().hash(&mut hasher);
+    config.regenerate_lockfile().hash(&mut hasher);
+    config.minimal_versions().hash(&mut hasher);
+
+    let crate_root = config.context().crate_root_path()?;
+    hash_dir(crate_root, crate_root, &mut hasher)?;
+
+    // The lockfile may be located in the root of a workspace, instead of the crate root
+    if let Some((workspace_root, lockfile)) = crate_root
+        .ancestors()
+        .skip(1)
+        .map(|dir| (dir, dir.join(CARGO_LOCK)))
+        .find(|(_, path)| path.is_file())
+    {
+        hash_file(workspace_root, &lockfile, &mut hasher)?;
+    }
+
+    Ok(hasher.finish())
+}
+
+/// Hash the relative paths and contents of all files in the given directory, recursively.
+///
+/// The `target` folder and hidden files and folders (such as `.git`) are skipped.
+fn hash_dir(root: &Path, dir: &Path, hasher: &mut impl Hasher) -> TResult<()> {
+    let read_dir = fs::read_dir(dir).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(dir.to_path_buf()),
+    })?;
+
+    let mut entries = read_dir
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(dir.to_path_buf()),
+        })?;
+
+    // The order in which entries are returned by `read_dir` is platform dependent
+    entries.sort();
+
+    for path in entries {
+        let skip = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.starts_with('.') || (dir == root && name == "target"))
+            .unwrap_or(false);
+
+        if skip {
+            continue;
+        }
+
+        if path.is_dir() {
+            hash_dir(root, &path, hasher)?;
+        } else if path.is_file() {
+            hash_file(root, &path, hasher)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn hash_file(root: &Path, path: &Path, hasher: &mut impl Hasher) -> TResult<()> {
+    let contents = fs::read(path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(path.to_path_buf()),
+    })?;
+
+    path.strip_prefix(root).unwrap_or(path).hash(hasher);
+    contents.hash(hasher);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use crate::{semver, Action};
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[test]
+    fn cache_contains_inserted_key() {
+        let tmp = TestDir::temp();
+        let cache = CheckCache::new(tmp.path("cargo-msrv"));
+
+        assert!(!cache.contains(1).unwrap());
+
+        cache.insert(1).unwrap();
+        cache.insert(2).unwrap();
+
+        assert!(cache.contains(1).unwrap());
+        assert!(cache.contains(2).unwrap());
+        assert!(!cache.contains(3).unwrap());
+    }
+
+    #[test]
+    fn key_changes_with_crate_contents() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("src", FileType::Dir)
+            .create("src/lib.rs", FileType::EmptyFile);
+
+        let config = ConfigBuilder::new(Action::Find, "x")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        let version = semver::Version::new(1, 56, 0);
+        let toolchain = ToolchainSpec::new(&version, "x");
+
+        let before = cache_key(&config, &toolchain).unwrap();
+        assert_eq!(before, cache_key(&config, &toolchain).unwrap());
+
+        fs::write(tmp.path("src/lib.rs"), "pub fn hello() {}").unwrap();
+
+        let after = cache_key(&config, &toolchain).unwrap();
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn key_does_not_change_with_target_folder_contents() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("target", FileType::Dir);
+
+        let config = ConfigBuilder::new(Action::Find, "x")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        let version = semver::Version::new(1, 56, 0);
+        let toolchain = ToolchainSpec::new(&version, "x");
+
+        let before = cache_key(&config, &toolchain).unwrap();
+
+        fs::write(tmp.path("target/output"), "build artifact").unwrap();
+
+        assert_eq!(before, cache_key(&config, &toolchain).unwrap());
+    }
+
+    #[test]
+    fn key_changes_with_toolchain() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+
+        let config = ConfigBuilder::new(Action::Find, "x")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        let first = semver::Version::new(1, 56, 0);
+        let second = semver::Version::new(1, 57, 0);
+
+        assert_ne!(
+            cache_key(&config, &ToolchainSpec::new(&first, "x")).unwrap(),
+            cache_key(&config, &ToolchainSpec::new(&second, "x")).unwrap()
+        );
+    }
+}
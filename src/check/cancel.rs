@@ -0,0 +1,35 @@
+//! Allows a run to be cancelled by the user with Ctrl-C, so the running check can be stopped, and
+//! the checks which were already completed can be reported before cargo-msrv exits.
+//!
+//! Cancellation is process wide, and can't be undone.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Exit code of a process which was interrupted by Ctrl-C (128 + SIGINT).
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Request that the run is cancelled: the running check is aborted, and no more checks are run.
+pub fn request_cancel() {
+    CANCEL_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Returns `true` if the run was cancelled.
+pub(crate) fn is_cancelled() -> bool {
+    CANCEL_REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Cancel the run on Ctrl-C, instead of exiting immediately. When Ctrl-C is pressed a second
+/// time, cargo-msrv exits immediately.
+pub(crate) fn install_handler() {
+    let result = ctrlc::set_handler(|| {
+        if CANCEL_REQUESTED.swap(true, Ordering::SeqCst) {
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+    });
+
+    if let Err(error) = result {
+        warn!(%error, "unable to install Ctrl-C handler");
+    }
+}
@@ -0,0 +1,138 @@
+//! When the Cargo version of a toolchain doesn't understand the format of the lockfile, e.g. a
+//! v3 or v4 lockfile checked with an old toolchain, the check is retried in a temporary copy of
+//! the project, with a lockfile generated by the Cargo version of the toolchain. The lockfile of
+//! the project itself is left untouched.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::lockfile::{find_lockfile, CARGO_LOCK};
+use crate::sandbox::copy_dir;
+
+/// Whether Cargo failed to read the lockfile, because it was written in a format which this
+/// Cargo version doesn't understand.
+pub(super) fn rejects_lockfile(stderr: &str) -> bool {
+    stderr.lines().any(|line| {
+        line.contains("failed to parse lock file at")
+            || line.contains("does not understand this lock file")
+    })
+}
+
+/// A copy of the project in a temporary directory, without its lockfile. The directory is
+/// removed when dropped.
+pub(super) struct ProjectCopy {
+    dir: PathBuf,
+    crate_root: PathBuf,
+}
+
+impl ProjectCopy {
+    /// Copy the directory which holds the lockfile of the crate, i.e. the crate root or the root
+    /// of its workspace. Returns `None` if the crate has no lockfile.
+    pub(super) fn new(crate_root: &Path, name: &str) -> TResult<Option<Self>> {
+        let lockfile = match find_lockfile(crate_root) {
+            Some(lockfile) => lockfile,
+            None => return Ok(None),
+        };
+
+        // The lockfile was found in one of the ancestors of the crate root
+        let root = lockfile.parent().unwrap_or(crate_root);
+        let relative_crate_root = crate_root
+            .strip_prefix(root)
+            .unwrap_or_else(|_| Path::new(""));
+
+        let dir = std::env::temp_dir().join(format!(
+            "cargo-msrv-lockfile-{}-{}",
+            std::process::id(),
+            name
+        ));
+
+        // Create the guard first, so a partial copy is removed as well
+        let copy = Self {
+            crate_root: dir.join(relative_crate_root),
+            dir,
+        };
+
+        copy_dir(root, &copy.dir)?;
+
+        let copied_lockfile = copy.dir.join(CARGO_LOCK);
+        fs::remove_file(&copied_lockfile).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::RemoveFile(copied_lockfile),
+        })?;
+
+        Ok(Some(copy))
+    }
+
+    /// The crate root within the copy, from which the check is run.
+    pub(super) fn crate_root(&self) -> &Path {
+        &self.crate_root
+    }
+
+    /// The lockfile which is generated within the copy.
+    pub(super) fn lockfile(&self) -> PathBuf {
+        self.dir.join(CARGO_LOCK)
+    }
+}
+
+impl Drop for ProjectCopy {
+    fn drop(&mut self) {
+        if let Err(error) = fs::remove_dir_all(&self.dir) {
+            warn!(%error, path = %self.dir.display(), "unable to remove copy of the project");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[yare::parameterized(
+        v3_with_old_cargo = { "error: failed to parse lock file at: /a/Cargo.lock\n\nCaused by:\n  invalid serialized PackageId for key `package.dependencies`", true },
+        v4_with_old_cargo = { "error: failed to parse lock file at: /a/Cargo.lock\n\nCaused by:\n  lock file version 4 requires `-Znext-lockfile-bump`", true },
+        unknown_version = { "error: lock file version `5` was found, but this version of Cargo does not understand this lock file, perhaps Cargo needs to be updated?", true },
+        compile_error = { "error[E0658]: use of unstable library feature 'let_else'", false },
+    )]
+    fn detects_rejected_lockfile(stderr: &str, expected: bool) {
+        assert_eq!(rejects_lockfile(stderr), expected);
+    }
+
+    #[test]
+    fn copy_without_lockfile_and_skipped_dirs() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("Cargo.lock", FileType::EmptyFile)
+            .create("src", FileType::Dir)
+            .create("src/lib.rs", FileType::EmptyFile)
+            .create("target", FileType::Dir)
+            .create("target/out", FileType::EmptyFile);
+
+        let copy = ProjectCopy::new(tmp.root(), "copy_without_lockfile")
+            .unwrap()
+            .unwrap();
+
+        assert!(copy.crate_root().join("Cargo.toml").is_file());
+        assert!(copy.crate_root().join("src/lib.rs").is_file());
+        assert!(!copy.lockfile().exists());
+        assert!(!copy.crate_root().join("target").exists());
+
+        // The lockfile of the project itself is untouched
+        assert!(tmp.path("Cargo.lock").is_file());
+    }
+
+    #[test]
+    fn copy_is_removed_on_drop() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("Cargo.lock", FileType::EmptyFile);
+
+        let copy = ProjectCopy::new(tmp.root(), "copy_is_removed")
+            .unwrap()
+            .unwrap();
+        let root = copy.crate_root().to_path_buf();
+        drop(copy);
+
+        assert!(!root.exists());
+    }
+}
@@ -1,17 +1,34 @@
+use crate::check::lockfile_fallback::{rejects_lockfile, ProjectCopy};
+use crate::check::skip;
 use crate::check::Check;
-use crate::command::RustupCommand;
-use crate::download::{DownloadToolchain, ToolchainDownloader};
+use crate::command::{Completion, RustupCommand, RustupOutput};
+use crate::config::{CheckRunner, SearchMethod};
+use crate::diagnosis::unsupported_flag;
+use crate::download::RetryPolicy;
 use crate::error::IoErrorSource;
 use crate::lockfile::{LockfileHandler, CARGO_LOCK};
-use crate::reporter::event::{CheckToolchain, Compatibility, CompatibilityCheckMethod, Method};
+use crate::reporter::event::{
+    CheckOutput, CheckSkipped, CheckTimedOut, CheckToolchain, Compatibility,
+    CompatibilityCheckMethod, Method, PlannedCheck, TemporaryLockfile, ToolchainFileBypassed,
+};
+use crate::sandbox::Sandbox;
 use crate::toolchain::ToolchainSpec;
+use crate::toolchain_file::ToolchainFile;
+use crate::toolchain_provider::{
+    DirectoryProvider, DockerProvider, RustupProvider, ToolchainProvider,
+};
 use crate::{CargoMSRVError, Config, Outcome, Reporter, TResult};
 use once_cell::unsync::OnceCell;
+use std::cell::RefCell;
+use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 pub struct RustupToolchainCheck<'reporter, R: Reporter> {
     reporter: &'reporter R,
     lockfile_path: OnceCell<PathBuf>,
+    bypasses_toolchain_file: OnceCell<bool>,
+    sandboxes: RefCell<Vec<Sandbox>>,
 }
 
 impl<'reporter, R: Reporter> Check for RustupToolchainCheck<'reporter, R> {
@@ -23,7 +40,7 @@ impl<'reporter, R: Reporter> Check for RustupToolchainCheck<'reporter, R> {
                 // temporarily move the lockfile if the user opted to ignore it, and it exists
                 let cargo_lock = self.lockfile_path(config)?;
 
-                let handle_wrap = if config.ignore_lockfile() && cargo_lock.is_file() {
+                let handle_wrap = if stashes_lockfile(config) && cargo_lock.is_file() {
                     let handle = LockfileHandler::new(cargo_lock).move_lockfile()?;
 
                     Some(handle)
@@ -31,23 +48,184 @@ impl<'reporter, R: Reporter> Check for RustupToolchainCheck<'reporter, R> {
                     None
                 };
 
-                self.prepare(toolchain, config)?;
+                let unavailable_component = self.prepare(toolchain, config)?;
 
-                let path = current_dir_crate_path(config)?;
-                let outcome =
-                    self.run_check_command_via_rustup(toolchain, path, config.check_command())?;
+                let check_dir = self.check_dir(config)?;
+                let path = check_dir.as_deref();
+                let target_dir = if config.per_toolchain_target_dir() {
+                    let build_dir = build_dir(config, path)?;
+                    Some(per_toolchain_target_dir(&build_dir).join(toolchain.spec()))
+                } else {
+                    None
+                };
+
+                let started = Instant::now();
+
+                // Only a skip which is requested while this check runs, should abort it
+                skip::clear_skip_request();
+
+                let regenerate_failure = if unavailable_component.is_some() {
+                    unavailable_component
+                } else if config.minimal_versions() {
+                    self.resolve_minimal_versions(toolchain, path, cargo_lock)?;
+                    None
+                } else if config.regenerate_lockfile() {
+                    self.regenerate_lockfile(toolchain, path, cargo_lock, config)?
+                } else {
+                    None
+                };
+
+                let outcome = if let Some(failure) = regenerate_failure {
+                    failure
+                } else {
+                    let outcome =
+                        self.run_checks(toolchain, path, target_dir.as_deref(), config)?;
+
+                    self.with_lockfile_fallback(outcome, toolchain, target_dir.as_deref(), config)?
+                };
 
                 // report outcome to UI
-                self.report_outcome(&outcome, config.no_check_feedback())?;
+                self.report_outcome(&outcome, started.elapsed(), config.no_check_feedback())?;
 
                 // move the lockfile back
                 if let Some(handle) = handle_wrap {
                     handle.move_lockfile_back()?;
+
+                    if config.regenerate_lockfile() || config.minimal_versions() {
+                        self.reporter.report_event(TemporaryLockfile::restored(
+                            cargo_lock,
+                            toolchain.to_owned(),
+                        ))?;
+                    }
                 }
 
                 Ok(outcome)
             })
     }
+
+    fn check_many(&self, config: &Config, toolchains: &[ToolchainSpec]) -> TResult<Vec<Outcome>> {
+        if toolchains.len() <= 1 || !checks_concurrently(config) {
+            return toolchains
+                .iter()
+                .map(|toolchain| self.check(config, toolchain))
+                .collect();
+        }
+
+        let unavailable_components = toolchains
+            .iter()
+            .map(|toolchain| self.prepare(toolchain, config))
+            .collect::<TResult<Vec<_>>>()?;
+
+        let check_dir = self.check_dir(config)?;
+        let path = check_dir.as_deref();
+        let build_dir = build_dir(config, path)?;
+        let target_dir = if config.per_toolchain_target_dir() {
+            per_toolchain_target_dir(&build_dir)
+        } else {
+            jobs_target_dir(&build_dir)
+        };
+        let check = config.check_command();
+        let provider = self.provider(config)?;
+
+        // Each check gets its own target directory, so the checks don't block each other on the
+        // lock of the build directory
+        let handles = toolchains
+            .iter()
+            .zip(unavailable_components)
+            .map(|(toolchain, unavailable_component)| {
+                if let Some(outcome) = unavailable_component {
+                    return Ok(Err(outcome));
+                }
+
+                let mut cmd: Vec<&str> = vec![toolchain.spec()];
+                cmd.extend_from_slice(&check);
+
+                let command = self
+                    .provider_command(provider.as_ref(), toolchain, &check, config)?
+                    .with_optional_dir(path)
+                    .with_env("CARGO_TARGET_DIR", target_dir.join(toolchain.spec()))
+                    .with_stdout()
+                    .with_stderr()
+                    .with_process_group();
+                let env = command.envs();
+
+                let child = command
+                    .spawn_reported(OsStr::new("run"), self.reporter)
+                    .map_err(|_| CargoMSRVError::UnableToRunCheck)?;
+
+                // Wait on a separate thread, so a child which fills up its stderr pipe doesn't
+                // block the other children
+                let args = cmd.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+                let started = Instant::now();
+                let timeout = config.check_timeout();
+
+                Ok(Ok((
+                    args,
+                    env,
+                    std::thread::spawn(move || {
+                        let completion = child.wait_interruptible(timeout, false, |_| Ok(()));
+                        (completion, started.elapsed())
+                    }),
+                )))
+            })
+            .collect::<TResult<Vec<_>>>()?;
+
+        toolchains
+            .iter()
+            .zip(handles)
+            .map(|(toolchain, handle)| {
+                let (args, env, handle) = match handle {
+                    Ok(handle) => handle,
+                    // The check was not run, since a component is unavailable for the toolchain
+                    Err(outcome) => {
+                        return self.reporter.run_scoped_event(
+                            CheckToolchain::new(toolchain.to_owned()),
+                            || {
+                                self.report_outcome(
+                                    &outcome,
+                                    Duration::ZERO,
+                                    config.no_check_feedback(),
+                                )?;
+
+                                Ok(outcome)
+                            },
+                        )
+                    }
+                };
+
+                let (completion, duration) = handle
+                    .join()
+                    .map_err(|_| CargoMSRVError::UnableToRunCheck)?;
+                let completion = completion.map_err(|_| CargoMSRVError::UnableToRunCheck)?;
+
+                self.reporter
+                    .run_scoped_event(CheckToolchain::new(toolchain.to_owned()), || {
+                        self.reporter.report_event(CompatibilityCheckMethod::new(
+                            toolchain.to_owned(),
+                            Method::rustup_run(&args, path)
+                                .with_env(env)
+                                .with_default_args(config.default_check_args()),
+                        ))?;
+
+                        let outcome = self.outcome_from_completion(
+                            config,
+                            toolchain,
+                            completion,
+                            &args.join(" "),
+                        )?;
+                        let outcome = self.with_lockfile_fallback(
+                            outcome,
+                            toolchain,
+                            Some(&target_dir.join(toolchain.spec())),
+                            config,
+                        )?;
+                        self.report_outcome(&outcome, duration, config.no_check_feedback())?;
+
+                        Ok(outcome)
+                    })
+            })
+            .collect()
+    }
 }
 
 impl<'reporter, R: Reporter> RustupToolchainCheck<'reporter, R> {
@@ -55,100 +233,578 @@ impl<'reporter, R: Reporter> RustupToolchainCheck<'reporter, R> {
         Self {
             reporter,
             lockfile_path: OnceCell::new(),
+            bypasses_toolchain_file: OnceCell::new(),
+            sandboxes: RefCell::new(Vec::new()),
         }
     }
 
-    fn prepare(&self, toolchain: &ToolchainSpec, config: &Config) -> TResult<()> {
-        let downloader = ToolchainDownloader::new(self.reporter);
-        downloader.download(toolchain)?;
+    /// The provider of the toolchains, which is rustup, unless a toolchain directory or the
+    /// docker runner is given.
+    fn provider<'a>(&'a self, config: &'a Config) -> TResult<Box<dyn ToolchainProvider + 'a>> {
+        if let Some(dir) = config.toolchain_dir() {
+            return Ok(Box::new(DirectoryProvider::new(dir)));
+        }
+
+        match config.check_runner() {
+            // When offline, only installed toolchains are checked
+            CheckRunner::Rustup => Ok(Box::new(RustupProvider::new(
+                self.reporter,
+                config.offline(),
+                RetryPolicy::new(config.install_retries(), config.install_retry_backoff()),
+            ))),
+            CheckRunner::Docker => Ok(Box::new(DockerProvider::new(
+                self.reporter,
+                config.context().crate_root_path()?,
+            )?)),
+        }
+    }
+
+    /// The check commands which would be run for the toolchain, without installing the
+    /// toolchain, or running the commands.
+    ///
+    /// Commands which only prepare the check, like regenerating the lockfile, are not included.
+    pub(crate) fn planned_checks(
+        &self,
+        config: &Config,
+        toolchain: &ToolchainSpec,
+    ) -> TResult<Vec<PlannedCheck>> {
+        let path = current_dir_crate_path(config)?;
+        let check_target_dir = if config.per_toolchain_target_dir() {
+            Some(per_toolchain_target_dir(&target_dir(config)?).join(toolchain.spec()))
+        } else if config.search_method() == SearchMethod::Linear
+            && config.jobs() > 1
+            && checks_concurrently(config)
+        {
+            Some(jobs_target_dir(&target_dir(config)?).join(toolchain.spec()))
+        } else {
+            None
+        };
+
+        // Build artifacts of checks in a container are written to a volume instead
+        let target_dir = match config.check_runner() {
+            CheckRunner::Docker => None,
+            CheckRunner::Rustup => match &check_target_dir {
+                Some(dir) => Some(dir.clone()),
+                None => Some(target_dir(config)?),
+            },
+        };
+
+        let check = config.check_command();
+        let checks = if config.check_targets().is_empty() {
+            vec![check]
+        } else {
+            config
+                .check_targets()
+                .iter()
+                .map(|target| {
+                    let mut cmd = check.clone();
+                    cmd.extend_from_slice(&["--target", target]);
+                    cmd
+                })
+                .collect()
+        };
+
+        checks
+            .iter()
+            .map(|check| {
+                let command = self.check_command(
+                    toolchain,
+                    path,
+                    check_target_dir.as_deref(),
+                    check,
+                    config,
+                )?;
+
+                Ok(PlannedCheck::new(
+                    toolchain.to_owned(),
+                    command.command_line(OsStr::new("run")),
+                    command.dir().map(Path::to_path_buf),
+                    command.envs(),
+                    target_dir.clone(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Make the toolchain, and the configured targets and components, available for the check.
+    ///
+    /// Returns the outcome of a failed check if a component is not available for the toolchain,
+    /// since the check command can't succeed without it.
+    fn prepare(&self, toolchain: &ToolchainSpec, config: &Config) -> TResult<Option<Outcome>> {
+        let provider = self.provider(config)?;
+        provider.provide(toolchain, config.check_targets())?;
 
-        if config.ignore_lockfile() {
+        if stashes_lockfile(config) {
             self.remove_lockfile(config)?;
         }
 
-        Ok(())
+        match provider.provide_components(toolchain, config.toolchain_components()) {
+            Ok(()) => Ok(None),
+            Err(CargoMSRVError::ComponentUnavailable { component, .. }) => {
+                Ok(Some(Outcome::new_failure(
+                    toolchain.to_owned(),
+                    format!(
+                        "The '{}' component is not available for Rust {}",
+                        component,
+                        toolchain.version()
+                    ),
+                )))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Run the check command, or once for each target if check targets are configured.
+    fn run_checks(
+        &self,
+        toolchain: &ToolchainSpec,
+        dir: Option<&Path>,
+        target_dir: Option<&Path>,
+        config: &Config,
+    ) -> TResult<Outcome> {
+        if config.check_targets().is_empty() {
+            self.run_check_command_via_rustup(
+                toolchain,
+                dir,
+                target_dir,
+                &config.check_command(),
+                config,
+            )
+        } else {
+            self.run_check_command_for_targets(toolchain, dir, target_dir, config)
+        }
+    }
+
+    /// Retry a check which failed because the Cargo version of the toolchain doesn't understand
+    /// the lockfile, in a temporary copy of the crate with a lockfile generated by that Cargo
+    /// version. A check which then passes is marked as passed with a downgraded lockfile.
+    ///
+    /// Not applicable when the lockfile may not be updated, or is already replaced for the check.
+    fn with_lockfile_fallback(
+        &self,
+        outcome: Outcome,
+        toolchain: &ToolchainSpec,
+        check_target_dir: Option<&Path>,
+        config: &Config,
+    ) -> TResult<Outcome> {
+        let rejected = match &outcome {
+            Outcome::Failure(failure) => rejects_lockfile(&failure.error_message),
+            Outcome::Success(_) => false,
+        };
+
+        if !rejected || stashes_lockfile(config) || config.lockfile_mode().is_locked() {
+            return Ok(outcome);
+        }
+
+        let check_dir = self.check_dir(config)?;
+        let crate_root = match &check_dir {
+            Some(dir) => dir.as_path(),
+            None => config.context().crate_root_path()?,
+        };
+        let copy = match ProjectCopy::new(crate_root, toolchain.spec())? {
+            Some(copy) => copy,
+            None => return Ok(outcome),
+        };
+
+        info!(
+            toolchain = toolchain.spec(),
+            "lockfile rejected, retrying with a downgraded lockfile"
+        );
+
+        if self
+            .generate_lockfile(toolchain, Some(copy.crate_root()), config)?
+            .is_some()
+        {
+            return Ok(outcome);
+        }
+
+        self.reporter.report_event(TemporaryLockfile::downgraded(
+            copy.lockfile(),
+            toolchain.to_owned(),
+        ))?;
+
+        // Keep the build artifacts in the target directory of the crate, since the copy is
+        // removed after the check
+        let target_dir = match check_target_dir {
+            Some(dir) => dir.to_path_buf(),
+            None => build_dir(config, check_dir.as_deref())?,
+        };
+
+        let retried = self.run_checks(
+            toolchain,
+            Some(copy.crate_root()),
+            Some(&target_dir),
+            config,
+        )?;
+
+        if retried.is_success() {
+            Ok(retried.with_lockfile_downgrade())
+        } else {
+            Ok(retried)
+        }
     }
 
     fn run_check_command_via_rustup(
         &self,
         toolchain: &ToolchainSpec,
         dir: Option<&Path>,
+        target_dir: Option<&Path>,
         check: &[&str],
+        config: &Config,
     ) -> TResult<Outcome> {
         let mut cmd: Vec<&str> = vec![toolchain.spec()];
         cmd.extend_from_slice(check);
 
+        let command = self
+            .check_command(toolchain, dir, target_dir, check, config)?
+            .with_stdout()
+            .with_stderr()
+            .with_process_group();
+
         self.reporter.report_event(CompatibilityCheckMethod::new(
             toolchain.to_owned(),
-            Method::rustup_run(&cmd, dir),
+            Method::rustup_run(&cmd, dir)
+                .with_env(command.envs())
+                .with_default_args(config.default_check_args()),
         ))?;
 
+        // Only the TUI can request to skip a check
+        let skippable = config.tui();
+        let show_check_output = config.show_check_output();
+
+        let completion = command
+            .spawn_reported(OsStr::new("run"), self.reporter)
+            .and_then(|child| {
+                child.wait_interruptible(config.check_timeout(), skippable, |line| {
+                    if show_check_output {
+                        self.reporter
+                            .report_event(CheckOutput::new(toolchain.to_owned(), line))?;
+                    }
+                    Ok(())
+                })
+            })
+            .map_err(|_| CargoMSRVError::UnableToRunCheck)?;
+
+        self.outcome_from_completion(config, toolchain, completion, &cmd.join(" "))
+    }
+
+    /// Interpret how a check command ended as the outcome of the check.
+    ///
+    /// A check which was skipped or timed out is considered failed.
+    fn outcome_from_completion(
+        &self,
+        config: &Config,
+        toolchain: &ToolchainSpec,
+        completion: Completion,
+        command: &str,
+    ) -> TResult<Outcome> {
+        self.reporter.report_event(completion.finished().clone())?;
+
+        let message = match completion {
+            Completion::Finished(output) => {
+                return outcome_from_output(config, toolchain, &output, command)
+            }
+            Completion::Skipped(_) => "The check was skipped".to_string(),
+            Completion::TimedOut(_) => {
+                let timeout = config.check_timeout().unwrap_or_default().as_secs();
+                let skip = config.treat_timeout_as_skip();
+
+                info!(toolchain = toolchain.spec(), timeout, "check timed out");
+
+                self.reporter.report_event(CheckTimedOut::new(
+                    toolchain.to_owned(),
+                    timeout,
+                    skip,
+                ))?;
+
+                if !skip {
+                    return Ok(Outcome::new_failure(
+                        toolchain.to_owned(),
+                        format!("The check timed out after {} seconds", timeout),
+                    ));
+                }
+
+                format!(
+                    "The check was skipped after timing out after {} seconds",
+                    timeout
+                )
+            }
+        };
+
+        self.reporter
+            .report_event(CheckSkipped::new(toolchain.to_owned()))?;
+
+        Ok(Outcome::new_failure(toolchain.to_owned(), message))
+    }
+
+    /// The command which runs the check command with the toolchain.
+    fn check_command(
+        &self,
+        toolchain: &ToolchainSpec,
+        dir: Option<&Path>,
+        target_dir: Option<&Path>,
+        check: &[&str],
+        config: &Config,
+    ) -> TResult<RustupCommand> {
+        let provider = self.provider(config)?;
+        let mut command = self
+            .provider_command(provider.as_ref(), toolchain, check, config)?
+            .with_optional_dir(dir);
+
+        if let Some(target_dir) = target_dir {
+            command = command.with_env("CARGO_TARGET_DIR", target_dir);
+        }
+
+        Ok(command)
+    }
+
+    /// The command which runs the program with the toolchain and the configured environment
+    /// variables, bypassing the rust-toolchain file which applies to the crate, if any.
+    fn provider_command(
+        &self,
+        provider: &dyn ToolchainProvider,
+        toolchain: &ToolchainSpec,
+        program: &[&str],
+        config: &Config,
+    ) -> TResult<RustupCommand> {
+        let mut env = config.check_env().to_vec();
+
+        if self.bypasses_toolchain_file(config)? {
+            env.extend(provider.toolchain_env(toolchain)?);
+        }
+
+        provider.command_with_env(toolchain, program, &env)
+    }
+
+    /// Whether a rust-toolchain file applies to the crate, which would otherwise select the
+    /// pinned toolchain instead of the toolchain which is checked. This is reported once, when
+    /// the toolchain file is detected.
+    fn bypasses_toolchain_file(&self, config: &Config) -> TResult<bool> {
+        self.bypasses_toolchain_file
+            .get_or_try_init(|| {
+                let crate_root = config.context().crate_root_path()?;
+
+                match ToolchainFile::try_from_dir_or_parents(crate_root)? {
+                    Some(file) => {
+                        self.reporter.report_event(ToolchainFileBypassed::new(
+                            file.path(),
+                            file.channel().map(String::from),
+                        ))?;
+
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            })
+            .copied()
+    }
+
+    /// Generate a fresh lockfile with the Cargo version of the given toolchain.
+    ///
+    /// Returns the outcome of a failed check if the lockfile could not be generated, since the
+    /// check command can't succeed without a lockfile which the toolchain understands.
+    fn regenerate_lockfile(
+        &self,
+        toolchain: &ToolchainSpec,
+        dir: Option<&Path>,
+        lockfile: &Path,
+        config: &Config,
+    ) -> TResult<Option<Outcome>> {
+        if let Some(failure) = self.generate_lockfile(toolchain, dir, config)? {
+            return Ok(Some(failure));
+        }
+
+        self.reporter.report_event(TemporaryLockfile::regenerated(
+            lockfile,
+            toolchain.to_owned(),
+        ))?;
+
+        Ok(None)
+    }
+
+    /// Run `cargo generate-lockfile` with the Cargo version of the given toolchain.
+    ///
+    /// Returns the outcome of a failed check if the lockfile could not be generated.
+    fn generate_lockfile(
+        &self,
+        toolchain: &ToolchainSpec,
+        dir: Option<&Path>,
+        config: &Config,
+    ) -> TResult<Option<Outcome>> {
+        let cmd = [toolchain.spec(), "cargo", "generate-lockfile"];
+
+        let rustup_output = self
+            .provider(config)?
+            .command(toolchain, &cmd[1..])?
+            .with_optional_dir(dir)
+            .with_stdout()
+            .with_stderr()
+            .run_reported(self.reporter)
+            .map_err(|_| CargoMSRVError::UnableToRunCheck)?;
+
+        if !rustup_output.exit_status().success() {
+            return outcome_from_output(config, toolchain, &rustup_output, &cmd.join(" "))
+                .map(Some);
+        }
+
+        Ok(None)
+    }
+
+    /// Generate a lockfile in which the dependencies are resolved to the minimal versions allowed
+    /// by their version requirements.
+    ///
+    /// The `-Z minimal-versions` flag is unstable, so the lockfile is generated by the nightly
+    /// toolchain, instead of the toolchain which is checked.
+    fn resolve_minimal_versions(
+        &self,
+        toolchain: &ToolchainSpec,
+        dir: Option<&Path>,
+        lockfile: &Path,
+    ) -> TResult<()> {
+        let cmd = [
+            "nightly",
+            "cargo",
+            "generate-lockfile",
+            "-Z",
+            "minimal-versions",
+        ];
+
         let rustup_output = RustupCommand::new()
             .with_args(cmd.iter())
             .with_optional_dir(dir)
             .with_stderr()
-            .run()
-            .map_err(|_| CargoMSRVError::UnableToRunCheck)?;
+            .run_reported(self.reporter)
+            .map_err(|_| CargoMSRVError::UnableToResolveMinimalVersions(String::new()))?;
+
+        if !rustup_output.exit_status().success() {
+            return Err(CargoMSRVError::UnableToResolveMinimalVersions(
+                rustup_output.stderr().to_string(),
+            ));
+        }
 
-        let status = rustup_output.exit_status();
+        self.reporter
+            .report_event(TemporaryLockfile::minimal_versions(
+                lockfile,
+                toolchain.to_owned(),
+            ))?;
 
-        if status.success() {
-            Ok(Outcome::new_success(toolchain.to_owned()))
-        } else {
-            let stderr = rustup_output.stderr();
-            let command = cmd.join(" ");
+        Ok(())
+    }
 
+    /// Run the check command once for each of the configured compilation targets, by passing
+    /// `--target <target>` to the check command.
+    ///
+    /// The toolchain is only compatible if the crate can be build for all targets; the outcome
+    /// of the first target which fails is returned.
+    fn run_check_command_for_targets(
+        &self,
+        toolchain: &ToolchainSpec,
+        dir: Option<&Path>,
+        target_dir: Option<&Path>,
+        config: &Config,
+    ) -> TResult<Outcome> {
+        let check = config.check_command();
+
+        for target in config.check_targets() {
             info!(
-                ?toolchain,
-                stderr,
-                cmd = command.as_str(),
-                "try_building run failed"
+                toolchain = toolchain.spec(),
+                compilation_target = target,
+                "checking target"
             );
 
-            Ok(Outcome::new_failure(
-                toolchain.to_owned(),
-                stderr.to_string(),
-            ))
+            let mut cmd = check.clone();
+            cmd.extend_from_slice(&["--target", target]);
+
+            let outcome =
+                self.run_check_command_via_rustup(toolchain, dir, target_dir, &cmd, config)?;
+
+            if !outcome.is_success() {
+                return Ok(outcome);
+            }
         }
+
+        Ok(Outcome::new_success(toolchain.to_owned()))
     }
 
-    fn report_outcome(&self, outcome: &Outcome, no_error_report: bool) -> TResult<()> {
-        match outcome {
+    fn report_outcome(
+        &self,
+        outcome: &Outcome,
+        duration: Duration,
+        no_error_report: bool,
+    ) -> TResult<()> {
+        let compatibility = match outcome {
+            // report compatibility with this toolchain
             Outcome::Success(outcome) => {
-                // report compatibility with this toolchain
-                self.reporter
-                    .report_event(Compatibility::compatible(outcome.toolchain_spec.to_owned()))?
+                Compatibility::compatible(outcome.toolchain_spec.to_owned())
+                    .with_lockfile_downgrade(outcome.lockfile_downgraded())
             }
+            // report incompatibility with this toolchain
             Outcome::Failure(outcome) if no_error_report => {
-                // report incompatibility with this toolchain
-                self.reporter.report_event(Compatibility::incompatible(
-                    outcome.toolchain_spec.to_owned(),
-                    None,
-                ))?
-            }
-            Outcome::Failure(outcome) => {
-                // report incompatibility with this toolchain
-                self.reporter.report_event(Compatibility::incompatible(
-                    outcome.toolchain_spec.to_owned(),
-                    Some(outcome.error_message.clone()),
-                ))?
+                Compatibility::incompatible(outcome.toolchain_spec.to_owned(), None)
+                    .with_output_log(outcome.output_log.clone())
+                    .with_unsupported_flag(outcome.unsupported_flag.clone())
             }
+            Outcome::Failure(outcome) => Compatibility::incompatible(
+                outcome.toolchain_spec.to_owned(),
+                Some(outcome.error_message.clone()),
+            )
+            .with_output_log(outcome.output_log.clone())
+            .with_unsupported_flag(outcome.unsupported_flag.clone()),
         };
 
+        self.reporter
+            .report_event(compatibility.with_duration(duration))?;
+
         Ok(())
     }
 
     fn lockfile_path(&self, config: &Config) -> TResult<&Path> {
-        let path = self.lockfile_path.get_or_try_init(|| {
-            config
-                .context()
-                .crate_root_path()
-                .map(|path| path.join(CARGO_LOCK))
-        })?;
+        let path = self
+            .lockfile_path
+            .get_or_try_init(|| match self.check_dir(config)? {
+                Some(dir) => Ok(dir.join(CARGO_LOCK)),
+                None => config
+                    .context()
+                    .crate_root_path()
+                    .map(|path| path.join(CARGO_LOCK)),
+            })?;
 
         Ok(path)
     }
 
+    /// The directory from which the check commands are run: the copy of the crate root within
+    /// the sandbox when sandboxed, or the crate root if it was given by the user.
+    ///
+    /// The workspace of the crate is copied into a sandbox by the first check which needs it. The
+    /// docker runner isn't sandboxed, since it mounts the crate read-only.
+    fn check_dir(&self, config: &Config) -> TResult<Option<PathBuf>> {
+        if !sandboxed(config) {
+            return Ok(current_dir_crate_path(config)?.map(Path::to_path_buf));
+        }
+
+        let crate_root = config.context().crate_root_path()?;
+        let mut sandboxes = self.sandboxes.borrow_mut();
+
+        if let Some(dir) = sandboxes
+            .iter()
+            .find_map(|sandbox| sandbox.path_of(crate_root))
+        {
+            return Ok(Some(dir));
+        }
+
+        let sandbox = Sandbox::new(config.context().manifest_path()?)?;
+        let dir = sandbox.path_of(crate_root).ok_or_else(|| {
+            CargoMSRVError::GenericMessage(format!(
+                "Unable to find the crate root '{}' within its sandbox",
+                crate_root.display()
+            ))
+        })?;
+
+        sandboxes.push(sandbox);
+
+        Ok(Some(dir))
+    }
+
     fn remove_lockfile(&self, config: &Config) -> TResult<()> {
         let lock_file = self.lockfile_path(config)?;
 
@@ -163,6 +819,152 @@ impl<'reporter, R: Reporter> RustupToolchainCheck<'reporter, R> {
     }
 }
 
+/// Whether the lockfile is moved out of the way during each check, and restored afterwards.
+fn stashes_lockfile(config: &Config) -> bool {
+    config.ignore_lockfile() || config.regenerate_lockfile() || config.minimal_versions()
+}
+
+/// Whether the checks are run from a copy of the workspace.
+fn sandboxed(config: &Config) -> bool {
+    config.sandbox() && matches!(config.check_runner(), CheckRunner::Rustup)
+}
+
+/// Whether the checks of multiple toolchains may be run concurrently.
+///
+/// Concurrent checks can't share a lockfile which is temporarily moved out of the way, checks for
+/// multiple targets are run one target at a time, the TUI can only skip the check which is
+/// currently running, and the streamed output of concurrent checks would be interleaved.
+fn checks_concurrently(config: &Config) -> bool {
+    !stashes_lockfile(config)
+        && config.check_targets().is_empty()
+        && !config.tui()
+        && !config.show_check_output()
+}
+
+/// Interpret the output of a `rustup run` check command as the outcome of a check.
+///
+/// The full output of a failed check is written to a log file, so it can be inspected
+/// after the run. When the check failed because the lockfile would have to be updated, while
+/// `--locked` or `--frozen` was given, the run fails instead, since each following check would
+/// fail for the same reason.
+fn outcome_from_output(
+    config: &Config,
+    toolchain: &ToolchainSpec,
+    rustup_output: &RustupOutput,
+    command: &str,
+) -> TResult<Outcome> {
+    if rustup_output.exit_status().success() {
+        Ok(Outcome::new_success(toolchain.to_owned()))
+    } else {
+        let stderr = rustup_output.stderr();
+
+        info!(?toolchain, stderr, cmd = command, "try_building run failed");
+
+        let mode = config.lockfile_mode();
+
+        if mode.is_locked() && requires_lockfile_update(stderr) {
+            return Err(CargoMSRVError::LockfileOutdated {
+                toolchain: toolchain.version().to_string(),
+                mode,
+                stderr: stderr.to_string(),
+            });
+        }
+
+        let log = write_output_log(config, toolchain, rustup_output, command)?;
+
+        // Old Cargo versions reject flags which were added later, without checking the crate
+        let flag = unsupported_flag(stderr);
+
+        if let Some(flag) = &flag {
+            info!(
+                toolchain = toolchain.spec(),
+                flag = flag.as_str(),
+                "check command is not supported by the toolchain"
+            );
+        }
+
+        Ok(
+            Outcome::new_failure(toolchain.to_owned(), stderr.to_string())
+                .with_output_log(log)
+                .with_unsupported_flag(flag),
+        )
+    }
+}
+
+/// Whether Cargo refused to update the lockfile, because `--locked` or `--frozen` was given.
+fn requires_lockfile_update(stderr: &str) -> bool {
+    stderr.lines().any(|line| {
+        line.contains("needs to be updated but --locked was passed")
+            || line.contains("needs to be updated but --frozen was passed")
+    })
+}
+
+/// Write the command, exit status, stdout and stderr of a check to
+/// `target/cargo-msrv/logs/<toolchain>.log`, and return the path of the log file.
+fn write_output_log(
+    config: &Config,
+    toolchain: &ToolchainSpec,
+    rustup_output: &RustupOutput,
+    command: &str,
+) -> TResult<PathBuf> {
+    let dir = logs_dir(config)?;
+    let path = dir.join(format!("{}.log", toolchain.spec()));
+
+    std::fs::create_dir_all(&dir).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(path.clone()),
+    })?;
+
+    let contents = format!(
+        "command: {}\nexit status: {}\n\n--- stdout ---\n{}\n--- stderr ---\n{}\n",
+        command,
+        rustup_output.exit_status(),
+        rustup_output.stdout(),
+        rustup_output.stderr(),
+    );
+
+    std::fs::write(&path, contents).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(path.clone()),
+    })?;
+
+    Ok(path)
+}
+
+/// The directory to which the output of failed checks is written.
+fn logs_dir(config: &Config) -> TResult<PathBuf> {
+    Ok(target_dir(config)?.join("cargo-msrv").join("logs"))
+}
+
+/// The directory in which each concurrently run check gets its own target directory.
+fn jobs_target_dir(target_dir: &Path) -> PathBuf {
+    target_dir.join("cargo-msrv").join("jobs")
+}
+
+/// The directory in which each toolchain gets its own target directory, which is kept across runs,
+/// so the build artifacts of a toolchain aren't invalidated by checks of other toolchains.
+fn per_toolchain_target_dir(target_dir: &Path) -> PathBuf {
+    target_dir.join("msrv")
+}
+
+/// The target directory to which the check commands write their build artifacts. When sandboxed,
+/// it's the target directory within the sandbox, unless `CARGO_TARGET_DIR` is set.
+fn build_dir(config: &Config, check_dir: Option<&Path>) -> TResult<PathBuf> {
+    match check_dir {
+        Some(dir) if sandboxed(config) && std::env::var_os("CARGO_TARGET_DIR").is_none() => {
+            Ok(dir.join("target"))
+        }
+        _ => target_dir(config),
+    }
+}
+
+fn target_dir(config: &Config) -> TResult<PathBuf> {
+    match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => Ok(PathBuf::from(dir)),
+        None => Ok(config.context().crate_root_path()?.join("target")),
+    }
+}
+
 /// If we manually specify the path to a crate (e.g. with --manifest-path or --path),
 /// we must supply the custom directory to our Command runner.
 fn current_dir_crate_path<'c>(config: &'c Config<'c>) -> TResult<Option<&'c Path>> {
@@ -207,3 +1009,128 @@ mod current_dir_crate_path_tests {
         assert!(res.is_none())
     }
 }
+
+#[cfg(test)]
+mod requires_lockfile_update_tests {
+    use super::requires_lockfile_update;
+
+    #[yare::parameterized(
+        locked = { "error: the lock file /a/Cargo.lock needs to be updated but --locked was passed to prevent this", true },
+        frozen = { "error: the lock file /a/Cargo.lock needs to be updated but --frozen was passed to prevent this", true },
+        compile_error = { "error[E0658]: use of unstable library feature 'let_else'", false },
+    )]
+    fn detects_refused_update(stderr: &str, expected: bool) {
+        assert_eq!(requires_lockfile_update(stderr), expected);
+    }
+}
+
+#[cfg(test)]
+mod planned_checks_tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use crate::reporter::{FakeTestReporter, TestReporter};
+    use crate::{semver, Action, Event};
+    use test_dir::{DirBuilder, TestDir};
+
+    #[test]
+    fn rustup_run_check_command() {
+        let config = ConfigBuilder::new(Action::Find, "x86_64-unknown-linux-gnu").build();
+        let reporter = FakeTestReporter::default();
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let checks = RustupToolchainCheck::new(&reporter)
+            .planned_checks(&config, &toolchain)
+            .unwrap();
+
+        assert_eq!(checks.len(), 1);
+        assert_eq!(
+            checks[0].command(),
+            &[
+                "rustup",
+                "run",
+                "1.60.0-x86_64-unknown-linux-gnu",
+                "cargo",
+                "check"
+            ]
+        );
+        assert!(checks[0].directory().is_none());
+        assert!(checks[0].env().is_empty());
+    }
+
+    #[test]
+    fn per_toolchain_target_dir_is_set() {
+        let config = ConfigBuilder::new(Action::Find, "x86_64-unknown-linux-gnu")
+            .per_toolchain_target_dir(true)
+            .build();
+        let reporter = FakeTestReporter::default();
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let checks = RustupToolchainCheck::new(&reporter)
+            .planned_checks(&config, &toolchain)
+            .unwrap();
+
+        let target_dir = checks[0].target_dir().unwrap();
+
+        assert!(target_dir.ends_with("msrv/1.60.0-x86_64-unknown-linux-gnu"));
+        assert_eq!(
+            checks[0].env().get("CARGO_TARGET_DIR").map(PathBuf::from),
+            Some(target_dir.to_path_buf())
+        );
+    }
+
+    #[test]
+    fn check_env_is_set() {
+        let config = ConfigBuilder::new(Action::Find, "x86_64-unknown-linux-gnu")
+            .check_env(vec![
+                ("RUSTFLAGS".to_string(), "--cfg ci -D warnings".to_string()),
+                ("CI".to_string(), "true".to_string()),
+            ])
+            .build();
+        let reporter = FakeTestReporter::default();
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let checks = RustupToolchainCheck::new(&reporter)
+            .planned_checks(&config, &toolchain)
+            .unwrap();
+
+        let env = checks[0].env();
+        assert_eq!(
+            env.get("RUSTFLAGS").map(String::as_str),
+            Some("--cfg ci -D warnings")
+        );
+        assert_eq!(env.get("CI").map(String::as_str), Some("true"));
+    }
+
+    #[test]
+    fn toolchain_file_is_bypassed() {
+        let tmp = TestDir::temp();
+        std::fs::write(tmp.path("rust-toolchain"), "1.70.0\n").unwrap();
+
+        let config = ConfigBuilder::new(Action::Find, "x86_64-unknown-linux-gnu")
+            .crate_path(Some(tmp.root()))
+            .build();
+        let reporter = TestReporter::default();
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let checks = RustupToolchainCheck::new(reporter.reporter())
+            .planned_checks(&config, &toolchain)
+            .unwrap();
+
+        assert_eq!(
+            checks[0].env().get("RUSTUP_TOOLCHAIN").map(String::as_str),
+            Some("1.60.0-x86_64-unknown-linux-gnu")
+        );
+
+        let expected: Vec<Event> = vec![ToolchainFileBypassed::new(
+            tmp.root().canonicalize().unwrap().join("rust-toolchain"),
+            Some("1.70.0".to_string()),
+        )
+        .into()];
+
+        phenomenon::contains_at_least_ordered(reporter.wait_for_events(), expected).assert_this();
+    }
+}
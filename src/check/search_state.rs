@@ -0,0 +1,143 @@
+use crate::error::{CargoMSRVError, IoErrorSource};
+use crate::toolchain::ToolchainSpec;
+use crate::{semver, TResult};
+use std::fs;
+use std::path::Path;
+
+/// The state of a search, which is persisted after every check, so a search which was
+/// interrupted can be resumed with `--resume`, without checking the decided versions again.
+///
+/// Each result is identified by the key of its check, so results are only reused when neither
+/// the toolchain, the check command, nor the contents of the crate changed.
+#[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) struct SearchState {
+    search_space: Vec<semver::Version>,
+    results: Vec<CheckResult>,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct CheckResult {
+    version: semver::Version,
+    toolchain: String,
+    key: String,
+    compatible: bool,
+}
+
+impl SearchState {
+    /// Read the persisted state. A state which doesn't exist, or can't be parsed, is empty.
+    pub(crate) fn load(path: &Path) -> TResult<Self> {
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.to_path_buf()),
+        })?;
+
+        Ok(serde_json::from_str(&contents).unwrap_or_else(|error| {
+            warn!(%error, path = %path.display(), "ignoring invalid search state");
+            Self::default()
+        }))
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> TResult<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::WriteFile(dir.to_path_buf()),
+            })?;
+        }
+
+        let contents =
+            serde_json::to_string_pretty(self).expect("Unable to serialize the search state");
+
+        fs::write(path, contents).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::WriteFile(path.to_path_buf()),
+        })
+    }
+
+    pub(crate) fn set_search_space(&mut self, search_space: &[semver::Version]) {
+        self.search_space = search_space.to_vec();
+    }
+
+    /// Record the outcome of a check, replacing an earlier result of the same check.
+    pub(crate) fn record(&mut self, toolchain: &ToolchainSpec, key: u64, compatible: bool) {
+        let key = format_key(key);
+        self.results.retain(|result| result.key != key);
+
+        self.results.push(CheckResult {
+            version: toolchain.version().clone(),
+            toolchain: toolchain.spec().to_string(),
+            key,
+            compatible,
+        });
+    }
+
+    /// Whether the check with the given key was found to be compatible, if it was decided.
+    pub(crate) fn decided(&self, key: u64) -> Option<bool> {
+        let key = format_key(key);
+
+        self.results
+            .iter()
+            .find(|result| result.key == key)
+            .map(|result| result.compatible)
+    }
+}
+
+fn format_key(key: u64) -> String {
+    format!("{:016x}", key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, TestDir};
+
+    #[test]
+    fn saved_state_is_loaded() {
+        let tmp = TestDir::temp();
+        let path = tmp.path("cargo-msrv/state.json");
+
+        let first = semver::Version::new(1, 56, 0);
+        let second = semver::Version::new(1, 57, 0);
+
+        let mut state = SearchState::default();
+        state.set_search_space(&[first.clone(), second.clone()]);
+        state.record(&ToolchainSpec::new(&first, "x"), 1, false);
+        state.record(&ToolchainSpec::new(&second, "x"), 2, true);
+        state.save(&path).unwrap();
+
+        let loaded = SearchState::load(&path).unwrap();
+
+        assert_eq!(loaded, state);
+        assert_eq!(loaded.decided(1), Some(false));
+        assert_eq!(loaded.decided(2), Some(true));
+        assert_eq!(loaded.decided(3), None);
+    }
+
+    #[test]
+    fn missing_state_is_empty() {
+        let tmp = TestDir::temp();
+
+        let state = SearchState::load(&tmp.path("state.json")).unwrap();
+
+        assert_eq!(state, SearchState::default());
+    }
+
+    #[test]
+    fn recorded_result_replaces_earlier_result() {
+        let version = semver::Version::new(1, 56, 0);
+        let toolchain = ToolchainSpec::new(&version, "x");
+
+        let mut state = SearchState::default();
+        state.record(&toolchain, 1, false);
+        state.record(&toolchain, 1, true);
+
+        assert_eq!(state.decided(1), Some(true));
+        assert_eq!(state.results.len(), 1);
+    }
+}
@@ -0,0 +1,35 @@
+//! Allows a running toolchain check to be skipped, for example by the user from the TUI.
+//!
+//! Skip requests are process wide: a request is taken by the first check which polls for it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SKIP_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Request that the toolchain check which is currently running is aborted, and marked as skipped.
+pub fn request_skip() {
+    SKIP_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Returns `true` if a skip was requested, and resets the request.
+pub(crate) fn take_skip_request() -> bool {
+    SKIP_REQUESTED.swap(false, Ordering::SeqCst)
+}
+
+/// Discard an outstanding skip request, which was made when no check was running.
+pub(crate) fn clear_skip_request() {
+    SKIP_REQUESTED.store(false, Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_request_is_taken_once() {
+        request_skip();
+
+        assert!(take_skip_request());
+        assert!(!take_skip_request());
+    }
+}
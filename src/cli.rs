@@ -1,24 +1,31 @@
 use crate::cli::configurators::Configure;
 use crate::cli::custom_check_opts::CustomCheckOpts;
+use crate::cli::feature_opts::FeatureOpts;
 use crate::cli::find_opts::FindOpts;
 use crate::cli::rust_releases_opts::RustReleasesOpts;
 use crate::cli::shared_opts::SharedOpts;
+use crate::cli::target_selection_opts::TargetSelectionOpts;
 use crate::cli::toolchain_opts::ToolchainOpts;
-use crate::config::list::ListMsrvVariant;
+use crate::cli::workspace_opts::WorkspaceOpts;
+use crate::config::list::{DependencyKind, ListMsrvVariant, ListSort};
+use crate::config::verify::FailOn;
 use crate::config::ConfigBuilder;
 use crate::default_target::default_target;
 use crate::manifest::bare_version::BareVersion;
-use crate::{Action, CargoMSRVError, Config};
+use crate::{semver, Action, CargoMSRVError, Config};
 use clap::{AppSettings, Args, Parser, Subcommand};
 use std::convert::{TryFrom, TryInto};
 use std::ffi::{OsStr, OsString};
 
 pub(in crate::cli) mod configurators;
 pub(crate) mod custom_check_opts;
+pub(crate) mod feature_opts;
 pub(crate) mod find_opts;
 pub(crate) mod rust_releases_opts;
 pub(crate) mod shared_opts;
+pub(crate) mod target_selection_opts;
 pub(crate) mod toolchain_opts;
+pub(crate) mod workspace_opts;
 
 #[derive(Debug, Parser)]
 #[clap(version, bin_name = "cargo", max_term_width = 120)]
@@ -111,10 +118,29 @@ pub(in crate::cli) enum SubCommand {
     /// Set the MSRV of the current crate to a given Rust version
     Set(SetOpts),
     /// Show the MSRV of your crate, as specified in the Cargo manifest
-    Show,
+    Show(ShowOpts),
     /// Verify whether the MSRV is satisfiable. The MSRV must be specified using the
     /// 'package.rust-version' or 'package.metadata.msrv' key in the Cargo.toml manifest.
     Verify(VerifyOpts),
+    /// Diagnose the environment cargo-msrv runs in, such as the availability of rustup and the
+    /// validity of the Cargo manifest
+    Doctor,
+    /// Suggest the newest versions of the dependencies which are compatible with the MSRV
+    Upgrade(UpgradeOpts),
+    /// Downgrade the dependencies in the lockfile which are not compatible with the MSRV
+    PinLockfile(PinLockfileOpts),
+    /// Compare the MSRV of the crate at two git revisions, and fail if it increased
+    Diff(DiffOpts),
+    /// Watch the Cargo manifest, lockfile and sources of the crate, and verify the MSRV again
+    /// whenever they change. Takes the same options as 'verify'.
+    Watch(VerifyOpts),
+    /// Check whether the MSRV complies with the MSRV policy, relative to the current stable Rust
+    /// release
+    Policy(PolicyOpts),
+    /// Print a JSON Schema which describes the events reported by the json and json-lines
+    /// output formats
+    #[clap(name = "__schema", hide = true)]
+    Schema,
 }
 
 #[derive(Debug, Args)]
@@ -123,6 +149,68 @@ pub(in crate::cli) struct ListOpts {
     /// Display the MSRV's of crates that your crate depends on
     #[clap(long, possible_values = ListMsrvVariant::variants(), default_value_t)]
     variant: ListMsrvVariant,
+
+    /// Only display the MSRV's of the crates which your crate depends on directly
+    ///
+    /// Equivalent to `--depth 1`.
+    #[clap(long, conflicts_with = "depth")]
+    direct_deps: bool,
+
+    /// Only display the MSRV's of dependencies up to the given depth in the dependency graph
+    ///
+    /// A depth of 1 includes only the direct dependencies of your crate, a depth of 2 also
+    /// includes the dependencies of your direct dependencies, and so on.
+    #[clap(long, value_name = "N")]
+    depth: Option<usize>,
+
+    /// Only display the dependencies whose MSRV is greater than the given version
+    ///
+    /// Dependencies which do not specify an MSRV are not displayed either. Useful to find the
+    /// dependencies which force the MSRV of your crate to be bumped.
+    #[clap(long, value_name = "VERSION")]
+    min_msrv: Option<BareVersion>,
+
+    /// The order in which the dependencies are displayed
+    ///
+    /// `msrv` orders from the greatest to the lowest MSRV, `name` orders alphabetically, and
+    /// `depth` orders by the distance from your crate in the dependency graph. The
+    /// `ordered-by-msrv` variant always groups the dependencies by MSRV; the order then applies
+    /// to the dependencies within each group.
+    #[clap(long, possible_values = ListSort::variants(), value_name = "ORDER")]
+    sort: Option<ListSort>,
+
+    /// Disregard the MSRV of the dependency with the given name
+    ///
+    /// Useful for dependencies which declare an overly conservative or wrong MSRV. May be given
+    /// multiple times. Overrides the `ignore-dependencies` key of the configuration file.
+    #[clap(
+        long = "ignore-dependency",
+        value_name = "NAME",
+        multiple_occurrences = true
+    )]
+    ignore_dependencies: Vec<String>,
+
+    /// Find the MSRV of each direct dependency by compiling it, instead of only reading the
+    /// MSRV it declares
+    ///
+    /// Each dependency from a registry or git repository is copied to a temporary directory, and
+    /// its MSRV is searched for in the same way as the MSRV of your crate. Ignored dependencies
+    /// are not compiled.
+    #[clap(long)]
+    verify_deps: bool,
+
+    /// The kinds of dependencies to list, separated by commas
+    ///
+    /// Dependencies of the given kinds are listed, together with their own dependencies.
+    /// Dev-dependencies are left out by default, since they're not needed to build your crate.
+    #[clap(
+        long,
+        possible_values = DependencyKind::variants(),
+        use_value_delimiter = true,
+        default_values = &["normal", "build"],
+        value_name = "KINDS"
+    )]
+    dep_kind: Vec<DependencyKind>,
 }
 
 #[derive(Debug, Args)]
@@ -136,6 +224,93 @@ pub(in crate::cli) struct SetOpts {
     /// `package.rust-version` in the Cargo manifest.
     #[clap(value_name = "MSRV")]
     msrv: BareVersion,
+
+    /// Also write the MSRV to the Clippy configuration file
+    ///
+    /// Sets the `msrv` key in `clippy.toml` (or `.clippy.toml` if it exists), so Clippy's
+    /// MSRV aware lints stay in sync with the MSRV in the Cargo manifest.
+    #[clap(long)]
+    sync_clippy: bool,
+
+    /// Set the MSRV for the workspace, and let each member inherit it
+    ///
+    /// Writes the MSRV to `workspace.package.rust-version` in the Cargo manifest of the
+    /// workspace root, and switches each member of the workspace over to
+    /// `rust-version.workspace = true`.
+    #[clap(long)]
+    propagate: bool,
+}
+
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "SHOW OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub(in crate::cli) struct ShowOpts {
+    /// Show the least recent Rust version which supports the edition of the crate instead
+    ///
+    /// The MSRV of a crate can't be older than the first Rust version which supports its
+    /// edition. For example, the 2021 edition requires at least Rust 1.56.
+    #[clap(long)]
+    edition: bool,
+}
+
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "PIN-LOCKFILE OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub(in crate::cli) struct PinLockfileOpts {
+    /// The Rust version which the locked dependency versions must support
+    ///
+    /// Defaults to the MSRV specified in the Cargo manifest.
+    #[clap(long, value_name = "MSRV")]
+    msrv: Option<BareVersion>,
+}
+
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "POLICY OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub(in crate::cli) struct PolicyOpts {
+    /// The MSRV to check, for example an MSRV found by `cargo msrv`
+    ///
+    /// Defaults to the MSRV specified in the Cargo manifest.
+    #[clap(long, value_name = "MSRV")]
+    msrv: Option<BareVersion>,
+
+    /// Require the MSRV to be at least this many minor releases behind the current stable release
+    ///
+    /// Together with `--minimum`, overrides the `policy` table of the configuration file.
+    #[clap(long, value_name = "RELEASES")]
+    sliding_window: Option<u64>,
+
+    /// Require the MSRV to be no newer than the given Rust version
+    ///
+    /// Together with `--sliding-window`, overrides the `policy` table of the configuration file.
+    #[clap(long, value_name = "VERSION")]
+    minimum: Option<BareVersion>,
+}
+
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "DIFF OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub(in crate::cli) struct DiffOpts {
+    /// The git revision to compare against, for example the target branch of a pull request
+    #[clap(value_name = "BASE")]
+    base: String,
+
+    /// The git revision to compare, defaults to the working tree
+    ///
+    /// When given, the revision is checked out in a temporary git worktree, like the base
+    /// revision. Otherwise, the crate is checked as is, including uncommitted changes.
+    #[clap(value_name = "HEAD")]
+    head: Option<String>,
+}
+
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "UPGRADE OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub(in crate::cli) struct UpgradeOpts {
+    /// The Rust version which the suggested dependency versions must support
+    ///
+    /// Defaults to the MSRV specified in the Cargo manifest.
+    #[clap(long, value_name = "MSRV")]
+    msrv: Option<BareVersion>,
+
+    /// Update the version requirements of the dependencies in the Cargo manifest
+    #[clap(long)]
+    write: bool,
 }
 
 #[derive(Debug, Args)]
@@ -150,14 +325,94 @@ pub(in crate::cli) struct VerifyOpts {
     #[clap(flatten)]
     pub(in crate::cli) toolchain_opts: ToolchainOpts,
 
+    #[clap(flatten)]
+    pub(in crate::cli) feature_opts: FeatureOpts,
+
+    #[clap(flatten)]
+    pub(in crate::cli) target_selection_opts: TargetSelectionOpts,
+
     #[clap(flatten)]
     pub(in crate::cli) custom_check: CustomCheckOpts,
 
     /// The Rust version, to check against for toolchain compatibility
     ///
-    /// If not set, the MSRV will be parsed from the Cargo manifest instead.
-    #[clap(long, value_name = "rust-version")]
+    /// If not set, the MSRV will be parsed from the Cargo manifest instead. Use this to check
+    /// whether the crate is compatible with an arbitrary Rust version, without changing the
+    /// Cargo manifest.
+    #[clap(long, visible_alias = "against", value_name = "rust-version")]
     rust_version: Option<BareVersion>,
+
+    /// Verify each member of the workspace against its own MSRV
+    ///
+    /// Members which do not specify an MSRV in their Cargo manifest are skipped. Verification
+    /// fails if any of the members is not compatible with its MSRV. If `--rust-version` is given,
+    /// each member will be verified against this version instead.
+    #[clap(long)]
+    pub(in crate::cli) workspace: bool,
+
+    #[clap(flatten)]
+    pub(in crate::cli) workspace_opts: WorkspaceOpts,
+
+    /// When verification fails
+    ///
+    /// With `lower`, verification fails if the crate is not compatible with its MSRV, i.e. when
+    /// the declared MSRV is too low. With `higher`, verification fails if the crate is also
+    /// compatible with the Rust release preceding its MSRV, i.e. when the declared MSRV is more
+    /// conservative than necessary; this requires checking the preceding release as well. `both`
+    /// fails in either case, and `never` only reports the result.
+    #[clap(
+        long,
+        possible_values = FailOn::variants(),
+        default_value_t,
+        value_name = "POLICY"
+    )]
+    fail_on: FailOn,
+
+    /// Once verification succeeds, search for an older Rust version which the crate is also
+    /// compatible with
+    ///
+    /// If one is found, it is reported that the MSRV could be lowered. This is advisory only:
+    /// verification doesn't fail because of it. Not supported with `--workspace`.
+    #[clap(long, conflicts_with = "workspace")]
+    find_lower: bool,
+
+    /// Verify the given version of the crate, as published to crates.io, instead of the local
+    /// sources
+    ///
+    /// The `.crate` archive is downloaded from crates.io, checked against the checksum listed in
+    /// the index, and unpacked into a temporary directory, which is removed afterwards. The
+    /// published crate is verified against the MSRV declared in its own Cargo manifest, which
+    /// catches releases whose sources differ from the local sources. Not supported with
+    /// `--offline`, `--workspace` or `--find-lower`.
+    #[clap(
+        long,
+        value_name = "VERSION",
+        conflicts_with_all = &["workspace", "find-lower"]
+    )]
+    published: Option<semver::Version>,
+
+    /// Report which toolchain would be checked, and how, without checking it
+    ///
+    /// Prints the check command, its working directory, environment variables and target
+    /// directory. No toolchains are installed, and nothing is compiled.
+    #[clap(long)]
+    pub(in crate::cli) dry_run: bool,
+
+    /// Run the checks from a copy of the workspace in a temporary directory
+    ///
+    /// The toolchains can't modify the lockfile, the target directory or files generated by
+    /// build scripts in the working tree. Build artifacts are written to the copy, unless
+    /// `CARGO_TARGET_DIR` is set, and are removed afterwards. Files written by cargo-msrv itself,
+    /// like the toolchain file of `--toolchain-file`, are still written to the working tree.
+    #[clap(long)]
+    pub(in crate::cli) sandbox: bool,
+
+    /// Show the output of the check command as it is written
+    ///
+    /// The output of a failed check is written to `target/cargo-msrv/logs/<toolchain>.log`
+    /// regardless.
+    #[clap(long)]
+    pub(in crate::cli) show_check_output: bool,
 }
 
 // Interpret the CLI config frontend as general Config
@@ -189,21 +444,58 @@ impl<'opts> TryFrom<&'opts CargoMsrvOpts> for Config<'opts> {
 
         let mut builder = ConfigBuilder::new(mode, &target);
 
-        builder = configurators::CustomCheckCommand::configure(builder, opts)?;
         builder = configurators::PathConfig::configure(builder, opts)?;
         builder = configurators::ManifestPathConfig::configure(builder, opts)?;
+        builder = configurators::ConfigFileConfig::configure(builder, opts)?;
+        builder = configurators::CustomCheckCommand::configure(builder, opts)?;
+        builder = configurators::FeaturesConfig::configure(builder, opts)?;
+        builder = configurators::TargetSelectionConfig::configure(builder, opts)?;
         builder = configurators::Target::configure(builder, opts)?;
+        builder = configurators::Host::configure(builder, opts)?;
+        builder = configurators::Components::configure(builder, opts)?;
         builder = configurators::MinVersion::configure(builder, opts)?;
+        builder = configurators::ReadDependencyMsrv::configure(builder, opts)?;
+        builder = configurators::MsrvResolution::configure(builder, opts)?;
         builder = configurators::MaxVersion::configure(builder, opts)?;
         builder = configurators::SearchMethodConfig::configure(builder, opts)?;
+        builder = configurators::Jobs::configure(builder, opts)?;
         builder = configurators::IncludeAllPatchReleases::configure(builder, opts)?;
+        builder = configurators::SearchGranularity::configure(builder, opts)?;
         builder = configurators::OutputToolchainFile::configure(builder, opts)?;
         builder = configurators::WriteMsrv::configure(builder, opts)?;
+        builder = configurators::WriteBadge::configure(builder, opts)?;
+        builder = configurators::WriteEnvConfig::configure(builder, opts)?;
+        builder = configurators::SyncClippy::configure(builder, opts)?;
+        builder = configurators::Certify::configure(builder, opts)?;
         builder = configurators::IgnoreLockfile::configure(builder, opts)?;
         builder = configurators::UserOutput::configure(builder, opts)?;
+        #[cfg(feature = "tui")]
+        {
+            builder = configurators::Tui::configure(builder, opts)?;
+        }
         builder = configurators::ReleaseSource::configure(builder, opts)?;
+        builder = configurators::ReleaseChannelConfig::configure(builder, opts)?;
         builder = configurators::Tracing::configure(builder, opts)?;
         builder = configurators::CheckFeedback::configure(builder, opts)?;
+        builder = configurators::Workspace::configure(builder, opts)?;
+        builder = configurators::CheckCache::configure(builder, opts)?;
+        builder = configurators::PerToolchainTargetDir::configure(builder, opts)?;
+        builder = configurators::FeatureMatrix::configure(builder, opts)?;
+        builder = configurators::Explain::configure(builder, opts)?;
+        builder = configurators::DryRun::configure(builder, opts)?;
+        builder = configurators::Sandbox::configure(builder, opts)?;
+        builder = configurators::ShowCheckOutput::configure(builder, opts)?;
+        builder = configurators::Offline::configure(builder, opts)?;
+        builder = configurators::ToolchainDir::configure(builder, opts)?;
+        builder = configurators::ToolchainPath::configure(builder, opts)?;
+        builder = configurators::Runner::configure(builder, opts)?;
+        builder = configurators::UninstallAfter::configure(builder, opts)?;
+        builder = configurators::Preflight::configure(builder, opts)?;
+        builder = configurators::CheckTimeout::configure(builder, opts)?;
+        builder = configurators::InstallRetries::configure(builder, opts)?;
+        builder = configurators::IndexCache::configure(builder, opts)?;
+        builder = configurators::Network::configure(builder, opts)?;
+        builder = configurators::Mirror::configure(builder, opts)?;
         builder = configurators::SubCommandConfigurator::configure(builder, opts)?;
 
         Ok(builder.build())
@@ -215,9 +507,16 @@ fn make_mode(opts: &CargoMsrvOpts) -> Action {
         .as_ref()
         .map(|subcommand| match subcommand {
             SubCommand::List(_) => Action::List,
-            SubCommand::Show => Action::Show,
+            SubCommand::Show(_) => Action::Show,
+            SubCommand::Doctor => Action::Doctor,
             SubCommand::Set(_) => Action::Set,
             SubCommand::Verify(_) => Action::Verify,
+            SubCommand::Upgrade(_) => Action::Upgrade,
+            SubCommand::PinLockfile(_) => Action::PinLockfile,
+            SubCommand::Diff(_) => Action::Diff,
+            SubCommand::Watch(_) => Action::Watch,
+            SubCommand::Policy(_) => Action::Policy,
+            SubCommand::Schema => Action::Schema,
         })
         .unwrap_or_else(|| {
             if opts.verify {
@@ -227,3 +526,14 @@ fn make_mode(opts: &CargoMsrvOpts) -> Action {
             }
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn verify_cli() {
+        CargoCli::command().debug_assert();
+    }
+}
@@ -2,38 +2,106 @@ use crate::cli::CargoMsrvOpts;
 use crate::config::ConfigBuilder;
 use crate::TResult;
 
+mod certify;
+mod check_cache;
 mod check_feedback;
+mod check_timeout;
+mod components;
+mod config_file;
 mod custom_check;
+mod dry_run;
+mod explain;
+mod feature_matrix;
+mod features;
+mod host;
 mod ignore_lockfile;
+mod index_cache;
+mod install_retries;
+mod jobs;
 mod manifest_path;
 mod max_version;
 mod min_version;
+mod mirror;
+mod msrv_resolution;
+mod network;
+mod offline;
 mod output_toolchain_file;
 mod path;
+mod per_toolchain_target_dir;
+mod preflight;
+mod read_dependency_msrv;
+mod release_channel;
 mod release_source;
+mod runner;
+mod sandbox;
 mod search_method;
 mod search_space;
+mod show_check_output;
 mod sub_command_configurator;
+mod sync_clippy;
 mod target;
+mod target_selection;
+mod toolchain_dir;
+mod toolchain_path;
 mod tracing_configurator;
+#[cfg(feature = "tui")]
+mod tui;
+mod uninstall_after;
 mod user_output;
+mod workspace;
+mod write_badge;
+mod write_env;
 mod write_msrv;
 
+pub(in crate::cli) use certify::Certify;
+pub(in crate::cli) use check_cache::CheckCache;
 pub(in crate::cli) use check_feedback::CheckFeedback;
+pub(in crate::cli) use check_timeout::CheckTimeout;
+pub(in crate::cli) use components::Components;
+pub(in crate::cli) use config_file::ConfigFileConfig;
 pub(in crate::cli) use custom_check::CustomCheckCommand;
+pub(in crate::cli) use dry_run::DryRun;
+pub(in crate::cli) use explain::Explain;
+pub(in crate::cli) use feature_matrix::FeatureMatrix;
+pub(in crate::cli) use features::FeaturesConfig;
+pub(in crate::cli) use host::Host;
 pub(in crate::cli) use ignore_lockfile::IgnoreLockfile;
+pub(in crate::cli) use index_cache::IndexCache;
+pub(in crate::cli) use install_retries::InstallRetries;
+pub(in crate::cli) use jobs::Jobs;
 pub(in crate::cli) use manifest_path::ManifestPathConfig;
 pub(in crate::cli) use max_version::MaxVersion;
 pub(in crate::cli) use min_version::MinVersion;
+pub(in crate::cli) use mirror::Mirror;
+pub(in crate::cli) use msrv_resolution::MsrvResolution;
+pub(in crate::cli) use network::Network;
+pub(in crate::cli) use offline::Offline;
 pub(in crate::cli) use output_toolchain_file::OutputToolchainFile;
 pub(in crate::cli) use path::PathConfig;
+pub(in crate::cli) use per_toolchain_target_dir::PerToolchainTargetDir;
+pub(in crate::cli) use preflight::Preflight;
+pub(in crate::cli) use read_dependency_msrv::ReadDependencyMsrv;
+pub(in crate::cli) use release_channel::ReleaseChannelConfig;
 pub(in crate::cli) use release_source::ReleaseSource;
+pub(in crate::cli) use runner::Runner;
+pub(in crate::cli) use sandbox::Sandbox;
 pub(in crate::cli) use search_method::SearchMethodConfig;
-pub(in crate::cli) use search_space::IncludeAllPatchReleases;
+pub(in crate::cli) use search_space::{IncludeAllPatchReleases, SearchGranularity};
+pub(in crate::cli) use show_check_output::ShowCheckOutput;
 pub(in crate::cli) use sub_command_configurator::SubCommandConfigurator;
+pub(in crate::cli) use sync_clippy::SyncClippy;
 pub(in crate::cli) use target::Target;
+pub(in crate::cli) use target_selection::TargetSelectionConfig;
+pub(in crate::cli) use toolchain_dir::ToolchainDir;
+pub(in crate::cli) use toolchain_path::ToolchainPath;
 pub(in crate::cli) use tracing_configurator::Tracing;
+#[cfg(feature = "tui")]
+pub(in crate::cli) use tui::Tui;
+pub(in crate::cli) use uninstall_after::UninstallAfter;
 pub(in crate::cli) use user_output::UserOutput;
+pub(in crate::cli) use workspace::Workspace;
+pub(in crate::cli) use write_badge::WriteBadge;
+pub(in crate::cli) use write_env::WriteEnvConfig;
 pub(in crate::cli) use write_msrv::WriteMsrv;
 
 /// Used to turn the CLI front-end into a flattened Config.
@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct CheckCache;
+
+impl Configure for CheckCache {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let toolchain_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => &verify.toolchain_opts,
+            _ => &opts.find_opts.toolchain_opts,
+        };
+
+        Ok(builder
+            .no_cache(toolchain_opts.no_cache)
+            .resume(toolchain_opts.resume))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+use std::time::Duration;
+
+pub(in crate::cli) struct CheckTimeout;
+
+impl Configure for CheckTimeout {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let toolchain_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => &verify.toolchain_opts,
+            _ => &opts.find_opts.toolchain_opts,
+        };
+
+        Ok(builder
+            .check_timeout(toolchain_opts.check_timeout.map(Duration::from_secs))
+            .treat_timeout_as_skip(toolchain_opts.treat_timeout_as_skip))
+    }
+}
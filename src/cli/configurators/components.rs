@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Components;
+
+impl Configure for Components {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let components = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                &verify.toolchain_opts.component
+            }
+            _ => &opts.find_opts.toolchain_opts.component,
+        };
+
+        Ok(builder.toolchain_components(components.clone()))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::cli::configurators::Configure;
+use crate::cli::CargoMsrvOpts;
+use crate::config::features::Features;
+use crate::config::file::ConfigFile;
+use crate::config::ConfigBuilder;
+use crate::error::{CargoMSRVError, IoErrorSource};
+use crate::TResult;
+use std::path::PathBuf;
+
+/// Applies the options from a `.cargo-msrv.toml` file in the crate root, if such a file exists.
+///
+/// Must run after the crate path and manifest path have been configured, and before options which
+/// may be overridden from the CLI, such as the custom check command and the target.
+pub(in crate::cli) struct ConfigFileConfig;
+
+impl Configure for ConfigFileConfig {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        _opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let dir = config_file_dir(&builder)?;
+
+        let config_file = match ConfigFile::try_from_dir(&dir)? {
+            Some(config_file) => config_file,
+            None => return Ok(builder),
+        };
+
+        info!(?config_file, "using config file");
+
+        let mut builder = builder;
+
+        if let Some(target) = config_file.target() {
+            builder = builder.target(target);
+        }
+
+        if let Some(check_command) = config_file.check_command() {
+            builder = builder.check_command(check_command.to_vec());
+        }
+
+        if let Some(check_args) = config_file.check_args() {
+            builder = builder.check_args(check_args.to_vec());
+        }
+
+        if let Some(default_check_args) = config_file.default_check_args() {
+            builder = builder.default_check_args(default_check_args);
+        }
+
+        if let Some(features) = config_file.features() {
+            builder = builder.features(Features::new(features, false, false));
+        }
+
+        if let Some(names) = config_file.ignore_dependencies() {
+            builder = builder.ignored_dependencies(names.to_vec());
+        }
+
+        if let Some(policy) = config_file.policy() {
+            builder = builder.msrv_policy(policy.clone());
+        }
+
+        Ok(builder)
+    }
+}
+
+fn config_file_dir(builder: &ConfigBuilder) -> TResult<PathBuf> {
+    if let Some(path) = builder.get_crate_path() {
+        return Ok(path.to_path_buf());
+    }
+
+    if let Some(dir) = builder.get_manifest_path().and_then(|path| path.parent()) {
+        return Ok(dir.to_path_buf());
+    }
+
+    std::env::current_dir().map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::CurrentDir,
+    })
+}
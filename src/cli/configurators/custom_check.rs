@@ -1,6 +1,6 @@
 use crate::cli::configurators::Configure;
-use crate::cli::find_opts::FindOpts;
-use crate::cli::{CargoMsrvOpts, SubCommand, VerifyOpts};
+use crate::cli::custom_check_opts::CustomCheckOpts;
+use crate::cli::{CargoMsrvOpts, SubCommand};
 use crate::config::ConfigBuilder;
 use crate::TResult;
 
@@ -11,34 +11,37 @@ impl Configure for CustomCheckCommand {
         builder: ConfigBuilder<'c>,
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
-        fn configure_from_verify<'c>(
+        fn configure_custom_check<'c>(
             builder: ConfigBuilder<'c>,
-            opts: &'c VerifyOpts,
+            opts: &'c CustomCheckOpts,
         ) -> ConfigBuilder<'c> {
-            if opts.custom_check.custom_check_command.is_empty() {
-                return builder;
+            let mut env = opts.env.clone();
+            if let Some(rustflags) = &opts.rustflags {
+                env.push(("RUSTFLAGS".to_string(), rustflags.clone()));
             }
 
-            let cmd = opts
-                .custom_check
-                .custom_check_command
-                .iter()
-                .map(|s| s.as_str())
-                .collect();
+            let mut builder = builder.check_env(env);
 
-            builder.check_command(cmd)
-        }
+            if opts.no_default_check_args {
+                builder = builder.default_check_args(false);
+            }
 
-        fn configure_from_find<'c>(
-            builder: ConfigBuilder<'c>,
-            opts: &'c FindOpts,
-        ) -> ConfigBuilder<'c> {
-            if opts.custom_check_opts.custom_check_command.is_empty() {
+            if let Some(kind) = opts.check_command {
+                let builder = builder.check_command(kind.command());
+
+                // Keep the check arguments from the config file, unless arguments were given
+                if opts.custom_check_command.is_empty() {
+                    return builder;
+                }
+
+                return builder.check_args(opts.custom_check_command.clone());
+            }
+
+            if opts.custom_check_command.is_empty() {
                 return builder;
             }
 
             let cmd = opts
-                .custom_check_opts
                 .custom_check_command
                 .iter()
                 .map(|s| s.as_str())
@@ -48,8 +51,10 @@ impl Configure for CustomCheckCommand {
         }
 
         let builder = match &opts.subcommand {
-            Some(SubCommand::Verify(verify)) => configure_from_verify(builder, verify),
-            None => configure_from_find(builder, &opts.find_opts),
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                configure_custom_check(builder, &verify.custom_check)
+            }
+            None => configure_custom_check(builder, &opts.find_opts.custom_check_opts),
             _ => builder,
         };
 
@@ -0,0 +1,20 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct DryRun;
+
+impl Configure for DryRun {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let dry_run = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => verify.dry_run,
+            _ => opts.find_opts.dry_run,
+        };
+
+        Ok(builder.dry_run(dry_run))
+    }
+}
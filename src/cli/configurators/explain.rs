@@ -0,0 +1,20 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Explain;
+
+impl Configure for Explain {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let explain = match &opts.subcommand {
+            Some(SubCommand::Verify(_) | SubCommand::Watch(_)) => false,
+            _ => opts.find_opts.explain,
+        };
+
+        Ok(builder.explain(explain))
+    }
+}
@@ -0,0 +1,20 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct FeatureMatrix;
+
+impl Configure for FeatureMatrix {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let feature_matrix = match &opts.subcommand {
+            Some(SubCommand::Verify(_) | SubCommand::Watch(_)) => false,
+            _ => opts.find_opts.feature_matrix,
+        };
+
+        Ok(builder.feature_matrix(feature_matrix))
+    }
+}
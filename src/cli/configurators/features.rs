@@ -0,0 +1,32 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::features::Features;
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+/// Selects the features which are enabled when running the check command.
+///
+/// Takes precedence over the features specified in the config file, if any feature option is given.
+pub(in crate::cli) struct FeaturesConfig;
+
+impl Configure for FeaturesConfig {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let feature_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => &verify.feature_opts,
+            _ => &opts.find_opts.feature_opts,
+        };
+
+        if !feature_opts.is_present() {
+            return Ok(builder);
+        }
+
+        Ok(builder.features(Features::new(
+            &feature_opts.features,
+            feature_opts.all_features,
+            feature_opts.no_default_features,
+        )))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Host;
+
+impl Configure for Host {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let toolchain_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => &verify.toolchain_opts,
+            _ => &opts.find_opts.toolchain_opts,
+        };
+
+        let host = match &toolchain_opts.host {
+            Some(host) => host,
+            None => return Ok(builder),
+        };
+
+        // A single target is otherwise used as the host of the toolchains; with an explicit
+        // host, it's checked as compilation target instead
+        let builder = match toolchain_opts.target.as_slice() {
+            [target] if target != host => builder.check_targets(vec![target.clone()]),
+            _ => builder,
+        };
+
+        Ok(builder.host(host))
+    }
+}
@@ -1,5 +1,6 @@
 use crate::cli::configurators::Configure;
 use crate::cli::CargoMsrvOpts;
+use crate::config::lockfile_mode::LockfileMode;
 use crate::config::ConfigBuilder;
 use crate::TResult;
 
@@ -10,6 +11,13 @@ impl Configure for IgnoreLockfile {
         builder: ConfigBuilder<'c>,
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
-        Ok(builder.ignore_lockfile(opts.find_opts.ignore_lockfile))
+        Ok(builder
+            .ignore_lockfile(opts.find_opts.ignore_lockfile)
+            .regenerate_lockfile(opts.find_opts.regenerate_lockfile)
+            .minimal_versions(opts.find_opts.minimal_versions)
+            .lockfile_mode(LockfileMode::new(
+                opts.find_opts.locked,
+                opts.find_opts.frozen,
+            )))
     }
 }
@@ -0,0 +1,25 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+use std::time::Duration;
+
+pub(in crate::cli) struct IndexCache;
+
+impl Configure for IndexCache {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let rust_releases_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                &verify.rust_releases_opts
+            }
+            _ => &opts.find_opts.rust_releases_opts,
+        };
+
+        Ok(builder
+            .refresh_index(rust_releases_opts.refresh_index)
+            .index_cache_ttl(Duration::from_secs(rust_releases_opts.index_cache_ttl)))
+    }
+}
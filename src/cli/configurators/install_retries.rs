@@ -0,0 +1,23 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+use std::time::Duration;
+
+pub(in crate::cli) struct InstallRetries;
+
+impl Configure for InstallRetries {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let toolchain_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => &verify.toolchain_opts,
+            _ => &opts.find_opts.toolchain_opts,
+        };
+
+        Ok(builder
+            .install_retries(toolchain_opts.install_retries)
+            .install_retry_backoff(Duration::from_secs(toolchain_opts.install_retry_backoff)))
+    }
+}
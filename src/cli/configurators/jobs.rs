@@ -0,0 +1,19 @@
+use crate::cli::configurators::Configure;
+use crate::cli::CargoMsrvOpts;
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Jobs;
+
+impl Configure for Jobs {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        if let Some(jobs) = opts.find_opts.jobs {
+            Ok(builder.jobs(jobs.get()))
+        } else {
+            Ok(builder)
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::cli::configurators::Configure;
+use crate::cli::rust_releases_opts::VersionOrChannel;
 use crate::cli::CargoMsrvOpts;
 use crate::config::ConfigBuilder;
 use crate::TResult;
@@ -10,7 +11,7 @@ impl Configure for MaxVersion {
         builder: ConfigBuilder<'c>,
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
-        if let Some(max) = &opts.find_opts.rust_releases_opts.max {
+        if let Some(VersionOrChannel::Version(max)) = &opts.find_opts.rust_releases_opts.max {
             Ok(builder.maximum_version(max.clone()))
         } else {
             Ok(builder)
@@ -0,0 +1,24 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Mirror;
+
+impl Configure for Mirror {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let rust_releases_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                &verify.rust_releases_opts
+            }
+            _ => &opts.find_opts.rust_releases_opts,
+        };
+
+        Ok(builder
+            .dist_url(rust_releases_opts.dist_url.as_deref())
+            .changelog_path(rust_releases_opts.changelog_path.as_ref()))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::cli::configurators::Configure;
+use crate::cli::CargoMsrvOpts;
+use crate::config::ConfigBuilder;
+use crate::msrv_resolver::MsrvResolver;
+use crate::TResult;
+
+pub(in crate::cli) struct MsrvResolution;
+
+impl Configure for MsrvResolution {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let shared_opts = &opts.shared_opts;
+
+        let default = MsrvResolver::default();
+        let priority = if shared_opts.msrv_key_priority.is_empty() {
+            default.priority().to_vec()
+        } else {
+            shared_opts.msrv_key_priority.clone()
+        };
+
+        Ok(builder.msrv_resolver(MsrvResolver::new(
+            priority,
+            !shared_opts.no_manifest_workaround,
+        )))
+    }
+}
@@ -0,0 +1,24 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Network;
+
+impl Configure for Network {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let rust_releases_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                &verify.rust_releases_opts
+            }
+            _ => &opts.find_opts.rust_releases_opts,
+        };
+
+        Ok(builder
+            .proxy(rust_releases_opts.proxy.as_deref())
+            .ca_cert(rust_releases_opts.cacert.as_ref()))
+    }
+}
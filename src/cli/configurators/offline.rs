@@ -0,0 +1,29 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::{CargoMSRVError, TResult};
+
+pub(in crate::cli) struct Offline;
+
+impl Configure for Offline {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let offline = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                if verify.rust_releases_opts.offline && verify.published.is_some() {
+                    return Err(CargoMSRVError::InvalidConfig(
+                        "--published downloads the crate from crates.io, and can't be used with --offline"
+                            .to_string(),
+                    ));
+                }
+
+                verify.rust_releases_opts.offline
+            }
+            _ => opts.find_opts.rust_releases_opts.offline,
+        };
+
+        Ok(builder.offline(offline))
+    }
+}
@@ -10,6 +10,12 @@ impl Configure for OutputToolchainFile {
         builder: ConfigBuilder<'c>,
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
-        Ok(builder.output_toolchain_file(opts.find_opts.write_toolchain_file))
+        let find_opts = &opts.find_opts;
+
+        Ok(builder
+            .output_toolchain_file(find_opts.write_toolchain_file)
+            .toolchain_file_components(find_opts.toolchain_file_component.clone())
+            .toolchain_file_targets(find_opts.toolchain_file_target.clone())
+            .toolchain_file_pin(find_opts.pin))
     }
 }
@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct PerToolchainTargetDir;
+
+impl Configure for PerToolchainTargetDir {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let per_toolchain_target_dir = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                verify.toolchain_opts.per_toolchain_target_dir
+            }
+            _ => opts.find_opts.toolchain_opts.per_toolchain_target_dir,
+        };
+
+        Ok(builder.per_toolchain_target_dir(per_toolchain_target_dir))
+    }
+}
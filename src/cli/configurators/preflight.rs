@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Preflight;
+
+impl Configure for Preflight {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let toolchain_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => &verify.toolchain_opts,
+            _ => &opts.find_opts.toolchain_opts,
+        };
+
+        Ok(builder
+            .max_downloads(toolchain_opts.max_downloads)
+            .require_disk_space(toolchain_opts.require_disk_space))
+    }
+}
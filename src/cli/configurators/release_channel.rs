@@ -0,0 +1,24 @@
+use crate::cli::configurators::Configure;
+use crate::cli::rust_releases_opts::VersionOrChannel;
+use crate::cli::CargoMsrvOpts;
+use crate::config::{ConfigBuilder, ReleaseChannel};
+use crate::TResult;
+
+pub(in crate::cli) struct ReleaseChannelConfig;
+
+impl Configure for ReleaseChannelConfig {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let rust_releases_opts = &opts.find_opts.rust_releases_opts;
+
+        if let Some(VersionOrChannel::Channel(channel)) = rust_releases_opts.max {
+            Ok(builder.release_channel(channel))
+        } else if rust_releases_opts.include_prerelease {
+            Ok(builder.release_channel(ReleaseChannel::Beta))
+        } else {
+            Ok(builder.release_channel(rust_releases_opts.channel))
+        }
+    }
+}
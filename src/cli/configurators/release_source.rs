@@ -1,4 +1,5 @@
 use crate::cli::configurators::Configure;
+use crate::cli::rust_releases_opts::ReleaseSourceOrFile;
 use crate::cli::CargoMsrvOpts;
 use crate::config::ConfigBuilder;
 use crate::TResult;
@@ -10,6 +11,13 @@ impl Configure for ReleaseSource {
         builder: ConfigBuilder<'c>,
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
-        Ok(builder.release_source(opts.find_opts.rust_releases_opts.release_source))
+        let builder = match &opts.find_opts.rust_releases_opts.release_source {
+            ReleaseSourceOrFile::Source(source) => builder.release_source(*source),
+            ReleaseSourceOrFile::File(path) => builder
+                .release_source(crate::config::ReleaseSource::File)
+                .release_file(Some(path)),
+        };
+
+        Ok(builder)
     }
 }
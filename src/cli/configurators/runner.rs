@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Runner;
+
+impl Configure for Runner {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let runner = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                verify.toolchain_opts.runner
+            }
+            _ => opts.find_opts.toolchain_opts.runner,
+        };
+
+        Ok(builder.check_runner(runner))
+    }
+}
@@ -0,0 +1,27 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::{CargoMSRVError, TResult};
+
+/// Runs the checks from a copy of the workspace, so they can't modify the working tree.
+pub(in crate::cli) struct Sandbox;
+
+impl Configure for Sandbox {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let sandbox = match &opts.subcommand {
+            // The copy would not be updated when the watched files change
+            Some(SubCommand::Watch(verify)) if verify.sandbox => {
+                return Err(CargoMSRVError::InvalidConfig(
+                    "--sandbox can't be used with `cargo msrv watch`".to_string(),
+                ))
+            }
+            Some(SubCommand::Verify(verify)) => verify.sandbox,
+            _ => opts.find_opts.sandbox,
+        };
+
+        Ok(builder.sandbox(sandbox))
+    }
+}
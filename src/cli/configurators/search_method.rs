@@ -10,9 +10,14 @@ impl Configure for SearchMethodConfig {
         builder: ConfigBuilder<'c>,
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
-        let method = match (opts.find_opts.linear, opts.find_opts.bisect) {
-            (true, false) => builder.search_method(SearchMethod::Linear),
-            (false, true) => builder.search_method(SearchMethod::Bisect),
+        let method = match (
+            opts.find_opts.search_method,
+            opts.find_opts.linear,
+            opts.find_opts.bisect,
+        ) {
+            (Some(method), _, _) => builder.search_method(method),
+            (None, true, false) => builder.search_method(SearchMethod::Linear),
+            (None, false, true) => builder.search_method(SearchMethod::Bisect),
             _ => builder.search_method(SearchMethod::default()),
         };
 
@@ -15,3 +15,14 @@ impl Configure for IncludeAllPatchReleases {
         ))
     }
 }
+
+pub(in crate::cli) struct SearchGranularity;
+
+impl Configure for SearchGranularity {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        Ok(builder.granularity(opts.find_opts.granularity))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct ShowCheckOutput;
+
+impl Configure for ShowCheckOutput {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let show_check_output = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                verify.show_check_output
+            }
+            _ => opts.find_opts.show_check_output,
+        };
+
+        Ok(builder.show_check_output(show_check_output))
+    }
+}
@@ -1,10 +1,18 @@
 use crate::cli::configurators::Configure;
-use crate::cli::{CargoMsrvOpts, ListOpts, SetOpts, SubCommand, VerifyOpts};
+use crate::cli::{
+    CargoMsrvOpts, DiffOpts, ListOpts, PinLockfileOpts, PolicyOpts, SetOpts, ShowOpts, SubCommand,
+    UpgradeOpts, VerifyOpts,
+};
+use crate::config::diff::DiffCmdConfig;
 use crate::config::list::ListCmdConfig;
+use crate::config::pin_lockfile::PinLockfileCmdConfig;
+use crate::config::policy::{MsrvPolicy, PolicyCmdConfig};
 use crate::config::set::SetCmdConfig;
-use crate::config::verify::VerifyCmdConfig;
+use crate::config::show::ShowCmdConfig;
+use crate::config::upgrade::UpgradeCmdConfig;
+use crate::config::verify::{FailOn, VerifyCmdConfig};
 use crate::config::{ConfigBuilder, SubCommandConfig};
-use crate::TResult;
+use crate::{CargoMSRVError, TResult};
 
 pub(in crate::cli) struct SubCommandConfigurator;
 
@@ -21,9 +29,29 @@ impl Configure for SubCommandConfigurator {
                 SubCommand::Set(opts) => {
                     return configure_set(builder, opts);
                 }
-                SubCommand::Verify(opts) => {
+                SubCommand::Show(opts) => {
+                    return configure_show(builder, opts);
+                }
+                SubCommand::Watch(opts) if opts.published.is_some() => {
+                    return Err(CargoMSRVError::InvalidConfig(
+                        "--published can't be used with `cargo msrv watch`".to_string(),
+                    ));
+                }
+                SubCommand::Verify(opts) | SubCommand::Watch(opts) => {
                     return configure_verify(builder, opts);
                 }
+                SubCommand::Upgrade(opts) => {
+                    return configure_upgrade(builder, opts);
+                }
+                SubCommand::PinLockfile(opts) => {
+                    return configure_pin_lockfile(builder, opts);
+                }
+                SubCommand::Diff(opts) => {
+                    return configure_diff(builder, opts);
+                }
+                SubCommand::Policy(opts) => {
+                    return configure_policy(builder, opts);
+                }
                 _ => {}
             }
         }
@@ -40,8 +68,25 @@ fn configure_list<'c>(
     builder: ConfigBuilder<'c>,
     opts: &'c ListOpts,
 ) -> TResult<ConfigBuilder<'c>> {
+    let depth = if opts.direct_deps {
+        Some(1)
+    } else {
+        opts.depth
+    };
+
     let config = ListCmdConfig {
         variant: opts.variant,
+        depth,
+        min_msrv: opts.min_msrv.clone(),
+        sort: opts.sort,
+        verify_deps: opts.verify_deps,
+        dependency_kinds: opts.dep_kind.clone(),
+    };
+
+    let builder = if opts.ignore_dependencies.is_empty() {
+        builder
+    } else {
+        builder.ignored_dependencies(opts.ignore_dependencies.clone())
     };
 
     let config = SubCommandConfig::ListConfig(config);
@@ -51,18 +96,93 @@ fn configure_list<'c>(
 fn configure_set<'c>(builder: ConfigBuilder<'c>, opts: &'c SetOpts) -> TResult<ConfigBuilder<'c>> {
     let config = SetCmdConfig {
         msrv: opts.msrv.clone(),
+        propagate: opts.propagate,
     };
 
     let config = SubCommandConfig::SetConfig(config);
     Ok(builder.sub_command_config(config))
 }
 
+fn configure_show<'c>(
+    builder: ConfigBuilder<'c>,
+    opts: &'c ShowOpts,
+) -> TResult<ConfigBuilder<'c>> {
+    let config = ShowCmdConfig {
+        edition: opts.edition,
+    };
+
+    let config = SubCommandConfig::ShowConfig(config);
+    Ok(builder.sub_command_config(config))
+}
+
+fn configure_pin_lockfile<'c>(
+    builder: ConfigBuilder<'c>,
+    opts: &'c PinLockfileOpts,
+) -> TResult<ConfigBuilder<'c>> {
+    let config = PinLockfileCmdConfig {
+        msrv: opts.msrv.clone(),
+    };
+
+    let config = SubCommandConfig::PinLockfileConfig(config);
+    Ok(builder.sub_command_config(config))
+}
+
+fn configure_diff<'c>(
+    builder: ConfigBuilder<'c>,
+    opts: &'c DiffOpts,
+) -> TResult<ConfigBuilder<'c>> {
+    let config = DiffCmdConfig {
+        base: opts.base.clone(),
+        head: opts.head.clone(),
+    };
+
+    let config = SubCommandConfig::DiffConfig(config);
+    Ok(builder.sub_command_config(config))
+}
+
+fn configure_policy<'c>(
+    builder: ConfigBuilder<'c>,
+    opts: &'c PolicyOpts,
+) -> TResult<ConfigBuilder<'c>> {
+    let config = PolicyCmdConfig {
+        msrv: opts.msrv.clone(),
+    };
+
+    let builder = if opts.sliding_window.is_none() && opts.minimum.is_none() {
+        builder
+    } else {
+        builder.msrv_policy(MsrvPolicy {
+            sliding_window: opts.sliding_window,
+            minimum: opts.minimum.clone(),
+        })
+    };
+
+    let config = SubCommandConfig::PolicyConfig(config);
+    Ok(builder.sub_command_config(config))
+}
+
+fn configure_upgrade<'c>(
+    builder: ConfigBuilder<'c>,
+    opts: &'c UpgradeOpts,
+) -> TResult<ConfigBuilder<'c>> {
+    let config = UpgradeCmdConfig {
+        msrv: opts.msrv.clone(),
+        write: opts.write,
+    };
+
+    let config = SubCommandConfig::UpgradeConfig(config);
+    Ok(builder.sub_command_config(config))
+}
+
 fn configure_verify<'c>(
     builder: ConfigBuilder<'c>,
     opts: &'c VerifyOpts,
 ) -> TResult<ConfigBuilder<'c>> {
     let config = VerifyCmdConfig {
         rust_version: opts.rust_version.clone(),
+        fail_on: opts.fail_on,
+        find_lower: opts.find_lower,
+        published: opts.published.clone(),
     };
 
     let config = SubCommandConfig::VerifyConfig(config);
@@ -70,7 +190,12 @@ fn configure_verify<'c>(
 }
 
 fn configure_deprecated_verify_flag(builder: ConfigBuilder) -> TResult<ConfigBuilder> {
-    let config = VerifyCmdConfig { rust_version: None };
+    let config = VerifyCmdConfig {
+        rust_version: None,
+        fail_on: FailOn::default(),
+        find_lower: false,
+        published: None,
+    };
 
     let config = SubCommandConfig::VerifyConfig(config);
     Ok(builder.sub_command_config(config))
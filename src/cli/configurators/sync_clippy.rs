@@ -0,0 +1,20 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct SyncClippy;
+
+impl Configure for SyncClippy {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let sync_clippy = match &opts.subcommand {
+            Some(SubCommand::Set(set_opts)) => set_opts.sync_clippy,
+            _ => opts.find_opts.sync_clippy,
+        };
+
+        Ok(builder.sync_clippy(sync_clippy))
+    }
+}
@@ -11,10 +11,10 @@ impl Configure for Target {
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
         // TODO{foresterre}: maybe also for `verify`, not just `find`?
-        if let Some(target) = &opts.find_opts.toolchain_opts.target {
-            Ok(builder.target(target.as_str()))
-        } else {
-            Ok(builder)
+        match opts.find_opts.toolchain_opts.target.as_slice() {
+            [] => Ok(builder),
+            [target] => Ok(builder.target(target.as_str())),
+            targets => Ok(builder.check_targets(targets.to_vec())),
         }
     }
 }
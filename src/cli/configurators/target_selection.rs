@@ -0,0 +1,34 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::target_selection::TargetSelection;
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+/// Selects the Cargo targets, such as examples and benchmarks, which are checked by the check
+/// command.
+pub(in crate::cli) struct TargetSelectionConfig;
+
+impl Configure for TargetSelectionConfig {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let target_selection_opts = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                &verify.target_selection_opts
+            }
+            _ => &opts.find_opts.target_selection_opts,
+        };
+
+        if !target_selection_opts.is_present() {
+            return Ok(builder);
+        }
+
+        Ok(builder.target_selection(TargetSelection::new(
+            target_selection_opts.all_targets,
+            target_selection_opts.examples,
+            target_selection_opts.benches,
+            target_selection_opts.tests,
+        )))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct ToolchainDir;
+
+impl Configure for ToolchainDir {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let dir = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                verify.toolchain_opts.toolchain_dir.as_ref()
+            }
+            _ => opts.find_opts.toolchain_opts.toolchain_dir.as_ref(),
+        };
+
+        Ok(builder.toolchain_dir(dir))
+    }
+}
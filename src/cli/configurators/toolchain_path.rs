@@ -0,0 +1,36 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::{CheckRunner, ConfigBuilder};
+use crate::{CargoMSRVError, TResult};
+
+/// Adds locally built toolchains to the search space of `cargo msrv` (find).
+pub(in crate::cli) struct ToolchainPath;
+
+impl Configure for ToolchainPath {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        if let Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) = &opts.subcommand {
+            if !verify.toolchain_opts.toolchain_path.is_empty() {
+                return Err(CargoMSRVError::InvalidConfig(
+                    "--toolchain-path can only be used when searching for the MSRV".to_string(),
+                ));
+            }
+
+            return Ok(builder);
+        }
+
+        let toolchain_opts = &opts.find_opts.toolchain_opts;
+
+        // Linked toolchains are managed by rustup, and can't be run in a container
+        if !toolchain_opts.toolchain_path.is_empty() && toolchain_opts.runner == CheckRunner::Docker
+        {
+            return Err(CargoMSRVError::InvalidConfig(
+                "--toolchain-path can't be used with `--runner docker`".to_string(),
+            ));
+        }
+
+        Ok(builder.toolchain_paths(toolchain_opts.toolchain_path.clone()))
+    }
+}
@@ -17,7 +17,8 @@ impl Configure for Tracing {
         let tracing_opts = TracingOptions::new(
             opts.shared_opts.debug_output_opts.log_target,
             opts.shared_opts.debug_output_opts.log_level,
-        );
+        )
+        .with_log_file(opts.shared_opts.debug_output_opts.log_file.clone());
 
         Ok(builder.tracing_config(tracing_opts))
     }
@@ -0,0 +1,22 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct UninstallAfter;
+
+impl Configure for UninstallAfter {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let uninstall_after = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                verify.toolchain_opts.uninstall_after
+            }
+            _ => opts.find_opts.toolchain_opts.uninstall_after,
+        };
+
+        Ok(builder.uninstall_after(uninstall_after))
+    }
+}
@@ -1,6 +1,6 @@
 use crate::cli::configurators::Configure;
 use crate::cli::CargoMsrvOpts;
-use crate::config::{ConfigBuilder, OutputFormat};
+use crate::config::{ConfigBuilder, OutputFormat, Verbosity};
 use crate::TResult;
 
 pub(in crate::cli) struct UserOutput;
@@ -10,11 +10,20 @@ impl Configure for UserOutput {
         builder: ConfigBuilder<'c>,
         opts: &'c CargoMsrvOpts,
     ) -> TResult<ConfigBuilder<'c>> {
+        let builder = builder
+            .write_report(opts.shared_opts.user_output_opts.write_report.as_ref())
+            .verbosity(Verbosity::from_flags(
+                opts.shared_opts.user_output_opts.quiet,
+                opts.shared_opts.user_output_opts.verbose,
+            ));
+
         if opts.shared_opts.user_output_opts.no_user_output {
             return Ok(builder.output_format(OutputFormat::None));
         }
 
         let format = opts.shared_opts.user_output_opts.output_format;
-        Ok(builder.output_format(format))
+        let file = opts.shared_opts.user_output_opts.output_file.as_ref();
+
+        Ok(builder.output_format(format).output_file(file))
     }
 }
@@ -0,0 +1,28 @@
+use crate::cli::configurators::Configure;
+use crate::cli::{CargoMsrvOpts, SubCommand};
+use crate::config::ConfigBuilder;
+use crate::TResult;
+
+pub(in crate::cli) struct Workspace;
+
+impl Configure for Workspace {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let (workspace, workspace_opts) = match &opts.subcommand {
+            Some(SubCommand::Verify(verify) | SubCommand::Watch(verify)) => {
+                (verify.workspace, &verify.workspace_opts)
+            }
+            _ => (opts.find_opts.workspace, &opts.find_opts.workspace_opts),
+        };
+
+        // Like Cargo, selecting packages implies a workspace run
+        let workspace = workspace || !workspace_opts.package.is_empty();
+
+        Ok(builder
+            .workspace(workspace)
+            .workspace_packages(workspace_opts.package.clone())
+            .workspace_excludes(workspace_opts.exclude.clone()))
+    }
+}
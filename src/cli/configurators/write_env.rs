@@ -0,0 +1,20 @@
+use crate::cli::configurators::Configure;
+use crate::cli::CargoMsrvOpts;
+use crate::config::{ConfigBuilder, WriteEnv};
+use crate::TResult;
+
+pub(in crate::cli) struct WriteEnvConfig;
+
+impl Configure for WriteEnvConfig {
+    fn configure<'c>(
+        builder: ConfigBuilder<'c>,
+        opts: &'c CargoMsrvOpts,
+    ) -> TResult<ConfigBuilder<'c>> {
+        let write_env = opts.find_opts.write_env.as_ref().map(|path| match path {
+            Some(path) => WriteEnv::File(path.clone()),
+            None => WriteEnv::GitHubActions,
+        });
+
+        Ok(builder.write_env(write_env))
+    }
+}
@@ -1,10 +1,83 @@
+use crate::config::check_command::CheckCommandKind;
 use clap::AppSettings;
 use clap::Args;
 
 #[derive(Debug, Args)]
 #[clap(next_help_heading = "CUSTOM CHECK OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
 pub struct CustomCheckOpts {
+    /// Select the Cargo subcommand which is used as check command
+    ///
+    /// For example, `--check-command test` only accepts a Rust version if the tests of the crate
+    /// pass. Arguments given after `--` are passed on to the selected subcommand, instead of
+    /// replacing the check command as a whole.
+    #[clap(long, arg_enum, value_name = "COMMAND")]
+    pub check_command: Option<CheckCommandKind>,
+
     /// Supply a custom `check` command to be used by cargo msrv
+    ///
+    /// If `--check-command` is given, these are the arguments passed on to the selected
+    /// subcommand instead.
     #[clap(last = true, required = false)]
     pub custom_check_command: Vec<String>,
+
+    /// Don't add the default check arguments to the check command
+    ///
+    /// By default, cargo-msrv adds the arguments which select the enabled features (e.g.
+    /// `--all-features`) and the checked targets (e.g. `--all-targets`) to the check command.
+    /// With this flag, only the check command and the arguments given after `--` are run.
+    #[clap(long)]
+    pub no_default_check_args: bool,
+
+    /// Set the `RUSTFLAGS` environment variable of each check command
+    ///
+    /// For example `--rustflags "--cfg ci -D warnings"`, so the checks are compiled with the
+    /// same flags as in CI. Takes precedence over a `RUSTFLAGS` variable given with `--env`.
+    #[clap(long, value_name = "FLAGS", allow_hyphen_values = true)]
+    pub rustflags: Option<String>,
+
+    /// Set an environment variable of each check command
+    ///
+    /// May be given multiple times. The variables are also included in the reported check
+    /// method of each check.
+    #[clap(
+        long = "env",
+        value_name = "KEY=VALUE",
+        multiple_occurrences = true,
+        parse(try_from_str = parse_env_var)
+    )]
+    pub env: Vec<(String, String)>,
+}
+
+/// Parse an environment variable given as `KEY=VALUE`.
+fn parse_env_var(input: &str) -> Result<(String, String), String> {
+    match input.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!(
+            "invalid environment variable '{}', expected KEY=VALUE",
+            input
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        key_value = { "RUSTFLAGS=-D warnings", Some(("RUSTFLAGS", "-D warnings")) },
+        empty_value = { "CI=", Some(("CI", "")) },
+        value_with_separator = { "KEY=a=b", Some(("KEY", "a=b")) },
+        no_separator = { "RUSTFLAGS", None },
+        no_key = { "=value", None },
+    )]
+    fn env_var(input: &str, expected: Option<(&str, &str)>) {
+        let parsed = parse_env_var(input).ok();
+
+        assert_eq!(
+            parsed
+                .as_ref()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+            expected
+        );
+    }
 }
@@ -0,0 +1,32 @@
+use clap::AppSettings;
+use clap::Args;
+
+// Cli Options to select the Cargo features which are enabled when running the check command
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "FEATURE OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub struct FeatureOpts {
+    /// Space or comma separated list of features to enable
+    ///
+    /// The features are passed on to the check command, using Cargo's `--features` flag.
+    #[clap(long, value_name = "FEATURES", multiple_occurrences = true)]
+    pub features: Vec<String>,
+
+    /// Enable all features of the crate
+    ///
+    /// Passed on to the check command, using Cargo's `--all-features` flag.
+    #[clap(long)]
+    pub all_features: bool,
+
+    /// Disable the default features of the crate
+    ///
+    /// Passed on to the check command, using Cargo's `--no-default-features` flag.
+    #[clap(long)]
+    pub no_default_features: bool,
+}
+
+impl FeatureOpts {
+    /// Whether any of the feature options were given.
+    pub fn is_present(&self) -> bool {
+        !self.features.is_empty() || self.all_features || self.no_default_features
+    }
+}
@@ -1,8 +1,15 @@
 use crate::cli::custom_check_opts::CustomCheckOpts;
+use crate::cli::feature_opts::FeatureOpts;
 use crate::cli::rust_releases_opts::RustReleasesOpts;
+use crate::cli::target_selection_opts::TargetSelectionOpts;
 use crate::cli::toolchain_opts::ToolchainOpts;
+use crate::cli::workspace_opts::WorkspaceOpts;
+use crate::config::toolchain_file::ToolchainFilePin;
+use crate::config::{Granularity, SearchMethod};
 use clap::AppSettings;
 use clap::Args;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 
 // Cli Options for top-level cargo-msrv (find) command
 #[derive(Debug, Args)]
@@ -22,6 +29,44 @@ pub struct FindOpts {
     #[clap(long, conflicts_with = "bisect")]
     pub linear: bool,
 
+    /// The search method which is used to find the MSRV
+    ///
+    /// `bisect` and `linear` are equivalent to `--bisect` and `--linear`. `gallop` checks the
+    /// most recent release first, and then steps further back with steps which double in size,
+    /// until it finds an incompatible release; the MSRV is then found with a binary search
+    /// between the last compatible and the first incompatible release. This needs fewer checks
+    /// than a binary search when the MSRV is recent.
+    #[clap(
+        long,
+        possible_values = SearchMethod::variants(),
+        value_name = "METHOD",
+        conflicts_with_all = &["bisect", "linear"]
+    )]
+    pub search_method: Option<SearchMethod>,
+
+    /// The precision of the search
+    ///
+    /// With `minor`, only the most recent patch release of each minor release is checked. With
+    /// `patch`, the search is run at minor granularity first, after which the patch releases
+    /// which precede the found release, within the same minor release, are checked as well, to
+    /// find the exact patch release.
+    #[clap(
+        long,
+        possible_values = Granularity::variants(),
+        default_value_t,
+        value_name = "GRANULARITY",
+        conflicts_with = "include-all-patch-releases"
+    )]
+    pub granularity: Granularity,
+
+    /// The amount of compatibility checks to run concurrently, when using a linear search
+    ///
+    /// Each concurrent check uses its own target directory, so each check has to build the
+    /// crate and its dependencies from scratch. This trades disk space for a reduction in
+    /// wall clock time on machines with many cores.
+    #[clap(long, value_name = "N", requires = "linear")]
+    pub jobs: Option<NonZeroUsize>,
+
     /// Pin the MSRV by writing the version to a rust-toolchain file
     ///
     /// The toolchain file will pin the Rust version for this crate.
@@ -29,6 +74,59 @@ pub struct FindOpts {
     #[clap(long, alias = "toolchain-file")]
     pub write_toolchain_file: bool,
 
+    /// Add the given components to the rust-toolchain file, when it is written
+    ///
+    /// Components already listed in an existing toolchain file are preserved.
+    #[clap(
+        long,
+        value_name = "COMPONENT",
+        multiple_occurrences = true,
+        use_value_delimiter = true,
+        requires = "write-toolchain-file"
+    )]
+    pub toolchain_file_component: Vec<String>,
+
+    /// Add the given targets to the rust-toolchain file, when it is written
+    ///
+    /// Targets already listed in an existing toolchain file are preserved.
+    #[clap(
+        long,
+        value_name = "TARGET",
+        multiple_occurrences = true,
+        use_value_delimiter = true,
+        requires = "write-toolchain-file"
+    )]
+    pub toolchain_file_target: Vec<String>,
+
+    /// How precisely the channel of the rust-toolchain file pins the MSRV, when it is written
+    ///
+    /// With `exact`, the channel is the exact release, e.g. `1.64.0`. With `minor`, the channel
+    /// is the minor version, e.g. `1.64`, so rustup uses its latest patch release.
+    #[clap(
+        long,
+        possible_values = ToolchainFilePin::variants(),
+        default_value_t,
+        value_name = "PRECISION",
+        requires = "write-toolchain-file"
+    )]
+    pub pin: ToolchainFilePin,
+
+    /// Write a shields.io endpoint badge with the MSRV to the given file
+    ///
+    /// The badge is a JSON document which can be displayed with
+    /// `https://img.shields.io/endpoint?url=<url of the file>`, so a badge which is generated in
+    /// CI always shows the current MSRV.
+    #[clap(long, value_name = "PATH")]
+    pub write_badge: Option<PathBuf>,
+
+    /// Write the MSRV as `MSRV=<version>` to the given environment file
+    ///
+    /// Lets later steps of a CI workflow install the toolchain of the MSRV. When no path is
+    /// given, the MSRV is written to the files given by `$GITHUB_ENV` (as `MSRV`) and
+    /// `$GITHUB_OUTPUT` (as the `msrv` step output) of GitHub Actions.
+    #[clap(long, value_name = "PATH")]
+    pub write_env: Option<Option<PathBuf>>,
+
     /// Temporarily remove the lockfile, so it will not interfere with the building process
     ///
     /// This is important when testing against older Rust versions such as Cargo versions prior to
@@ -36,10 +134,54 @@ pub struct FindOpts {
     #[clap(long)]
     pub ignore_lockfile: bool,
 
+    /// Generate a fresh lockfile with each checked toolchain
+    ///
+    /// The lockfile is stashed, and regenerated by the Cargo version of the checked toolchain
+    /// before running the check command. The original lockfile is restored after each check.
+    /// A toolchain which fails to generate the lockfile is considered incompatible.
+    #[clap(long)]
+    pub regenerate_lockfile: bool,
+
+    /// Resolve dependencies to the minimal versions allowed by their version requirements
+    ///
+    /// Before each check, the lockfile is stashed and a new lockfile is generated with
+    /// `cargo generate-lockfile -Z minimal-versions`, which requires a nightly toolchain to be
+    /// installed. The original lockfile is restored after each check.
+    #[clap(long, conflicts_with_all = &["regenerate-lockfile", "toolchain-dir"])]
+    pub minimal_versions: bool,
+
+    /// Require the lockfile to be up to date, so every toolchain is checked against the same
+    /// dependency versions
+    ///
+    /// Passed on to the check command, using Cargo's `--locked` flag. When the lockfile would have
+    /// to be updated, the run fails, instead of considering the toolchain incompatible.
+    #[clap(
+        long,
+        conflicts_with_all = &["ignore-lockfile", "regenerate-lockfile", "minimal-versions"]
+    )]
+    pub locked: bool,
+
+    /// Like `--locked`, and additionally don't let the check command access the network
+    ///
+    /// Passed on to the check command, using Cargo's `--frozen` flag.
+    #[clap(
+        long,
+        conflicts_with_all = &["ignore-lockfile", "regenerate-lockfile", "minimal-versions"]
+    )]
+    pub frozen: bool,
+
     /// Don't read the `edition` of the crate and do not use its value to reduce the search space
     #[clap(long)]
     pub no_read_min_edition: bool,
 
+    /// Don't use the greatest MSRV declared by the dependencies to reduce the search space
+    ///
+    /// By default, the `rust-version` (or `package.metadata.msrv`) of each dependency is read,
+    /// and the greatest of these is used as the least recent version to take into account, since
+    /// Cargo refuses to build a crate with a toolchain older than the MSRV of its dependencies.
+    #[clap(long)]
+    pub no_read_dependency_msrv: bool,
+
     /// Don't print the result of compatibility checks
     ///
     /// The feedback of a compatibility check can be useful to determine why a certain Rust
@@ -57,12 +199,93 @@ pub struct FindOpts {
     #[clap(long)]
     pub write_msrv: bool,
 
+    /// Also write the MSRV to the Clippy configuration file, when writing the MSRV
+    ///
+    /// Sets the `msrv` key in `clippy.toml` (or `.clippy.toml` if it exists), so Clippy's
+    /// MSRV aware lints stay in sync with the MSRV in the Cargo manifest.
+    #[clap(long)]
+    pub sync_clippy: bool,
+
+    /// Record the inputs of the checks in the Cargo manifest, after the MSRV has been found
+    ///
+    /// Writes the toolchain, the date, the check command and a hash of the lockfile to the
+    /// `package.metadata.msrv-verified` table. The `verify` subcommand warns when these inputs
+    /// changed since, as the MSRV may need to be certified again.
+    #[clap(long)]
+    pub certify: bool,
+
+    /// Find the MSRV of each member of the workspace
+    ///
+    /// Each workspace member will be checked separately. The MSRV of each member is reported,
+    /// in addition to the MSRV of the workspace as a whole, which is the greatest MSRV of its
+    /// members.
+    #[clap(long)]
+    pub workspace: bool,
+
+    #[clap(flatten)]
+    pub workspace_opts: WorkspaceOpts,
+
+    /// Find the MSRV of each combination of the features of the crate
+    ///
+    /// The default features are disabled, and the crate is checked once for every combination
+    /// of the features declared in its Cargo manifest. The MSRV of each combination is reported
+    /// as a matrix, in addition to the greatest MSRV of all combinations.
+    #[clap(long, conflicts_with = "workspace")]
+    pub feature_matrix: bool,
+
+    /// Explain which features of the crate require the MSRV
+    ///
+    /// After the MSRV has been found, the crate is checked once more against the Rust release
+    /// preceding the MSRV. The language and library features which this release rejects, and
+    /// the files and lines which use them, are reported.
+    #[clap(long)]
+    pub explain: bool,
+
+    /// Report which toolchains would be checked, and how, without checking them
+    ///
+    /// Prints the search space, and for each toolchain the check command, its working
+    /// directory, environment variables and target directory. No toolchains are installed, and
+    /// nothing is compiled.
+    #[clap(long, conflicts_with = "explain")]
+    pub dry_run: bool,
+
+    /// Run the checks from a copy of the workspace in a temporary directory
+    ///
+    /// The toolchains can't modify the lockfile, the target directory or files generated by
+    /// build scripts in the working tree. Build artifacts are written to the copy, unless
+    /// `CARGO_TARGET_DIR` is set, and are removed afterwards. Files written by cargo-msrv itself,
+    /// like the toolchain file of `--toolchain-file`, are still written to the working tree.
+    #[clap(long)]
+    pub sandbox: bool,
+
+    /// Show the output of the check command of each toolchain as it is written
+    ///
+    /// The output of each failed check is written to `target/cargo-msrv/logs/<toolchain>.log`
+    /// regardless. Checks are not run concurrently when this flag is given.
+    #[clap(long, conflicts_with = "no-check-feedback")]
+    pub show_check_output: bool,
+
+    /// Show an interactive view of the search
+    ///
+    /// Shows which Rust versions of the search space passed or failed, and which toolchain is
+    /// currently being checked. Press `s` to abort the running check, and consider the
+    /// toolchain incompatible, or `q` to quit.
+    #[cfg(feature = "tui")]
+    #[clap(long, conflicts_with_all = &["output-format", "no-user-output"])]
+    pub tui: bool,
+
     #[clap(flatten)]
     pub rust_releases_opts: RustReleasesOpts,
 
     #[clap(flatten)]
     pub toolchain_opts: ToolchainOpts,
 
+    #[clap(flatten)]
+    pub feature_opts: FeatureOpts,
+
+    #[clap(flatten)]
+    pub target_selection_opts: TargetSelectionOpts,
+
     #[clap(flatten)]
     pub custom_check_opts: CustomCheckOpts,
 }
@@ -1,8 +1,11 @@
+use crate::config::ReleaseChannel;
 use crate::manifest::bare_version;
 use crate::manifest::bare_version::BareVersion;
 use crate::ReleaseSource;
 use clap::AppSettings;
 use clap::Args;
+use std::fmt;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 #[derive(Debug, Args)]
@@ -18,19 +21,133 @@ pub struct RustReleasesOpts {
     #[clap(long, value_name = "VERSION_SPEC or EDITION", alias = "minimum")]
     pub min: Option<EditionOrVersion>,
 
-    /// Most recent version to take into account
+    /// Most recent version or release channel to take into account
     ///
     /// Given version must match a valid Rust toolchain, and be semver compatible, or
     /// be a two component `major.minor` version.
-    #[clap(long, value_name = "VERSION_SPEC", alias = "maximum")]
-    pub max: Option<BareVersion>,
+    ///
+    /// The channel `stable` takes every stable release into account, while `beta` also takes
+    /// the beta pre-release of the upcoming Rust version into account.
+    #[clap(long, value_name = "VERSION_SPEC or CHANNEL", alias = "maximum")]
+    pub max: Option<VersionOrChannel>,
 
     /// Include all patch releases, instead of only the last
     #[clap(long)]
     pub include_all_patch_releases: bool,
 
-    #[clap(long, possible_values = ReleaseSource::variants(), default_value_t, value_name = "SOURCE")]
-    pub release_source: ReleaseSource,
+    /// Source of the Rust releases to take into account
+    ///
+    /// One of `rust-changelog` or `rust-dist`, or `file://<path>` to read the releases from a
+    /// JSON or TOML file which lists Rust versions, e.g. `["1.56.1", "1.60.0"]` or
+    /// `releases = ["1.56.1", "1.60.0"]`. The latter restricts the search space to the listed
+    /// versions, for example to the compiler versions which were approved internally.
+    #[clap(long, default_value_t, value_name = "SOURCE")]
+    pub release_source: ReleaseSourceOrFile,
+
+    /// Also take the pre-release of the upcoming Rust version into account
+    ///
+    /// When set to `beta`, the current beta toolchain is considered in addition to the stable
+    /// releases. When set to `nightly`, both the current beta and nightly toolchains are
+    /// considered. This is useful for crates which track upcoming Rust releases.
+    #[clap(long, possible_values = ReleaseChannel::variants(), default_value_t, value_name = "CHANNEL")]
+    pub channel: ReleaseChannel,
+
+    /// Also take the beta pre-release of the upcoming Rust version into account
+    ///
+    /// Shorthand for `--channel beta`.
+    #[clap(long, conflicts_with = "channel")]
+    pub include_prerelease: bool,
+
+    /// Only take the locally installed toolchains into account
+    ///
+    /// The release index is not fetched, and no toolchains are installed. Instead, the search
+    /// space is made up of the stable toolchains listed by `rustup toolchain list`, which allows
+    /// cargo-msrv to be used in air-gapped environments. Cargo's `--offline` flag is passed on to
+    /// the check command, so dependencies are only taken from the local registry cache.
+    #[clap(long)]
+    pub offline: bool,
+
+    /// Fetch the release index, even if a cached index is available
+    #[clap(long)]
+    pub refresh_index: bool,
+
+    /// How long, in seconds, a cached release index may be used before it is fetched again
+    ///
+    /// The release index is cached in the `cargo-msrv` folder of the user's cache directory.
+    /// A value of 0 disables the use of the cached index.
+    #[clap(long, value_name = "SECONDS", default_value_t = 86400)]
+    pub index_cache_ttl: u64,
+
+    /// Fetch the release index through the given HTTP(S) proxy
+    ///
+    /// Defaults to the proxy given by the `HTTPS_PROXY` environment variable, if any. The proxy
+    /// is also used by rustup, when installing toolchains.
+    #[clap(long, value_name = "URL")]
+    pub proxy: Option<String>,
+
+    /// Also trust the certificate authorities in the given PEM bundle
+    ///
+    /// Useful on networks which intercept TLS connections with an internal certificate
+    /// authority. The bundle is also used by rustup, when installing toolchains.
+    #[clap(long, value_name = "FILE")]
+    pub cacert: Option<PathBuf>,
+
+    /// Install toolchains from the given (mirrored) distribution server
+    ///
+    /// Passed on to rustup as `RUSTUP_DIST_SERVER`. If the mirror is a local directory (or
+    /// `file://` URL), the `rust-dist` release source lists the releases from the channel
+    /// manifests in its `dist` folder.
+    #[clap(long, value_name = "URL")]
+    pub dist_url: Option<String>,
+
+    /// Read the releases from a local copy of the Rust changelog (RELEASES.md)
+    ///
+    /// Used by the `rust-changelog` release source, instead of fetching the changelog.
+    #[clap(long, value_name = "FILE")]
+    pub changelog_path: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub enum ReleaseSourceOrFile {
+    Source(ReleaseSource),
+    File(PathBuf),
+}
+
+impl Default for ReleaseSourceOrFile {
+    fn default() -> Self {
+        Self::Source(ReleaseSource::default())
+    }
+}
+
+impl fmt::Display for ReleaseSourceOrFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(source) => write!(f, "{}", source),
+            Self::File(path) => write!(f, "file://{}", path.display()),
+        }
+    }
+}
+
+impl FromStr for ReleaseSourceOrFile {
+    type Err = ParseReleaseSourceOrFileError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.strip_prefix("file://") {
+            Some("") => Err(ParseReleaseSourceOrFileError::MissingPath),
+            Some(path) => Ok(Self::File(PathBuf::from(path))),
+            None => ReleaseSource::from_str(input)
+                .map(Self::Source)
+                .map_err(|_| ParseReleaseSourceOrFileError::Unknown(input.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseReleaseSourceOrFileError {
+    #[error("Release source '{0}' is not supported, expected 'rust-changelog', 'rust-dist' or 'file://<path>'")]
+    Unknown(String),
+    #[error("The 'file://' release source requires the path of a releases file, e.g. 'file://releases.toml'")]
+    MissingPath,
 }
 
 #[derive(Debug)]
@@ -48,13 +165,54 @@ impl EditionOrVersion {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug)]
+pub enum VersionOrChannel {
+    Version(BareVersion),
+    Channel(ReleaseChannel),
+}
+
+impl FromStr for VersionOrChannel {
+    type Err = ParseVersionOrChannelError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "stable" => Ok(Self::Channel(ReleaseChannel::Stable)),
+            "beta" => Ok(Self::Channel(ReleaseChannel::Beta)),
+            version => BareVersion::from_str(version)
+                .map(Self::Version)
+                .map_err(|err| {
+                    ParseVersionOrChannelError::VersionOrChannel(version.to_string(), err)
+                }),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseVersionOrChannelError {
+    #[error("Value '{0}' could not be parsed as a valid Rust version, or as one of the channels 'stable' or 'beta': {1}")]
+    VersionOrChannel(String, bare_version::Error),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize)]
 pub enum Edition {
+    #[serde(rename = "2015")]
     Edition2015,
+    #[serde(rename = "2018")]
     Edition2018,
+    #[serde(rename = "2021")]
     Edition2021,
 }
 
+impl fmt::Display for Edition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Edition2015 => write!(f, "2015"),
+            Self::Edition2018 => write!(f, "2018"),
+            Self::Edition2021 => write!(f, "2021"),
+        }
+    }
+}
+
 impl FromStr for Edition {
     type Err = ParseEditionError;
 
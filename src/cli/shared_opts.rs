@@ -1,6 +1,7 @@
 use crate::config::{OutputFormat, TracingTargetOption};
 
 use crate::log_level::LogLevel;
+use crate::msrv_resolver::MsrvKey;
 use clap::AppSettings;
 use clap::ArgGroup;
 use clap::Args;
@@ -19,6 +20,27 @@ pub struct SharedOpts {
     #[clap(long, value_name = "Cargo Manifest", global = true)]
     pub manifest_path: Option<PathBuf>,
 
+    /// The keys of the Cargo manifest from which the declared MSRV of a crate is read, from the
+    /// highest to the lowest priority
+    ///
+    /// Defaults to `rust-version,metadata-msrv`: the `package.rust-version` is used if present,
+    /// and the `package.metadata.msrv` otherwise. Keys which are left out are not read.
+    #[clap(
+        long = "msrv-key-priority",
+        possible_values = MsrvKey::variants(),
+        use_value_delimiter = true,
+        value_name = "KEYS",
+        global = true
+    )]
+    pub msrv_key_priority: Vec<MsrvKey>,
+
+    /// Don't parse the Cargo manifest of a dependency when `cargo metadata` doesn't output its MSRV
+    ///
+    /// Versions of Cargo prior to 1.58 don't output the `rust-version` of dependencies, in which
+    /// case their Cargo manifests are parsed as a workaround.
+    #[clap(long, global = true)]
+    pub no_manifest_workaround: bool,
+
     #[clap(flatten)]
     pub user_output_opts: UserOutputOpts,
 
@@ -38,9 +60,40 @@ pub struct UserOutputOpts {
     )]
     pub output_format: OutputFormat,
 
+    /// Write the output to the given file
+    ///
+    /// Used by output formats which produce a report, such as `junit`, `sarif` and `html`.
+    /// Defaults to `junit.xml` for the `junit` output format, `cargo-msrv.sarif` for the `sarif`
+    /// output format, and `cargo-msrv.html` for the `html` output format.
+    #[clap(long, value_name = "FILE", global = true)]
+    pub output_file: Option<PathBuf>,
+
+    /// Write a JSON report with all events to the given file
+    ///
+    /// The report is written regardless of the output format, and contains the same document as
+    /// the `json` output format, including the search space, the result and duration of each
+    /// check, and the MSRV.
+    #[clap(long, value_name = "FILE", global = true)]
+    pub write_report: Option<PathBuf>,
+
     /// Disable user output
     #[clap(long, global = true)]
     pub no_user_output: bool,
+
+    /// Only print the final result, such as the MSRV, to stdout
+    ///
+    /// Meant to be used in scripts, e.g. `$(cargo msrv find -q)`. Errors are still printed to
+    /// stderr. Only applies to the `human` output format.
+    #[clap(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Print more details about each check; may be given twice
+    ///
+    /// With `-v`, the command line of each check is printed. With `-vv`, the working directory
+    /// and the environment variables set for each check are printed as well. Only applies to the
+    /// `human` output format.
+    #[clap(short, long, global = true, parse(from_occurrences))]
+    pub verbose: u64,
 }
 
 #[derive(Debug, Args)]
@@ -63,4 +116,12 @@ pub struct DebugOutputOpts {
     /// Specify the severity of logs which should be
     #[clap(long, default_value_t, value_name = "LEVEL", global = true)]
     pub log_level: LogLevel,
+
+    /// Also write timestamped, structured (JSON) logs to the given file
+    ///
+    /// The logs include each reported event, and the full command line, exit code and duration
+    /// of each spawned process. Useful to debug failures on CI after the fact. Unlike the log
+    /// target, the file is overwritten on each run.
+    #[clap(long, value_name = "FILE", global = true, conflicts_with = "no-log")]
+    pub log_file: Option<PathBuf>,
 }
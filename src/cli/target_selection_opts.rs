@@ -0,0 +1,39 @@
+use clap::AppSettings;
+use clap::Args;
+
+// Cli Options to select the Cargo targets which are checked by the check command
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "TARGET SELECTION OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub struct TargetSelectionOpts {
+    /// Check all targets of the crate, including examples, tests and benchmarks
+    ///
+    /// Passed on to the check command, using Cargo's `--all-targets` flag. By default, only the
+    /// library and binary targets are checked.
+    #[clap(long)]
+    pub all_targets: bool,
+
+    /// Check the examples of the crate
+    ///
+    /// Passed on to the check command, using Cargo's `--examples` flag.
+    #[clap(long)]
+    pub examples: bool,
+
+    /// Check the benchmarks of the crate
+    ///
+    /// Passed on to the check command, using Cargo's `--benches` flag.
+    #[clap(long)]
+    pub benches: bool,
+
+    /// Check the tests of the crate
+    ///
+    /// Passed on to the check command, using Cargo's `--tests` flag.
+    #[clap(long)]
+    pub tests: bool,
+}
+
+impl TargetSelectionOpts {
+    /// Whether any of the target selection options were given.
+    pub fn is_present(&self) -> bool {
+        self.all_targets || self.examples || self.benches || self.tests
+    }
+}
@@ -1,5 +1,7 @@
+use crate::config::CheckRunner;
 use clap::AppSettings;
 use clap::Args;
+use std::path::PathBuf;
 
 // Cli Options for commands which invoke Rust toolchains, such as the top level cargo msrv command
 // (find) or cargo msrv verify
@@ -7,6 +9,145 @@ use clap::Args;
 #[clap(next_help_heading = "TOOLCHAIN OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
 pub struct ToolchainOpts {
     /// Check against a custom target (instead of the rustup default)
-    #[clap(long, value_name = "TARGET")]
-    pub target: Option<String>,
+    ///
+    /// May be given multiple times. When multiple targets are given, each toolchain is checked
+    /// against every target, by installing the target with `rustup target add` and passing
+    /// `--target <TARGET>` to the check command. A toolchain is only considered compatible if the
+    /// crate can be built for all targets.
+    #[clap(long, value_name = "TARGET", multiple_occurrences = true)]
+    pub target: Vec<String>,
+
+    /// Install and run the toolchains for the given host triple, instead of the default host
+    ///
+    /// Checks each Rust version with the `<version>-<HOST>` toolchain, e.g. to check against the
+    /// GNU (`x86_64-pc-windows-gnu`) or MSVC (`x86_64-pc-windows-msvc`) flavour of the toolchain
+    /// on Windows, regardless of the default host of rustup. A `--target` given alongside is
+    /// passed to the check command as compilation target.
+    #[clap(long, value_name = "TRIPLE")]
+    pub host: Option<String>,
+
+    /// Install the given components, such as `clippy`, with each toolchain
+    ///
+    /// Required when the check command needs a component, e.g. `--check-command clippy`. A
+    /// toolchain for which a component is not available, as is the case for some components on
+    /// old toolchains, is considered incompatible. Has no effect with `--toolchain-dir` or the
+    /// `docker` runner, which provide the toolchains as they are.
+    #[clap(
+        long,
+        value_name = "COMPONENT",
+        multiple_occurrences = true,
+        use_value_delimiter = true
+    )]
+    pub component: Vec<String>,
+
+    /// Don't use cached check results from previous runs
+    ///
+    /// By default, cargo-msrv remembers which toolchains were found to be compatible with your
+    /// crate, and skips checks which have succeeded before, given that neither the crate sources,
+    /// the lockfile nor the check command have changed. The cache is stored in the
+    /// `cargo-msrv` folder of the Cargo target directory.
+    #[clap(long)]
+    pub no_cache: bool,
+
+    /// Resume an interrupted search
+    ///
+    /// The state of the search is written to `target/cargo-msrv/state.json` after every check.
+    /// The versions which were decided before the search was interrupted are not checked again.
+    #[clap(long, conflicts_with = "no-cache")]
+    pub resume: bool,
+
+    /// Use a dedicated Cargo target directory for each toolchain
+    ///
+    /// Switching between toolchains invalidates the build artifacts in a shared target
+    /// directory. With this flag, each check uses the `msrv/<toolchain>` folder of the Cargo
+    /// target directory instead, so build artifacts are reused by later checks of the same
+    /// toolchain, also across runs.
+    #[clap(long)]
+    pub per_toolchain_target_dir: bool,
+
+    /// Use the toolchains in the given directory, instead of installing them with rustup
+    ///
+    /// Each toolchain is a folder which is named after its version, with or without the target,
+    /// e.g. `1.60.0` or `1.60.0-x86_64-unknown-linux-gnu`, and contains the `bin` folder of the
+    /// toolchain. The search space consists of the toolchains in the directory. Useful in
+    /// environments without rustup, where toolchains are provided by e.g. Nix or a package
+    /// manager.
+    #[clap(long, value_name = "DIR")]
+    pub toolchain_dir: Option<PathBuf>,
+
+    /// Add a locally built toolchain, such as a compiler in development, to the search space
+    ///
+    /// The toolchain is linked with `rustup toolchain link`, and checked as a pre-release of the
+    /// Rust version reported by its `rustc`, alongside the released versions. The path is the
+    /// folder which contains the `bin` folder of the toolchain, e.g.
+    /// `build/x86_64-unknown-linux-gnu/stage1` in the rust repository. May be given multiple
+    /// times. Only applies when searching for the MSRV.
+    #[clap(
+        long,
+        value_name = "PATH",
+        multiple_occurrences = true,
+        conflicts_with = "toolchain-dir"
+    )]
+    pub toolchain_path: Vec<PathBuf>,
+
+    /// How the check command is run with each toolchain
+    ///
+    /// With `docker`, each check runs in an official `rust:<version>` container, in which the
+    /// crate is mounted read-only, and build artifacts are written to a separate volume. This
+    /// gives hermetic results, and leaves the rustup installation of the host untouched.
+    #[clap(
+        long,
+        possible_values = CheckRunner::variants(),
+        default_value_t,
+        value_name = "RUNNER",
+        conflicts_with = "toolchain-dir"
+    )]
+    pub runner: CheckRunner,
+
+    /// Uninstall the toolchains which were installed for this run, once it completes
+    ///
+    /// The toolchains which were installed before the run are kept. Useful when searching
+    /// leaves many toolchains behind, which take up a lot of disk space. Has no effect with
+    /// `--offline`, `--toolchain-dir` or the `docker` runner, which don't install toolchains
+    /// with rustup.
+    #[clap(long)]
+    pub uninstall_after: bool,
+
+    /// Abort when more than the given number of toolchains would be downloaded
+    ///
+    /// Before the checks are run, the number of toolchains which are not installed yet, and may
+    /// be downloaded by rustup, is estimated from the search space and the search method.
+    #[clap(long, value_name = "COUNT")]
+    pub max_downloads: Option<u64>,
+
+    /// Abort when the toolchains which would be downloaded don't fit on the disk
+    ///
+    /// By default, a note is shown when the estimated size of the toolchains exceeds the
+    /// available disk space in the rustup home directory.
+    #[clap(long)]
+    pub require_disk_space: bool,
+
+    /// Abort a check command which runs longer than the given number of seconds
+    ///
+    /// Very old toolchains occasionally hang on some build scripts. A check which is aborted
+    /// is considered failed, and the toolchain incompatible.
+    #[clap(long, value_name = "SECONDS")]
+    pub check_timeout: Option<u64>,
+
+    /// Report a check which is aborted by --check-timeout as skipped, instead of as failed
+    #[clap(long, requires = "check-timeout")]
+    pub treat_timeout_as_skip: bool,
+
+    /// How often to retry the installation of a toolchain when it fails
+    ///
+    /// Network hiccups may cause `rustup install` to fail. A failed installation is retried
+    /// this many times before the run fails.
+    #[clap(long, value_name = "COUNT", default_value_t = 2)]
+    pub install_retries: u32,
+
+    /// How long, in seconds, to wait before retrying a failed installation
+    ///
+    /// The delay doubles with each further retry.
+    #[clap(long, value_name = "SECONDS", default_value_t = 2)]
+    pub install_retry_backoff: u64,
 }
@@ -0,0 +1,31 @@
+use clap::AppSettings;
+use clap::Args;
+
+// Cli Options to select the workspace members, for commands which support the `--workspace` flag,
+// such as the top level cargo msrv command (find) or cargo msrv verify
+#[derive(Debug, Args)]
+#[clap(next_help_heading = "WORKSPACE OPTIONS", setting = AppSettings::DeriveDisplayOrder)]
+pub struct WorkspaceOpts {
+    /// Only run for the given workspace member
+    ///
+    /// May be given multiple times. Implies `--workspace`, limited to the given members.
+    #[clap(
+        long,
+        short = 'p',
+        value_name = "SPEC",
+        multiple_occurrences = true,
+        conflicts_with = "exclude"
+    )]
+    pub package: Vec<String>,
+
+    /// Exclude the given workspace member
+    ///
+    /// May be given multiple times. Can only be used together with `--workspace`.
+    #[clap(
+        long,
+        value_name = "SPEC",
+        multiple_occurrences = true,
+        requires = "workspace"
+    )]
+    pub exclude: Vec<String>,
+}
@@ -1,14 +1,37 @@
+use std::collections::BTreeMap;
 use std::ffi::{OsStr, OsString};
-use std::path::Path;
-use std::process::{Command, Stdio};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::check::{cancel, skip};
 
 use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::reporter::event::{SubprocessFinished, SubprocessStarted};
+use crate::reporter::Reporter;
+use process_group::ProcessGroup;
+
+mod process_group;
+
+/// The id of the next process which is spawned, which identifies the process in the
+/// `SubprocessStarted` and `SubprocessFinished` events.
+static NEXT_PROCESS_ID: AtomicU64 = AtomicU64::new(1);
 
 pub struct RustupCommand {
     command: Command,
     args: Vec<OsString>,
     stdout: Stdio,
     stderr: Stdio,
+    /// Whether the program of a toolchain is run directly, instead of through rustup, in which
+    /// case no rustup subcommand is passed.
+    direct: bool,
+    /// Whether the process is spawned in its own process group, see
+    /// [RustupCommand::with_process_group](RustupCommand::with_process_group).
+    process_group: bool,
 }
 
 impl RustupCommand {
@@ -18,9 +41,52 @@ impl RustupCommand {
             args: Vec::new(),
             stdout: Stdio::null(),
             stderr: Stdio::null(),
+            direct: false,
+            process_group: false,
+        }
+    }
+
+    /// A command which runs a program with a toolchain which is not managed by rustup, from the
+    /// `bin` folder of the toolchain.
+    ///
+    /// The `bin` folder is prepended to the `PATH`, so a program like `cargo` uses the `rustc` of
+    /// the same toolchain. Programs which are not part of the toolchain are taken from the `PATH`.
+    pub fn toolchain_program(bin_dir: &Path, program: &str) -> Self {
+        let binary = toolchain_binary(bin_dir, program);
+
+        let mut command = if binary.is_file() {
+            Self::program(binary)
+        } else {
+            Self::program(program)
+        };
+
+        let path = std::env::var_os("PATH").unwrap_or_default();
+        let paths = std::iter::once(bin_dir.to_path_buf()).chain(std::env::split_paths(&path));
+
+        if let Ok(path) = std::env::join_paths(paths) {
+            command = command.with_env("PATH", path);
+        }
+
+        command.with_env("RUSTC", toolchain_binary(bin_dir, "rustc"))
+    }
+
+    /// A command which runs the given program directly, instead of through rustup.
+    pub fn program(program: impl AsRef<OsStr>) -> Self {
+        Self {
+            command: Command::new(program),
+            args: Vec::new(),
+            stdout: Stdio::null(),
+            stderr: Stdio::null(),
+            direct: true,
+            process_group: false,
         }
     }
 
+    pub fn with_env(mut self, key: impl AsRef<OsStr>, value: impl AsRef<OsStr>) -> Self {
+        let _ = self.command.env(key, value);
+        self
+    }
+
     pub fn with_dir(mut self, path: impl AsRef<Path>) -> Self {
         let _ = self.command.current_dir(path);
         self
@@ -48,6 +114,50 @@ impl RustupCommand {
         self
     }
 
+    /// Spawn the process in its own process group, so when it is interrupted by
+    /// [RustupChild::wait_interruptible](RustupChild::wait_interruptible), the processes it
+    /// spawned, such as `rustc` and build scripts, are killed as well.
+    ///
+    /// The process no longer receives the interrupt signal of the terminal, so it should only be
+    /// used for processes which are waited on with `wait_interruptible`, which kills the process
+    /// when the run is cancelled.
+    pub fn with_process_group(mut self) -> Self {
+        self.process_group = true;
+        self
+    }
+
+    /// The program and arguments which would be spawned with the given `rustup` command.
+    pub fn command_line(&self, cmd: &OsStr) -> Vec<String> {
+        let subcommand = if self.direct { None } else { Some(cmd) };
+
+        std::iter::once(self.command.get_program())
+            .chain(subcommand)
+            .chain(self.args.iter().map(OsString::as_os_str))
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    /// The environment variables which are set for the command, in addition to the inherited
+    /// environment.
+    pub fn envs(&self) -> BTreeMap<String, String> {
+        self.command
+            .get_envs()
+            .filter_map(|(key, value)| {
+                value.map(|value| {
+                    (
+                        key.to_string_lossy().into_owned(),
+                        value.to_string_lossy().into_owned(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// The working directory of the command, if it is not the current directory.
+    pub fn dir(&self) -> Option<&Path> {
+        self.command.get_current_dir()
+    }
+
     /// Execute `rustup run [...]`
     pub fn run(self) -> TResult<RustupOutput> {
         self.execute(OsStr::new("run"))
@@ -69,39 +179,409 @@ impl RustupCommand {
     /// * [RustupCommand::run](RustupCommand::run)
     /// * [RustupCommand::install](RustupCommand::run)
     /// * [RustupCommand::show](RustupCommand::run)
-    pub fn execute(mut self, cmd: &OsStr) -> TResult<RustupOutput> {
-        debug!(
-            cmd = ?cmd,
-            args = ?self.args.as_slice()
+    pub fn execute(self, cmd: &OsStr) -> TResult<RustupOutput> {
+        self.spawn(cmd)?.wait()
+    }
+
+    /// Execute `rustup run [...]`, and report that the process was started and has finished.
+    pub fn run_reported(self, reporter: &impl Reporter) -> TResult<RustupOutput> {
+        self.execute_reported(OsStr::new("run"), reporter)
+    }
+
+    /// Execute a given `rustup` command, and report that the process was started and has
+    /// finished.
+    ///
+    /// See also [RustupCommand::execute](RustupCommand::execute).
+    pub fn execute_reported(self, cmd: &OsStr, reporter: &impl Reporter) -> TResult<RustupOutput> {
+        let output = self.spawn_reported(cmd, reporter)?.wait()?;
+        reporter.report_event(output.finished().clone())?;
+
+        Ok(output)
+    }
+
+    /// Spawn a given `rustup` command, and report that the process was started.
+    ///
+    /// The caller is responsible for reporting that the process has finished, see
+    /// [RustupOutput::finished](RustupOutput::finished) and
+    /// [Completion::finished](Completion::finished).
+    pub fn spawn_reported(self, cmd: &OsStr, reporter: &impl Reporter) -> TResult<RustupChild> {
+        let child = self.spawn(cmd)?;
+        reporter.report_event(child.started())?;
+
+        Ok(child)
+    }
+
+    /// Spawn a given `rustup` command, without waiting for it to finish.
+    ///
+    /// See also [RustupCommand::execute](RustupCommand::execute).
+    pub fn spawn(mut self, cmd: &OsStr) -> TResult<RustupChild> {
+        let process = Process {
+            id: NEXT_PROCESS_ID.fetch_add(1, Ordering::Relaxed),
+            argv: self.command_line(cmd),
+            env: self.envs(),
+            dir: self.dir().map(Path::to_path_buf),
+            started: Instant::now(),
+        };
+
+        info!(
+            id = process.id,
+            command = process.argv.join(" ").as_str(),
+            dir = ?process.dir,
+            env = ?process.env,
+            "spawning process"
         );
 
-        self.command.arg(&cmd);
+        if !self.direct {
+            self.command.arg(&cmd);
+        }
         self.command.args(self.args);
 
         self.command.stdout(self.stdout);
         self.command.stderr(self.stderr);
 
+        if self.process_group {
+            process_group::configure(&mut self.command);
+        }
+
         let child = self.command.spawn().map_err(|error| CargoMSRVError::Io {
             error,
             source: IoErrorSource::SpawnProcess(cmd.to_owned()),
         })?;
-        let output = child
+
+        let group = if self.process_group {
+            Some(ProcessGroup::of(&child))
+        } else {
+            None
+        };
+
+        Ok(RustupChild {
+            child,
+            cmd: cmd.to_owned(),
+            process,
+            group,
+        })
+    }
+}
+
+/// What's known about a spawned process, to describe it in events and logs.
+struct Process {
+    id: u64,
+    /// The program and the arguments which were passed to it
+    argv: Vec<String>,
+    env: BTreeMap<String, String>,
+    dir: Option<PathBuf>,
+    started: Instant,
+}
+
+impl Process {
+    /// Log that the process exited, with its exit code and how long it ran, and describe it as
+    /// an event.
+    ///
+    /// The `status` is `None` if the process was killed, because its check was skipped or timed
+    /// out.
+    fn finished(&self, status: Option<ExitStatus>) -> SubprocessFinished {
+        let duration = self.started.elapsed();
+
+        info!(
+            id = self.id,
+            command = self.argv.join(" ").as_str(),
+            exit_code = ?status.and_then(|status| status.code()),
+            success = status.map_or(false, |status| status.success()),
+            killed = status.is_none(),
+            duration_secs = duration.as_secs_f64(),
+            "process exited"
+        );
+
+        match status {
+            Some(status) => SubprocessFinished::exited(
+                self.id,
+                self.argv.clone(),
+                status.code(),
+                status.success(),
+                duration,
+            ),
+            None => SubprocessFinished::killed(self.id, self.argv.clone(), duration),
+        }
+    }
+}
+
+/// A spawned `rustup` process.
+pub struct RustupChild {
+    child: std::process::Child,
+    cmd: OsString,
+    process: Process,
+    group: Option<ProcessGroup>,
+}
+
+impl RustupChild {
+    /// The event which reports that this process was spawned.
+    pub fn started(&self) -> SubprocessStarted {
+        SubprocessStarted::new(
+            self.process.id,
+            self.process.argv.clone(),
+            self.process.env.clone(),
+            self.process.dir.clone(),
+        )
+    }
+
+    /// Wait for the process to finish, and collect its output.
+    pub fn wait(self) -> TResult<RustupOutput> {
+        let cmd = self.cmd;
+        let output = self
+            .child
             .wait_with_output()
             .map_err(|error| CargoMSRVError::Io {
                 error,
-                source: IoErrorSource::WaitForProcessAndCollectOutput(cmd.to_owned()),
+                source: IoErrorSource::WaitForProcessAndCollectOutput(cmd),
             })?;
 
+        let finished = self.process.finished(Some(output.status));
+
         Ok(RustupOutput {
             output,
+            finished,
             stdout: once_cell::sync::OnceCell::new(),
             stderr: once_cell::sync::OnceCell::new(),
         })
     }
+
+    /// Kill the process, and, if it was spawned in its own process group, its descendants.
+    fn kill(&mut self) {
+        match &self.group {
+            Some(group) => group.kill(&mut self.child),
+            None => {
+                let _ = self.child.kill();
+            }
+        }
+    }
+
+    /// Wait for the process to finish, and collect its output, while passing each line which is
+    /// written to stderr to `on_line`, unless the process is interrupted.
+    ///
+    /// The process is killed when it runs longer than the `timeout`, or, if `skippable`, when a
+    /// skip is requested while waiting. If it was spawned in its own process group, the processes
+    /// it spawned are killed as well. When the run is cancelled, the process is killed, and
+    /// [`CargoMSRVError::Cancelled`] is returned. Stops passing lines, but keeps collecting the output, if
+    /// `on_line` returns an error. The first such error is returned once the process has
+    /// finished.
+    ///
+    /// See also [skip::request_skip](crate::check::skip::request_skip).
+    pub fn wait_interruptible(
+        mut self,
+        timeout: Option<Duration>,
+        skippable: bool,
+        mut on_line: impl FnMut(&str) -> TResult<()>,
+    ) -> TResult<Completion> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+        // The pipes are drained while waiting, so the process doesn't block on a full pipe
+        let stdout = self.child.stdout.take().map(drain);
+        let stderr = self.child.stderr.take().map(drain_lines);
+
+        let mut failure = None;
+        let mut pass_lines = |lines: &mpsc::Receiver<String>, wait: bool| {
+            let mut pass = |line: String| {
+                if failure.is_none() {
+                    failure = on_line(&line).err();
+                }
+            };
+
+            if wait {
+                lines.iter().for_each(&mut pass);
+            } else {
+                lines.try_iter().for_each(&mut pass);
+            }
+        };
+
+        let status = loop {
+            if let Some((lines, _)) = &stderr {
+                pass_lines(lines, false);
+            }
+
+            let exited = self.child.try_wait().map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::WaitForProcessAndCollectOutput(self.cmd.clone()),
+            })?;
+
+            if let Some(status) = exited {
+                break status;
+            }
+
+            if cancel::is_cancelled() {
+                self.kill();
+                let _ = self.child.wait();
+
+                self.process.finished(None);
+
+                return Err(CargoMSRVError::Cancelled);
+            }
+
+            let interruption: Option<fn(SubprocessFinished) -> Completion> = if skippable
+                && skip::take_skip_request()
+            {
+                Some(Completion::Skipped)
+            } else if timeout.map_or(false, |timeout| self.process.started.elapsed() >= timeout) {
+                Some(Completion::TimedOut)
+            } else {
+                None
+            };
+
+            if let Some(interruption) = interruption {
+                self.kill();
+                let _ = self.child.wait();
+
+                return Ok(interruption(self.process.finished(None)));
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        };
+
+        let finished = self.process.finished(Some(status));
+
+        // Pass the lines which were written just before the process exited
+        if let Some((lines, _)) = &stderr {
+            pass_lines(lines, true);
+        }
+
+        if let Some(error) = failure {
+            return Err(error);
+        }
+
+        let collect = |handle: Option<thread::JoinHandle<Vec<u8>>>| {
+            handle
+                .and_then(|handle| handle.join().ok())
+                .unwrap_or_default()
+        };
+
+        Ok(Completion::Finished(RustupOutput {
+            output: std::process::Output {
+                status,
+                stdout: collect(stdout),
+                stderr: collect(stderr.map(|(_, handle)| handle)),
+            },
+            finished,
+            stdout: once_cell::sync::OnceCell::new(),
+            stderr: once_cell::sync::OnceCell::new(),
+        }))
+    }
+}
+
+/// How a process which may be interrupted ended.
+pub enum Completion {
+    /// The process finished by itself
+    Finished(RustupOutput),
+    /// The process was killed, because a skip was requested
+    Skipped(SubprocessFinished),
+    /// The process was killed, because it ran longer than the timeout
+    TimedOut(SubprocessFinished),
+}
+
+impl Completion {
+    /// The event which reports that the process has finished, or was killed.
+    pub fn finished(&self) -> &SubprocessFinished {
+        match self {
+            Self::Finished(output) => output.finished(),
+            Self::Skipped(finished) | Self::TimedOut(finished) => finished,
+        }
+    }
+}
+
+impl RustupChild {
+    /// Wait for the process to finish, and collect its output, while passing each line which is
+    /// written to stderr to `on_line` as soon as it is written.
+    ///
+    /// Stops reading lines, but keeps collecting the output, if `on_line` returns an error. The
+    /// first such error is returned once the process has finished.
+    pub fn wait_with_stderr_lines(
+        mut self,
+        mut on_line: impl FnMut(&str) -> TResult<()>,
+    ) -> TResult<RustupOutput> {
+        let stdout = self.child.stdout.take().map(drain);
+
+        let mut stderr = Vec::new();
+        let mut failure = None;
+
+        if let Some(pipe) = self.child.stderr.take() {
+            for line in BufReader::new(pipe).split(b'\n').flatten() {
+                if failure.is_none() {
+                    let text = String::from_utf8_lossy(&line);
+                    failure = on_line(text.trim_end()).err();
+                }
+
+                stderr.extend_from_slice(&line);
+                stderr.push(b'\n');
+            }
+        }
+
+        let status = self.child.wait().map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::WaitForProcessAndCollectOutput(self.cmd.clone()),
+        })?;
+
+        let finished = self.process.finished(Some(status));
+
+        if let Some(error) = failure {
+            return Err(error);
+        }
+
+        Ok(RustupOutput {
+            output: std::process::Output {
+                status,
+                stdout: stdout
+                    .and_then(|handle| handle.join().ok())
+                    .unwrap_or_default(),
+                stderr,
+            },
+            finished,
+            stdout: once_cell::sync::OnceCell::new(),
+            stderr: once_cell::sync::OnceCell::new(),
+        })
+    }
+}
+
+/// The path of a binary in the `bin` folder of a toolchain.
+fn toolchain_binary(bin_dir: &Path, name: &str) -> PathBuf {
+    let mut file = OsString::from(name);
+    file.push(std::env::consts::EXE_SUFFIX);
+
+    bin_dir.join(file)
+}
+
+/// Read the given pipe to its end on a separate thread.
+fn drain(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+/// Read the given pipe to its end on a separate thread, while sending each line as soon as it
+/// is read.
+fn drain_lines(
+    pipe: impl Read + Send + 'static,
+) -> (mpsc::Receiver<String>, thread::JoinHandle<Vec<u8>>) {
+    let (sender, receiver) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        let mut buffer = Vec::new();
+
+        for line in BufReader::new(pipe).split(b'\n').flatten() {
+            let _ = sender.send(String::from_utf8_lossy(&line).trim_end().to_string());
+
+            buffer.extend_from_slice(&line);
+            buffer.push(b'\n');
+        }
+
+        buffer
+    });
+
+    (receiver, handle)
 }
 
 pub struct RustupOutput {
     output: std::process::Output,
+    finished: SubprocessFinished,
     stdout: once_cell::sync::OnceCell<String>,
     stderr: once_cell::sync::OnceCell<String>,
 }
@@ -122,4 +602,9 @@ impl RustupOutput {
     pub fn exit_status(&self) -> std::process::ExitStatus {
         self.output.status
     }
+
+    /// The event which reports that the process has finished.
+    pub fn finished(&self) -> &SubprocessFinished {
+        &self.finished
+    }
 }
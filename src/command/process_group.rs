@@ -0,0 +1,182 @@
+//! Kill a spawned process together with the processes it spawned in turn.
+//!
+//! When a check is interrupted, killing only the `cargo` process would leave the `rustc` and build
+//! script processes which it spawned running, and these keep the target directory locked. On Unix,
+//! the process is spawned as leader of its own process group, and the group is killed. On
+//! Windows, the process is assigned to a job object, and the job is terminated.
+
+use std::process::{Child, Command};
+
+/// Spawn the process of the command as leader of a new process group.
+#[cfg(unix)]
+pub(super) fn configure(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+
+    // SAFETY: `setpgid` is async-signal-safe, and doesn't allocate
+    unsafe {
+        command.pre_exec(|| {
+            if libc::setpgid(0, 0) == 0 {
+                Ok(())
+            } else {
+                Err(std::io::Error::last_os_error())
+            }
+        });
+    }
+}
+
+#[cfg(not(unix))]
+pub(super) fn configure(_command: &mut Command) {}
+
+/// The spawned process, and the processes it spawns.
+pub(super) struct ProcessGroup {
+    #[cfg(windows)]
+    job: Option<job::Job>,
+}
+
+impl ProcessGroup {
+    /// The group of the given process, which must have been spawned from a command prepared by
+    /// [`configure`].
+    pub(super) fn of(child: &Child) -> Self {
+        #[cfg(windows)]
+        {
+            Self {
+                job: job::Job::assign(child),
+            }
+        }
+
+        #[cfg(not(windows))]
+        {
+            let _ = child;
+            Self {}
+        }
+    }
+
+    /// Kill the process and its descendants. Falls back to killing only the process itself, if
+    /// the group can't be killed.
+    pub(super) fn kill(&self, child: &mut Child) {
+        #[cfg(unix)]
+        {
+            use std::convert::TryFrom;
+
+            // A negative pid signals each process in the process group
+            let killed = libc::pid_t::try_from(child.id())
+                .map(|pid| unsafe { libc::kill(-pid, libc::SIGKILL) } == 0)
+                .unwrap_or(false);
+
+            if killed {
+                return;
+            }
+        }
+
+        #[cfg(windows)]
+        {
+            if let Some(job) = &self.job {
+                if job.terminate() {
+                    return;
+                }
+            }
+        }
+
+        warn!(
+            pid = child.id(),
+            "unable to kill process group, killing process"
+        );
+
+        let _ = child.kill();
+    }
+}
+
+#[cfg(windows)]
+mod job {
+    use std::os::windows::io::AsRawHandle;
+    use std::process::Child;
+    use std::ptr;
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::jobapi2::{AssignProcessToJobObject, CreateJobObjectW, TerminateJobObject};
+    use winapi::um::winnt::HANDLE;
+
+    /// A job object, to which a process and the processes it spawns are assigned.
+    pub(super) struct Job(HANDLE);
+
+    // SAFETY: the handle of a job object may be used from any thread
+    unsafe impl Send for Job {}
+    unsafe impl Sync for Job {}
+
+    impl Job {
+        /// Create a job, and assign the process to it. Processes which the process spawns
+        /// afterwards are assigned to the job as well.
+        pub(super) fn assign(child: &Child) -> Option<Self> {
+            let handle = unsafe { CreateJobObjectW(ptr::null_mut(), ptr::null()) };
+
+            if handle.is_null() {
+                return None;
+            }
+
+            let job = Self(handle);
+            let assigned =
+                unsafe { AssignProcessToJobObject(job.0, child.as_raw_handle() as HANDLE) } != 0;
+
+            if assigned {
+                Some(job)
+            } else {
+                None
+            }
+        }
+
+        /// Terminate each process of the job, and whether this succeeded.
+        pub(super) fn terminate(&self) -> bool {
+            unsafe { TerminateJobObject(self.0, 1) != 0 }
+        }
+    }
+
+    impl Drop for Job {
+        fn drop(&mut self) {
+            unsafe {
+                CloseHandle(self.0);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    fn is_running(pid: libc::pid_t) -> bool {
+        unsafe { libc::kill(pid, 0) == 0 }
+    }
+
+    #[test]
+    fn kills_descendants() {
+        let mut command = Command::new("sh");
+        command
+            .args(["-c", "sleep 60 & echo $!; wait"])
+            .stdout(Stdio::piped());
+        configure(&mut command);
+
+        let mut child = command.spawn().unwrap();
+        let group = ProcessGroup::of(&child);
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let grandchild = line.trim().parse::<libc::pid_t>().unwrap();
+        assert!(is_running(grandchild));
+
+        group.kill(&mut child);
+        child.wait().unwrap();
+
+        // The orphaned grandchild is reaped by init, once it has been killed
+        let started = Instant::now();
+        while is_running(grandchild) && started.elapsed() < Duration::from_secs(10) {
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        assert!(!is_running(grandchild));
+    }
+}
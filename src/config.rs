@@ -1,12 +1,23 @@
 use clap::ArgEnum;
+use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use crate::cli::CargoCli;
+use crate::config::diff::DiffCmdConfig;
+use crate::config::features::Features;
 use crate::config::list::ListCmdConfig;
+use crate::config::lockfile_mode::LockfileMode;
+use crate::config::pin_lockfile::PinLockfileCmdConfig;
+use crate::config::policy::{MsrvPolicy, PolicyCmdConfig};
 use crate::config::set::SetCmdConfig;
+use crate::config::show::ShowCmdConfig;
+use crate::config::target_selection::TargetSelection;
+use crate::config::toolchain_file::ToolchainFilePin;
+use crate::config::upgrade::UpgradeCmdConfig;
 use crate::config::verify::VerifyCmdConfig;
 use crate::ctx::{ContextValues, LazyContext};
 use rust_releases::semver;
@@ -14,17 +25,57 @@ use rust_releases::semver;
 use crate::error::{CargoMSRVError, TResult};
 use crate::log_level::LogLevel;
 use crate::manifest::bare_version;
+use crate::msrv_resolver::MsrvResolver;
 
+pub(crate) mod check_command;
+pub(crate) mod diff;
+pub(crate) mod features;
+pub(crate) mod file;
 pub(crate) mod list;
+pub(crate) mod lockfile_mode;
+pub(crate) mod pin_lockfile;
+pub(crate) mod policy;
 pub(crate) mod set;
+pub(crate) mod show;
+pub(crate) mod target_selection;
+pub(crate) mod toolchain_file;
+pub(crate) mod upgrade;
 pub(crate) mod verify;
 
+/// How long a cached release index may be used by default, before it is fetched again.
+pub const DEFAULT_INDEX_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How often the installation of a toolchain is retried by default, when it fails.
+pub const DEFAULT_INSTALL_RETRIES: u32 = 2;
+
+/// How long to wait by default before the first retry of a failed toolchain installation.
+pub const DEFAULT_INSTALL_RETRY_BACKOFF: Duration = Duration::from_secs(2);
+
 #[derive(Debug, Clone, Copy)]
 pub enum OutputFormat {
     /// Progress bar rendered to stderr
     Human,
-    /// Json status updates printed to stdout
+    /// Json document with all status updates printed to stderr, once finished
     Json,
+    /// Json status updates printed to stderr as they happen, one per line
+    JsonLines,
+    /// GitHub Actions workflow commands printed to stdout, which are shown as annotations
+    Github,
+    /// JUnit XML test report, written to the output file
+    Junit,
+    /// TOML document with the final result, printed to stdout
+    Toml,
+    /// SARIF report of the verification result, written to the output file
+    Sarif,
+    /// GitHub flavored Markdown document with the result, printed to stdout
+    Markdown,
+    /// Standalone HTML document with a sortable table of the listed dependencies, written to the
+    /// output file
+    Html,
+    /// Graphviz DOT digraph of the listed dependencies, colored by MSRV, printed to stdout
+    Dot,
+    /// Only the resulting MSRV, printed to stdout, without any decoration
+    Minimal,
     /// No output -- meant to be used for debugging and testing
     None,
 }
@@ -40,6 +91,15 @@ impl fmt::Display for OutputFormat {
         match self {
             Self::Human => write!(f, "human"),
             Self::Json => write!(f, "json"),
+            Self::JsonLines => write!(f, "json-lines"),
+            Self::Github => write!(f, "github"),
+            Self::Junit => write!(f, "junit"),
+            Self::Toml => write!(f, "toml"),
+            Self::Sarif => write!(f, "sarif"),
+            Self::Markdown => write!(f, "markdown"),
+            Self::Html => write!(f, "html"),
+            Self::Dot => write!(f, "dot"),
+            Self::Minimal => write!(f, "minimal"),
             Self::None => write!(f, "none"),
         }
     }
@@ -52,6 +112,15 @@ impl FromStr for OutputFormat {
         match s {
             "human" => Ok(Self::Human),
             "json" => Ok(Self::Json),
+            "json-lines" => Ok(Self::JsonLines),
+            "github" => Ok(Self::Github),
+            "junit" => Ok(Self::Junit),
+            "toml" => Ok(Self::Toml),
+            "sarif" => Ok(Self::Sarif),
+            "markdown" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            "dot" => Ok(Self::Dot),
+            "minimal" => Ok(Self::Minimal),
             unknown => Err(CargoMSRVError::InvalidConfig(format!(
                 "Given output format '{}' is not valid",
                 unknown
@@ -62,11 +131,32 @@ impl FromStr for OutputFormat {
 
 impl OutputFormat {
     pub const JSON: &'static str = "json";
+    pub const JSON_LINES: &'static str = "json-lines";
+    pub const GITHUB: &'static str = "github";
+    pub const JUNIT: &'static str = "junit";
+    pub const TOML: &'static str = "toml";
+    pub const SARIF: &'static str = "sarif";
+    pub const MARKDOWN: &'static str = "markdown";
+    pub const HTML: &'static str = "html";
+    pub const DOT: &'static str = "dot";
+    pub const MINIMAL: &'static str = "minimal";
 
     /// A set of formats which may be given as a configuration option
     ///   through the CLI.
     pub fn custom_formats() -> &'static [&'static str] {
-        &["human", Self::JSON]
+        &[
+            "human",
+            Self::JSON,
+            Self::JSON_LINES,
+            Self::GITHUB,
+            Self::JUNIT,
+            Self::TOML,
+            Self::SARIF,
+            Self::MARKDOWN,
+            Self::HTML,
+            Self::DOT,
+            Self::MINIMAL,
+        ]
     }
 
     /// Parse the output format from the given `&str`.
@@ -77,11 +167,51 @@ impl OutputFormat {
     pub fn from_custom_format_str(item: &str) -> Self {
         match item {
             Self::JSON => Self::Json,
+            Self::JSON_LINES => Self::JsonLines,
+            Self::GITHUB => Self::Github,
+            Self::JUNIT => Self::Junit,
+            Self::TOML => Self::Toml,
+            Self::SARIF => Self::Sarif,
+            Self::MARKDOWN => Self::Markdown,
+            Self::HTML => Self::Html,
+            Self::DOT => Self::Dot,
+            Self::MINIMAL => Self::Minimal,
             _ => unreachable!(),
         }
     }
 }
 
+/// How much user output is printed by the `human` output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only the final result, e.g. the MSRV, printed to stdout, so it can be used in scripts
+    Quiet,
+    /// The progress of each check, and the result
+    Normal,
+    /// In addition, the command line of each check
+    Verbose,
+    /// In addition, the working directory and environment variables of each check
+    VeryVerbose,
+}
+
+impl Default for Verbosity {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+impl Verbosity {
+    /// The verbosity given by the number of `-v` flags, unless `-q` is given.
+    pub fn from_flags(quiet: bool, verbose: u64) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Self::Quiet,
+            (false, 0) => Self::Normal,
+            (false, 1) => Self::Verbose,
+            (false, _) => Self::VeryVerbose,
+        }
+    }
+}
+
 /// Gets a [`Config`] from the given matches, but sets output_format to None
 ///
 /// This is meant to be used for testing
@@ -104,6 +234,20 @@ pub enum Action {
     Set,
     // Shows the MSRV of the current crate as specified in the Cargo manifest
     Show,
+    // Diagnoses the environment in which cargo-msrv runs
+    Doctor,
+    // Suggests the newest versions of the dependencies which are compatible with the MSRV
+    Upgrade,
+    // Downgrades the locked dependencies which are not compatible with the MSRV
+    PinLockfile,
+    // Compares the MSRV of the crate at two git revisions
+    Diff,
+    // Verifies the MSRV again whenever the crate changes
+    Watch,
+    // Checks whether the MSRV complies with the MSRV policy
+    Policy,
+    // Prints the JSON Schema of the reported events
+    Schema,
 }
 
 impl From<Action> for &'static str {
@@ -114,6 +258,13 @@ impl From<Action> for &'static str {
             Action::Verify => "verify",
             Action::Set => "set",
             Action::Show => "show",
+            Action::Doctor => "doctor",
+            Action::Upgrade => "upgrade",
+            Action::PinLockfile => "pin-lockfile",
+            Action::Diff => "diff",
+            Action::Watch => "watch",
+            Action::Policy => "policy",
+            Action::Schema => "schema",
         }
     }
 }
@@ -124,6 +275,8 @@ pub enum ReleaseSource {
     RustChangelog,
     #[cfg(feature = "rust-releases-dist-source")]
     RustDist,
+    /// A user-provided list of versions, see [`Config::release_file`]
+    File,
 }
 
 impl Default for ReleaseSource {
@@ -132,16 +285,6 @@ impl Default for ReleaseSource {
     }
 }
 
-impl ReleaseSource {
-    pub(crate) fn variants() -> &'static [&'static str] {
-        &[
-            "rust-changelog",
-            #[cfg(feature = "rust-releases-dist-source")]
-            "rust-dist",
-        ]
-    }
-}
-
 impl FromStr for ReleaseSource {
     type Err = CargoMSRVError;
 
@@ -156,6 +299,7 @@ impl From<ReleaseSource> for &'static str {
             ReleaseSource::RustChangelog => "rust-changelog",
             #[cfg(feature = "rust-releases-dist-source")]
             ReleaseSource::RustDist => "rust-dist",
+            ReleaseSource::File => "file",
         }
     }
 }
@@ -179,15 +323,156 @@ impl fmt::Display for ReleaseSource {
             Self::RustChangelog => write!(f, "rust-changelog"),
             #[cfg(feature = "rust-releases-dist-source")]
             Self::RustDist => write!(f, "rust-dist"),
+            Self::File => write!(f, "file"),
         }
     }
 }
 
+/// Runs the check command with a toolchain.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckRunner {
+    /// Run the check command with `rustup run`
+    Rustup,
+    /// Run the check command in an official `rust:<version>` Docker container
+    Docker,
+}
+
+impl Default for CheckRunner {
+    fn default() -> Self {
+        Self::Rustup
+    }
+}
+
+impl CheckRunner {
+    pub(crate) fn variants() -> &'static [&'static str] {
+        &["rustup", "docker"]
+    }
+}
+
+impl FromStr for CheckRunner {
+    type Err = CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rustup" => Ok(Self::Rustup),
+            "docker" => Ok(Self::Docker),
+            unknown => Err(CargoMSRVError::InvalidConfig(format!(
+                "Given check runner '{}' is not valid",
+                unknown
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for CheckRunner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rustup => write!(f, "rustup"),
+            Self::Docker => write!(f, "docker"),
+        }
+    }
+}
+
+/// The environment file(s) to which the MSRV is written, so later steps of a CI workflow can use it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WriteEnv {
+    /// Write `MSRV=<version>` to the given file.
+    File(PathBuf),
+    /// Write `MSRV=<version>` to the file given by `$GITHUB_ENV`, and the `msrv` step output to
+    /// the file given by `$GITHUB_OUTPUT`, as far as these are set.
+    GitHubActions,
+}
+
+/// The Rust release channel up to which toolchains are taken into account.
+///
+/// The beta and nightly channels are included in addition to the stable releases, as the
+/// pre-releases of the upcoming stable versions.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseChannel {
+    Stable,
+    Beta,
+    Nightly,
+}
+
+impl Default for ReleaseChannel {
+    fn default() -> Self {
+        Self::Stable
+    }
+}
+
+impl ReleaseChannel {
+    pub(crate) fn variants() -> &'static [&'static str] {
+        &["stable", "beta", "nightly"]
+    }
+}
+
+impl FromStr for ReleaseChannel {
+    type Err = CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl From<ReleaseChannel> for &'static str {
+    fn from(value: ReleaseChannel) -> Self {
+        match value {
+            ReleaseChannel::Stable => "stable",
+            ReleaseChannel::Beta => "beta",
+            ReleaseChannel::Nightly => "nightly",
+        }
+    }
+}
+
+impl TryFrom<&str> for ReleaseChannel {
+    type Error = CargoMSRVError;
+
+    fn try_from(channel: &str) -> Result<Self, Self::Error> {
+        match channel {
+            "stable" => Ok(Self::Stable),
+            "beta" => Ok(Self::Beta),
+            "nightly" => Ok(Self::Nightly),
+            s => Err(CargoMSRVError::ReleaseChannelParseError(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Into::<&'static str>::into(*self))
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SearchMethod {
     Linear,
     Bisect,
+    Gallop,
+}
+
+impl SearchMethod {
+    pub(crate) fn variants() -> &'static [&'static str] {
+        &["bisect", "linear", "gallop"]
+    }
+}
+
+impl FromStr for SearchMethod {
+    type Err = CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(Self::Linear),
+            "bisect" => Ok(Self::Bisect),
+            "gallop" => Ok(Self::Gallop),
+            s => Err(CargoMSRVError::InvalidConfig(format!(
+                "No such search method '{}'",
+                s
+            ))),
+        }
+    }
 }
 
 impl From<SearchMethod> for &'static str {
@@ -195,16 +480,76 @@ impl From<SearchMethod> for &'static str {
         match method {
             SearchMethod::Linear => "linear",
             SearchMethod::Bisect => "bisect",
+            SearchMethod::Gallop => "gallop",
         }
     }
 }
 
+impl fmt::Display for SearchMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Into::<&'static str>::into(*self))
+    }
+}
+
 impl Default for SearchMethod {
     fn default() -> Self {
         Self::Bisect
     }
 }
 
+/// The precision of the MSRV search.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Granularity {
+    /// Only the most recent patch release of each minor release is checked.
+    Minor,
+    /// The search is run at minor granularity first, after which the patch releases preceding
+    /// the found release, within the same minor release, are checked as well.
+    Patch,
+}
+
+impl Granularity {
+    pub(crate) fn variants() -> &'static [&'static str] {
+        &["minor", "patch"]
+    }
+}
+
+impl FromStr for Granularity {
+    type Err = CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minor" => Ok(Self::Minor),
+            "patch" => Ok(Self::Patch),
+            s => Err(CargoMSRVError::InvalidConfig(format!(
+                "No such granularity '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+impl From<Granularity> for &'static str {
+    fn from(granularity: Granularity) -> Self {
+        match granularity {
+            Granularity::Minor => "minor",
+            Granularity::Patch => "patch",
+        }
+    }
+}
+
+impl fmt::Display for Granularity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Into::<&'static str>::into(*self))
+    }
+}
+
+impl Default for Granularity {
+    fn default() -> Self {
+        Self::Minor
+    }
+}
+
 // TODO{foresterre}:
 //  This Config approach does not scale with the amount of options
 //  we now have. It also not allow us to easily merge several layers of option inputs,
@@ -213,21 +558,79 @@ impl Default for SearchMethod {
 pub struct Config<'a> {
     action: Action,
     target: String,
-    check_command: Vec<&'a str>,
+    host: Option<String>,
+    check_command: Vec<Cow<'a, str>>,
+    check_args: Vec<String>,
+    default_check_args: bool,
+    check_env: Vec<(String, String)>,
+    features: Features,
+    target_selection: TargetSelection,
+    lockfile_mode: LockfileMode,
     crate_path: Option<PathBuf>,
     manifest_path: Option<PathBuf>,
     include_all_patch_releases: bool,
     minimum_version: Option<bare_version::BareVersion>,
     maximum_version: Option<bare_version::BareVersion>,
     search_method: SearchMethod,
+    granularity: Granularity,
     output_toolchain_file: bool,
     write_msrv: bool,
+    sync_clippy: bool,
+    certify: bool,
     ignore_lockfile: bool,
+    regenerate_lockfile: bool,
+    minimal_versions: bool,
+    tui: bool,
     output_format: OutputFormat,
+    verbosity: Verbosity,
+    output_file: Option<PathBuf>,
+    write_report: Option<PathBuf>,
+    write_badge: Option<PathBuf>,
+    write_env: Option<WriteEnv>,
     release_source: ReleaseSource,
+    release_file: Option<PathBuf>,
+    release_channel: ReleaseChannel,
     tracing_config: Option<TracingOptions>,
     no_read_min_edition: Option<semver::Version>,
+    read_dependency_msrv: bool,
     no_check_feedback: bool,
+    workspace: bool,
+    workspace_packages: Vec<String>,
+    workspace_excludes: Vec<String>,
+    no_cache: bool,
+    resume: bool,
+    per_toolchain_target_dir: bool,
+    feature_matrix: bool,
+    explain: bool,
+    dry_run: bool,
+    sandbox: bool,
+    show_check_output: bool,
+    check_timeout: Option<Duration>,
+    treat_timeout_as_skip: bool,
+    install_retries: u32,
+    install_retry_backoff: Duration,
+    toolchain_file_components: Vec<String>,
+    toolchain_file_targets: Vec<String>,
+    toolchain_file_pin: ToolchainFilePin,
+    offline: bool,
+    toolchain_dir: Option<PathBuf>,
+    toolchain_paths: Vec<PathBuf>,
+    check_runner: CheckRunner,
+    uninstall_after: bool,
+    max_downloads: Option<u64>,
+    require_disk_space: bool,
+    check_targets: Vec<String>,
+    toolchain_components: Vec<String>,
+    refresh_index: bool,
+    index_cache_ttl: Duration,
+    proxy: Option<String>,
+    ca_cert: Option<PathBuf>,
+    dist_url: Option<String>,
+    changelog_path: Option<PathBuf>,
+    jobs: usize,
+    ignored_dependencies: Vec<String>,
+    msrv_policy: MsrvPolicy,
+    msrv_resolver: MsrvResolver,
 
     sub_command_config: SubCommandConfig,
     ctx: LazyContext,
@@ -238,24 +641,83 @@ impl<'a> Config<'a> {
         Self {
             action,
             target: target.into(),
-            check_command: vec!["cargo", "check"],
+            host: None,
+            check_command: vec![Cow::Borrowed("cargo"), Cow::Borrowed("check")],
+            check_args: Vec::new(),
+            default_check_args: true,
+            check_env: Vec::new(),
+            features: Features::default(),
+            target_selection: TargetSelection::default(),
+            lockfile_mode: LockfileMode::default(),
             crate_path: None,
             manifest_path: None,
             include_all_patch_releases: false,
             minimum_version: None,
             maximum_version: None,
             search_method: SearchMethod::default(),
+            granularity: Granularity::default(),
             output_toolchain_file: false,
             write_msrv: false,
+            sync_clippy: false,
+            certify: false,
             ignore_lockfile: false,
+            regenerate_lockfile: false,
+            minimal_versions: false,
+            tui: false,
             output_format: OutputFormat::Human,
+            verbosity: Verbosity::default(),
+            output_file: None,
+            write_report: None,
+            write_badge: None,
+            write_env: None,
             release_source: ReleaseSource::RustChangelog,
+            release_file: None,
+            release_channel: ReleaseChannel::default(),
             tracing_config: None,
             no_read_min_edition: None,
+            read_dependency_msrv: false,
             no_check_feedback: false,
+            workspace: false,
+            workspace_packages: Vec::new(),
+            workspace_excludes: Vec::new(),
+            no_cache: false,
+            resume: false,
+            per_toolchain_target_dir: false,
+            feature_matrix: false,
+            explain: false,
+            dry_run: false,
+            sandbox: false,
+            show_check_output: false,
+            check_timeout: None,
+            treat_timeout_as_skip: false,
+            install_retries: DEFAULT_INSTALL_RETRIES,
+            install_retry_backoff: DEFAULT_INSTALL_RETRY_BACKOFF,
+            toolchain_file_components: Vec::new(),
+            toolchain_file_targets: Vec::new(),
+            toolchain_file_pin: ToolchainFilePin::default(),
+            offline: false,
+            toolchain_dir: None,
+            toolchain_paths: Vec::new(),
+            check_runner: CheckRunner::default(),
+            uninstall_after: false,
+            max_downloads: None,
+            require_disk_space: false,
+            check_targets: Vec::new(),
+            toolchain_components: Vec::new(),
+            refresh_index: false,
+            index_cache_ttl: DEFAULT_INDEX_CACHE_TTL,
+            proxy: None,
+            ca_cert: None,
+            dist_url: None,
+            changelog_path: None,
+            jobs: 1,
+            ignored_dependencies: Vec::new(),
+            msrv_policy: MsrvPolicy::default(),
+            msrv_resolver: MsrvResolver::default(),
             sub_command_config: SubCommandConfig::None,
             ctx: LazyContext::default(),
         }
+        .init_context()
     }
 
     pub fn action(&self) -> Action {
@@ -266,12 +728,64 @@ impl<'a> Config<'a> {
         &self.target
     }
 
-    pub fn check_command(&self) -> &Vec<&'a str> {
-        &self.check_command
+    /// The host triple of the toolchains, if it was given explicitly with `--host`, instead of
+    /// the default host of rustup. Equal to [`Config::target`] when given.
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// The check command, including the default check arguments, followed by the arguments
+    /// which are passed on to the check command.
+    pub fn check_command(&self) -> Vec<&str> {
+        self.check_command
+            .iter()
+            .map(AsRef::as_ref)
+            .chain(self.default_check_args())
+            .chain(self.check_args.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// The arguments which cargo-msrv adds to the check command: the arguments which select the
+    /// enabled features and the checked targets, and Cargo's `--locked`, `--frozen` and
+    /// `--offline` flags. Empty if the default check arguments are disabled.
+    pub fn default_check_args(&self) -> Vec<&str> {
+        if !self.default_check_args {
+            return Vec::new();
+        }
+
+        let offline: &[&str] = if self.offline { &["--offline"] } else { &[] };
+
+        self.features
+            .args()
+            .iter()
+            .chain(self.target_selection.args())
+            .map(String::as_str)
+            .chain(self.lockfile_mode.args().iter().copied())
+            .chain(offline.iter().copied())
+            .collect()
     }
 
     pub fn check_command_string(&self) -> String {
-        self.check_command.join(" ")
+        self.check_command().join(" ")
+    }
+
+    /// The environment variables which are set for each check command, such as `RUSTFLAGS`.
+    pub fn check_env(&self) -> &[(String, String)] {
+        &self.check_env
+    }
+
+    pub fn features(&self) -> &Features {
+        &self.features
+    }
+
+    /// The Cargo targets which are checked, in addition to the targets Cargo checks by default.
+    pub fn target_selection(&self) -> &TargetSelection {
+        &self.target_selection
+    }
+
+    /// Whether the check command may update the lockfile.
+    pub fn lockfile_mode(&self) -> LockfileMode {
+        self.lockfile_mode
     }
 
     /// Should not be used directly. Use the context instead.
@@ -300,6 +814,12 @@ impl<'a> Config<'a> {
         self.search_method
     }
 
+    /// Whether the MSRV is refined to the least recent compatible patch release, after the
+    /// search at minor granularity.
+    pub fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
     pub fn output_toolchain_file(&self) -> bool {
         self.output_toolchain_file
     }
@@ -308,18 +828,82 @@ impl<'a> Config<'a> {
         self.write_msrv
     }
 
+    pub fn sync_clippy(&self) -> bool {
+        self.sync_clippy
+    }
+
+    /// Whether the inputs of the checks are recorded in the Cargo manifest, after the MSRV has
+    /// been found.
+    pub fn certify(&self) -> bool {
+        self.certify
+    }
+
     pub fn ignore_lockfile(&self) -> bool {
         self.ignore_lockfile
     }
 
+    /// Whether a fresh lockfile is generated by each checked toolchain, while the original
+    /// lockfile is stashed.
+    pub fn regenerate_lockfile(&self) -> bool {
+        self.regenerate_lockfile
+    }
+
+    /// Whether the dependencies are resolved to the minimal versions allowed by their version
+    /// requirements, using a nightly toolchain, before each check.
+    pub fn minimal_versions(&self) -> bool {
+        self.minimal_versions
+    }
+
+    /// Whether the interactive terminal user interface is used, instead of the regular user
+    /// output. Requires the `tui` feature.
+    pub fn tui(&self) -> bool {
+        self.tui
+    }
+
     pub fn output_format(&self) -> OutputFormat {
         self.output_format
     }
 
+    /// How much user output is printed by the `human` output format.
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    /// The file to which output formats which produce a report, such as JUnit, are written.
+    pub fn output_file(&self) -> Option<&Path> {
+        self.output_file.as_deref()
+    }
+
+    /// The file to which a JSON report with all events is written, regardless of the output
+    /// format, if any.
+    pub fn write_report(&self) -> Option<&Path> {
+        self.write_report.as_deref()
+    }
+
+    /// The file to which a shields.io endpoint badge with the MSRV is written, if any.
+    pub fn write_badge(&self) -> Option<&Path> {
+        self.write_badge.as_deref()
+    }
+
+    /// The environment file(s) to which the MSRV is written, if any.
+    pub fn write_env(&self) -> Option<&WriteEnv> {
+        self.write_env.as_ref()
+    }
+
     pub fn release_source(&self) -> ReleaseSource {
         self.release_source
     }
 
+    /// The file which lists the Rust versions to take into account, for the `file` release
+    /// source.
+    pub fn release_file(&self) -> Option<&Path> {
+        self.release_file.as_deref()
+    }
+
+    pub fn release_channel(&self) -> ReleaseChannel {
+        self.release_channel
+    }
+
     /// Options as to configure tracing (and logging) settings. If absent, tracing will be disabled.
     pub fn tracing(&self) -> Option<&TracingOptions> {
         self.tracing_config.as_ref()
@@ -329,10 +913,218 @@ impl<'a> Config<'a> {
         self.no_read_min_edition.as_ref()
     }
 
+    /// Whether the greatest MSRV declared by the dependencies of the crate should be used as the
+    /// least recent version of the search space.
+    pub fn read_dependency_msrv(&self) -> bool {
+        self.read_dependency_msrv
+    }
+
     pub fn no_check_feedback(&self) -> bool {
         self.no_check_feedback
     }
 
+    pub fn workspace(&self) -> bool {
+        self.workspace
+    }
+
+    /// The workspace members to run for, when running for the workspace. When empty, all
+    /// members are included, except for the excluded members.
+    pub fn workspace_packages(&self) -> &[String] {
+        &self.workspace_packages
+    }
+
+    /// The workspace members to skip, when running for the workspace.
+    pub fn workspace_excludes(&self) -> &[String] {
+        &self.workspace_excludes
+    }
+
+    pub fn no_cache(&self) -> bool {
+        self.no_cache
+    }
+
+    /// Whether the outcomes of an earlier, interrupted search are reused, instead of checking
+    /// these toolchains again.
+    pub fn resume(&self) -> bool {
+        self.resume
+    }
+
+    pub fn per_toolchain_target_dir(&self) -> bool {
+        self.per_toolchain_target_dir
+    }
+
+    /// Whether to find the MSRV of each combination of the features of the crate.
+    pub fn feature_matrix(&self) -> bool {
+        self.feature_matrix
+    }
+
+    /// Whether to determine which features of the crate require the MSRV, by checking the
+    /// release preceding the MSRV.
+    pub fn explain(&self) -> bool {
+        self.explain
+    }
+
+    /// Whether to only report which checks would be run, instead of running them.
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Whether to run the checks from a copy of the workspace in a temporary directory, so they
+    /// can't modify the working tree.
+    pub fn sandbox(&self) -> bool {
+        self.sandbox
+    }
+
+    /// Whether to show the output of each check command as it is written.
+    pub fn show_check_output(&self) -> bool {
+        self.show_check_output
+    }
+
+    /// How long a check command may run before it is aborted, if limited.
+    pub fn check_timeout(&self) -> Option<Duration> {
+        self.check_timeout
+    }
+
+    /// Whether a check which is aborted because of the timeout, is reported as skipped instead
+    /// of as failed.
+    pub fn treat_timeout_as_skip(&self) -> bool {
+        self.treat_timeout_as_skip
+    }
+
+    /// How often the installation of a toolchain, or of the standard library of a target, is
+    /// retried when it fails.
+    pub fn install_retries(&self) -> u32 {
+        self.install_retries
+    }
+
+    /// How long to wait before the first retry of a failed installation. The delay doubles
+    /// with each further retry.
+    pub fn install_retry_backoff(&self) -> Duration {
+        self.install_retry_backoff
+    }
+
+    /// The components to add to the toolchain file, when it is written.
+    pub fn toolchain_file_components(&self) -> &[String] {
+        &self.toolchain_file_components
+    }
+
+    /// The targets to add to the toolchain file, when it is written.
+    pub fn toolchain_file_targets(&self) -> &[String] {
+        &self.toolchain_file_targets
+    }
+
+    /// How precisely the channel of the toolchain file pins the MSRV, when it is written.
+    pub fn toolchain_file_pin(&self) -> ToolchainFilePin {
+        self.toolchain_file_pin
+    }
+
+    /// Whether to use only the locally installed toolchains, instead of fetching the release
+    /// index and installing toolchains.
+    pub fn offline(&self) -> bool {
+        self.offline
+    }
+
+    /// The directory with the pre-installed toolchains to use instead of rustup, if any.
+    pub fn toolchain_dir(&self) -> Option<&Path> {
+        self.toolchain_dir.as_deref()
+    }
+
+    /// The locally built toolchains which are linked with rustup, and added to the search space.
+    pub fn toolchain_paths(&self) -> &[PathBuf] {
+        &self.toolchain_paths
+    }
+
+    /// How the check command is run with each toolchain.
+    pub fn check_runner(&self) -> CheckRunner {
+        self.check_runner
+    }
+
+    /// Whether the toolchains which were installed by rustup for this run are uninstalled, once
+    /// it completes.
+    pub fn uninstall_after(&self) -> bool {
+        self.uninstall_after
+    }
+
+    /// The maximum number of toolchains which may be downloaded by a run, if limited.
+    pub fn max_downloads(&self) -> Option<u64> {
+        self.max_downloads
+    }
+
+    /// Whether the run is aborted when the toolchains which would be downloaded don't fit on
+    /// the disk, instead of only reporting it.
+    pub fn require_disk_space(&self) -> bool {
+        self.require_disk_space
+    }
+
+    /// The compilation targets which each toolchain is checked against, when more than one
+    /// target is given. When empty, only the target of the toolchain itself is checked.
+    pub fn check_targets(&self) -> &[String] {
+        &self.check_targets
+    }
+
+    /// The components, such as `clippy`, which are installed with each toolchain.
+    pub fn toolchain_components(&self) -> &[String] {
+        &self.toolchain_components
+    }
+
+    /// Whether to fetch the release index, even if a cached index is available.
+    pub fn refresh_index(&self) -> bool {
+        self.refresh_index
+    }
+
+    /// How long a cached release index may be used, before it is fetched again.
+    pub fn index_cache_ttl(&self) -> Duration {
+        self.index_cache_ttl
+    }
+
+    /// The proxy through which the release index is fetched, if given with `--proxy`.
+    ///
+    /// When absent, the proxy given by the `HTTPS_PROXY` environment variable is used, if any.
+    pub fn proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    /// The bundle of certificate authorities which are trusted when fetching the release index,
+    /// in addition to the default ones.
+    pub fn ca_cert(&self) -> Option<&Path> {
+        self.ca_cert.as_deref()
+    }
+
+    /// The (mirrored) distribution server from which toolchains are installed, instead of
+    /// `https://static.rust-lang.org`.
+    ///
+    /// If it is a local directory, the `rust-dist` release source lists the releases from its
+    /// channel manifests.
+    pub fn dist_url(&self) -> Option<&str> {
+        self.dist_url.as_deref()
+    }
+
+    /// A local copy of the Rust changelog, which is used by the `rust-changelog` release source,
+    /// instead of fetching the changelog.
+    pub fn changelog_path(&self) -> Option<&Path> {
+        self.changelog_path.as_deref()
+    }
+
+    /// The amount of checks which may be run concurrently.
+    pub fn jobs(&self) -> usize {
+        self.jobs
+    }
+
+    /// The names of the dependencies whose MSRV is disregarded, because it is known to be overly
+    /// conservative or wrong.
+    pub fn ignored_dependencies(&self) -> &[String] {
+        &self.ignored_dependencies
+    }
+
+    /// The rules which the MSRV must comply with, as checked by `cargo msrv policy`.
+    pub fn msrv_policy(&self) -> &MsrvPolicy {
+        &self.msrv_policy
+    }
+
+    /// How the declared MSRV of a crate, or of its dependencies, is read from their Cargo manifest.
+    pub fn msrv_resolver(&self) -> &MsrvResolver {
+        &self.msrv_resolver
+    }
+
     pub fn sub_command_config(&self) -> &SubCommandConfig {
         &self.sub_command_config
     }
@@ -377,8 +1169,44 @@ impl<'a> ConfigBuilder<'a> {
         self
     }
 
-    pub fn check_command(mut self, cmd: Vec<&'a str>) -> Self {
-        self.inner.check_command = cmd;
+    pub fn host(mut self, host: &str) -> Self {
+        self.inner.target = host.to_string();
+        self.inner.host = Some(host.to_string());
+        self
+    }
+
+    pub fn check_command<S: Into<Cow<'a, str>>>(mut self, cmd: Vec<S>) -> Self {
+        self.inner.check_command = cmd.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn check_args(mut self, args: Vec<String>) -> Self {
+        self.inner.check_args = args;
+        self
+    }
+
+    pub fn default_check_args(mut self, answer: bool) -> Self {
+        self.inner.default_check_args = answer;
+        self
+    }
+
+    pub fn check_env(mut self, env: Vec<(String, String)>) -> Self {
+        self.inner.check_env = env;
+        self
+    }
+
+    pub fn features(mut self, features: Features) -> Self {
+        self.inner.features = features;
+        self
+    }
+
+    pub fn target_selection(mut self, target_selection: TargetSelection) -> Self {
+        self.inner.target_selection = target_selection;
+        self
+    }
+
+    pub fn lockfile_mode(mut self, lockfile_mode: LockfileMode) -> Self {
+        self.inner.lockfile_mode = lockfile_mode;
         self
     }
 
@@ -396,6 +1224,10 @@ impl<'a> ConfigBuilder<'a> {
         self.inner.crate_path.as_deref()
     }
 
+    pub fn get_manifest_path(&self) -> Option<&Path> {
+        self.inner.manifest_path.as_deref()
+    }
+
     pub fn include_all_patch_releases(mut self, answer: bool) -> Self {
         self.inner.include_all_patch_releases = answer;
         self
@@ -416,6 +1248,11 @@ impl<'a> ConfigBuilder<'a> {
         self
     }
 
+    pub fn granularity(mut self, granularity: Granularity) -> Self {
+        self.inner.granularity = granularity;
+        self
+    }
+
     pub fn output_toolchain_file(mut self, choice: bool) -> Self {
         self.inner.output_toolchain_file = choice;
         self
@@ -426,21 +1263,81 @@ impl<'a> ConfigBuilder<'a> {
         self
     }
 
+    pub fn sync_clippy(mut self, answer: bool) -> Self {
+        self.inner.sync_clippy = answer;
+        self
+    }
+
+    pub fn certify(mut self, choice: bool) -> Self {
+        self.inner.certify = choice;
+        self
+    }
+
     pub fn ignore_lockfile(mut self, choice: bool) -> Self {
         self.inner.ignore_lockfile = choice;
         self
     }
 
+    pub fn regenerate_lockfile(mut self, choice: bool) -> Self {
+        self.inner.regenerate_lockfile = choice;
+        self
+    }
+
+    pub fn minimal_versions(mut self, choice: bool) -> Self {
+        self.inner.minimal_versions = choice;
+        self
+    }
+
+    pub fn tui(mut self, choice: bool) -> Self {
+        self.inner.tui = choice;
+        self
+    }
+
     pub fn output_format(mut self, output_format: OutputFormat) -> Self {
         self.inner.output_format = output_format;
         self
     }
 
+    pub fn verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.inner.verbosity = verbosity;
+        self
+    }
+
+    pub fn write_badge<P: AsRef<Path>>(mut self, path: Option<P>) -> Self {
+        self.inner.write_badge = path.map(|p| PathBuf::from(p.as_ref()));
+        self
+    }
+
+    pub fn write_env(mut self, write_env: Option<WriteEnv>) -> Self {
+        self.inner.write_env = write_env;
+        self
+    }
+
+    pub fn output_file<P: AsRef<Path>>(mut self, path: Option<P>) -> Self {
+        self.inner.output_file = path.map(|p| PathBuf::from(p.as_ref()));
+        self
+    }
+
+    pub fn write_report<P: AsRef<Path>>(mut self, path: Option<P>) -> Self {
+        self.inner.write_report = path.map(|p| PathBuf::from(p.as_ref()));
+        self
+    }
+
     pub fn release_source(mut self, release_source: ReleaseSource) -> Self {
         self.inner.release_source = release_source;
         self
     }
 
+    pub fn release_file<P: AsRef<Path>>(mut self, path: Option<P>) -> Self {
+        self.inner.release_file = path.map(|p| PathBuf::from(p.as_ref()));
+        self
+    }
+
+    pub fn release_channel(mut self, release_channel: ReleaseChannel) -> Self {
+        self.inner.release_channel = release_channel;
+        self
+    }
+
     pub fn tracing_config(mut self, cfg: TracingOptions) -> Self {
         self.inner.tracing_config = Some(cfg);
         self
@@ -451,11 +1348,201 @@ impl<'a> ConfigBuilder<'a> {
         self
     }
 
+    pub fn read_dependency_msrv(mut self, answer: bool) -> Self {
+        self.inner.read_dependency_msrv = answer;
+        self
+    }
+
     pub fn no_check_feedback(mut self, choice: bool) -> Self {
         self.inner.no_check_feedback = choice;
         self
     }
 
+    pub fn workspace(mut self, choice: bool) -> Self {
+        self.inner.workspace = choice;
+        self
+    }
+
+    pub fn workspace_packages(mut self, packages: Vec<String>) -> Self {
+        self.inner.workspace_packages = packages;
+        self
+    }
+
+    pub fn workspace_excludes(mut self, packages: Vec<String>) -> Self {
+        self.inner.workspace_excludes = packages;
+        self
+    }
+
+    pub fn no_cache(mut self, choice: bool) -> Self {
+        self.inner.no_cache = choice;
+        self
+    }
+
+    pub fn resume(mut self, choice: bool) -> Self {
+        self.inner.resume = choice;
+        self
+    }
+
+    pub fn per_toolchain_target_dir(mut self, answer: bool) -> Self {
+        self.inner.per_toolchain_target_dir = answer;
+        self
+    }
+
+    pub fn feature_matrix(mut self, answer: bool) -> Self {
+        self.inner.feature_matrix = answer;
+        self
+    }
+
+    pub fn explain(mut self, answer: bool) -> Self {
+        self.inner.explain = answer;
+        self
+    }
+
+    pub fn dry_run(mut self, answer: bool) -> Self {
+        self.inner.dry_run = answer;
+        self
+    }
+
+    pub fn sandbox(mut self, answer: bool) -> Self {
+        self.inner.sandbox = answer;
+        self
+    }
+
+    pub fn show_check_output(mut self, answer: bool) -> Self {
+        self.inner.show_check_output = answer;
+        self
+    }
+
+    pub fn check_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.inner.check_timeout = timeout;
+        self
+    }
+
+    pub fn treat_timeout_as_skip(mut self, answer: bool) -> Self {
+        self.inner.treat_timeout_as_skip = answer;
+        self
+    }
+
+    pub fn install_retries(mut self, retries: u32) -> Self {
+        self.inner.install_retries = retries;
+        self
+    }
+
+    pub fn install_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.inner.install_retry_backoff = backoff;
+        self
+    }
+
+    pub fn toolchain_file_components(mut self, components: Vec<String>) -> Self {
+        self.inner.toolchain_file_components = components;
+        self
+    }
+
+    pub fn toolchain_file_targets(mut self, targets: Vec<String>) -> Self {
+        self.inner.toolchain_file_targets = targets;
+        self
+    }
+
+    pub fn toolchain_file_pin(mut self, pin: ToolchainFilePin) -> Self {
+        self.inner.toolchain_file_pin = pin;
+        self
+    }
+
+    pub fn offline(mut self, answer: bool) -> Self {
+        self.inner.offline = answer;
+        self
+    }
+
+    pub fn toolchain_dir<P: AsRef<Path>>(mut self, path: Option<P>) -> Self {
+        self.inner.toolchain_dir = path.map(|p| PathBuf::from(p.as_ref()));
+        self
+    }
+
+    pub fn toolchain_paths(mut self, paths: Vec<PathBuf>) -> Self {
+        self.inner.toolchain_paths = paths;
+        self
+    }
+
+    pub fn check_runner(mut self, runner: CheckRunner) -> Self {
+        self.inner.check_runner = runner;
+        self
+    }
+
+    pub fn uninstall_after(mut self, choice: bool) -> Self {
+        self.inner.uninstall_after = choice;
+        self
+    }
+
+    pub fn max_downloads(mut self, max: Option<u64>) -> Self {
+        self.inner.max_downloads = max;
+        self
+    }
+
+    pub fn require_disk_space(mut self, choice: bool) -> Self {
+        self.inner.require_disk_space = choice;
+        self
+    }
+
+    pub fn check_targets(mut self, targets: Vec<String>) -> Self {
+        self.inner.check_targets = targets;
+        self
+    }
+
+    pub fn toolchain_components(mut self, components: Vec<String>) -> Self {
+        self.inner.toolchain_components = components;
+        self
+    }
+
+    pub fn refresh_index(mut self, answer: bool) -> Self {
+        self.inner.refresh_index = answer;
+        self
+    }
+
+    pub fn index_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.inner.index_cache_ttl = ttl;
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Option<impl Into<String>>) -> Self {
+        self.inner.proxy = proxy.map(Into::into);
+        self
+    }
+
+    pub fn ca_cert<P: AsRef<Path>>(mut self, path: Option<P>) -> Self {
+        self.inner.ca_cert = path.map(|p| PathBuf::from(p.as_ref()));
+        self
+    }
+
+    pub fn dist_url(mut self, url: Option<impl Into<String>>) -> Self {
+        self.inner.dist_url = url.map(Into::into);
+        self
+    }
+
+    pub fn changelog_path<P: AsRef<Path>>(mut self, path: Option<P>) -> Self {
+        self.inner.changelog_path = path.map(|p| PathBuf::from(p.as_ref()));
+        self
+    }
+
+    pub fn jobs(mut self, jobs: usize) -> Self {
+        self.inner.jobs = jobs;
+        self
+    }
+
+    pub fn ignored_dependencies(mut self, names: Vec<String>) -> Self {
+        self.inner.ignored_dependencies = names;
+        self
+    }
+
+    pub fn msrv_policy(mut self, policy: MsrvPolicy) -> Self {
+        self.inner.msrv_policy = policy;
+        self
+    }
+
+    pub fn msrv_resolver(mut self, msrv_resolver: MsrvResolver) -> Self {
+        self.inner.msrv_resolver = msrv_resolver;
+        self
+    }
+
     pub fn sub_command_config(mut self, cmd_config: SubCommandConfig) -> Self {
         self.inner.sub_command_config = cmd_config;
         self
@@ -482,15 +1569,23 @@ macro_rules! as_sub_command_config {
 #[derive(Debug, Clone)]
 pub enum SubCommandConfig {
     None,
+    DiffConfig(DiffCmdConfig),
     ListConfig(ListCmdConfig),
+    PinLockfileConfig(PinLockfileCmdConfig),
+    PolicyConfig(PolicyCmdConfig),
     SetConfig(SetCmdConfig),
-    ShowConfig,
+    ShowConfig(ShowCmdConfig),
+    UpgradeConfig(UpgradeCmdConfig),
     VerifyConfig(VerifyCmdConfig),
 }
 
 impl SubCommandConfig {
+    as_sub_command_config!(diff, DiffConfig, DiffCmdConfig);
     as_sub_command_config!(list, ListConfig, ListCmdConfig);
+    as_sub_command_config!(pin_lockfile, PinLockfileConfig, PinLockfileCmdConfig);
+    as_sub_command_config!(policy, PolicyConfig, PolicyCmdConfig);
     as_sub_command_config!(set, SetConfig, SetCmdConfig);
+    as_sub_command_config!(upgrade, UpgradeConfig, UpgradeCmdConfig);
     as_sub_command_config!(verify, VerifyConfig, VerifyCmdConfig);
 }
 
@@ -498,11 +1593,22 @@ impl SubCommandConfig {
 pub struct TracingOptions {
     target: TracingTargetOption,
     level: LogLevel,
+    log_file: Option<PathBuf>,
 }
 
 impl TracingOptions {
     pub fn new(target: TracingTargetOption, level: LogLevel) -> Self {
-        Self { target, level }
+        Self {
+            target,
+            level,
+            log_file: None,
+        }
+    }
+
+    /// Also write structured (JSON) logs to the given file.
+    pub fn with_log_file(mut self, log_file: Option<PathBuf>) -> Self {
+        self.log_file = log_file;
+        self
     }
 }
 
@@ -511,6 +1617,7 @@ impl Default for TracingOptions {
         Self {
             target: TracingTargetOption::File,
             level: LogLevel::default(),
+            log_file: None,
         }
     }
 }
@@ -523,6 +1630,11 @@ impl TracingOptions {
     pub fn level(&self) -> &LogLevel {
         &self.level
     }
+
+    /// The file to which structured logs are written, in addition to the log target.
+    pub fn log_file(&self) -> Option<&Path> {
+        self.log_file.as_deref()
+    }
 }
 
 #[derive(Debug, Copy, Clone, ArgEnum)]
@@ -0,0 +1,44 @@
+use clap::ArgEnum;
+
+/// A Cargo subcommand which can be selected as the check command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ArgEnum, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckCommandKind {
+    /// `cargo check`: the crate type checks
+    Check,
+    /// `cargo build`: the crate compiles, including code generation and linking
+    Build,
+    /// `cargo test`: the tests of the crate pass
+    Test,
+    /// `cargo clippy`: the crate passes Clippy's lints; requires the `clippy` component
+    Clippy,
+}
+
+impl CheckCommandKind {
+    /// The command, including the Cargo subcommand.
+    pub fn command(&self) -> Vec<&'static str> {
+        let subcommand = match self {
+            Self::Check => "check",
+            Self::Build => "build",
+            Self::Test => "test",
+            Self::Clippy => "clippy",
+        };
+
+        vec!["cargo", subcommand]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        check = { CheckCommandKind::Check, vec!["cargo", "check"] },
+        build = { CheckCommandKind::Build, vec!["cargo", "build"] },
+        test = { CheckCommandKind::Test, vec!["cargo", "test"] },
+        clippy = { CheckCommandKind::Clippy, vec!["cargo", "clippy"] },
+    )]
+    fn command(kind: CheckCommandKind, expected: Vec<&str>) {
+        assert_eq!(kind.command(), expected);
+    }
+}
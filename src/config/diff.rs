@@ -0,0 +1,5 @@
+#[derive(Clone, Debug)]
+pub struct DiffCmdConfig {
+    pub base: String,
+    pub head: Option<String>,
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+
+/// The Cargo features which are enabled when running the check command.
+///
+/// The selected features are passed on to the check command as the corresponding Cargo flags.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Features {
+    features: Vec<String>,
+    all_features: bool,
+    no_default_features: bool,
+    #[serde(skip)]
+    args: Vec<String>,
+}
+
+impl Features {
+    /// Each of the given `features` may be a comma or space separated list of features, like the
+    /// `--features` flag of Cargo accepts.
+    pub fn new<S: AsRef<str>>(
+        features: &[S],
+        all_features: bool,
+        no_default_features: bool,
+    ) -> Self {
+        let features = features
+            .iter()
+            .flat_map(|list| list.as_ref().split(|c: char| c == ',' || c.is_whitespace()))
+            .filter(|feature| !feature.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        let mut args = Vec::new();
+
+        if !features.is_empty() {
+            args.push("--features".to_string());
+            args.push(features.join(","));
+        }
+
+        if all_features {
+            args.push("--all-features".to_string());
+        }
+
+        if no_default_features {
+            args.push("--no-default-features".to_string());
+        }
+
+        Self {
+            features,
+            all_features,
+            no_default_features,
+            args,
+        }
+    }
+
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    pub fn all_features(&self) -> bool {
+        self.all_features
+    }
+
+    pub fn no_default_features(&self) -> bool {
+        self.no_default_features
+    }
+
+    /// Whether only the default features of the crate are enabled.
+    pub fn is_default(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// The arguments which are appended to the check command.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+}
+
+impl fmt::Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_default() {
+            f.write_str("default")
+        } else {
+            f.write_str(&self.args.join(" "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_features() {
+        let features = Features::default();
+
+        assert!(features.is_default());
+        assert!(features.args().is_empty());
+        assert_eq!(features.to_string(), "default");
+    }
+
+    #[test]
+    fn feature_lists_are_split() {
+        let features = Features::new(&["a,b", "c d", ""], false, false);
+
+        assert_eq!(features.features(), &["a", "b", "c", "d"]);
+        assert_eq!(features.args(), &["--features", "a,b,c,d"]);
+    }
+
+    #[test]
+    fn all_flags() {
+        let features = Features::new(&["a"], true, true);
+
+        assert_eq!(
+            features.args(),
+            &["--features", "a", "--all-features", "--no-default-features"]
+        );
+        assert_eq!(
+            features.to_string(),
+            "--features a --all-features --no-default-features"
+        );
+    }
+}
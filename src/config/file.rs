@@ -0,0 +1,369 @@
+use std::convert::TryFrom;
+use std::path::Path;
+
+use toml_edit::{Document, TableLike, TomlError};
+
+use crate::config::policy::MsrvPolicy;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::manifest::bare_version::BareVersion;
+
+/// Name of the cargo-msrv configuration file, which is looked up in the root of a crate.
+pub const CONFIG_FILE_NAME: &str = ".cargo-msrv.toml";
+
+const CHECK_COMMAND: &str = "check-command";
+const CHECK_ARGS: &str = "check-args";
+const DEFAULT_CHECK_ARGS: &str = "default-check-args";
+const TARGET: &str = "target";
+const FEATURES: &str = "features";
+const IGNORE_DEPENDENCIES: &str = "ignore-dependencies";
+const POLICY: &str = "policy";
+const SLIDING_WINDOW: &str = "sliding-window";
+const MINIMUM: &str = "minimum";
+
+/// Options which can be stored in a `.cargo-msrv.toml` configuration file, so they don't have to be
+/// repeated for each invocation of cargo-msrv.
+///
+/// Options given on the command line take precedence over options read from this file.
+///
+/// ```toml
+/// check-command = ["cargo", "test"]
+/// check-args = ["--locked"]
+/// default-check-args = false
+/// target = "x86_64-unknown-linux-gnu"
+/// features = ["serde"]
+/// ignore-dependencies = ["some-crate"]
+///
+/// [policy]
+/// sliding-window = 4
+/// minimum = "1.65"
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConfigFile {
+    check_command: Option<Vec<String>>,
+    check_args: Option<Vec<String>>,
+    default_check_args: Option<bool>,
+    target: Option<String>,
+    features: Option<Vec<String>>,
+    ignore_dependencies: Option<Vec<String>>,
+    policy: Option<MsrvPolicy>,
+}
+
+impl ConfigFile {
+    /// Read the configuration file from the given directory, if it exists.
+    pub fn try_from_dir(dir: &Path) -> TResult<Option<Self>> {
+        let path = dir.join(CONFIG_FILE_NAME);
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(&path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.clone()),
+        })?;
+
+        contents
+            .parse::<ConfigFile>()
+            .map(Some)
+            .map_err(|error| CargoMSRVError::ConfigFile { path, error })
+    }
+
+    pub fn check_command(&self) -> Option<&[String]> {
+        self.check_command.as_deref()
+    }
+
+    /// Arguments which are appended to the check command.
+    pub fn check_args(&self) -> Option<&[String]> {
+        self.check_args.as_deref()
+    }
+
+    /// Whether cargo-msrv adds the arguments which select the features and targets to the check
+    /// command.
+    pub fn default_check_args(&self) -> Option<bool> {
+        self.default_check_args
+    }
+
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+
+    pub fn features(&self) -> Option<&[String]> {
+        self.features.as_deref()
+    }
+
+    pub fn ignore_dependencies(&self) -> Option<&[String]> {
+        self.ignore_dependencies.as_deref()
+    }
+
+    pub fn policy(&self) -> Option<&MsrvPolicy> {
+        self.policy.as_ref()
+    }
+}
+
+impl std::str::FromStr for ConfigFile {
+    type Err = Error;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let document = contents.parse::<Document>()?;
+
+        if let Some((key, _)) = document.iter().find(|(key, _)| {
+            ![
+                CHECK_COMMAND,
+                CHECK_ARGS,
+                DEFAULT_CHECK_ARGS,
+                TARGET,
+                FEATURES,
+                IGNORE_DEPENDENCIES,
+                POLICY,
+            ]
+            .contains(key)
+        }) {
+            return Err(Error::UnknownKey(key.to_string()));
+        }
+
+        Ok(Self {
+            check_command: string_array(&document, CHECK_COMMAND)?,
+            check_args: string_array(&document, CHECK_ARGS)?,
+            default_check_args: boolean(&document, DEFAULT_CHECK_ARGS)?,
+            target: string(&document, TARGET)?,
+            features: string_array(&document, FEATURES)?,
+            ignore_dependencies: string_array(&document, IGNORE_DEPENDENCIES)?,
+            policy: policy(&document)?,
+        })
+    }
+}
+
+fn policy(document: &Document) -> Result<Option<MsrvPolicy>, Error> {
+    let table = match document.get(POLICY) {
+        Some(item) => item.as_table_like().ok_or(Error::InvalidValue {
+            key: POLICY,
+            expected: "a table",
+        })?,
+        None => return Ok(None),
+    };
+
+    if let Some((key, _)) = table
+        .iter()
+        .find(|(key, _)| ![SLIDING_WINDOW, MINIMUM].contains(key))
+    {
+        return Err(Error::UnknownKey(format!("{}.{}", POLICY, key)));
+    }
+
+    Ok(Some(MsrvPolicy {
+        sliding_window: sliding_window(table)?,
+        minimum: minimum(table)?,
+    }))
+}
+
+fn sliding_window(table: &dyn TableLike) -> Result<Option<u64>, Error> {
+    table
+        .get(SLIDING_WINDOW)
+        .map(|item| {
+            item.as_integer()
+                .and_then(|releases| u64::try_from(releases).ok())
+                .ok_or(Error::InvalidValue {
+                    key: SLIDING_WINDOW,
+                    expected: "a non-negative integer",
+                })
+        })
+        .transpose()
+}
+
+fn minimum(table: &dyn TableLike) -> Result<Option<BareVersion>, Error> {
+    table
+        .get(MINIMUM)
+        .map(|item| {
+            item.as_str()
+                .and_then(|version| version.parse::<BareVersion>().ok())
+                .ok_or(Error::InvalidValue {
+                    key: MINIMUM,
+                    expected: "a Rust version, such as \"1.65\"",
+                })
+        })
+        .transpose()
+}
+
+fn string(document: &Document, key: &'static str) -> Result<Option<String>, Error> {
+    document
+        .get(key)
+        .map(|item| {
+            item.as_str().map(String::from).ok_or(Error::InvalidValue {
+                key,
+                expected: "a string",
+            })
+        })
+        .transpose()
+}
+
+fn boolean(document: &Document, key: &'static str) -> Result<Option<bool>, Error> {
+    document
+        .get(key)
+        .map(|item| {
+            item.as_bool().ok_or(Error::InvalidValue {
+                key,
+                expected: "a boolean",
+            })
+        })
+        .transpose()
+}
+
+fn string_array(document: &Document, key: &'static str) -> Result<Option<Vec<String>>, Error> {
+    let invalid_value = || Error::InvalidValue {
+        key,
+        expected: "an array of strings",
+    };
+
+    document
+        .get(key)
+        .map(|item| {
+            item.as_array().ok_or_else(invalid_value).and_then(|array| {
+                array
+                    .iter()
+                    .map(|value| value.as_str().map(String::from).ok_or_else(invalid_value))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+        })
+        .transpose()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Unable to parse config file: {0}")]
+    Toml(#[from] TomlError),
+
+    #[error("Unknown key '{0}'")]
+    UnknownKey(String),
+
+    #[error("Expected '{key}' to be {expected}")]
+    InvalidValue {
+        key: &'static str,
+        expected: &'static str,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let config = "".parse::<ConfigFile>().unwrap();
+
+        assert_eq!(config, ConfigFile::default());
+    }
+
+    #[test]
+    fn all_keys() {
+        let contents = r#"
+check-command = ["cargo", "test"]
+check-args = ["--locked"]
+default-check-args = false
+target = "x86_64-unknown-linux-gnu"
+features = ["a", "b"]
+ignore-dependencies = ["c"]
+"#;
+
+        let config = contents.parse::<ConfigFile>().unwrap();
+
+        assert_eq!(
+            config.check_command().unwrap(),
+            &["cargo".to_string(), "test".to_string()]
+        );
+        assert_eq!(config.check_args().unwrap(), &["--locked".to_string()]);
+        assert_eq!(config.default_check_args(), Some(false));
+        assert_eq!(config.target().unwrap(), "x86_64-unknown-linux-gnu");
+        assert_eq!(
+            config.features().unwrap(),
+            &["a".to_string(), "b".to_string()]
+        );
+        assert_eq!(config.ignore_dependencies().unwrap(), &["c".to_string()]);
+    }
+
+    #[test]
+    fn policy() {
+        let contents = r#"
+[policy]
+sliding-window = 4
+minimum = "1.65"
+"#;
+
+        let config = contents.parse::<ConfigFile>().unwrap();
+
+        assert_eq!(
+            config.policy().unwrap(),
+            &MsrvPolicy {
+                sliding_window: Some(4),
+                minimum: Some(BareVersion::TwoComponents(1, 65)),
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_policy_key() {
+        let err = "[policy]\nwindow = 4".parse::<ConfigFile>().unwrap_err();
+
+        assert!(matches!(err, Error::UnknownKey(key) if key == "policy.window"));
+    }
+
+    #[test]
+    fn sliding_window_is_negative() {
+        let err = "[policy]\nsliding-window = -1"
+            .parse::<ConfigFile>()
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::InvalidValue {
+                key: SLIDING_WINDOW,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn unknown_key() {
+        let err = "hello = 1".parse::<ConfigFile>().unwrap_err();
+
+        assert!(matches!(err, Error::UnknownKey(key) if key == "hello"));
+    }
+
+    #[test]
+    fn check_command_not_an_array() {
+        let err = r#"check-command = "cargo test""#.parse::<ConfigFile>().unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::InvalidValue {
+                key: CHECK_COMMAND,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn target_not_a_string() {
+        let err = "target = 1".parse::<ConfigFile>().unwrap_err();
+
+        assert!(matches!(err, Error::InvalidValue { key: TARGET, .. }));
+    }
+
+    #[test]
+    fn default_check_args_not_a_boolean() {
+        let err = r#"default-check-args = "no""#.parse::<ConfigFile>().unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::InvalidValue {
+                key: DEFAULT_CHECK_ARGS,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn from_dir_without_config_file() {
+        let dir = std::env::temp_dir().join("cargo-msrv-no-config-file-here");
+
+        assert!(ConfigFile::try_from_dir(&dir).unwrap().is_none());
+    }
+}
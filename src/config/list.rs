@@ -1,19 +1,34 @@
+use crate::manifest::bare_version::BareVersion;
 use std::fmt::Formatter;
 use std::{fmt, str::FromStr};
 
 #[derive(Clone, Debug)]
 pub struct ListCmdConfig {
     pub variant: ListMsrvVariant,
+    /// Only list dependencies up to this depth in the dependency graph
+    pub depth: Option<usize>,
+    /// Only list dependencies with an MSRV greater than this version
+    pub min_msrv: Option<BareVersion>,
+    /// Order of the listed dependencies, or `None` for the default order of the variant
+    pub sort: Option<ListSort>,
+    /// Whether to find the MSRV of the direct dependencies by compiling them
+    pub verify_deps: bool,
+    /// The kinds of dependencies of the root crate which are listed, together with their own
+    /// dependencies
+    pub dependency_kinds: Vec<DependencyKind>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ListMsrvVariant {
     DirectDeps,
     OrderedByMSRV,
+    /// The transitive dependencies, and the chains of dependencies which introduce them
+    Blame,
 }
 
 pub(crate) const DIRECT_DEPS: &str = "direct-deps";
 pub(crate) const ORDERED_BY_MSRV: &str = "ordered-by-msrv";
+pub(crate) const BLAME: &str = "blame";
 
 impl FromStr for ListMsrvVariant {
     type Err = crate::CargoMSRVError;
@@ -22,6 +37,7 @@ impl FromStr for ListMsrvVariant {
         Ok(match s {
             DIRECT_DEPS => Self::DirectDeps,
             ORDERED_BY_MSRV => Self::OrderedByMSRV,
+            BLAME => Self::Blame,
             elsy => {
                 return Err(crate::CargoMSRVError::InvalidConfig(format!(
                     "No such list variant '{}'",
@@ -37,13 +53,14 @@ impl fmt::Display for ListMsrvVariant {
         match self {
             Self::DirectDeps => write!(f, "{}", DIRECT_DEPS),
             Self::OrderedByMSRV => write!(f, "{}", ORDERED_BY_MSRV),
+            Self::Blame => write!(f, "{}", BLAME),
         }
     }
 }
 
 impl ListMsrvVariant {
     pub(crate) const fn variants() -> &'static [&'static str] {
-        &[DIRECT_DEPS, ORDERED_BY_MSRV]
+        &[DIRECT_DEPS, ORDERED_BY_MSRV, BLAME]
     }
 }
 
@@ -52,3 +69,104 @@ impl Default for ListMsrvVariant {
         Self::OrderedByMSRV
     }
 }
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ListSort {
+    /// From the greatest to the lowest MSRV
+    Msrv,
+    /// Alphabetically by name
+    Name,
+    /// By the distance from the root crate in the dependency graph
+    Depth,
+}
+
+pub(crate) const SORT_MSRV: &str = "msrv";
+pub(crate) const SORT_NAME: &str = "name";
+pub(crate) const SORT_DEPTH: &str = "depth";
+
+impl FromStr for ListSort {
+    type Err = crate::CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            SORT_MSRV => Self::Msrv,
+            SORT_NAME => Self::Name,
+            SORT_DEPTH => Self::Depth,
+            elsy => {
+                return Err(crate::CargoMSRVError::InvalidConfig(format!(
+                    "No such sort order '{}'",
+                    elsy
+                )))
+            }
+        })
+    }
+}
+
+impl fmt::Display for ListSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Msrv => write!(f, "{}", SORT_MSRV),
+            Self::Name => write!(f, "{}", SORT_NAME),
+            Self::Depth => write!(f, "{}", SORT_DEPTH),
+        }
+    }
+}
+
+impl ListSort {
+    pub(crate) const fn variants() -> &'static [&'static str] {
+        &[SORT_MSRV, SORT_NAME, SORT_DEPTH]
+    }
+}
+
+/// The kind of dependency through which the root crate depends on a package.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyKind {
+    Normal,
+    Dev,
+    Build,
+}
+
+pub(crate) const KIND_NORMAL: &str = "normal";
+pub(crate) const KIND_DEV: &str = "dev";
+pub(crate) const KIND_BUILD: &str = "build";
+
+impl FromStr for DependencyKind {
+    type Err = crate::CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            KIND_NORMAL => Self::Normal,
+            KIND_DEV => Self::Dev,
+            KIND_BUILD => Self::Build,
+            elsy => {
+                return Err(crate::CargoMSRVError::InvalidConfig(format!(
+                    "No such dependency kind '{}'",
+                    elsy
+                )))
+            }
+        })
+    }
+}
+
+impl fmt::Display for DependencyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal => write!(f, "{}", KIND_NORMAL),
+            Self::Dev => write!(f, "{}", KIND_DEV),
+            Self::Build => write!(f, "{}", KIND_BUILD),
+        }
+    }
+}
+
+impl DependencyKind {
+    pub(crate) const fn variants() -> &'static [&'static str] {
+        &[KIND_NORMAL, KIND_DEV, KIND_BUILD]
+    }
+
+    /// The kinds of dependencies which are needed to build the crate; dev-dependencies are only
+    /// needed for tests, examples and benchmarks.
+    pub(crate) fn build_kinds() -> Vec<Self> {
+        vec![Self::Normal, Self::Build]
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+
+/// Whether the check commands may update the lockfile, as set by Cargo's `--locked` and `--frozen`
+/// flags.
+///
+/// With a locked lockfile, each candidate toolchain is checked against the same dependency
+/// versions. The flag is passed on to the check command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockfileMode {
+    /// Cargo may update the lockfile, e.g. when it's missing or out of date
+    Unlocked,
+    /// Cargo's `--locked`: the lockfile may not be updated
+    Locked,
+    /// Cargo's `--frozen`: the lockfile may not be updated, and the network may not be accessed
+    Frozen,
+}
+
+impl Default for LockfileMode {
+    fn default() -> Self {
+        Self::Unlocked
+    }
+}
+
+impl LockfileMode {
+    pub fn new(locked: bool, frozen: bool) -> Self {
+        if frozen {
+            Self::Frozen
+        } else if locked {
+            Self::Locked
+        } else {
+            Self::Unlocked
+        }
+    }
+
+    /// Whether the lockfile may not be updated by the check command.
+    pub fn is_locked(self) -> bool {
+        self != Self::Unlocked
+    }
+
+    /// The arguments which are passed on to the check command.
+    pub fn args(self) -> &'static [&'static str] {
+        match self {
+            Self::Unlocked => &[],
+            Self::Locked => &["--locked"],
+            Self::Frozen => &["--frozen"],
+        }
+    }
+}
+
+impl fmt::Display for LockfileMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unlocked => f.write_str("unlocked"),
+            Self::Locked => f.write_str("--locked"),
+            Self::Frozen => f.write_str("--frozen"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        unlocked = { false, false, LockfileMode::Unlocked, &[] },
+        locked = { true, false, LockfileMode::Locked, &["--locked"] },
+        frozen = { false, true, LockfileMode::Frozen, &["--frozen"] },
+        frozen_implies_locked = { true, true, LockfileMode::Frozen, &["--frozen"] },
+    )]
+    fn mode_from_flags(locked: bool, frozen: bool, expected: LockfileMode, args: &[&str]) {
+        let mode = LockfileMode::new(locked, frozen);
+
+        assert_eq!(mode, expected);
+        assert_eq!(mode.args(), args);
+        assert_eq!(mode.is_locked(), locked || frozen);
+    }
+}
@@ -0,0 +1,6 @@
+use crate::manifest::bare_version::BareVersion;
+
+#[derive(Clone, Debug)]
+pub struct PinLockfileCmdConfig {
+    pub msrv: Option<BareVersion>,
+}
@@ -0,0 +1,22 @@
+use crate::manifest::bare_version::BareVersion;
+
+/// Rules which the MSRV of a crate must comply with, relative to the current stable Rust release.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MsrvPolicy {
+    /// The MSRV must be at least this many minor releases behind the current stable release.
+    pub sliding_window: Option<u64>,
+    /// The MSRV must not be newer than this Rust version.
+    pub minimum: Option<BareVersion>,
+}
+
+impl MsrvPolicy {
+    /// Returns `true` if the policy has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.sliding_window.is_none() && self.minimum.is_none()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PolicyCmdConfig {
+    pub msrv: Option<BareVersion>,
+}
@@ -3,4 +3,6 @@ use crate::manifest::bare_version::BareVersion;
 #[derive(Clone, Debug)]
 pub struct SetCmdConfig {
     pub msrv: BareVersion,
+    /// Set the MSRV in the workspace root, and let each member of the workspace inherit it.
+    pub propagate: bool,
 }
@@ -0,0 +1,4 @@
+#[derive(Clone, Debug)]
+pub struct ShowCmdConfig {
+    pub edition: bool,
+}
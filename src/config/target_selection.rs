@@ -0,0 +1,111 @@
+use std::fmt;
+
+/// The Cargo targets, such as examples and benchmarks, which are checked by the check command,
+/// in addition to the library and binary targets which Cargo checks by default.
+///
+/// The selected targets are passed on to the check command as the corresponding Cargo flags.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct TargetSelection {
+    all_targets: bool,
+    examples: bool,
+    benches: bool,
+    tests: bool,
+    #[serde(skip)]
+    args: Vec<String>,
+}
+
+impl TargetSelection {
+    pub fn new(all_targets: bool, examples: bool, benches: bool, tests: bool) -> Self {
+        let args = [
+            (all_targets, "--all-targets"),
+            (examples, "--examples"),
+            (benches, "--benches"),
+            (tests, "--tests"),
+        ]
+        .iter()
+        .filter(|(selected, _)| *selected)
+        .map(|(_, flag)| flag.to_string())
+        .collect();
+
+        Self {
+            all_targets,
+            examples,
+            benches,
+            tests,
+            args,
+        }
+    }
+
+    pub fn all_targets(&self) -> bool {
+        self.all_targets
+    }
+
+    pub fn examples(&self) -> bool {
+        self.examples
+    }
+
+    pub fn benches(&self) -> bool {
+        self.benches
+    }
+
+    pub fn tests(&self) -> bool {
+        self.tests
+    }
+
+    /// Whether only the targets which Cargo checks by default are selected.
+    pub fn is_default(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// The arguments which are appended to the check command.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+}
+
+impl fmt::Display for TargetSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_default() {
+            f.write_str("default")
+        } else {
+            f.write_str(&self.args.join(" "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_targets() {
+        let targets = TargetSelection::default();
+
+        assert!(targets.is_default());
+        assert!(targets.args().is_empty());
+        assert_eq!(targets.to_string(), "default");
+    }
+
+    #[test]
+    fn all_flags() {
+        let targets = TargetSelection::new(true, true, true, true);
+
+        assert_eq!(
+            targets.args(),
+            &["--all-targets", "--examples", "--benches", "--tests"]
+        );
+        assert_eq!(
+            targets.to_string(),
+            "--all-targets --examples --benches --tests"
+        );
+    }
+
+    #[test]
+    fn examples_only() {
+        let targets = TargetSelection::new(false, true, false, false);
+
+        assert!(!targets.is_default());
+        assert_eq!(targets.args(), &["--examples"]);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::semver;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// How precisely the channel of a written rust-toolchain file pins the Rust version.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ToolchainFilePin {
+    /// Pin the exact release, e.g. `1.64.0`
+    Exact,
+    /// Pin the minor version, e.g. `1.64`, so rustup uses the latest patch release of it
+    Minor,
+}
+
+pub(crate) const EXACT: &str = "exact";
+pub(crate) const MINOR: &str = "minor";
+
+impl ToolchainFilePin {
+    pub(crate) const fn variants() -> &'static [&'static str] {
+        &[EXACT, MINOR]
+    }
+
+    /// The channel of the toolchain file, for the given version.
+    pub fn channel(self, version: &semver::Version) -> String {
+        match self {
+            Self::Exact => format!("{}.{}.{}", version.major, version.minor, version.patch),
+            Self::Minor => format!("{}.{}", version.major, version.minor),
+        }
+    }
+}
+
+impl FromStr for ToolchainFilePin {
+    type Err = crate::CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            EXACT => Self::Exact,
+            MINOR => Self::Minor,
+            elsy => {
+                return Err(crate::CargoMSRVError::InvalidConfig(format!(
+                    "No such toolchain file pin '{}'",
+                    elsy
+                )))
+            }
+        })
+    }
+}
+
+impl fmt::Display for ToolchainFilePin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact => write!(f, "{}", EXACT),
+            Self::Minor => write!(f, "{}", MINOR),
+        }
+    }
+}
+
+impl Default for ToolchainFilePin {
+    fn default() -> Self {
+        Self::Exact
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    #[parameterized(
+        exact = { ToolchainFilePin::Exact, "1.64.0" },
+        minor = { ToolchainFilePin::Minor, "1.64" },
+    )]
+    fn channel(pin: ToolchainFilePin, expected: &str) {
+        assert_eq!(pin.channel(&semver::Version::new(1, 64, 0)), expected);
+    }
+
+    #[parameterized(
+        exact = { ToolchainFilePin::Exact },
+        minor = { ToolchainFilePin::Minor },
+    )]
+    fn roundtrip(pin: ToolchainFilePin) {
+        assert_eq!(pin.to_string().parse::<ToolchainFilePin>().unwrap(), pin);
+    }
+}
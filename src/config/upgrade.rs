@@ -0,0 +1,7 @@
+use crate::manifest::bare_version::BareVersion;
+
+#[derive(Clone, Debug)]
+pub struct UpgradeCmdConfig {
+    pub msrv: Option<BareVersion>,
+    pub write: bool,
+}
@@ -1,6 +1,134 @@
+use std::fmt::Formatter;
+use std::{fmt, str::FromStr};
+
 use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::VerifyStatus;
+use crate::semver;
 
 #[derive(Clone, Debug)]
 pub struct VerifyCmdConfig {
     pub rust_version: Option<BareVersion>,
+    /// When verification fails
+    pub fail_on: FailOn,
+    /// Whether to search for an older compatible Rust version, once verification succeeds
+    pub find_lower: bool,
+    /// Verify this version of the crate, as published to crates.io, instead of the local sources
+    pub published: Option<semver::Version>,
+}
+
+/// Policy which decides whether `cargo msrv verify` fails, based on how the declared MSRV
+/// compares to the actual MSRV.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FailOn {
+    /// Fail when the declared MSRV is lower than the actual MSRV, i.e. when the crate is not
+    /// compatible with its declared MSRV
+    Lower,
+    /// Fail when the declared MSRV is higher than necessary, i.e. when the crate is also
+    /// compatible with the Rust release preceding its declared MSRV
+    Higher,
+    /// Fail when the declared MSRV is either lower or higher than the actual MSRV
+    Both,
+    /// Never fail, only report the result
+    Never,
+}
+
+pub(crate) const LOWER: &str = "lower";
+pub(crate) const HIGHER: &str = "higher";
+pub(crate) const BOTH: &str = "both";
+pub(crate) const NEVER: &str = "never";
+
+impl FailOn {
+    pub(crate) const fn variants() -> &'static [&'static str] {
+        &[LOWER, HIGHER, BOTH, NEVER]
+    }
+
+    /// Whether verification fails if the crate is not compatible with its declared MSRV.
+    pub fn fails_on_lower(self) -> bool {
+        matches!(self, Self::Lower | Self::Both)
+    }
+
+    /// Whether verification fails if the crate is also compatible with the Rust release
+    /// preceding its declared MSRV.
+    ///
+    /// Only then the preceding release will be checked.
+    pub fn fails_on_higher(self) -> bool {
+        matches!(self, Self::Higher | Self::Both)
+    }
+
+    /// Whether verification fails for a crate with the given status.
+    pub fn fails_on(self, status: VerifyStatus) -> bool {
+        match status {
+            VerifyStatus::Incompatible => self.fails_on_lower(),
+            VerifyStatus::TooConservative => self.fails_on_higher(),
+            VerifyStatus::Compatible | VerifyStatus::Skipped => false,
+        }
+    }
+}
+
+impl FromStr for FailOn {
+    type Err = crate::CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            LOWER => Self::Lower,
+            HIGHER => Self::Higher,
+            BOTH => Self::Both,
+            NEVER => Self::Never,
+            elsy => {
+                return Err(crate::CargoMSRVError::InvalidConfig(format!(
+                    "No such fail-on policy '{}'",
+                    elsy
+                )))
+            }
+        })
+    }
+}
+
+impl fmt::Display for FailOn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lower => write!(f, "{}", LOWER),
+            Self::Higher => write!(f, "{}", HIGHER),
+            Self::Both => write!(f, "{}", BOTH),
+            Self::Never => write!(f, "{}", NEVER),
+        }
+    }
+}
+
+impl Default for FailOn {
+    fn default() -> Self {
+        Self::Lower
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    #[parameterized(
+        lower = { FailOn::Lower, true, false },
+        higher = { FailOn::Higher, false, true },
+        both = { FailOn::Both, true, true },
+        never = { FailOn::Never, false, false },
+    )]
+    fn fails_on(policy: FailOn, incompatible: bool, too_conservative: bool) {
+        assert_eq!(policy.fails_on(VerifyStatus::Incompatible), incompatible);
+        assert_eq!(
+            policy.fails_on(VerifyStatus::TooConservative),
+            too_conservative
+        );
+        assert!(!policy.fails_on(VerifyStatus::Compatible));
+        assert!(!policy.fails_on(VerifyStatus::Skipped));
+    }
+
+    #[parameterized(
+        lower = { FailOn::Lower },
+        higher = { FailOn::Higher },
+        both = { FailOn::Both },
+        never = { FailOn::Never },
+    )]
+    fn roundtrip(policy: FailOn) {
+        assert_eq!(policy.to_string().parse::<FailOn>().unwrap(), policy);
+    }
 }
@@ -0,0 +1,47 @@
+//! Calendar dates, without pulling in a date-time library.
+
+use std::time::SystemTime;
+
+/// The current date (UTC), formatted as `YYYY-MM-DD`.
+pub(crate) fn today() -> String {
+    let days = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|since| since.as_secs() / 86400)
+        .unwrap_or_default();
+
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Convert the number of days since 1970-01-01 to a (year, month, day) date of the proleptic
+/// Gregorian calendar, see <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        epoch = { 0, (1970, 1, 1) },
+        march = { 19_052, (2022, 3, 1) },
+        y2k = { 10_957, (2000, 1, 1) },
+        leap_year = { 11_016, (2000, 2, 29) },
+    )]
+    fn civil_dates(days: i64, expected: (i64, u32, u32)) {
+        assert_eq!(civil_from_days(days), expected);
+    }
+}
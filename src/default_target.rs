@@ -1,10 +1,18 @@
+use std::process::Command;
+
 use crate::command::RustupCommand;
 use crate::error::{CargoMSRVError, TResult};
 
 /// Uses the `.rustup/settings.toml` file to determine the default target (aka the
 /// `default_host_triple`) if not set by a user.
+///
+/// If rustup is not installed, the host of the `rustc` on the `PATH` is used instead.
 pub fn default_target() -> TResult<String> {
-    let output = RustupCommand::new().with_stdout().show()?;
+    let output = match RustupCommand::new().with_stdout().show() {
+        Ok(output) => output,
+        Err(CargoMSRVError::Io { .. }) => return rustc_host(),
+        Err(error) => return Err(error),
+    };
 
     let stdout = output.stdout();
 
@@ -19,3 +27,43 @@ pub fn default_target() -> TResult<String> {
                 .map(String::from)
         })
 }
+
+/// The host triple of `rustc`, as reported by `rustc -vV`.
+fn rustc_host() -> TResult<String> {
+    let output = Command::new("rustc")
+        .arg("-vV")
+        .output()
+        .map_err(|_| CargoMSRVError::DefaultHostTripleNotFound)?;
+
+    parse_rustc_host(&String::from_utf8_lossy(&output.stdout))
+        .ok_or(CargoMSRVError::DefaultHostTripleNotFound)
+}
+
+fn parse_rustc_host(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(|host| host.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rustc_host_from_verbose_version() {
+        let output = "rustc 1.63.0 (4b91a6ea7 2022-08-08)
+binary: rustc
+commit-hash: 4b91a6ea7258a947e59c6522cd5898e7c0a6a88f
+commit-date: 2022-08-08
+host: x86_64-unknown-linux-gnu
+release: 1.63.0
+LLVM version: 14.0.5
+";
+
+        assert_eq!(
+            parse_rustc_host(output),
+            Some("x86_64-unknown-linux-gnu".to_string())
+        );
+    }
+}
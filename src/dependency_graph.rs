@@ -1,6 +1,10 @@
+use crate::config::list::DependencyKind;
+use crate::msrv_resolver::MsrvResolver;
+use crate::semver;
 use cargo_metadata::{Package, PackageId};
+use petgraph::stable_graph::{NodeIndex, StableDiGraph};
 use petgraph::visit::Dfs;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 pub(crate) mod resolver;
 
@@ -23,6 +27,8 @@ pub struct DependencyGraph {
     packages: PackageGraph,
     // The root crate is the crate we're creating the dependency graph for.
     root_crate: PackageId,
+    // The kinds of dependencies through which the root crate depends on each package.
+    kinds: HashMap<PackageId, Vec<DependencyKind>>,
 }
 
 impl DependencyGraph {
@@ -31,6 +37,7 @@ impl DependencyGraph {
             index: HashMap::default(),
             packages: PackageGraph::with_capacity(0, 0),
             root_crate,
+            kinds: HashMap::default(),
         }
     }
 
@@ -39,6 +46,7 @@ impl DependencyGraph {
             index: HashMap::default(),
             packages: PackageGraph::with_capacity(cap, cap),
             root_crate,
+            kinds: HashMap::default(),
         }
     }
 
@@ -53,6 +61,163 @@ impl DependencyGraph {
     pub fn root_crate(&self) -> &PackageId {
         &self.root_crate
     }
+
+    /// The kinds of dependencies through which the root crate depends on the given package,
+    /// directly or transitively. Empty for the root crate itself.
+    pub fn dependency_kinds(&self, package: &PackageId) -> &[DependencyKind] {
+        self.kinds
+            .get(package)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Remove the packages on which the root crate doesn't depend, such as the dev-dependencies
+    /// of the root crate when those aren't followed.
+    pub(crate) fn reachable_from_root(self) -> Self {
+        self.with_max_depth(usize::MAX)
+    }
+
+    /// Let each package inherit the kinds of the direct dependencies of the root crate through
+    /// which it is reached.
+    pub(crate) fn inherit_kinds(&mut self, direct_kinds: HashMap<PackageId, Vec<DependencyKind>>) {
+        let mut kinds = HashMap::<PackageId, Vec<DependencyKind>>::new();
+
+        for (direct, inherited) in direct_kinds {
+            let start = match self.index.get(&direct) {
+                Some(&index) => index.into(),
+                None => continue,
+            };
+
+            let mut dfs = Dfs::new(&self.packages, start);
+
+            while let Some(node) = dfs.next(&self.packages) {
+                let package_kinds = kinds.entry(self.packages[node].id.clone()).or_default();
+                package_kinds.extend(inherited.iter().copied());
+                package_kinds.sort();
+                package_kinds.dedup();
+            }
+        }
+
+        self.kinds = kinds;
+    }
+
+    /// Remove the packages which are more than `max_depth` edges away from the root crate.
+    ///
+    /// A `max_depth` of `1` retains the root crate and its direct dependencies.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        let root_index = self.index[&self.root_crate].into();
+        let depths = node_depths(&self.packages, root_index);
+
+        self.packages
+            .retain_nodes(|_, node| depths.get(&node).map_or(false, |&depth| depth <= max_depth));
+
+        let packages = &self.packages;
+        self.index
+            .retain(|_, &mut index| packages.contains_node(index.into()));
+
+        self
+    }
+
+    /// The dependency which declares the greatest MSRV, and its MSRV.
+    ///
+    /// The root crate itself, and the dependencies named in `ignored`, are not taken into account.
+    /// Returns `None` if none of the remaining dependencies declare an MSRV.
+    pub fn max_dependency_msrv(
+        &self,
+        msrv_resolver: &MsrvResolver,
+        ignored: &[String],
+    ) -> Option<(&Package, semver::Version)> {
+        self.packages
+            .node_weights()
+            .filter(|package| package.id != self.root_crate)
+            .filter(|package| !ignored.contains(&package.name))
+            .filter_map(|package| {
+                msrv_resolver
+                    .package_msrv(package)
+                    .map(|msrv| (package, msrv))
+            })
+            .max_by(|(_, lhs), (_, rhs)| lhs.cmp(rhs))
+    }
+
+    /// The chains of dependencies through which the root crate depends on the given package,
+    /// similar to `cargo tree --invert`.
+    ///
+    /// There is one chain for each direct dependency of the root crate which depends on the
+    /// package, directly or transitively. Each chain starts with this direct dependency, ends with
+    /// the package itself, and is the shortest such chain.
+    pub fn dependency_chains(&self, package: &PackageId) -> Vec<Vec<&Package>> {
+        let root_index = self.index[&self.root_crate].into();
+        let package_index = match self.index.get(package) {
+            Some(&index) => index.into(),
+            None => return Vec::new(),
+        };
+
+        self.packages
+            .neighbors(root_index)
+            .filter_map(|direct| shortest_path(&self.packages, direct, package_index))
+            .map(|path| path.into_iter().map(|node| &self.packages[node]).collect())
+            .collect()
+    }
+}
+
+/// Compute for each node reachable from `root`, the length of the shortest path from the root.
+fn node_depths<N, E>(
+    graph: &StableDiGraph<N, E, PackageGraphIndex>,
+    root: NodeIndex<PackageGraphIndex>,
+) -> HashMap<NodeIndex<PackageGraphIndex>, usize> {
+    let mut depths = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    depths.insert(root, 0);
+    queue.push_back(root);
+
+    while let Some(node) = queue.pop_front() {
+        let depth = depths[&node];
+
+        for neighbor in graph.neighbors(node) {
+            if !depths.contains_key(&neighbor) {
+                depths.insert(neighbor, depth + 1);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    depths
+}
+
+/// The shortest path from `from` to `to`, including both, if `to` is reachable from `from`.
+fn shortest_path<N, E>(
+    graph: &StableDiGraph<N, E, PackageGraphIndex>,
+    from: NodeIndex<PackageGraphIndex>,
+    to: NodeIndex<PackageGraphIndex>,
+) -> Option<Vec<NodeIndex<PackageGraphIndex>>> {
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    predecessors.insert(from, None);
+    queue.push_back(from);
+
+    while let Some(node) = queue.pop_front() {
+        if node == to {
+            let mut path = vec![to];
+
+            while let Some(&Some(predecessor)) = predecessors.get(path.last()?) {
+                path.push(predecessor);
+            }
+
+            path.reverse();
+            return Some(path);
+        }
+
+        for neighbor in graph.neighbors(node) {
+            if !predecessors.contains_key(&neighbor) {
+                predecessors.insert(neighbor, Some(node));
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
 }
 
 impl PartialEq for DependencyGraph {
@@ -74,3 +239,54 @@ impl PartialEq for DependencyGraph {
         self.root_crate() == other.root_crate() && packages(self) == packages(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn depths_are_shortest_paths_from_root() {
+        let mut graph = StableDiGraph::<(), (), PackageGraphIndex>::with_capacity(4, 4);
+        let root = graph.add_node(());
+        let direct = graph.add_node(());
+        let transitive = graph.add_node(());
+        let unreachable = graph.add_node(());
+
+        graph.add_edge(root, direct, ());
+        graph.add_edge(direct, transitive, ());
+        // `transitive` is also a direct dependency of the root
+        graph.add_edge(root, transitive, ());
+        graph.add_edge(unreachable, root, ());
+
+        let depths = node_depths(&graph, root);
+
+        assert_eq!(depths[&root], 0);
+        assert_eq!(depths[&direct], 1);
+        assert_eq!(depths[&transitive], 1);
+        assert!(!depths.contains_key(&unreachable));
+    }
+
+    #[test]
+    fn shortest_path_between_nodes() {
+        let mut graph = StableDiGraph::<(), (), PackageGraphIndex>::with_capacity(5, 5);
+        let direct = graph.add_node(());
+        let left = graph.add_node(());
+        let right = graph.add_node(());
+        let middle = graph.add_node(());
+        let offender = graph.add_node(());
+
+        graph.add_edge(direct, left, ());
+        graph.add_edge(left, middle, ());
+        graph.add_edge(middle, offender, ());
+        // A shorter path to the offender
+        graph.add_edge(direct, right, ());
+        graph.add_edge(right, offender, ());
+
+        assert_eq!(
+            shortest_path(&graph, direct, offender),
+            Some(vec![direct, right, offender])
+        );
+        assert_eq!(shortest_path(&graph, direct, direct), Some(vec![direct]));
+        assert_eq!(shortest_path(&graph, offender, direct), None);
+    }
+}
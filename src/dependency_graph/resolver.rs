@@ -1,14 +1,20 @@
+use crate::config::list::DependencyKind;
 use crate::config::Config;
 use crate::dependency_graph::DependencyGraph;
 use crate::error::{CargoMSRVError, TResult};
-use cargo_metadata::MetadataCommand;
+use crate::workspace::is_virtual_manifest;
+use cargo_metadata::{DependencyKind as CargoDependencyKind, MetadataCommand};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 pub(crate) trait DependencyResolver {
     fn resolve(&self) -> TResult<DependencyGraph>;
 }
 
 pub(crate) struct CargoMetadataResolver {
+    manifest_path: PathBuf,
     metadata_command: MetadataCommand,
+    dependency_kinds: Vec<DependencyKind>,
 }
 
 impl CargoMetadataResolver {
@@ -17,19 +23,49 @@ impl CargoMetadataResolver {
 
         let mut metadata_command = MetadataCommand::new();
         metadata_command.manifest_path(manifest_path);
+        metadata_command.other_options(filter_platform_args(config));
 
-        Ok(Self { metadata_command })
+        Ok(Self {
+            manifest_path: manifest_path.to_path_buf(),
+            metadata_command,
+            dependency_kinds: DependencyKind::build_kinds(),
+        })
+    }
+
+    /// Only resolve the dependencies of the root crate of the given kinds, together with their
+    /// own dependencies. By default, dev-dependencies are left out.
+    pub fn with_dependency_kinds(mut self, kinds: Vec<DependencyKind>) -> Self {
+        self.dependency_kinds = kinds;
+        self
     }
 }
 
+/// Only resolve the dependencies which are built for the targets the crate is checked against,
+/// so platform-specific dependencies of other targets (e.g. `winapi` on Linux) are left out.
+fn filter_platform_args(config: &Config) -> Vec<String> {
+    let targets = if config.check_targets().is_empty() {
+        vec![config.target().as_str()]
+    } else {
+        config.check_targets().iter().map(String::as_str).collect()
+    };
+
+    targets
+        .into_iter()
+        .flat_map(|target| ["--filter-platform".to_string(), target.to_string()])
+        .collect()
+}
+
 impl DependencyResolver for CargoMetadataResolver {
     fn resolve(&self) -> TResult<DependencyGraph> {
         let result = self.metadata_command.exec()?;
 
-        let our_crate = result
-            .root_package()
-            .ok_or(CargoMSRVError::NoCrateRootFound)
-            .map(|pkg| pkg.id.clone())?;
+        let our_crate = match result.root_package() {
+            Some(package) => package.id.clone(),
+            None if is_virtual_manifest(&self.manifest_path) => {
+                return Err(CargoMSRVError::VirtualManifest(self.manifest_path.clone()));
+            }
+            None => return Err(CargoMSRVError::NoCrateRootFound),
+        };
 
         if let Some(dependencies) = result.resolve {
             let node_alloc = dependencies.nodes.len();
@@ -39,44 +75,138 @@ impl DependencyResolver for CargoMetadataResolver {
                 &mut graph,
                 result.packages.into_iter(),
                 dependencies.nodes.into_iter(),
+                &self.dependency_kinds,
             );
 
-            Ok(graph)
+            Ok(graph.reachable_from_root())
         } else {
             Ok(DependencyGraph::empty(our_crate))
         }
     }
 }
 
-/// Builds a package graph from  1) a set of packages and 2) a given dependency graph.
-fn build_package_graph<Ip, Id>(graph: &mut DependencyGraph, packages: Ip, dependencies: Id)
-where
+/// Builds a package graph from  1) a set of packages and 2) a given dependency graph, only
+/// following the dependencies of the given kinds.
+fn build_package_graph<Ip, Id>(
+    graph: &mut DependencyGraph,
+    packages: Ip,
+    dependencies: Id,
+    kinds: &[DependencyKind],
+) where
     Ip: IntoIterator<Item = cargo_metadata::Package>,
     Id: IntoIterator<Item = cargo_metadata::Node>,
 {
-    // Add nodes to the petgraph
-    for package in packages {
+    let dependencies = dependencies.into_iter().collect::<Vec<_>>();
+    let resolved = dependencies
+        .iter()
+        .map(|node| &node.id)
+        .collect::<HashSet<_>>();
+
+    // Add nodes to the petgraph, leaving out the packages which aren't part of the resolved graph,
+    // such as the dependencies of another platform
+    for package in packages
+        .into_iter()
+        .filter(|package| resolved.contains(&package.id))
+    {
         let package_id = package.id.clone();
         let node_index = graph.packages.add_node(package);
         let _ = graph.index.insert(package_id, node_index.index());
     }
 
+    // The kinds of the dependencies of the root crate, which are inherited by their own
+    // dependencies
+    let mut direct_kinds = HashMap::new();
+
     for dependency in dependencies {
         for child in dependency.deps {
-            use cargo_metadata::DependencyKind;
-            // do not include dev-dependencies
-            // you need normal and build dependencies to build crates, but not dev
-            if child
+            let mut child_kinds = child
                 .dep_kinds
                 .iter()
-                .all(|k| k.kind == DependencyKind::Normal || k.kind == DependencyKind::Build)
-            {
-                let child = graph.index[&child.pkg];
-                let ancestor = graph.index[&dependency.id];
+                .filter_map(|info| dependency_kind(&info.kind))
+                .filter(|kind| kinds.contains(kind))
+                .collect::<Vec<_>>();
+
+            if child_kinds.is_empty() {
+                continue;
+            }
 
-                // add link
-                graph.packages.add_edge(ancestor.into(), child.into(), ());
+            let child_index = graph.index[&child.pkg];
+            let ancestor = graph.index[&dependency.id];
+
+            // add link
+            graph
+                .packages
+                .add_edge(ancestor.into(), child_index.into(), ());
+
+            if dependency.id == graph.root_crate {
+                child_kinds.sort();
+                child_kinds.dedup();
+                direct_kinds.insert(child.pkg, child_kinds);
             }
         }
     }
+
+    graph.inherit_kinds(direct_kinds);
+}
+
+fn dependency_kind(kind: &CargoDependencyKind) -> Option<DependencyKind> {
+    match kind {
+        CargoDependencyKind::Normal => Some(DependencyKind::Normal),
+        CargoDependencyKind::Development => Some(DependencyKind::Dev),
+        CargoDependencyKind::Build => Some(DependencyKind::Build),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Action, ConfigBuilder};
+
+    #[test]
+    fn cargo_dependency_kinds() {
+        assert_eq!(
+            dependency_kind(&CargoDependencyKind::Normal),
+            Some(DependencyKind::Normal)
+        );
+        assert_eq!(
+            dependency_kind(&CargoDependencyKind::Development),
+            Some(DependencyKind::Dev)
+        );
+        assert_eq!(
+            dependency_kind(&CargoDependencyKind::Build),
+            Some(DependencyKind::Build)
+        );
+        assert_eq!(dependency_kind(&CargoDependencyKind::Unknown), None);
+    }
+
+    #[test]
+    fn filter_platform_of_target() {
+        let config = ConfigBuilder::new(Action::List, "x86_64-unknown-linux-gnu").build();
+
+        assert_eq!(
+            filter_platform_args(&config),
+            vec!["--filter-platform", "x86_64-unknown-linux-gnu"]
+        );
+    }
+
+    #[test]
+    fn filter_platform_of_each_check_target() {
+        let config = ConfigBuilder::new(Action::List, "x86_64-unknown-linux-gnu")
+            .check_targets(vec![
+                "x86_64-pc-windows-msvc".to_string(),
+                "wasm32-unknown-unknown".to_string(),
+            ])
+            .build();
+
+        assert_eq!(
+            filter_platform_args(&config),
+            vec![
+                "--filter-platform",
+                "x86_64-pc-windows-msvc",
+                "--filter-platform",
+                "wasm32-unknown-unknown"
+            ]
+        );
+    }
 }
@@ -0,0 +1,460 @@
+//! Diagnose why a toolchain was found to be incompatible, from the output of the check command.
+
+use std::fmt;
+
+/// The probable cause of a failed check.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Diagnosis {
+    /// A dependency requires a more recent Rust version, as specified by its `rust-version`.
+    DependencyRustVersion { package: String, required: String },
+    /// The Cargo manifest of a dependency requires a Cargo feature which is not supported by the
+    /// toolchain, for example the `edition2021` feature.
+    DependencyManifest { package: String, feature: String },
+    /// A crate uses a language or library feature which is not stable in the toolchain.
+    UnstableFeature {
+        package: Option<String>,
+        feature: String,
+    },
+    /// A crate failed to compile, for an unknown reason.
+    CompileError { package: String },
+    /// The check command uses a flag, such as `--all-targets`, which is not supported by the
+    /// Cargo version of the toolchain, so the crate was not checked at all.
+    UnsupportedFlag { flag: String },
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DependencyRustVersion { package, required } => write!(
+                f,
+                "dependency '{}' requires Rust {} or newer",
+                package, required
+            ),
+            Self::DependencyManifest { package, feature } => write!(
+                f,
+                "the Cargo manifest of dependency '{}' requires Cargo feature '{}'",
+                package, feature
+            ),
+            Self::UnstableFeature {
+                package: Some(package),
+                feature,
+            } => write!(
+                f,
+                "crate '{}' uses a feature which is not stable in this Rust version: {}",
+                package, feature
+            ),
+            Self::UnstableFeature {
+                package: None,
+                feature,
+            } => write!(
+                f,
+                "a feature is used which is not stable in this Rust version: {}",
+                feature
+            ),
+            Self::CompileError { package } => write!(f, "crate '{}' failed to compile", package),
+            Self::UnsupportedFlag { flag } => write!(
+                f,
+                "the check command uses '{}', which is not supported by the Cargo version of this toolchain",
+                flag
+            ),
+        }
+    }
+}
+
+/// A language or library feature which is not supported by a toolchain, as reported by rustc.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnsupportedFeature {
+    /// The error message, e.g. `use of unstable library feature 'let_else'`.
+    pub message: String,
+    /// The Rust version which is required for the feature, if rustc reports it.
+    pub required: Option<String>,
+    /// The file in which the feature is used.
+    pub file: Option<String>,
+    /// The line on which the feature is used.
+    pub line: Option<u32>,
+}
+
+/// Find the features which are not supported by the toolchain, from the (stderr) output of the
+/// check command.
+///
+/// Recognizes unstable feature errors (E0658), and errors which mention the Rust version which
+/// is required, such as:
+///
+/// ```text
+/// error[E0658]: use of unstable library feature 'let_else'
+///  --> src/lib.rs:2:5
+/// ```
+///
+/// Duplicate reports are only included once.
+pub fn unsupported_features(output: &str) -> Vec<UnsupportedFeature> {
+    let lines = output.lines().collect::<Vec<_>>();
+    let mut features = Vec::<UnsupportedFeature>::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let message = match after(line, "error[E0658]: ") {
+            Some(message) => message,
+            None if line.starts_with("error") && line.contains("requires Rust ") => {
+                line.split_once(": ").map_or(*line, |(_, message)| message)
+            }
+            None => continue,
+        };
+
+        let required = after(message, "requires Rust ")
+            .and_then(|rest| rest.split_whitespace().next())
+            .map(|version| version.trim_end_matches(|c: char| !c.is_ascii_digit()))
+            .map(String::from);
+
+        // The location follows the error message, before the next diagnostic
+        let location = lines[i + 1..]
+            .iter()
+            .take_while(|line| !line.starts_with("error") && !line.starts_with("warning"))
+            .find_map(|line| after(line, "--> "));
+
+        let (file, line) = match location.and_then(parse_location) {
+            Some((file, line)) => (Some(file.to_string()), Some(line)),
+            None => (None, None),
+        };
+
+        let feature = UnsupportedFeature {
+            message: message.trim().to_string(),
+            required,
+            file,
+            line,
+        };
+
+        if !features.contains(&feature) {
+            features.push(feature);
+        }
+    }
+
+    features
+}
+
+/// Split a location like `src/lib.rs:2:5` into its file and line.
+fn parse_location(location: &str) -> Option<(&str, u32)> {
+    let mut parts = location.trim().rsplitn(3, ':');
+    let _column = parts.next()?;
+    let line = parts.next()?.parse().ok()?;
+    let file = parts.next()?;
+
+    Some((file, line))
+}
+
+/// Find the most probable cause of a failed check, from the (stderr) output of the check command.
+///
+/// Returns `None` if the output doesn't contain any of the known causes.
+pub fn diagnose(output: &str) -> Option<Diagnosis> {
+    unsupported_flag(output)
+        .map(|flag| Diagnosis::UnsupportedFlag { flag })
+        .or_else(|| dependency_rust_version(output))
+        .or_else(|| dependency_manifest(output))
+        .or_else(|| unstable_feature(output))
+        .or_else(|| compile_error(output))
+}
+
+/// Cargo refuses to build a package which requires a more recent Rust version.
+///
+/// Older versions of Cargo report:
+///
+/// `error: package `foo v1.2.3` cannot be built because it requires rustc 1.60 or newer, ...`
+///
+/// Newer versions of Cargo report:
+///
+/// ```text
+/// error: rustc 1.56.0 is not supported by the following package:
+///   foo@1.2.3 requires rustc 1.60
+/// ```
+fn dependency_rust_version(output: &str) -> Option<Diagnosis> {
+    output.lines().find_map(|line| {
+        if let Some(rest) = after(line, "package `") {
+            let (package, rest) = rest.split_once('`')?;
+            let required = after(rest, "requires rustc ")?.split_whitespace().next()?;
+
+            return Some(Diagnosis::DependencyRustVersion {
+                package: package.to_string(),
+                required: required.to_string(),
+            });
+        }
+
+        let (package, required) = line.trim().split_once(" requires rustc ")?;
+
+        if package.contains(' ') {
+            return None;
+        }
+
+        Some(Diagnosis::DependencyRustVersion {
+            package: package.to_string(),
+            required: required.split_whitespace().next()?.to_string(),
+        })
+    })
+}
+
+/// Cargo fails to parse the manifest of a dependency, for example because it uses a newer edition.
+///
+/// ```text
+/// error: failed to parse manifest at `/home/user/.cargo/registry/src/index/foo-1.2.3/Cargo.toml`
+///
+/// Caused by:
+///   feature `edition2021` is required
+/// ```
+fn dependency_manifest(output: &str) -> Option<Diagnosis> {
+    let manifest = output
+        .lines()
+        .find_map(|line| after(line, "failed to parse manifest at `"))?;
+    let manifest = manifest.split('`').next()?;
+
+    let package = manifest
+        .trim_end_matches("Cargo.toml")
+        .trim_end_matches(|c| c == '/' || c == '\\')
+        .rsplit(|c| c == '/' || c == '\\')
+        .next()?;
+
+    let feature = output
+        .lines()
+        .find_map(|line| after(line, "feature `"))
+        .and_then(|rest| rest.split('`').next())
+        .filter(|_| output.contains("` is required"))?;
+
+    Some(Diagnosis::DependencyManifest {
+        package: package.to_string(),
+        feature: feature.to_string(),
+    })
+}
+
+/// rustc reports the use of unstable features with error code E0658.
+fn unstable_feature(output: &str) -> Option<Diagnosis> {
+    let feature = output
+        .lines()
+        .find_map(|line| after(line, "error[E0658]: "))?;
+
+    Some(Diagnosis::UnstableFeature {
+        package: could_not_compile(output).map(String::from),
+        feature: feature.trim().to_string(),
+    })
+}
+
+/// The flag of the check command which Cargo doesn't recognize, if the check failed because of
+/// it, instead of because of the crate.
+///
+/// Depending on its version, Cargo reports:
+///
+/// ```text
+/// error: Unknown flag: '--all-targets'
+/// error: Found argument '--all-targets' which wasn't expected, or isn't valid in this context
+/// error: unexpected argument '--all-targets' found
+/// ```
+pub fn unsupported_flag(output: &str) -> Option<String> {
+    output.lines().find_map(|line| {
+        let rest = after(line, "error: Unknown flag: '")
+            .or_else(|| after(line, "error: Found argument '"))
+            .or_else(|| after(line, "error: unexpected argument '"))?;
+
+        rest.split('\'')
+            .next()
+            .filter(|flag| flag.starts_with('-'))
+            .map(String::from)
+    })
+}
+
+fn compile_error(output: &str) -> Option<Diagnosis> {
+    could_not_compile(output).map(|package| Diagnosis::CompileError {
+        package: package.to_string(),
+    })
+}
+
+/// The name of the crate which failed to compile: `error: could not compile `foo``
+fn could_not_compile(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .find_map(|line| after(line, "could not compile `"))
+        .and_then(|rest| rest.split('`').next())
+}
+
+/// The remainder of `line` after the first occurrence of `pattern`.
+fn after<'l>(line: &'l str, pattern: &str) -> Option<&'l str> {
+    line.find(pattern).map(|i| &line[i + pattern.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dependency_requires_newer_rustc_old_cargo() {
+        let output = "error: package `foo v1.2.3` cannot be built because it requires rustc 1.60 or newer, while the currently active rustc version is 1.56.0";
+
+        assert_eq!(
+            diagnose(output),
+            Some(Diagnosis::DependencyRustVersion {
+                package: "foo v1.2.3".to_string(),
+                required: "1.60".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn dependency_requires_newer_rustc_new_cargo() {
+        let output = r#"error: rustc 1.56.0 is not supported by the following package:
+  foo@1.2.3 requires rustc 1.60
+"#;
+
+        assert_eq!(
+            diagnose(output),
+            Some(Diagnosis::DependencyRustVersion {
+                package: "foo@1.2.3".to_string(),
+                required: "1.60".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn dependency_manifest_requires_feature() {
+        let output = r#"error: failed to download `foo v1.2.3`
+
+Caused by:
+  unable to get packages from source
+
+Caused by:
+  failed to parse manifest at `/home/user/.cargo/registry/src/github.com-1ecc6299db9ec823/foo-1.2.3/Cargo.toml`
+
+Caused by:
+  feature `edition2021` is required
+"#;
+
+        assert_eq!(
+            diagnose(output),
+            Some(Diagnosis::DependencyManifest {
+                package: "foo-1.2.3".to_string(),
+                feature: "edition2021".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn unstable_feature_in_crate() {
+        let output = r#"   Compiling bar v0.1.0 (/home/user/bar)
+error[E0658]: use of unstable library feature 'let_else'
+ --> src/lib.rs:2:5
+  |
+2 |     let Some(x) = y else { return };
+  |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+error: could not compile `bar` due to previous error
+"#;
+
+        assert_eq!(
+            diagnose(output),
+            Some(Diagnosis::UnstableFeature {
+                package: Some("bar".to_string()),
+                feature: "use of unstable library feature 'let_else'".to_string(),
+            })
+        );
+    }
+
+    #[yare::parameterized(
+        docopt = { "error: Unknown flag: '--all-targets'\n\nUsage:\n    cargo check [options]\n" },
+        clap2 = { "error: Found argument '--all-targets' which wasn't expected, or isn't valid in this context\n\nUSAGE:\n    cargo check [OPTIONS]\n" },
+        clap4 = { "error: unexpected argument '--all-targets' found\n\nUsage: cargo check [OPTIONS]\n" },
+    )]
+    fn flag_unsupported_by_cargo(output: &str) {
+        assert_eq!(
+            diagnose(output),
+            Some(Diagnosis::UnsupportedFlag {
+                flag: "--all-targets".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn unexpected_value_is_not_a_flag() {
+        let output =
+            "error: Found argument 'foo' which wasn't expected, or isn't valid in this context";
+
+        assert_eq!(unsupported_flag(output), None);
+    }
+
+    #[test]
+    fn unknown_compile_error() {
+        let output = r#"error[E0425]: cannot find value `x` in this scope
+error: could not compile `bar` due to previous error
+"#;
+
+        assert_eq!(
+            diagnose(output),
+            Some(Diagnosis::CompileError {
+                package: "bar".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn no_diagnosis() {
+        assert_eq!(diagnose("error: something unexpected happened"), None);
+    }
+
+    #[test]
+    fn unsupported_features_with_locations() {
+        let output = r#"   Compiling bar v0.1.0 (/home/user/bar)
+error[E0658]: use of unstable library feature 'let_else'
+ --> src/lib.rs:2:5
+  |
+2 |     let Some(x) = y else { return };
+  |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+error[E0658]: const generics are unstable
+  --> src/generic.rs:10:12
+   |
+10 | struct A<const N: usize>;
+   |            ^
+
+error[E0658]: use of unstable library feature 'let_else'
+ --> src/lib.rs:2:5
+
+error: could not compile `bar` due to 2 previous errors
+"#;
+
+        assert_eq!(
+            unsupported_features(output),
+            vec![
+                UnsupportedFeature {
+                    message: "use of unstable library feature 'let_else'".to_string(),
+                    required: None,
+                    file: Some("src/lib.rs".to_string()),
+                    line: Some(2),
+                },
+                UnsupportedFeature {
+                    message: "const generics are unstable".to_string(),
+                    required: None,
+                    file: Some("src/generic.rs".to_string()),
+                    line: Some(10),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_feature_with_required_rust_version() {
+        let output = r#"error: `async fn` in traits requires Rust 1.75.
+ --> src/lib.rs:4:5
+"#;
+
+        assert_eq!(
+            unsupported_features(output),
+            vec![UnsupportedFeature {
+                message: "`async fn` in traits requires Rust 1.75.".to_string(),
+                required: Some("1.75".to_string()),
+                file: Some("src/lib.rs".to_string()),
+                line: Some(4),
+            }]
+        );
+    }
+
+    #[test]
+    fn no_unsupported_features() {
+        let output = r#"error[E0425]: cannot find value `x` in this scope
+error: could not compile `bar` due to previous error
+"#;
+
+        assert!(unsupported_features(output).is_empty());
+    }
+}
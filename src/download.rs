@@ -1,5 +1,12 @@
+use std::ffi::OsStr;
+use std::thread;
+use std::time::Duration;
+
+use crate::check::cancel;
 use crate::command::RustupCommand;
-use crate::reporter::event::SetupToolchain;
+use crate::reporter::event::{
+    SetupStage, SetupToolchain, SetupToolchainProgress, SetupToolchainRetry,
+};
 use crate::toolchain::ToolchainSpec;
 use crate::{CargoMSRVError, Reporter, TResult};
 
@@ -7,14 +14,218 @@ pub trait DownloadToolchain {
     fn download(&self, toolchain: &ToolchainSpec) -> TResult<()>;
 }
 
+/// How often a failed installation is retried, and how long to wait before each retry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    retries: u32,
+    backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Retry a failed installation up to `retries` times. The first retry is delayed by
+    /// `backoff`, and the delay doubles with each further retry.
+    pub fn new(retries: u32, backoff: Duration) -> Self {
+        Self { retries, backoff }
+    }
+
+    /// The delay before the given retry, starting at 1.
+    fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+
+        self.backoff.checked_mul(factor).unwrap_or(Duration::MAX)
+    }
+}
+
 #[derive(Debug)]
 pub struct ToolchainDownloader<'reporter, R: Reporter> {
     reporter: &'reporter R,
+    retry_policy: RetryPolicy,
 }
 
 impl<'reporter, R: Reporter> ToolchainDownloader<'reporter, R> {
-    pub fn new(reporter: &'reporter R) -> Self {
-        Self { reporter }
+    pub fn new(reporter: &'reporter R, retry_policy: RetryPolicy) -> Self {
+        Self {
+            reporter,
+            retry_policy,
+        }
+    }
+
+    /// Run the given installation, and retry it according to the retry policy while it fails.
+    ///
+    /// Each retry is reported, and the error of the last attempt is returned once no retries are
+    /// left.
+    fn retrying(
+        &self,
+        toolchain: &ToolchainSpec,
+        mut install: impl FnMut() -> TResult<()>,
+    ) -> TResult<()> {
+        let mut retry = 0;
+
+        loop {
+            match install() {
+                Ok(()) => return Ok(()),
+                // A failure to report an event won't be resolved by trying again
+                Err(CargoMSRVError::Storyteller) => return Err(CargoMSRVError::Storyteller),
+                // Neither will a cancelled run
+                Err(_) if cancel::is_cancelled() => return Err(CargoMSRVError::Cancelled),
+                // Nor a component which doesn't exist for the toolchain
+                Err(error @ CargoMSRVError::ComponentUnavailable { .. }) => return Err(error),
+                Err(error) if retry < self.retry_policy.retries => {
+                    retry += 1;
+                    let delay = self.retry_policy.delay(retry);
+
+                    warn!(
+                        toolchain = toolchain.spec(),
+                        retry,
+                        delay_secs = delay.as_secs(),
+                        %error,
+                        "retrying installation"
+                    );
+
+                    self.reporter.report_event(SetupToolchainRetry::new(
+                        toolchain.to_owned(),
+                        retry,
+                        self.retry_policy.retries,
+                        delay.as_secs(),
+                        error.to_string(),
+                    ))?;
+
+                    thread::sleep(delay);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Install the standard library of the given compilation target for the toolchain, with
+    /// `rustup target add`. Does nothing if the target is already installed.
+    #[instrument(skip(self, toolchain, target))]
+    pub fn add_target(&self, toolchain: &ToolchainSpec, target: &str) -> TResult<()> {
+        self.retrying(toolchain, || self.install_target(toolchain, target))
+    }
+
+    /// Install the given component, such as `clippy`, for the toolchain, with
+    /// `rustup component add`. Does nothing if the component is already installed.
+    ///
+    /// Returns [`CargoMSRVError::ComponentUnavailable`] if the toolchain has no such component,
+    /// which is not retried.
+    #[instrument(skip(self, toolchain, component))]
+    pub fn add_component(&self, toolchain: &ToolchainSpec, component: &str) -> TResult<()> {
+        self.retrying(toolchain, || self.install_component(toolchain, component))
+    }
+
+    /// Install the toolchain with `rustup install`.
+    fn install(&self, toolchain: &ToolchainSpec) -> TResult<()> {
+        // rustup reports its progress on stderr, which is relayed as it is written
+        let rustup = RustupCommand::new()
+            .with_stdout()
+            .with_stderr()
+            .with_args(&["--profile", "minimal", toolchain.spec()])
+            .spawn_reported(OsStr::new("install"), self.reporter)?
+            .wait_with_stderr_lines(|line| match parse_progress(line) {
+                Some((stage, component)) => {
+                    self.reporter.report_event(SetupToolchainProgress::new(
+                        toolchain.to_owned(),
+                        stage,
+                        component,
+                    ))?;
+                    Ok(())
+                }
+                None => Ok(()),
+            })?;
+
+        self.reporter.report_event(rustup.finished().clone())?;
+
+        let status = rustup.exit_status();
+
+        if !status.success() {
+            error!(
+                toolchain = toolchain.spec(),
+                stdout = rustup.stdout(),
+                stderr = rustup.stderr(),
+                "rustup failed to install toolchain"
+            );
+
+            eprintln!(
+                "Toolchain Download Failed -> \n\n{:?}\n{:?}\n{:?}\n{:?}\n<-\n\n",
+                toolchain.spec(),
+                rustup.stdout(),
+                rustup.stderr(),
+                "rustup failed to install toolchain"
+            );
+
+            return Err(CargoMSRVError::RustupInstallFailed(
+                toolchain.spec().to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn install_target(&self, toolchain: &ToolchainSpec, target: &str) -> TResult<()> {
+        info!(
+            toolchain = toolchain.spec(),
+            compilation_target = target,
+            "installing target"
+        );
+
+        let rustup = RustupCommand::new()
+            .with_stdout()
+            .with_stderr()
+            .with_args(&["add", "--toolchain", toolchain.spec(), target])
+            .execute_reported(std::ffi::OsStr::new("target"), self.reporter)?;
+
+        if !rustup.exit_status().success() {
+            error!(
+                toolchain = toolchain.spec(),
+                compilation_target = target,
+                stderr = rustup.stderr(),
+                "rustup failed to install target"
+            );
+
+            return Err(CargoMSRVError::RustupTargetAddFailed {
+                toolchain: toolchain.spec().to_string(),
+                target: target.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn install_component(&self, toolchain: &ToolchainSpec, component: &str) -> TResult<()> {
+        info!(
+            toolchain = toolchain.spec(),
+            component, "installing component"
+        );
+
+        let rustup = RustupCommand::new()
+            .with_stdout()
+            .with_stderr()
+            .with_args(&["add", "--toolchain", toolchain.spec(), component])
+            .execute_reported(std::ffi::OsStr::new("component"), self.reporter)?;
+
+        if !rustup.exit_status().success() {
+            error!(
+                toolchain = toolchain.spec(),
+                component,
+                stderr = rustup.stderr(),
+                "rustup failed to install component"
+            );
+
+            if is_component_unavailable(rustup.stderr()) {
+                return Err(CargoMSRVError::ComponentUnavailable {
+                    toolchain: toolchain.spec().to_string(),
+                    component: component.to_string(),
+                });
+            }
+
+            return Err(CargoMSRVError::RustupComponentAddFailed {
+                toolchain: toolchain.spec().to_string(),
+                component: component.to_string(),
+            });
+        }
+
+        Ok(())
     }
 }
 
@@ -25,36 +236,155 @@ impl<'reporter, R: Reporter> DownloadToolchain for ToolchainDownloader<'reporter
 
         self.reporter
             .run_scoped_event(SetupToolchain::new(toolchain.to_owned()), || {
-                let rustup = RustupCommand::new()
-                    .with_stdout()
-                    .with_stderr()
-                    .with_args(&["--profile", "minimal", toolchain.spec()])
-                    .install()?;
+                self.retrying(toolchain, || self.install(toolchain))
+            })
+    }
+}
 
-                let status = rustup.exit_status();
+/// Whether `rustup component add` failed because the component doesn't exist for the toolchain,
+/// rather than because it could not be downloaded, e.g.
+/// `error: toolchain '1.20.0-x86_64-unknown-linux-gnu' does not contain component 'clippy'`.
+fn is_component_unavailable(stderr: &str) -> bool {
+    stderr.contains("does not contain component") || stderr.contains("is unavailable for download")
+}
 
-                if !status.success() {
-                    error!(
-                        toolchain = toolchain.spec(),
-                        stdout = rustup.stdout(),
-                        stderr = rustup.stderr(),
-                        "rustup failed to install toolchain"
-                    );
+/// Parse a progress line of `rustup install`, such as `info: downloading component 'cargo'`,
+/// into its stage and component.
+fn parse_progress(line: &str) -> Option<(SetupStage, Option<String>)> {
+    let message = line.trim().strip_prefix("info: ")?;
 
-                    eprintln!(
-                        "Toolchain Download Failed -> \n\n{:?}\n{:?}\n{:?}\n{:?}\n<-\n\n",
-                        toolchain.spec(),
-                        rustup.stdout(),
-                        rustup.stderr(),
-                        "rustup failed to install toolchain"
-                    );
+    if message.starts_with("syncing channel updates") {
+        return Some((SetupStage::SyncingChannel, None));
+    }
 
-                    return Err(CargoMSRVError::RustupInstallFailed(
-                        toolchain.spec().to_string(),
-                    ));
-                }
+    let (stage, rest) = if let Some(rest) = message.strip_prefix("downloading component ") {
+        (SetupStage::Downloading, rest)
+    } else if let Some(rest) = message.strip_prefix("installing component ") {
+        (SetupStage::Installing, rest)
+    } else {
+        return None;
+    };
+
+    let component = rest.trim_matches(|c| c == '\'' || c == '`').to_string();
 
+    Some((stage, Some(component)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::semver;
+    use std::cell::Cell;
+    use yare::parameterized;
+
+    #[parameterized(
+        first = { 1, 2 },
+        second = { 2, 4 },
+        third = { 3, 8 },
+    )]
+    fn retry_delay(retry: u32, expected_secs: u64) {
+        let policy = RetryPolicy::new(3, Duration::from_secs(2));
+
+        assert_eq!(policy.delay(retry), Duration::from_secs(expected_secs));
+    }
+
+    #[test]
+    fn retries_until_installed() {
+        let reporter = TestReporter::default();
+        let downloader =
+            ToolchainDownloader::new(reporter.reporter(), RetryPolicy::new(2, Duration::ZERO));
+        let version = semver::Version::new(1, 2, 3);
+        let toolchain = ToolchainSpec::new(&version, "test_target");
+        let attempts = Cell::new(0);
+
+        let result = downloader.retrying(&toolchain, || {
+            attempts.set(attempts.get() + 1);
+
+            if attempts.get() < 3 {
+                Err(CargoMSRVError::RustupInstallFailed("1.2.3".to_string()))
+            } else {
                 Ok(())
+            }
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.get(), 3);
+
+        let retries = reporter
+            .wait_for_events()
+            .into_iter()
+            .filter(|event| matches!(event.message(), Message::SetupToolchainRetry(_)))
+            .count();
+
+        assert_eq!(retries, 2);
+    }
+
+    #[test]
+    fn fails_once_out_of_retries() {
+        let reporter = TestReporter::default();
+        let downloader =
+            ToolchainDownloader::new(reporter.reporter(), RetryPolicy::new(1, Duration::ZERO));
+        let version = semver::Version::new(1, 2, 3);
+        let toolchain = ToolchainSpec::new(&version, "test_target");
+        let attempts = Cell::new(0);
+
+        let result = downloader.retrying(&toolchain, || {
+            attempts.set(attempts.get() + 1);
+            Err(CargoMSRVError::RustupInstallFailed("1.2.3".to_string()))
+        });
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::RustupInstallFailed(_))
+        ));
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[test]
+    fn unavailable_component_is_not_retried() {
+        let reporter = TestReporter::default();
+        let downloader =
+            ToolchainDownloader::new(reporter.reporter(), RetryPolicy::new(2, Duration::ZERO));
+        let version = semver::Version::new(1, 2, 3);
+        let toolchain = ToolchainSpec::new(&version, "test_target");
+        let attempts = Cell::new(0);
+
+        let result = downloader.retrying(&toolchain, || {
+            attempts.set(attempts.get() + 1);
+            Err(CargoMSRVError::ComponentUnavailable {
+                toolchain: "1.2.3".to_string(),
+                component: "clippy".to_string(),
             })
+        });
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::ComponentUnavailable { .. })
+        ));
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[parameterized(
+        not_contained = { "error: toolchain '1.20.0-x86_64-unknown-linux-gnu' does not contain component 'clippy' for target 'x86_64-unknown-linux-gnu'", true },
+        unavailable = { "error: component 'clippy' for target 'x86_64-unknown-linux-gnu' is unavailable for download for channel '1.20.0'", true },
+        network = { "error: could not download file from 'https://static.rust-lang.org/dist/channel-rust-1.20.0.toml.sha256'", false },
+    )]
+    fn component_unavailable(stderr: &str, expected: bool) {
+        assert_eq!(is_component_unavailable(stderr), expected);
+    }
+
+    #[parameterized(
+        syncing = { "info: syncing channel updates for '1.60.0-x86_64-unknown-linux-gnu'", Some((SetupStage::SyncingChannel, None)) },
+        downloading = { "info: downloading component 'rust-std'", Some((SetupStage::Downloading, Some("rust-std"))) },
+        installing = { "info: installing component 'cargo'", Some((SetupStage::Installing, Some("cargo"))) },
+        other_info = { "info: latest update on 2022-04-07, rust version 1.60.0", None },
+        not_info = { "error: toolchain '1.60.0' is not installable", None },
+    )]
+    fn progress(line: &str, expected: Option<(SetupStage, Option<&str>)>) {
+        let expected = expected.map(|(stage, component)| (stage, component.map(String::from)));
+
+        assert_eq!(parse_progress(line), expected);
     }
 }
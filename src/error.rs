@@ -7,11 +7,15 @@ use std::string::FromUtf8Error;
 use rust_releases::Release;
 use storyteller::ReporterError;
 
-use crate::cli::rust_releases_opts::{ParseEditionError, ParseEditionOrVersionError};
+use crate::cli::rust_releases_opts::{
+    ParseEditionError, ParseEditionOrVersionError, ParseVersionOrChannelError,
+};
+use crate::config::lockfile_mode::LockfileMode;
 use crate::log_level::ParseLogLevelError;
 use crate::manifest::bare_version::{BareVersion, NoVersionMatchesManifestMsrvError};
+use crate::reporter::event::InvalidSearchSpace;
 
-use crate::sub_command::{show, verify};
+use crate::sub_command::{diff, doctor, pin_lockfile, policy, show, upgrade, verify};
 
 pub(crate) type TResult<T> = Result<T, CargoMSRVError>;
 
@@ -23,6 +27,12 @@ pub enum CargoMSRVError {
     #[error(transparent)]
     CargoMetadata(#[from] cargo_metadata::Error),
 
+    #[error("Invalid config file '{path}': {error}")]
+    ConfigFile {
+        path: PathBuf,
+        error: crate::config::file::Error,
+    },
+
     #[error("The default host triple (target) could not be found.")]
     DefaultHostTripleNotFound,
 
@@ -38,9 +48,18 @@ pub enum CargoMSRVError {
         source: IoErrorSource,
     },
 
+    #[error("The run was cancelled.")]
+    Cancelled,
+
+    #[error("Unable to certify the MSRV: 'package.metadata' in '{}' is not a table", .0.display())]
+    CertificationNotATable(PathBuf),
+
     #[error("{0}")]
     InvalidConfig(String),
 
+    #[error("{0}")]
+    InvalidSearchSpace(InvalidSearchSpace),
+
     #[error(transparent)]
     InvalidRustVersionNumber(#[from] std::num::ParseIntError),
 
@@ -53,7 +72,16 @@ pub enum CargoMSRVError {
     #[error(transparent)]
     NoToolchainsToTry(#[from] NoToolchainsToTryError),
 
-    #[error("Unable to set MSRV for workspace, try setting it for individual packages instead.")]
+    #[error("Package '{0}' is not a member of the workspace")]
+    UnknownWorkspaceMember(String),
+
+    #[error("No members of the workspace at '{}' were selected, after applying --package, --exclude and the default-members of the workspace", .0.display())]
+    NoWorkspaceMembersSelected(PathBuf),
+
+    #[error("The Cargo manifest at '{}' is a virtual manifest, which does not define a package. Select a workspace member with --manifest-path, or run from the directory of a workspace member", .0.display())]
+    VirtualManifest(PathBuf),
+
+    #[error("Unable to set MSRV for workspace, try setting it for individual packages instead, or use --propagate to let the members inherit it from the workspace.")]
     WorkspaceFound,
 
     #[error(transparent)]
@@ -68,6 +96,9 @@ pub enum CargoMSRVError {
     #[error(transparent)]
     ParseEditionOrVersion(#[from] ParseEditionOrVersionError),
 
+    #[error(transparent)]
+    ParseVersionOrChannel(#[from] ParseVersionOrChannelError),
+
     #[error(transparent)]
     ParseLogLevel(#[from] ParseLogLevelError),
 
@@ -81,15 +112,52 @@ pub enum CargoMSRVError {
     #[cfg(feature = "rust-releases-dist-source")]
     RustReleasesRustDistSource(#[from] rust_releases::RustDistError),
 
+    #[error("Invalid releases file '{}': {reason}", .path.display())]
+    InvalidReleasesFile { path: PathBuf, reason: String },
+
     #[error("Unable to parse rust-releases source from '{0}'")]
     RustReleasesSourceParseError(String),
 
+    #[error("Unable to parse release channel from '{0}'")]
+    ReleaseChannelParseError(String),
+
     #[error("There are no Rust releases in the rust-releases index")]
     RustReleasesEmptyReleaseSet,
 
     #[error("Unable to install toolchain with `rustup install {0}`.")]
     RustupInstallFailed(String),
 
+    #[error("Unable to install target '{target}' with `rustup target add --toolchain {toolchain} {target}`.")]
+    RustupTargetAddFailed { toolchain: String, target: String },
+
+    #[error("Unable to install component '{component}' with `rustup component add --toolchain {toolchain} {component}`.")]
+    RustupComponentAddFailed {
+        toolchain: String,
+        component: String,
+    },
+
+    #[error("Component '{component}' is not available for toolchain '{toolchain}'.")]
+    ComponentUnavailable {
+        toolchain: String,
+        component: String,
+    },
+
+    #[error("Unable to list the installed toolchains with `rustup toolchain list`.")]
+    UnableToListInstalledToolchains,
+
+    #[error("No stable Rust toolchains are installed for target '{0}', which are required to run offline. Install one with `rustup install <version>`.")]
+    NoInstalledToolchains(String),
+
+    #[error("Up to {downloads} toolchains would be downloaded, which exceeds the maximum of {max} set by --max-downloads. Narrow the search space with --min and --max, or use --bisect.")]
+    TooManyDownloads { downloads: u64, max: u64 },
+
+    #[error("The toolchains which would be downloaded take about {}, but only {} of disk space is available in '{}'.", crate::formatting::byte_size(*.required), crate::formatting::byte_size(*.available), .path.display())]
+    InsufficientDiskSpace {
+        required: u64,
+        available: u64,
+        path: PathBuf,
+    },
+
     #[error("Check toolchain (with `rustup run <toolchain> <command>`) failed.")]
     RustupRunWithCommandFailed,
 
@@ -108,12 +176,54 @@ pub enum CargoMSRVError {
     #[error(transparent)]
     SubCommandShow(#[from] show::Error),
 
+    #[error(transparent)]
+    SubCommandDoctor(#[from] doctor::Error),
+
+    #[error(transparent)]
+    SubCommandUpgrade(#[from] upgrade::Error),
+
+    #[error(transparent)]
+    SubCommandPinLockfile(#[from] pin_lockfile::Error),
+
+    #[error(transparent)]
+    SubCommandDiff(#[from] diff::Error),
+
+    #[error(transparent)]
+    SubCommandPolicy(#[from] policy::Error),
+
     #[error(transparent)]
     SystemTime(#[from] std::time::SystemTimeError),
 
     #[error("The given toolchain could not be found. Run `rustup toolchain list` for an overview of installed toolchains.")]
     ToolchainNotInstalled,
 
+    #[error("Toolchain '{toolchain}' could not be found in toolchain directory '{}'.", .dir.display())]
+    ToolchainNotInDirectory { toolchain: String, dir: PathBuf },
+
+    #[error("The standard library of target '{target}' is not installed for toolchain '{toolchain}' in the toolchain directory.")]
+    TargetNotInToolchainDirectory { toolchain: String, target: String },
+
+    #[error("Unable to link the toolchain at '{}': {reason}", .path.display())]
+    LinkToolchainFailed { path: PathBuf, reason: String },
+
+    #[error("Unable to pull Docker image '{0}'. Is Docker installed and running?")]
+    DockerPullFailed(String),
+
+    #[error("Checking multiple targets is not supported with `--runner docker`.")]
+    DockerRunnerTargets,
+
+    #[error("No stable Rust toolchains for target '{target}' were found in toolchain directory '{}'.", .dir.display())]
+    NoToolchainsInDirectory { target: String, dir: PathBuf },
+
+    #[error("No Rust releases were found in the changelog '{}'.", .0.display())]
+    NoReleasesInChangelog(PathBuf),
+
+    #[error("No channel manifests of Rust releases (channel-rust-<version>.toml) were found in '{}'.", .0.display())]
+    NoReleasesInDistMirror(PathBuf),
+
+    #[error("Unable to list the releases of distribution server '{0}', since it is not a local directory. Use --changelog-path to read the releases from a copy of the Rust changelog instead.")]
+    UnableToListDistMirror(String),
+
     #[error("The given target could not be found. Run `rustup target list` for an overview of available toolchains.")]
     UnknownTarget,
 
@@ -140,6 +250,166 @@ Thank you in advance!"#
 
     #[error("Unable to run the checking command. If --check <cmd> is specified, you could try to verify if you can run the cmd manually.")]
     UnableToRunCheck,
+
+    #[error("The lockfile needs to be updated to check Rust {toolchain}, but {mode} was given. Update the lockfile, for example with `cargo update`, or run without {mode}.\n{stderr}")]
+    LockfileOutdated {
+        toolchain: String,
+        mode: LockfileMode,
+        stderr: String,
+    },
+
+    #[error("Unable to resolve the minimal versions of the dependencies with `rustup run nightly cargo generate-lockfile -Z minimal-versions`. Is a nightly toolchain installed?\n{0}")]
+    UnableToResolveMinimalVersions(String),
+}
+
+impl CargoMSRVError {
+    /// A stable, machine-readable code for the cause of the error, so tools which run
+    /// cargo-msrv can act on specific failures.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::RustupInstallFailed(_)
+            | Self::RustupTargetAddFailed { .. }
+            | Self::RustupComponentAddFailed { .. }
+            | Self::ComponentUnavailable { .. }
+            | Self::DockerPullFailed(_) => ErrorCode::ToolchainInstallFailed,
+            Self::ToolchainNotInstalled
+            | Self::ToolchainNotInDirectory { .. }
+            | Self::TargetNotInToolchainDirectory { .. }
+            | Self::NoInstalledToolchains(_)
+            | Self::NoToolchainsInDirectory { .. }
+            | Self::UnableToListInstalledToolchains
+            | Self::UnknownTarget
+            | Self::LinkToolchainFailed { .. }
+            | Self::DefaultHostTripleNotFound => ErrorCode::ToolchainUnavailable,
+            Self::RustupRunWithCommandFailed
+            | Self::UnableToRunCheck
+            | Self::UnableToResolveMinimalVersions(_) => ErrorCode::CheckFailed,
+            Self::RustReleasesSource(_)
+            | Self::InvalidReleasesFile { .. }
+            | Self::RustReleasesEmptyReleaseSet
+            | Self::NoReleasesInChangelog(_)
+            | Self::NoReleasesInDistMirror(_)
+            | Self::UnableToListDistMirror(_)
+            | Self::UnableToCacheChannelManifest
+            | Self::UnableToParseRustVersion => ErrorCode::IndexUnavailable,
+            #[cfg(feature = "rust-releases-dist-source")]
+            Self::RustReleasesRustDistSource(_) => ErrorCode::IndexUnavailable,
+            Self::BareVersionParse(_)
+            | Self::CargoMetadata(_)
+            | Self::ParseToml(_)
+            | Self::NoCrateRootFound
+            | Self::NoVersionMatchesManifestMSRV(_)
+            | Self::CertificationNotATable(_) => ErrorCode::ManifestInvalid,
+            Self::NoMSRVKeyInCargoToml(_) => ErrorCode::MsrvNotDeclared,
+            Self::ConfigFile { .. }
+            | Self::Env(_)
+            | Self::InvalidConfig(_)
+            | Self::InvalidRustVersionNumber(_)
+            | Self::ParseEdition(_)
+            | Self::ParseEditionOrVersion(_)
+            | Self::ParseVersionOrChannel(_)
+            | Self::ParseLogLevel(_)
+            | Self::RustReleasesSourceParseError(_)
+            | Self::ReleaseChannelParseError(_)
+            | Self::SemverError(_)
+            | Self::UnableToParseCliArgs
+            | Self::UnknownWorkspaceMember(_)
+            | Self::NoWorkspaceMembersSelected(_)
+            | Self::VirtualManifest(_)
+            | Self::WorkspaceFound
+            | Self::DockerRunnerTargets => ErrorCode::ConfigInvalid,
+            Self::InvalidSearchSpace(_) | Self::NoToolchainsToTry(_) => ErrorCode::SearchSpaceEmpty,
+            Self::TooManyDownloads { .. } | Self::InsufficientDiskSpace { .. } => {
+                ErrorCode::PreflightLimitExceeded
+            }
+            Self::UnableToFindAnyGoodVersion { .. } => ErrorCode::NoCompatibleVersion,
+            Self::SetMsrv(_) => ErrorCode::SetMsrvFailed,
+            Self::SubCommandVerify(
+                verify::Error::DownloadFailed { .. }
+                | verify::Error::NotPublished { .. }
+                | verify::Error::ChecksumMismatch { .. },
+            ) => ErrorCode::DownloadFailed,
+            Self::SubCommandVerify(verify::Error::NoPackageName(_)) => ErrorCode::ManifestInvalid,
+            Self::SubCommandVerify(_) => ErrorCode::VerifyFailed,
+            Self::SubCommandShow(show::Error::NoMSRVInCargoManifest(_)) => {
+                ErrorCode::MsrvNotDeclared
+            }
+            Self::SubCommandDoctor(doctor::Error::ProblemsFound(_)) => ErrorCode::ProblemsFound,
+            Self::SubCommandUpgrade(upgrade::Error::NoMsrv(_))
+            | Self::SubCommandPinLockfile(pin_lockfile::Error::NoMsrv(_)) => {
+                ErrorCode::MsrvNotDeclared
+            }
+            Self::SubCommandUpgrade(upgrade::Error::NoPackage(_)) => ErrorCode::ManifestInvalid,
+            Self::LockfileOutdated { .. } => ErrorCode::LockfileOutdated,
+            Self::SubCommandPinLockfile(pin_lockfile::Error::NoLockfile(_))
+            | Self::SubCommandPinLockfile(pin_lockfile::Error::InvalidLockfile) => {
+                ErrorCode::LockfileInvalid
+            }
+            Self::SubCommandDiff(diff::Error::MsrvIncreased { .. }) => ErrorCode::MsrvIncreased,
+            Self::SubCommandDiff(diff::Error::GitFailed { .. }) => ErrorCode::GitFailed,
+            Self::SubCommandPolicy(policy::Error::NoMsrv(_)) => ErrorCode::MsrvNotDeclared,
+            Self::SubCommandPolicy(policy::Error::NoPolicy) => ErrorCode::ConfigInvalid,
+            Self::SubCommandPolicy(policy::Error::NoStableRelease) => ErrorCode::IndexUnavailable,
+            Self::SubCommandPolicy(policy::Error::Violated { .. }) => ErrorCode::PolicyViolated,
+            Self::Io { .. } | Self::InvalidUTF8(_) | Self::SystemTime(_) => ErrorCode::Io,
+            Self::Cancelled => ErrorCode::UserAbort,
+            Self::GenericMessage(_) | Self::Storyteller => ErrorCode::Internal,
+        }
+    }
+}
+
+/// The cause of a failure, as reported in the `code` field of the `terminate_with_failure` event
+/// of the JSON output.
+///
+/// Codes are never renamed or removed, so they can be relied upon by CI scripts; new codes may be
+/// added.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCode {
+    /// A toolchain, or the standard library of a target, could not be installed
+    ToolchainInstallFailed,
+    /// A required toolchain or target is not installed, and may not be installed
+    ToolchainUnavailable,
+    /// The check command could not be run
+    CheckFailed,
+    /// The Rust releases index could not be fetched or read
+    IndexUnavailable,
+    /// The Cargo manifest, or the crate metadata, could not be read
+    ManifestInvalid,
+    /// The Cargo manifest doesn't declare an MSRV, while one is required
+    MsrvNotDeclared,
+    /// The given options, or the configuration file, are invalid
+    ConfigInvalid,
+    /// There are no Rust versions to check within the given bounds
+    SearchSpaceEmpty,
+    /// The toolchains which would be downloaded exceed the limits of the pre-flight estimate
+    PreflightLimitExceeded,
+    /// None of the checked Rust versions is compatible
+    NoCompatibleVersion,
+    /// The crate is not compatible with its MSRV, or the MSRV is too conservative
+    VerifyFailed,
+    /// The MSRV increased between two revisions
+    MsrvIncreased,
+    /// The MSRV violates the MSRV policy
+    PolicyViolated,
+    /// The MSRV could not be written to the Cargo manifest
+    SetMsrvFailed,
+    /// The lockfile could not be read
+    LockfileInvalid,
+    /// The lockfile needs to be updated, while `--locked` or `--frozen` was given
+    LockfileOutdated,
+    /// `cargo msrv doctor` found problems with the environment
+    ProblemsFound,
+    /// A git command failed
+    GitFailed,
+    /// The published crate could not be downloaded or unpacked
+    DownloadFailed,
+    /// A file could not be read or written, or a process could not be run
+    Io,
+    /// The run was cancelled by the user, e.g. with Ctrl-C
+    UserAbort,
+    /// An unexpected failure within cargo-msrv
+    Internal,
 }
 
 impl From<String> for CargoMSRVError {
@@ -168,6 +438,9 @@ pub enum IoErrorSource {
     #[error("Unable to rename file '{0}'")]
     RenameFile(PathBuf),
 
+    #[error("Unable to write to stdout")]
+    WriteStdout,
+
     #[error("Unable to spawn process '{0:?}'")]
     SpawnProcess(OsString),
 
@@ -181,6 +454,12 @@ pub enum SetMsrvError {
         "Unable to set the MSRV in the 'package.metadata' table: 'package.metadata' is not a table"
     )]
     NotATable,
+
+    #[error("Unable to set the MSRV of the workspace: the Cargo manifest at '{}' does not define a workspace", .0.display())]
+    NoWorkspace(PathBuf),
+
+    #[error("Unable to set MSRV {0} for the workspace: members can only inherit an MSRV of Rust 1.56 or later, the first version which supports 'rust-version'")]
+    WorkspaceMsrvUnsupported(BareVersion),
 }
 
 #[derive(Debug, thiserror::Error)]
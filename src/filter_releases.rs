@@ -1,4 +1,5 @@
 use crate::manifest::bare_version;
+use crate::reporter::event::InvalidSearchSpace;
 use crate::{semver, Config};
 use rust_releases::linear::LatestStableReleases;
 use rust_releases::Release;
@@ -23,6 +24,50 @@ pub fn filter_releases(config: &Config, releases: &[Release]) -> Vec<Release> {
         .collect::<Vec<_>>()
 }
 
+/// Check whether the range given by the minimum and maximum version can contain any of the
+/// releases.
+///
+/// A range which falls between two releases is not rejected here, but results in an empty
+/// search space.
+pub fn validate_search_space(
+    config: &Config,
+    releases: &[Release],
+) -> Result<(), InvalidSearchSpace> {
+    let min = config.minimum_version();
+    let max = config.maximum_version();
+    let latest = releases.iter().map(Release::version).max();
+    let earliest = releases.iter().map(Release::version).min();
+
+    if let (Some(min), Some(max)) = (min, max) {
+        if !max.is_at_most(&min.to_semver_version()) {
+            return Err(InvalidSearchSpace::MinAboveMax {
+                min: min.clone(),
+                max: max.clone(),
+            });
+        }
+    }
+
+    if let (Some(min), Some(latest)) = (min, latest) {
+        if !min.is_at_least(latest) {
+            return Err(InvalidSearchSpace::MinAboveLatest {
+                min: min.clone(),
+                latest: latest.clone(),
+            });
+        }
+    }
+
+    if let (Some(max), Some(earliest)) = (max, earliest) {
+        if !max.is_at_most(earliest) {
+            return Err(InvalidSearchSpace::MaxBelowEarliest {
+                max: max.clone(),
+                earliest: earliest.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 fn include_version(
     current: &semver::Version,
     min_version: Option<&bare_version::BareVersion>,
@@ -29,3 +29,30 @@ pub fn table<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Table {
             .with(Alignment::top()),
     )
 }
+
+/// Format a number of bytes in MiB, or in GiB when it's at least 1 GiB.
+pub fn byte_size(bytes: u64) -> String {
+    const MIB: u64 = 1024 * 1024;
+    const GIB: u64 = 1024 * MIB;
+
+    if bytes >= GIB {
+        format!("{:.1} GiB", bytes as f64 / GIB as f64)
+    } else {
+        format!("{} MiB", bytes / MIB)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::byte_size;
+    use yare::parameterized;
+
+    #[parameterized(
+        zero = { 0, "0 MiB" },
+        mebibytes = { 450 * 1024 * 1024, "450 MiB" },
+        gibibytes = { 3 * 1024 * 1024 * 1024 / 2, "1.5 GiB" },
+    )]
+    fn format_byte_size(bytes: u64, expected: &str) {
+        assert_eq!(byte_size(bytes), expected);
+    }
+}
@@ -0,0 +1,118 @@
+//! An on-disk cache of the release index, so the index doesn't have to be fetched on every run.
+
+use std::fs;
+use std::iter::FromIterator;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use rust_releases::{semver, Release, ReleaseIndex};
+
+use crate::config::ReleaseSource;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+
+/// The cached release index of a single release source.
+///
+/// The index is stored as a plain list of versions, one per line, most recent first. The
+/// modification time of the file is used to determine the age of the cached index.
+pub struct IndexCache {
+    path: PathBuf,
+}
+
+impl IndexCache {
+    pub fn new(dir: &Path, source: ReleaseSource) -> Self {
+        Self {
+            path: dir.join(format!("index-{}", source)),
+        }
+    }
+
+    /// The default cache directory, i.e. the `cargo-msrv` folder in the cache directory of the user.
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|path| path.join("cargo-msrv"))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read the cached index, if it exists and is younger than the given time to live.
+    pub fn read(&self, ttl: Duration) -> Option<ReleaseIndex> {
+        let modified = fs::metadata(&self.path).ok()?.modified().ok()?;
+        let age = SystemTime::now().duration_since(modified).ok()?;
+
+        if age >= ttl {
+            return None;
+        }
+
+        let contents = fs::read_to_string(&self.path).ok()?;
+
+        let releases = contents
+            .lines()
+            .map(|line| semver::Version::parse(line.trim()).map(Release::new_stable))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+
+        (!releases.is_empty()).then(|| ReleaseIndex::from_iter(releases))
+    }
+
+    pub fn write(&self, index: &ReleaseIndex) -> TResult<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::WriteFile(dir.to_path_buf()),
+            })?;
+        }
+
+        let contents = index
+            .releases()
+            .iter()
+            .map(|release| format!("{}\n", release.version()))
+            .collect::<String>();
+
+        fs::write(&self.path, contents).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::WriteFile(self.path.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, TestDir};
+
+    fn index() -> ReleaseIndex {
+        ReleaseIndex::from_iter(vec![
+            Release::new_stable(semver::Version::new(1, 63, 0)),
+            Release::new_stable(semver::Version::new(1, 62, 1)),
+        ])
+    }
+
+    #[test]
+    fn read_written_index() {
+        let tmp = TestDir::temp();
+        let cache = IndexCache::new(tmp.root(), ReleaseSource::RustChangelog);
+
+        cache.write(&index()).unwrap();
+        let cached = cache.read(Duration::from_secs(60)).unwrap();
+
+        assert_eq!(cached.releases(), index().releases());
+    }
+
+    #[test]
+    fn expired_index() {
+        let tmp = TestDir::temp();
+        let cache = IndexCache::new(tmp.root(), ReleaseSource::RustChangelog);
+
+        cache.write(&index()).unwrap();
+
+        assert!(cache.read(Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn no_cached_index() {
+        let tmp = TestDir::temp();
+        let cache = IndexCache::new(tmp.root(), ReleaseSource::RustChangelog);
+
+        assert!(cache.read(Duration::from_secs(60)).is_none());
+    }
+}
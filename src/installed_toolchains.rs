@@ -0,0 +1,207 @@
+//! A release index of the locally installed toolchains, which can be used when the release index
+//! can't be fetched, for example in air-gapped environments.
+
+use std::ffi::OsStr;
+use std::iter::FromIterator;
+use std::path::Path;
+
+use rust_releases::{semver, Release, ReleaseIndex};
+
+use crate::command::RustupCommand;
+use crate::config::{CheckRunner, Config};
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+
+/// Build a release index from the stable toolchains for the given target, which are listed by
+/// `rustup toolchain list`.
+pub fn installed_toolchains_index(target: &str) -> TResult<ReleaseIndex> {
+    let output = list_toolchains()?;
+    let versions = installed_versions(&output, target);
+
+    if versions.is_empty() {
+        return Err(CargoMSRVError::NoInstalledToolchains(target.to_string()));
+    }
+
+    Ok(ReleaseIndex::from_iter(
+        versions.into_iter().map(Release::new_stable),
+    ))
+}
+
+/// Whether the checks install toolchains with rustup.
+///
+/// Nothing is installed when the toolchains are taken from a toolchain directory or run in
+/// Docker containers, or when running offline or as a dry run.
+pub(crate) fn installs_toolchains(config: &Config) -> bool {
+    config.check_runner() == CheckRunner::Rustup
+        && config.toolchain_dir().is_none()
+        && !config.offline()
+        && !config.dry_run()
+}
+
+/// The names of all toolchains which are currently installed by rustup, as listed by
+/// `rustup toolchain list`.
+pub(crate) fn installed_toolchain_names() -> TResult<Vec<String>> {
+    let output = list_toolchains()?;
+
+    Ok(toolchain_names(&output))
+}
+
+fn list_toolchains() -> TResult<String> {
+    let output = RustupCommand::new()
+        .with_args(["list"])
+        .with_stdout()
+        .execute(OsStr::new("toolchain"))?;
+
+    if !output.exit_status().success() {
+        return Err(CargoMSRVError::UnableToListInstalledToolchains);
+    }
+
+    Ok(output.stdout().to_string())
+}
+
+/// Parse the toolchain names from the output of `rustup toolchain list`, dropping annotations
+/// like `(default)`.
+fn toolchain_names(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(String::from)
+        .collect()
+}
+
+/// Build a release index from the stable toolchains in the given toolchain directory, which
+/// are named after their version, with or without the given target.
+///
+/// See also [DirectoryProvider](crate::toolchain_provider::DirectoryProvider).
+pub fn toolchain_dir_index(dir: &Path, target: &str) -> TResult<ReleaseIndex> {
+    let entries = std::fs::read_dir(dir).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(dir.to_path_buf()),
+    })?;
+
+    let names = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+
+    let versions = toolchain_dir_versions(&names, target);
+
+    if versions.is_empty() {
+        return Err(CargoMSRVError::NoToolchainsInDirectory {
+            target: target.to_string(),
+            dir: dir.to_path_buf(),
+        });
+    }
+
+    Ok(ReleaseIndex::from_iter(
+        versions.into_iter().map(Release::new_stable),
+    ))
+}
+
+/// Parse the versions of the stable toolchains from the names of the folders of a toolchain
+/// directory, most recent first. Toolchains for other targets are skipped.
+fn toolchain_dir_versions(names: &[String], target: &str) -> Vec<semver::Version> {
+    let suffix = format!("-{}", target);
+
+    let mut versions = names
+        .iter()
+        .map(|name| name.strip_suffix(suffix.as_str()).unwrap_or(name))
+        .filter_map(|version| semver::Version::parse(version).ok())
+        .filter(|version| version.pre.is_empty())
+        .collect::<Vec<_>>();
+
+    versions.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    versions.dedup();
+    versions
+}
+
+/// Parse the versions of the stable toolchains for the given target from the output of
+/// `rustup toolchain list`, most recent first.
+///
+/// Channel toolchains like `stable` or `nightly-2022-08-01` are skipped, since they don't name
+/// a specific Rust version.
+fn installed_versions(output: &str, target: &str) -> Vec<semver::Version> {
+    let suffix = format!("-{}", target);
+
+    let mut versions = output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter_map(|toolchain| toolchain.strip_suffix(suffix.as_str()))
+        .filter_map(|version| semver::Version::parse(version).ok())
+        .filter(|version| version.pre.is_empty())
+        .collect::<Vec<_>>();
+
+    versions.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    versions.dedup();
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_installed_versions() {
+        let output = "stable-x86_64-unknown-linux-gnu (default)
+nightly-2022-08-01-x86_64-unknown-linux-gnu
+1.56.0-x86_64-unknown-linux-gnu
+1.60.1-x86_64-unknown-linux-gnu
+1.58.0-aarch64-unknown-linux-gnu
+";
+
+        let versions = installed_versions(output, "x86_64-unknown-linux-gnu");
+
+        assert_eq!(
+            versions,
+            vec![
+                semver::Version::new(1, 60, 1),
+                semver::Version::new(1, 56, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_toolchain_names() {
+        let output = "stable-x86_64-unknown-linux-gnu (default)
+1.56.0-x86_64-unknown-linux-gnu
+";
+
+        assert_eq!(
+            toolchain_names(output),
+            vec![
+                "stable-x86_64-unknown-linux-gnu".to_string(),
+                "1.56.0-x86_64-unknown-linux-gnu".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_toolchain_dir_versions() {
+        let names = [
+            "1.56.0",
+            "1.60.1-x86_64-unknown-linux-gnu",
+            "1.56.0-x86_64-unknown-linux-gnu",
+            "1.58.0-aarch64-unknown-linux-gnu",
+            "stable-x86_64-unknown-linux-gnu",
+            "1.62.0-beta.1",
+        ]
+        .map(String::from);
+
+        let versions = toolchain_dir_versions(&names, "x86_64-unknown-linux-gnu");
+
+        assert_eq!(
+            versions,
+            vec![
+                semver::Version::new(1, 60, 1),
+                semver::Version::new(1, 56, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn no_installed_toolchains() {
+        let versions = installed_versions("no installed toolchains\n", "x86_64-unknown-linux-gnu");
+
+        assert!(versions.is_empty());
+    }
+}
@@ -5,8 +5,9 @@
 //! Issues and ideas may be reported via the [issue tracker](https://github.com/foresterre/cargo-msrv/issues),
 //! and questions can be asked on the [discussion forum](https://github.com/foresterre/cargo-msrv/discussions).
 //!
-//! The docs focus on how to use `cargo-msrv` from the command line. If you want to also use it as a library,
-//! please feel free to open an [issue](https://github.com/foresterre/cargo-msrv/issues/new).
+//! The docs focus on how to use `cargo-msrv` from the command line. To use it as a library instead, see the
+//! [`api`] module, which provides functions to find and verify the MSRV of a crate, and to list the MSRV's of its
+//! dependencies.
 
 #![deny(clippy::all)]
 #![allow(clippy::upper_case_acronyms, clippy::unnecessary_wraps)]
@@ -16,18 +17,26 @@ extern crate core;
 extern crate tracing;
 
 pub use crate::outcome::Outcome;
-pub use crate::sub_command::{Find, List, Set, Show, SubCommand, Verify};
+pub use crate::sub_command::{
+    Diff, Doctor, DryRun, Find, List, PinLockfile, Policy, Schema, Set, Show, SubCommand, Upgrade,
+    Verify, VerifyDeps, Watch,
+};
+pub use rust_releases::semver;
 
 #[cfg(feature = "rust-releases-dist-source")]
 use rust_releases::RustDist;
-use rust_releases::{semver, Channel, FetchResources, ReleaseIndex, RustChangelog, Source};
+use rust_releases::{Channel, FetchResources, ReleaseIndex, RustChangelog, Source};
 
-use crate::check::RustupToolchainCheck;
+use crate::check::{cancel, CachedCheck, RustupToolchainCheck};
 use crate::config::{Action, Config, ReleaseSource};
 use crate::error::{CargoMSRVError, TResult};
+use crate::index_cache::IndexCache;
+use crate::preflight::preflight;
 use crate::reporter::event::{ActionMessage, FetchIndex, Meta};
 use crate::reporter::{Event, Reporter};
+use crate::toolchain_cleanup::ToolchainCleanup;
 
+pub mod api;
 pub mod check;
 pub mod cli;
 pub mod config;
@@ -36,22 +45,40 @@ pub mod exit_code;
 pub mod reporter;
 pub mod toolchain;
 
+pub(crate) mod certification;
 pub(crate) mod combinators;
 pub(crate) mod command;
 pub(crate) mod ctx;
+pub(crate) mod date;
 pub(crate) mod default_target;
 pub(crate) mod dependency_graph;
+pub(crate) mod diagnosis;
 pub(crate) mod download;
 pub(crate) mod filter_releases;
 pub(crate) mod formatting;
+pub(crate) mod index_cache;
+pub(crate) mod installed_toolchains;
+pub(crate) mod linked_toolchain;
 pub(crate) mod lockfile;
 pub(crate) mod log_level;
 pub(crate) mod manifest;
+pub(crate) mod mirror;
 pub(crate) mod msrv;
+pub(crate) mod msrv_resolver;
+pub(crate) mod network;
 pub(crate) mod outcome;
+pub(crate) mod preflight;
+pub(crate) mod prerelease;
+pub(crate) mod registry_index;
+pub(crate) mod release_file;
+pub(crate) mod sandbox;
 pub(crate) mod search_method;
 pub(crate) mod sub_command;
+pub(crate) mod toolchain_cleanup;
+pub(crate) mod toolchain_file;
+pub(crate) mod toolchain_provider;
 pub(crate) mod typed_bool;
+pub(crate) mod workspace;
 pub(crate) mod writer;
 
 pub fn run_app(config: &Config, reporter: &impl Reporter) -> TResult<()> {
@@ -66,19 +93,59 @@ pub fn run_app(config: &Config, reporter: &impl Reporter) -> TResult<()> {
 
     reporter.report_event(ActionMessage::new(action))?;
 
+    let cleanup = ToolchainCleanup::prepare(config)?;
+    let result = run_action(config, reporter, action);
+
+    // The toolchains are also uninstalled when the action failed
+    if let Some(cleanup) = cleanup {
+        cleanup.run(reporter)?;
+    }
+
+    result
+}
+
+fn run_action(config: &Config, reporter: &impl Reporter, action: Action) -> TResult<()> {
     match action {
-        Action::Find => {
+        Action::Find | Action::Verify if config.dry_run() => {
             let index = fetch_index(config, reporter)?;
             let runner = RustupToolchainCheck::new(reporter);
+            DryRun::new(&index, runner).run(config, reporter)?;
+        }
+        Action::Find => {
+            cancel::install_handler();
+            let index = fetch_index(config, reporter)?;
+            preflight(config, reporter, &index)?;
+            let runner = CachedCheck::new(reporter, RustupToolchainCheck::new(reporter));
             Find::new(&index, runner).run(config, reporter)?;
         }
         Action::Verify => {
+            cancel::install_handler();
             let index = fetch_index(config, reporter)?;
-            let runner = RustupToolchainCheck::new(reporter);
+            preflight(config, reporter, &index)?;
+            let runner = CachedCheck::new(reporter, RustupToolchainCheck::new(reporter));
             Verify::new(&index, runner).run(config, reporter)?;
         }
+        Action::Diff => {
+            cancel::install_handler();
+            let index = fetch_index(config, reporter)?;
+            preflight(config, reporter, &index)?;
+            let runner = CachedCheck::new(reporter, RustupToolchainCheck::new(reporter));
+            Diff::new(&index, runner).run(config, reporter)?;
+        }
+        Action::Watch => {
+            let index = fetch_index(config, reporter)?;
+            preflight(config, reporter, &index)?;
+            let runner = CachedCheck::new(reporter, RustupToolchainCheck::new(reporter));
+            Watch::new(&index, runner).run(config, reporter)?;
+        }
         Action::List => {
             List::default().run(config, reporter)?;
+
+            if config.sub_command_config().list().verify_deps {
+                let index = fetch_index(config, reporter)?;
+                let runner = CachedCheck::new(reporter, RustupToolchainCheck::new(reporter));
+                VerifyDeps::new(&index, runner).run(config, reporter)?;
+            }
         }
         Action::Set => {
             Set::default().run(config, reporter)?;
@@ -86,28 +153,164 @@ pub fn run_app(config: &Config, reporter: &impl Reporter) -> TResult<()> {
         Action::Show => {
             Show::default().run(config, reporter)?;
         }
+        Action::Doctor => {
+            Doctor::default().run(config, reporter)?;
+        }
+        Action::Upgrade => {
+            Upgrade::default().run(config, reporter)?;
+        }
+        Action::PinLockfile => {
+            PinLockfile::default().run(config, reporter)?;
+        }
+        Action::Policy => {
+            let index = fetch_index(config, reporter)?;
+            Policy::new(&index).run(config, reporter)?;
+        }
+        Action::Schema => {
+            Schema::default().run(config, reporter)?;
+        }
     }
 
     Ok(())
 }
 
 fn fetch_index(config: &Config, reporter: &impl Reporter) -> TResult<ReleaseIndex> {
-    reporter.run_scoped_event(FetchIndex::new(config.release_source()), || {
-        let source = config.release_source();
+    let index = fetch_release_index(config, reporter)?;
+
+    linked_toolchain::with_linked_toolchains(index, config, reporter)
+}
+
+fn fetch_release_index(config: &Config, reporter: &impl Reporter) -> TResult<ReleaseIndex> {
+    if let Some(dir) = config.toolchain_dir() {
+        info!(dir = %dir.display(), "using toolchain directory as index");
+
+        return installed_toolchains::toolchain_dir_index(dir, config.target());
+    }
+
+    if config.offline() {
+        info!("using installed toolchains as index");
 
+        // Pre-releases are not added, since they are not installed under a stable version
+        return installed_toolchains::installed_toolchains_index(config.target());
+    }
+
+    network::apply_network_settings(config)?;
+    mirror::apply_dist_url(config)?;
+
+    let source = config.release_source();
+
+    // A mirrored index is read from the local file system, so it is not cached
+    if let Some(index) = mirrored_index(config, source, reporter)? {
+        return Ok(prerelease::with_prereleases(
+            index,
+            config.release_channel(),
+        ));
+    }
+
+    let cache = IndexCache::default_dir().map(|dir| IndexCache::new(&dir, source));
+
+    if !config.refresh_index() {
+        let cached = cache
+            .as_ref()
+            .and_then(|cache| cache.read(config.index_cache_ttl()));
+
+        if let Some(index) = cached {
+            info!(
+                source = Into::<&'static str>::into(source),
+                "using cached index"
+            );
+
+            reporter.report_event(FetchIndex::cached(source))?;
+
+            return Ok(prerelease::with_prereleases(
+                index,
+                config.release_channel(),
+            ));
+        }
+    }
+
+    let index = reporter.run_scoped_event(FetchIndex::new(source), || {
         info!(
             source = Into::<&'static str>::into(source),
             "fetching index"
         );
 
-        let index = match config.release_source() {
+        let index = match source {
             ReleaseSource::RustChangelog => {
                 RustChangelog::fetch_channel(Channel::Stable)?.build_index()?
             }
             #[cfg(feature = "rust-releases-dist-source")]
             ReleaseSource::RustDist => RustDist::fetch_channel(Channel::Stable)?.build_index()?,
+            ReleaseSource::File => unreachable!("a releases file is read as a local index"),
         };
 
-        Ok(index)
-    })
+        Ok::<_, CargoMSRVError>(index)
+    });
+
+    let index = match index {
+        Ok(index) => index,
+        Err(error) => {
+            reporter.report_event(network::fetch_failure(config, source, &error))?;
+            return Err(error);
+        }
+    };
+
+    if let Some(cache) = &cache {
+        if let Err(error) = cache.write(&index) {
+            warn!(%error, path = %cache.path().display(), "unable to cache the index");
+        }
+    }
+
+    Ok(prerelease::with_prereleases(
+        index,
+        config.release_channel(),
+    ))
+}
+
+/// The release index of the mirrored distribution given by `--changelog-path` or `--dist-url`,
+/// or of the releases file given by `--release-source file://<path>`, if any.
+fn mirrored_index(
+    config: &Config,
+    source: ReleaseSource,
+    reporter: &impl Reporter,
+) -> TResult<Option<ReleaseIndex>> {
+    let index = match source {
+        ReleaseSource::RustChangelog => match config.changelog_path() {
+            Some(path) => reporter.run_scoped_event(FetchIndex::new(source), || {
+                mirror::changelog_file_index(path)
+            })?,
+            None => return Ok(None),
+        },
+        #[cfg(feature = "rust-releases-dist-source")]
+        ReleaseSource::RustDist if config.changelog_path().is_some() => {
+            return Err(CargoMSRVError::InvalidConfig(
+                "The --changelog-path option can only be used with the rust-changelog release source"
+                    .to_string(),
+            ));
+        }
+        #[cfg(feature = "rust-releases-dist-source")]
+        ReleaseSource::RustDist => match config.dist_url() {
+            Some(url) => reporter
+                .run_scoped_event(FetchIndex::new(source), || mirror::dist_mirror_index(url))?,
+            None => return Ok(None),
+        },
+        ReleaseSource::File => {
+            let path = config.release_file().ok_or_else(|| {
+                CargoMSRVError::InvalidConfig(
+                    "The file release source requires the path of a releases file".to_string(),
+                )
+            })?;
+
+            reporter.run_scoped_event(FetchIndex::new(source), || {
+                release_file::release_file_index(path)
+            })?
+        }
+    };
+
+    info!(
+        source = Into::<&'static str>::into(source),
+        "using mirrored index"
+    );
+
+    Ok(Some(index))
 }
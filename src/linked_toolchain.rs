@@ -0,0 +1,259 @@
+//! Locally built toolchains, such as a compiler which is being developed, given by
+//! `--toolchain-path`.
+//!
+//! Each toolchain is linked with `rustup toolchain link`, and added to the search space as the
+//! version reported by its `rustc`, with `linked.<name>` as semver pre-release identifier, e.g.
+//! `1.75.0-linked.stage1`. Like the beta and nightly pre-releases, it sorts below the release of
+//! the same version, but above the previous release.
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::path::Path;
+
+use rust_releases::semver::{Prerelease, Version};
+use rust_releases::{Release, ReleaseIndex};
+
+use crate::command::RustupCommand;
+use crate::config::Config;
+use crate::error::{CargoMSRVError, TResult};
+use crate::reporter::Reporter;
+
+/// The pre-release identifier which marks the version of a linked toolchain.
+const LINKED_PRERELEASE: &str = "linked.";
+
+/// The prefix of the name under which a toolchain is linked with rustup.
+const TOOLCHAIN_PREFIX: &str = "cargo-msrv-";
+
+/// Link the toolchains given by `--toolchain-path`, and add them to the index.
+///
+/// On a dry run, the toolchains are added, but not linked.
+pub fn with_linked_toolchains(
+    index: ReleaseIndex,
+    config: &Config,
+    reporter: &impl Reporter,
+) -> TResult<ReleaseIndex> {
+    if config.toolchain_paths().is_empty() {
+        return Ok(index);
+    }
+
+    let mut names = BTreeSet::new();
+    let mut releases = index.releases().to_vec();
+
+    for path in config.toolchain_paths() {
+        let name = unique_name(path, &mut names);
+        let version = linked_version(rustc_version(path, reporter)?, &name).ok_or_else(|| {
+            CargoMSRVError::LinkToolchainFailed {
+                path: path.to_path_buf(),
+                reason: format!("'{}' is not a valid toolchain name", name),
+            }
+        })?;
+
+        if !config.dry_run() {
+            link(path, &toolchain_name(&name), reporter)?;
+        }
+
+        info!(path = %path.display(), %version, "added linked toolchain");
+
+        releases.push(Release::new_stable(version));
+    }
+
+    releases.sort_by(|lhs, rhs| rhs.version().cmp(lhs.version()));
+
+    Ok(releases.into_iter().collect())
+}
+
+/// The name under which the toolchain of the given version is linked with rustup, if it is the
+/// version of a linked toolchain.
+pub fn linked_toolchain_name(version: &Version) -> Option<String> {
+    version
+        .pre
+        .as_str()
+        .strip_prefix(LINKED_PRERELEASE)
+        .map(toolchain_name)
+}
+
+fn toolchain_name(name: &str) -> String {
+    format!("{}{}", TOOLCHAIN_PREFIX, name)
+}
+
+/// The version of the linked toolchain, which has the Rust version reported by its `rustc`, but
+/// sorts below its release.
+fn linked_version(rustc_version: Version, name: &str) -> Option<Version> {
+    let mut version = Version::new(
+        rustc_version.major,
+        rustc_version.minor,
+        rustc_version.patch,
+    );
+    version.pre = Prerelease::new(&format!("{}{}", LINKED_PRERELEASE, name)).ok()?;
+
+    Some(version)
+}
+
+/// A name for the toolchain, derived from the name of its folder, which is valid as pre-release
+/// identifier, and differs from the names of the other linked toolchains.
+fn unique_name(path: &Path, names: &mut BTreeSet<String>) -> String {
+    let base = path
+        .file_name()
+        .map(|name| {
+            name.to_string_lossy()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "custom".to_string());
+
+    let mut name = base.clone();
+    let mut n = 1;
+
+    while names.contains(&name) {
+        n += 1;
+        name = format!("{}-{}", base, n);
+    }
+
+    names.insert(name.clone());
+    name
+}
+
+/// The Rust version of the toolchain, as reported by `rustc --version`.
+fn rustc_version(path: &Path, reporter: &impl Reporter) -> TResult<Version> {
+    let failed = |reason: String| CargoMSRVError::LinkToolchainFailed {
+        path: path.to_path_buf(),
+        reason,
+    };
+
+    let rustc = path
+        .join("bin")
+        .join(format!("rustc{}", std::env::consts::EXE_SUFFIX));
+
+    if !rustc.is_file() {
+        return Err(failed(format!(
+            "no rustc binary was found at '{}'",
+            rustc.display()
+        )));
+    }
+
+    let output = RustupCommand::program(&rustc)
+        .with_args(["--version"])
+        .with_stdout()
+        .execute_reported(OsStr::new("--version"), reporter)?;
+
+    if !output.exit_status().success() {
+        return Err(failed("`rustc --version` failed".to_string()));
+    }
+
+    parse_rustc_version(output.stdout()).ok_or_else(|| {
+        failed(format!(
+            "unable to parse the version of rustc from '{}'",
+            output.stdout().trim()
+        ))
+    })
+}
+
+/// Parse the version from the output of `rustc --version`, e.g. `rustc 1.75.0-dev`.
+fn parse_rustc_version(output: &str) -> Option<Version> {
+    output
+        .split_ascii_whitespace()
+        .nth(1)
+        .and_then(|version| Version::parse(version).ok())
+}
+
+/// Link the toolchain at the given path with rustup, under the given name.
+fn link(path: &Path, name: &str, reporter: &impl Reporter) -> TResult<()> {
+    info!(path = %path.display(), name, "linking toolchain");
+
+    let output = RustupCommand::new()
+        .with_args(["link", name])
+        .with_args([path])
+        .with_stderr()
+        .execute_reported(OsStr::new("toolchain"), reporter)?;
+
+    if !output.exit_status().success() {
+        return Err(CargoMSRVError::LinkToolchainFailed {
+            path: path.to_path_buf(),
+            reason: output.stderr().trim().to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Action;
+    use crate::reporter::FakeTestReporter;
+    use std::iter::FromIterator;
+    use std::path::PathBuf;
+
+    #[yare::parameterized(
+        dev = { "rustc 1.75.0-dev\n", Some((1, 75, 0)) },
+        nightly = { "rustc 1.76.0-nightly (a1a37735c 2023-11-23)\n", Some((1, 76, 0)) },
+        stable = { "rustc 1.74.0 (79e9716c9 2023-11-13)\n", Some((1, 74, 0)) },
+        invalid = { "error: not a rustc\n", None },
+    )]
+    fn version_of_rustc(output: &str, expected: Option<(u64, u64, u64)>) {
+        let version = parse_rustc_version(output);
+
+        assert_eq!(
+            version.map(|version| (version.major, version.minor, version.patch)),
+            expected
+        );
+    }
+
+    #[test]
+    fn linked_version_sorts_below_its_release() {
+        let version = linked_version(Version::parse("1.75.0-dev").unwrap(), "stage1").unwrap();
+
+        assert_eq!(version.to_string(), "1.75.0-linked.stage1");
+        assert!(version < Version::new(1, 75, 0));
+        assert!(version > Version::new(1, 74, 1));
+    }
+
+    #[test]
+    fn name_of_linked_toolchain() {
+        let version = linked_version(Version::new(1, 75, 0), "stage1").unwrap();
+
+        assert_eq!(
+            linked_toolchain_name(&version).as_deref(),
+            Some("cargo-msrv-stage1")
+        );
+        assert_eq!(linked_toolchain_name(&Version::new(1, 75, 0)), None);
+        assert_eq!(
+            linked_toolchain_name(&Version::parse("1.76.0-beta").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let mut names = BTreeSet::new();
+
+        let paths = [
+            PathBuf::from("build/x86_64-unknown-linux-gnu/stage1"),
+            PathBuf::from("other/stage1"),
+            PathBuf::from("/"),
+        ];
+
+        let unique = paths
+            .iter()
+            .map(|path| unique_name(path, &mut names))
+            .collect::<Vec<_>>();
+
+        assert_eq!(unique, vec!["stage1", "stage1-2", "custom"]);
+    }
+
+    #[test]
+    fn index_without_toolchain_paths_is_unchanged() {
+        let index = ReleaseIndex::from_iter(vec![
+            Release::new_stable(Version::new(1, 63, 0)),
+            Release::new_stable(Version::new(1, 62, 1)),
+        ]);
+        let config = Config::new(Action::Find, "x86_64-unknown-linux-gnu");
+        let reporter = FakeTestReporter::default();
+
+        let index = with_linked_toolchains(index, &config, &reporter).unwrap();
+
+        assert_eq!(index.releases().len(), 2);
+    }
+}
@@ -20,6 +20,16 @@ impl LockfileState for Complete {}
 pub const CARGO_LOCK: &str = "Cargo.lock";
 const CARGO_LOCK_REPLACEMENT: &str = "Cargo.lock-ignored-for-cargo-msrv";
 
+/// The lockfile of the crate, if it has one.
+///
+/// The lockfile may be located in the root of a workspace, instead of the crate root.
+pub(crate) fn find_lockfile(crate_root: &Path) -> Option<PathBuf> {
+    crate_root
+        .ancestors()
+        .map(|dir| dir.join(CARGO_LOCK))
+        .find(|path| path.is_file())
+}
+
 impl LockfileHandler<Start> {
     pub fn new<P: AsRef<Path>>(lock_file: P) -> Self {
         Self {
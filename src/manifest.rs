@@ -1,3 +1,4 @@
+use crate::cli::rust_releases_opts::Edition;
 use crate::manifest::bare_version::BareVersion;
 use std::convert::TryFrom;
 use toml_edit::{Document, Item, TomlError};
@@ -19,12 +20,39 @@ pub trait TomlParser {
 #[derive(Debug)]
 pub struct CargoManifest {
     minimum_rust_version: Option<BareVersion>,
+    rust_version: Option<BareVersion>,
+    metadata_msrv: Option<BareVersion>,
+    edition: Option<Edition>,
+    features: Vec<String>,
 }
 
 impl CargoManifest {
+    /// The MSRV of the crate: the `package.rust-version` if it is specified, or else the
+    /// `package.metadata.msrv`.
     pub fn minimum_rust_version(&self) -> Option<&BareVersion> {
         self.minimum_rust_version.as_ref()
     }
+
+    /// The MSRV as specified by the `package.rust-version` key.
+    pub fn rust_version(&self) -> Option<&BareVersion> {
+        self.rust_version.as_ref()
+    }
+
+    /// The MSRV as specified by the `package.metadata.msrv` key.
+    pub fn metadata_msrv(&self) -> Option<&BareVersion> {
+        self.metadata_msrv.as_ref()
+    }
+
+    /// The edition specified by the `package.edition` key, if it's an edition known to
+    /// cargo-msrv.
+    pub fn edition(&self) -> Option<Edition> {
+        self.edition
+    }
+
+    /// The features declared in the `[features]` table, excluding the `default` feature.
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
 }
 
 /// A parser for `Cargo.toml` files. Only handles the parts necessary for `cargo-msrv`.
@@ -56,57 +84,84 @@ impl TryFrom<Document> for CargoManifest {
     type Error = crate::CargoMSRVError;
 
     fn try_from(map: Document) -> Result<Self, Self::Error> {
-        let minimum_rust_version = minimum_rust_version(&map)?;
+        let rust_version = parse_version(find_rust_version(&map))?;
+        let metadata_msrv = parse_version(find_metadata_msrv(&map))?;
+        let edition = find_edition(&map).and_then(|edition| edition.parse().ok());
+        let features = features(&map);
+
+        // Use the MSRV from the `package.rust-version` key if it exists,
+        // and fallback to our own `package.metadata.msrv` if it doesn't
+        let minimum_rust_version = rust_version.clone().or_else(|| metadata_msrv.clone());
 
         Ok(Self {
             minimum_rust_version,
+            rust_version,
+            metadata_msrv,
+            edition,
+            features,
         })
     }
 }
 
-fn minimum_rust_version(value: &Document) -> Result<Option<BareVersion>, crate::CargoMSRVError> {
-    let version = match find_minimum_rust_version(value) {
-        Some(version) => version,
-        None => return Ok(None),
-    };
+fn features(document: &Document) -> Vec<String> {
+    document
+        .as_table()
+        .get("features")
+        .and_then(Item::as_table_like)
+        .map(|features| {
+            features
+                .iter()
+                .map(|(name, _)| name)
+                .filter(|&name| name != "default")
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
-    Ok(Some(version.parse()?))
+fn parse_version(version: Option<&str>) -> Result<Option<BareVersion>, crate::CargoMSRVError> {
+    version
+        .map(|version| version.parse().map_err(From::from))
+        .transpose()
 }
 
-/// Parse the minimum supported Rust version (MSRV) from `Cargo.toml` manifest data.
-fn find_minimum_rust_version(document: &Document) -> Option<&str> {
-    /// Parses the `MSRV` as supported by Cargo since Rust 1.56.0
-    ///
-    /// [`Cargo`]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-rust-version-field
-    fn find_rust_version(document: &Document) -> Option<&str> {
-        document
-            .as_table()
-            .get("package")
-            .and_then(Item::as_table)
-            .and_then(|package| package.get("rust-version"))
-            .and_then(Item::as_str)
-    }
+/// Parses the `MSRV` as supported by Cargo since Rust 1.56.0
+///
+/// [`Cargo`]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-rust-version-field
+fn find_rust_version(document: &Document) -> Option<&str> {
+    document
+        .as_table()
+        .get("package")
+        .and_then(Item::as_table)
+        .and_then(|package| package.get("rust-version"))
+        .and_then(Item::as_str)
+}
 
-    /// Parses the MSRV as supported by `cargo-msrv`, since prior to the release of Rust
-    /// 1.56.0
-    fn find_metadata_msrv(document: &Document) -> Option<&str> {
-        document
-            .as_table()
-            .get("package")
-            .and_then(Item::as_table)
-            .and_then(|package| package.get("metadata"))
-            .and_then(Item::as_table_like)
-            .and_then(|metadata| metadata.get("msrv"))
-            .and_then(Item::as_str)
-    }
+fn find_edition(document: &Document) -> Option<&str> {
+    document
+        .as_table()
+        .get("package")
+        .and_then(Item::as_table)
+        .and_then(|package| package.get("edition"))
+        .and_then(Item::as_str)
+}
 
-    // Parse the MSRV from the `package.rust-version` key if it exists,
-    // and try to fallback to our own `package.metadata.msrv` if it doesn't
-    find_rust_version(document).or_else(|| find_metadata_msrv(document))
+/// Parses the MSRV as supported by `cargo-msrv`, since prior to the release of Rust
+/// 1.56.0
+fn find_metadata_msrv(document: &Document) -> Option<&str> {
+    document
+        .as_table()
+        .get("package")
+        .and_then(Item::as_table)
+        .and_then(|package| package.get("metadata"))
+        .and_then(Item::as_table_like)
+        .and_then(|metadata| metadata.get("msrv"))
+        .and_then(Item::as_str)
 }
 
 #[cfg(test)]
 mod minimal_version_tests {
+    use crate::cli::rust_releases_opts::Edition;
     use crate::error::CargoMSRVError;
     use crate::manifest::bare_version::Error;
     use crate::manifest::{BareVersion, CargoManifest, CargoManifestParser, TomlParser};
@@ -207,6 +262,114 @@ rust-version = "1.56.0-nightly"
         }
     }
 
+    #[test]
+    fn parse_rust_version_and_metadata_msrv() {
+        let contents = r#"[package]
+name = "some"
+version = "0.1.0"
+edition = "2018"
+rust-version = "1.56"
+
+[package.metadata]
+msrv = "1.58.0"
+"#;
+
+        let manifest = CargoManifestParser::default()
+            .parse::<Document>(contents)
+            .unwrap();
+
+        let manifest = CargoManifest::try_from(manifest).unwrap();
+
+        assert_eq!(
+            manifest.rust_version(),
+            Some(&BareVersion::TwoComponents(1, 56))
+        );
+        assert_eq!(
+            manifest.metadata_msrv(),
+            Some(&BareVersion::ThreeComponents(1, 58, 0))
+        );
+        assert_eq!(
+            manifest.minimum_rust_version(),
+            Some(&BareVersion::TwoComponents(1, 56))
+        );
+    }
+
+    #[test]
+    fn parse_features() {
+        let contents = r#"[package]
+name = "some"
+version = "0.1.0"
+edition = "2018"
+
+[features]
+default = ["std"]
+std = []
+serde = ["dep:serde"]
+"#;
+
+        let manifest = CargoManifestParser::default()
+            .parse::<Document>(contents)
+            .unwrap();
+
+        let manifest = CargoManifest::try_from(manifest).unwrap();
+
+        assert_eq!(
+            manifest.features(),
+            &["std".to_string(), "serde".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_no_features() {
+        let contents = r#"[package]
+name = "some"
+version = "0.1.0"
+edition = "2018"
+"#;
+
+        let manifest = CargoManifestParser::default()
+            .parse::<Document>(contents)
+            .unwrap();
+
+        let manifest = CargoManifest::try_from(manifest).unwrap();
+
+        assert!(manifest.features().is_empty());
+    }
+
+    #[test]
+    fn parse_edition() {
+        let contents = r#"[package]
+name = "some"
+version = "0.1.0"
+edition = "2018"
+"#;
+
+        let manifest = CargoManifestParser::default()
+            .parse::<Document>(contents)
+            .unwrap();
+
+        let manifest = CargoManifest::try_from(manifest).unwrap();
+
+        assert_eq!(manifest.edition(), Some(Edition::Edition2018));
+    }
+
+    #[test]
+    fn parse_unknown_edition() {
+        let contents = r#"[package]
+name = "some"
+version = "0.1.0"
+edition = "2099"
+"#;
+
+        let manifest = CargoManifestParser::default()
+            .parse::<Document>(contents)
+            .unwrap();
+
+        let manifest = CargoManifest::try_from(manifest).unwrap();
+
+        assert!(manifest.edition().is_none());
+    }
+
     #[test]
     fn parse_rust_version_two_components() {
         let contents = r#"[package]
@@ -0,0 +1,217 @@
+//! A release index of a mirrored Rust distribution, for environments without access to
+//! `static.rust-lang.org`.
+//!
+//! The releases are read from a local copy of the Rust changelog (`RELEASES.md`), or from the
+//! channel manifests of a local mirror of the distribution server. Toolchains are installed from
+//! the mirror by passing its URL to rustup, as `RUSTUP_DIST_SERVER`.
+
+use std::iter::FromIterator;
+use std::path::{Path, PathBuf};
+
+use rust_releases::{semver, Release, ReleaseIndex};
+
+use crate::date::today;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::Config;
+
+const DIST_SERVER_VARIABLE: &str = "RUSTUP_DIST_SERVER";
+
+/// Let rustup install toolchains from the distribution server given by `--dist-url`.
+pub(crate) fn apply_dist_url(config: &Config) -> TResult<()> {
+    if let Some(url) = config.dist_url() {
+        let server = match local_mirror(url) {
+            Some(dir) => format!("file://{}", absolute(&dir)?.display()),
+            None => url.trim_end_matches('/').to_string(),
+        };
+
+        info!(dist_server = server.as_str(), "using distribution server");
+
+        std::env::set_var(DIST_SERVER_VARIABLE, server);
+    }
+
+    Ok(())
+}
+
+/// Build a release index from a local copy of the Rust changelog.
+///
+/// Releases which are dated in the future are skipped, since the changelog lists the upcoming
+/// release before it is published.
+pub(crate) fn changelog_file_index(path: &Path) -> TResult<ReleaseIndex> {
+    let contents = std::fs::read_to_string(path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(path.to_path_buf()),
+    })?;
+
+    let versions = changelog_versions(&contents, &today());
+
+    if versions.is_empty() {
+        return Err(CargoMSRVError::NoReleasesInChangelog(path.to_path_buf()));
+    }
+
+    Ok(ReleaseIndex::from_iter(
+        versions.into_iter().map(Release::new_stable),
+    ))
+}
+
+/// Build a release index from the channel manifests (`dist/channel-rust-<version>.toml`) of the
+/// distribution server given by `--dist-url`, which must be a local directory or `file://` URL,
+/// since a remote server can't be asked to list its files.
+#[cfg(feature = "rust-releases-dist-source")]
+pub(crate) fn dist_mirror_index(url: &str) -> TResult<ReleaseIndex> {
+    let root =
+        local_mirror(url).ok_or_else(|| CargoMSRVError::UnableToListDistMirror(url.to_string()))?;
+    let dir = root.join("dist");
+
+    let entries = std::fs::read_dir(&dir).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(dir.clone()),
+    })?;
+
+    let names = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+
+    let versions = manifest_versions(&names);
+
+    if versions.is_empty() {
+        return Err(CargoMSRVError::NoReleasesInDistMirror(dir));
+    }
+
+    Ok(ReleaseIndex::from_iter(
+        versions.into_iter().map(Release::new_stable),
+    ))
+}
+
+/// The directory of the mirror, if the URL points to the local file system.
+fn local_mirror(url: &str) -> Option<PathBuf> {
+    if let Some(path) = url.strip_prefix("file://") {
+        return Some(PathBuf::from(path));
+    }
+
+    let is_remote = url.contains("://");
+    (!is_remote).then(|| PathBuf::from(url))
+}
+
+fn absolute(path: &Path) -> TResult<PathBuf> {
+    if path.is_absolute() {
+        return Ok(path.to_path_buf());
+    }
+
+    let current_dir = std::env::current_dir().map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::CurrentDir,
+    })?;
+
+    Ok(current_dir.join(path))
+}
+
+/// Parse the released versions from the headers of the changelog, which look like
+/// `Version 1.60.0 (2022-04-07)`, most recent first.
+///
+/// Releases dated after `today` (formatted as `YYYY-MM-DD`) are skipped.
+fn changelog_versions(contents: &str, today: &str) -> Vec<semver::Version> {
+    let mut versions = contents
+        .lines()
+        .filter_map(|line| line.strip_prefix("Version "))
+        .filter_map(|header| {
+            let mut parts = header.split_whitespace();
+            let version = semver::Version::parse(parts.next()?).ok()?;
+            let date = parts
+                .next()
+                .map(|date| date.trim_matches(|c| c == '(' || c == ')'));
+
+            match date {
+                Some(date) if date > today => None,
+                _ => Some(version),
+            }
+        })
+        .filter(|version| version.pre.is_empty())
+        .collect::<Vec<_>>();
+
+    versions.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    versions.dedup();
+    versions
+}
+
+/// Parse the versions of the channel manifests, e.g. `channel-rust-1.60.0.toml`, most recent
+/// first. Manifests of channels like `stable`, or of a `major.minor` version, are skipped.
+#[cfg(feature = "rust-releases-dist-source")]
+fn manifest_versions(names: &[String]) -> Vec<semver::Version> {
+    let mut versions = names
+        .iter()
+        .filter_map(|name| name.strip_prefix("channel-rust-")?.strip_suffix(".toml"))
+        .filter_map(|version| semver::Version::parse(version).ok())
+        .filter(|version| version.pre.is_empty())
+        .collect::<Vec<_>>();
+
+    versions.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    versions.dedup();
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_changelog_versions() {
+        let changelog = "Version 1.61.0 (2022-05-19)
+==========================
+
+Language
+--------
+- [Const functions may now specify `extern \"C\"` or `extern \"Rust\"`][93487]
+
+Version 1.60.0 (2022-04-07)
+==========================
+
+Version 1.59.0 (2022-02-24)
+==========================
+";
+
+        let versions = changelog_versions(changelog, "2022-05-01");
+
+        assert_eq!(
+            versions,
+            vec![
+                semver::Version::new(1, 60, 0),
+                semver::Version::new(1, 59, 0)
+            ]
+        );
+    }
+
+    #[cfg(feature = "rust-releases-dist-source")]
+    #[test]
+    fn parse_manifest_versions() {
+        let names = [
+            "channel-rust-1.60.0.toml",
+            "channel-rust-1.60.0.toml.sha256",
+            "channel-rust-1.60.toml",
+            "channel-rust-stable.toml",
+            "channel-rust-1.61.0.toml",
+            "channel-rust-1.62.0-beta.1.toml",
+            "2022-04-07",
+        ]
+        .map(String::from);
+
+        let versions = manifest_versions(&names);
+
+        assert_eq!(
+            versions,
+            vec![
+                semver::Version::new(1, 61, 0),
+                semver::Version::new(1, 60, 0)
+            ]
+        );
+    }
+
+    #[yare::parameterized(
+        remote = { "https://mirror.example.com/rust", None },
+        file_url = { "file:///srv/rust", Some(PathBuf::from("/srv/rust")) },
+        path = { "/srv/rust", Some(PathBuf::from("/srv/rust")) },
+    )]
+    fn mirror_dir(url: &str, expected: Option<PathBuf>) {
+        assert_eq!(local_mirror(url), expected);
+    }
+}
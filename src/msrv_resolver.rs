@@ -0,0 +1,270 @@
+use crate::error::CargoMSRVError;
+use crate::manifest::bare_version::BareVersion;
+use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
+use crate::semver;
+use cargo_metadata::Package;
+use std::convert::TryFrom;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use toml_edit::Document;
+
+/// A key in the Cargo manifest by which the MSRV of a package may be declared.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MsrvKey {
+    /// The `package.rust-version` key, supported by Cargo since Rust 1.56
+    RustVersion,
+    /// The `package.metadata.msrv` key, for crates with an MSRV prior to Rust 1.56
+    MetadataMsrv,
+}
+
+pub(crate) const RUST_VERSION: &str = "rust-version";
+pub(crate) const METADATA_MSRV: &str = "metadata-msrv";
+
+impl MsrvKey {
+    pub(crate) const fn variants() -> &'static [&'static str] {
+        &[RUST_VERSION, METADATA_MSRV]
+    }
+}
+
+impl FromStr for MsrvKey {
+    type Err = CargoMSRVError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            RUST_VERSION => Ok(Self::RustVersion),
+            METADATA_MSRV => Ok(Self::MetadataMsrv),
+            unknown => Err(CargoMSRVError::InvalidConfig(format!(
+                "Given MSRV source '{}' is not valid",
+                unknown
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for MsrvKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RustVersion => write!(f, "{}", RUST_VERSION),
+            Self::MetadataMsrv => write!(f, "{}", METADATA_MSRV),
+        }
+    }
+}
+
+/// Determines the declared MSRV of a package, from the first key in the priority which declares
+/// one.
+///
+/// By default, `package.rust-version` takes priority over `package.metadata.msrv`. Keys which are
+/// left out of the priority are not read at all.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MsrvResolver {
+    priority: Vec<MsrvKey>,
+    manifest_workaround: bool,
+}
+
+impl Default for MsrvResolver {
+    fn default() -> Self {
+        Self {
+            priority: vec![MsrvKey::RustVersion, MsrvKey::MetadataMsrv],
+            manifest_workaround: true,
+        }
+    }
+}
+
+impl MsrvResolver {
+    pub fn new(priority: Vec<MsrvKey>, manifest_workaround: bool) -> Self {
+        Self {
+            priority,
+            manifest_workaround,
+        }
+    }
+
+    /// The keys which are read, from the highest to the lowest priority.
+    pub fn priority(&self) -> &[MsrvKey] {
+        &self.priority
+    }
+
+    /// Whether the Cargo manifest of a package is parsed, when `cargo metadata` doesn't output
+    /// its MSRV.
+    pub fn manifest_workaround(&self) -> bool {
+        self.manifest_workaround
+    }
+
+    /// The declared MSRV of a package, as output by `cargo metadata`.
+    ///
+    /// Versions of Cargo prior to 1.58 don't output the `rust-version` of a package. As a
+    /// workaround, the Cargo manifest of the package is parsed instead, unless disabled.
+    pub fn package_msrv(&self, package: &Package) -> Option<semver::Version> {
+        self.package_msrv_with_key(package)
+            .map(|(version, _)| version)
+    }
+
+    /// The declared MSRV of a package, together with the key which declares it.
+    pub fn package_msrv_with_key(&self, package: &Package) -> Option<(semver::Version, MsrvKey)> {
+        self.priority
+            .iter()
+            .find_map(|key| {
+                let version = match key {
+                    MsrvKey::RustVersion => package_rust_version(package),
+                    MsrvKey::MetadataMsrv => package_metadata_msrv(package),
+                };
+
+                version.map(|version| (version, *key))
+            })
+            .or_else(|| {
+                if self.manifest_workaround {
+                    self.manifest_path_msrv(package.manifest_path.as_std_path())
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// The declared MSRV of a parsed Cargo manifest.
+    pub fn manifest_msrv<'m>(&self, manifest: &'m CargoManifest) -> Option<&'m BareVersion> {
+        self.manifest_msrv_with_key(manifest)
+            .map(|(version, _)| version)
+    }
+
+    fn manifest_msrv_with_key<'m>(
+        &self,
+        manifest: &'m CargoManifest,
+    ) -> Option<(&'m BareVersion, MsrvKey)> {
+        self.priority.iter().find_map(|key| {
+            let version = match key {
+                MsrvKey::RustVersion => manifest.rust_version(),
+                MsrvKey::MetadataMsrv => manifest.metadata_msrv(),
+            };
+
+            version.map(|version| (version, *key))
+        })
+    }
+
+    fn manifest_path_msrv(&self, path: &Path) -> Option<(semver::Version, MsrvKey)> {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| {
+                CargoManifestParser::default()
+                    .parse::<Document>(&contents)
+                    .ok()
+            })
+            .and_then(|document| CargoManifest::try_from(document).ok())
+            .and_then(|manifest| {
+                self.manifest_msrv_with_key(&manifest)
+                    .map(|(version, key)| (version.to_semver_version(), key))
+            })
+    }
+}
+
+fn package_rust_version(package: &Package) -> Option<semver::Version> {
+    package.rust_version.as_ref().map(|req| {
+        let comparator = &req.comparators[0];
+        semver::Version::new(
+            comparator.major,
+            comparator.minor.unwrap_or_default(),
+            comparator.patch.unwrap_or_default(),
+        )
+    })
+}
+
+fn package_metadata_msrv(package: &Package) -> Option<semver::Version> {
+    package
+        .metadata
+        .get("msrv")
+        .and_then(|value| value.as_str())
+        .and_then(|value| value.parse::<BareVersion>().ok())
+        .map(|version| version.to_semver_version())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(contents: &str) -> CargoManifest {
+        let document = CargoManifestParser::default()
+            .parse::<Document>(contents)
+            .unwrap();
+
+        CargoManifest::try_from(document).unwrap()
+    }
+
+    const BOTH_KEYS: &str = r#"
+[package]
+name = "a"
+version = "0.1.0"
+rust-version = "1.58"
+
+[package.metadata]
+msrv = "1.40.0"
+"#;
+
+    #[test]
+    fn rust_version_takes_priority_by_default() {
+        let manifest = manifest(BOTH_KEYS);
+
+        assert_eq!(
+            MsrvResolver::default().manifest_msrv(&manifest),
+            Some(&BareVersion::TwoComponents(1, 58))
+        );
+    }
+
+    #[test]
+    fn configured_priority() {
+        let manifest = manifest(BOTH_KEYS);
+        let resolver = MsrvResolver::new(vec![MsrvKey::MetadataMsrv, MsrvKey::RustVersion], true);
+
+        assert_eq!(
+            resolver.manifest_msrv(&manifest),
+            Some(&BareVersion::ThreeComponents(1, 40, 0))
+        );
+    }
+
+    #[test]
+    fn key_which_declares_msrv() {
+        let manifest = manifest(BOTH_KEYS);
+        let resolver = MsrvResolver::new(vec![MsrvKey::MetadataMsrv, MsrvKey::RustVersion], true);
+
+        assert_eq!(
+            MsrvResolver::default()
+                .manifest_msrv_with_key(&manifest)
+                .map(|(_, key)| key),
+            Some(MsrvKey::RustVersion)
+        );
+        assert_eq!(
+            resolver
+                .manifest_msrv_with_key(&manifest)
+                .map(|(_, key)| key),
+            Some(MsrvKey::MetadataMsrv)
+        );
+    }
+
+    #[test]
+    fn keys_left_out_of_priority_are_not_read() {
+        let manifest = manifest(
+            r#"
+[package]
+name = "a"
+version = "0.1.0"
+
+[package.metadata]
+msrv = "1.40.0"
+"#,
+        );
+        let resolver = MsrvResolver::new(vec![MsrvKey::RustVersion], true);
+
+        assert!(resolver.manifest_msrv(&manifest).is_none());
+    }
+
+    #[test]
+    fn parse_key() {
+        assert_eq!(
+            "rust-version".parse::<MsrvKey>().unwrap(),
+            MsrvKey::RustVersion
+        );
+        assert_eq!(
+            "metadata-msrv".parse::<MsrvKey>().unwrap(),
+            MsrvKey::MetadataMsrv
+        );
+        assert!("toolchain-file".parse::<MsrvKey>().is_err());
+    }
+}
@@ -0,0 +1,201 @@
+//! Proxy and certificate settings of the network requests which fetch the release index.
+//!
+//! The release index is fetched by the HTTP client of `rust-releases`, and toolchains are
+//! installed by rustup. Both read their proxy and trusted certificate authorities from the
+//! environment, so the settings are passed on as environment variables of this process, which
+//! are inherited by each spawned rustup process.
+
+use crate::config::ReleaseSource;
+use crate::reporter::event::{FetchFailureReason, FetchIndexFailed};
+use crate::{CargoMSRVError, Config, TResult};
+use std::error::Error;
+
+const PROXY_VARIABLES: [&str; 2] = ["HTTPS_PROXY", "https_proxy"];
+const CA_CERT_VARIABLE: &str = "SSL_CERT_FILE";
+
+/// Pass the proxy given by `--proxy`, and the certificate bundle given by `--cacert`, on to the
+/// HTTP client and rustup.
+pub(crate) fn apply_network_settings(config: &Config) -> TResult<()> {
+    if let Some(proxy) = config.proxy() {
+        validate_proxy(proxy)?;
+
+        info!(proxy, "using proxy");
+
+        std::env::set_var("HTTPS_PROXY", proxy);
+        std::env::set_var("HTTP_PROXY", proxy);
+    }
+
+    if let Some(ca_cert) = config.ca_cert() {
+        if !ca_cert.is_file() {
+            return Err(CargoMSRVError::InvalidConfig(format!(
+                "The certificate bundle '{}' given by --cacert does not exist",
+                ca_cert.display()
+            )));
+        }
+
+        info!(ca_cert = %ca_cert.display(), "using certificate bundle");
+
+        std::env::set_var(CA_CERT_VARIABLE, ca_cert);
+    }
+
+    Ok(())
+}
+
+/// The proxy through which the release index is fetched, if any.
+fn proxy(config: &Config) -> Option<String> {
+    config.proxy().map(ToOwned::to_owned).or_else(|| {
+        PROXY_VARIABLES
+            .iter()
+            .find_map(|variable| std::env::var(variable).ok())
+            .filter(|proxy| !proxy.is_empty())
+    })
+}
+
+fn validate_proxy(proxy: &str) -> TResult<()> {
+    const SCHEMES: [&str; 4] = ["http://", "https://", "socks5://", "socks5h://"];
+
+    if SCHEMES.iter().any(|scheme| proxy.starts_with(scheme)) {
+        Ok(())
+    } else {
+        Err(CargoMSRVError::InvalidConfig(format!(
+            "The proxy '{}' given by --proxy must be a URL starting with one of: {}",
+            proxy,
+            SCHEMES.join(", ")
+        )))
+    }
+}
+
+/// Describe why the release index could not be fetched, and how the user may resolve it.
+pub(crate) fn fetch_failure(
+    config: &Config,
+    source: ReleaseSource,
+    error: &CargoMSRVError,
+) -> FetchIndexFailed {
+    let error = error_chain(error);
+    let proxy = proxy(config);
+    let reason = failure_reason(&error, proxy.is_some());
+
+    let advice = match reason {
+        FetchFailureReason::Certificate => match config.ca_cert() {
+            Some(ca_cert) => format!(
+                "The certificate of the server is not trusted. Check whether the certificate bundle '{}' contains the certificate authority of your network.",
+                ca_cert.display()
+            ),
+            None => "The certificate of the server is not trusted. If your network uses an internal certificate authority, pass its certificate bundle with --cacert <file>.".to_string(),
+        },
+        FetchFailureReason::Proxy => format!(
+            "The proxy '{}' could not be used. Check the URL given by --proxy or the HTTPS_PROXY environment variable.",
+            proxy.unwrap_or_default()
+        ),
+        FetchFailureReason::Connection if proxy.is_none() => "The server could not be reached. If your network requires a proxy, pass it with --proxy <url>, or set the HTTPS_PROXY environment variable. To use only the installed toolchains, run with --offline.".to_string(),
+        FetchFailureReason::Connection => format!(
+            "The server could not be reached through the proxy '{}'. To use only the installed toolchains, run with --offline.",
+            proxy.unwrap_or_default()
+        ),
+        FetchFailureReason::Other => "To use only the installed toolchains, run with --offline.".to_string(),
+    };
+
+    FetchIndexFailed::new(source, reason, error, advice)
+}
+
+fn failure_reason(error: &str, has_proxy: bool) -> FetchFailureReason {
+    let error = error.to_lowercase();
+    let mentions = |needles: &[&str]| needles.iter().any(|needle| error.contains(needle));
+
+    if mentions(&[
+        "certificate",
+        "unknownissuer",
+        "unknown issuer",
+        "tls",
+        "ssl",
+    ]) {
+        FetchFailureReason::Certificate
+    } else if mentions(&["proxy"]) {
+        FetchFailureReason::Proxy
+    } else if mentions(&[
+        "dns",
+        "resolve",
+        "connect",
+        "timed out",
+        "unreachable",
+        "network",
+    ]) {
+        if has_proxy && mentions(&["connection refused"]) {
+            FetchFailureReason::Proxy
+        } else {
+            FetchFailureReason::Connection
+        }
+    } else {
+        FetchFailureReason::Other
+    }
+}
+
+/// The error, followed by each of its causes.
+fn error_chain(error: &CargoMSRVError) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+
+    while let Some(cause) = source {
+        let text = cause.to_string();
+
+        // Transparent errors repeat the message of their cause
+        if !message.ends_with(&text) {
+            message = format!("{}: {}", message, text);
+        }
+
+        source = cause.source();
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use crate::Action;
+
+    #[yare::parameterized(
+        unknown_issuer = { "invalid peer certificate: UnknownIssuer", false, FetchFailureReason::Certificate },
+        self_signed = { "error:1416F086:SSL routines:certificate verify failed", false, FetchFailureReason::Certificate },
+        proxy = { "unable to connect to proxy", true, FetchFailureReason::Proxy },
+        proxy_refused = { "Connection refused (os error 111)", true, FetchFailureReason::Proxy },
+        refused = { "Connection refused (os error 111)", false, FetchFailureReason::Connection },
+        dns = { "failed to lookup address information: Name or service not known (dns error)", false, FetchFailureReason::Connection },
+        other = { "unable to parse the release index", false, FetchFailureReason::Other },
+    )]
+    fn reason(error: &str, has_proxy: bool, expected: FetchFailureReason) {
+        assert_eq!(failure_reason(error, has_proxy), expected);
+    }
+
+    #[yare::parameterized(
+        http = { "http://proxy.example.com:3128", true },
+        https = { "https://proxy.example.com", true },
+        socks = { "socks5://localhost:1080", true },
+        no_scheme = { "proxy.example.com:3128", false },
+    )]
+    fn proxy_url(proxy: &str, valid: bool) {
+        assert_eq!(validate_proxy(proxy).is_ok(), valid);
+    }
+
+    #[test]
+    fn missing_ca_cert() {
+        let config = ConfigBuilder::new(Action::Find, "")
+            .ca_cert(Some("this-bundle-does-not-exist.pem"))
+            .build();
+
+        assert!(apply_network_settings(&config).is_err());
+    }
+
+    #[test]
+    fn certificate_advice_mentions_cacert() {
+        let config = ConfigBuilder::new(Action::Find, "").build();
+        let error =
+            CargoMSRVError::GenericMessage("invalid peer certificate: UnknownIssuer".into());
+
+        let failure = fetch_failure(&config, ReleaseSource::RustChangelog, &error);
+
+        assert_eq!(failure.reason(), FetchFailureReason::Certificate);
+        assert!(failure.advice().contains("--cacert"));
+    }
+}
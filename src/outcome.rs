@@ -4,6 +4,7 @@
 
 use crate::toolchain::OwnedToolchainSpec;
 use rust_releases::semver;
+use std::path::{Path, PathBuf};
 
 #[derive(Clone, Debug)]
 pub enum Outcome {
@@ -13,16 +14,50 @@ pub enum Outcome {
 
 impl Outcome {
     pub fn new_success(toolchain_spec: OwnedToolchainSpec) -> Self {
-        Self::Success(SuccessOutcome { toolchain_spec })
+        Self::Success(SuccessOutcome {
+            toolchain_spec,
+            lockfile_downgraded: false,
+        })
     }
 
     pub fn new_failure(toolchain_spec: OwnedToolchainSpec, error_message: String) -> Self {
         Self::Failure(FailureOutcome {
             toolchain_spec,
             error_message,
+            output_log: None,
+            unsupported_flag: None,
         })
     }
 
+    /// Mark a successful check as having passed only with a lockfile generated by the Cargo
+    /// version of the toolchain, since it didn't understand the lockfile of the project.
+    pub(crate) fn with_lockfile_downgrade(mut self) -> Self {
+        if let Self::Success(outcome) = &mut self {
+            outcome.lockfile_downgraded = true;
+        }
+
+        self
+    }
+
+    /// Mark a failed check as having failed because the check command uses a flag which is not
+    /// supported by the Cargo version of the toolchain.
+    pub(crate) fn with_unsupported_flag(mut self, flag: Option<String>) -> Self {
+        if let Self::Failure(outcome) = &mut self {
+            outcome.unsupported_flag = flag;
+        }
+
+        self
+    }
+
+    /// Set the file to which the full output of a failed check was written.
+    pub(crate) fn with_output_log(mut self, path: PathBuf) -> Self {
+        if let Self::Failure(outcome) = &mut self {
+            outcome.output_log = Some(path);
+        }
+
+        self
+    }
+
     pub fn is_success(&self) -> bool {
         match self {
             Self::Success { .. } => true,
@@ -48,10 +83,33 @@ impl Outcome {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SuccessOutcome {
     pub(crate) toolchain_spec: OwnedToolchainSpec,
+    /// Whether the check passed only with a downgraded lockfile.
+    pub(crate) lockfile_downgraded: bool,
+}
+
+impl SuccessOutcome {
+    pub fn lockfile_downgraded(&self) -> bool {
+        self.lockfile_downgraded
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FailureOutcome {
     pub(crate) toolchain_spec: OwnedToolchainSpec,
     pub(crate) error_message: String,
+    /// The file to which the full output of the check was written, if any.
+    pub(crate) output_log: Option<PathBuf>,
+    /// The flag of the check command which Cargo didn't recognize, if the check failed because
+    /// of it.
+    pub(crate) unsupported_flag: Option<String>,
+}
+
+impl FailureOutcome {
+    pub fn output_log(&self) -> Option<&Path> {
+        self.output_log.as_deref()
+    }
+
+    pub fn unsupported_flag(&self) -> Option<&str> {
+        self.unsupported_flag.as_deref()
+    }
 }
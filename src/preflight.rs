@@ -0,0 +1,182 @@
+//! Estimate the toolchains which will be downloaded for the checks, and whether they fit on the
+//! disk, before any check is run. See `--max-downloads` and `--require-disk-space`.
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rust_releases::{Release, ReleaseIndex};
+
+use crate::config::{Action, Config, SearchMethod};
+use crate::error::{CargoMSRVError, TResult};
+use crate::filter_releases::filter_releases;
+use crate::installed_toolchains::{installed_toolchain_names, installs_toolchains};
+use crate::reporter::event::PreflightEstimate;
+use crate::reporter::Reporter;
+use crate::search_method::bisect::max_steps;
+use crate::search_method::gallop;
+use crate::semver;
+use crate::sub_command::verify;
+use crate::toolchain::ToolchainSpec;
+
+/// The approximate size of the download of a toolchain with the minimal rustup profile.
+const TOOLCHAIN_DOWNLOAD_SIZE: u64 = 120 * 1024 * 1024;
+
+/// The approximate disk space taken by an installed toolchain with the minimal rustup profile.
+const TOOLCHAIN_DISK_SPACE: u64 = 450 * 1024 * 1024;
+
+/// Estimate the toolchains which will be downloaded by the checks of the current action, and
+/// report the estimate.
+///
+/// Fails if more toolchains would be downloaded than allowed by `--max-downloads`, or if
+/// `--require-disk-space` is given and the toolchains don't fit on the disk.
+pub(crate) fn preflight(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+) -> TResult<()> {
+    if !installs_toolchains(config) {
+        return Ok(());
+    }
+
+    let versions = match config.action() {
+        // If the version can't be determined, for example for a workspace, the verification
+        // itself will report why
+        Action::Verify | Action::Watch => match verify::rust_versions(config, release_index) {
+            Ok(versions) => versions,
+            Err(error) => {
+                warn!(%error, "unable to estimate toolchain downloads");
+                return Ok(());
+            }
+        },
+        _ => filter_releases(config, release_index.releases())
+            .iter()
+            .map(Release::version)
+            .cloned()
+            .collect(),
+    };
+
+    let installed = installed_toolchain_names()?
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+
+    let downloads = estimate_downloads(config, &versions, &installed);
+    let rustup_home = rustup_home();
+    let available = rustup_home.as_deref().and_then(available_disk_space);
+
+    let estimate = PreflightEstimate::new(
+        downloads,
+        downloads * TOOLCHAIN_DOWNLOAD_SIZE,
+        downloads * TOOLCHAIN_DISK_SPACE,
+        available,
+        rustup_home.unwrap_or_default(),
+    );
+
+    info!(downloads, ?available, "estimated toolchain downloads");
+
+    reporter.report_event(estimate.clone())?;
+
+    match config.max_downloads() {
+        Some(max) if downloads > max => {
+            return Err(CargoMSRVError::TooManyDownloads { downloads, max });
+        }
+        _ => {}
+    }
+
+    if config.require_disk_space() && estimate.exceeds_available_disk_space() {
+        return Err(CargoMSRVError::InsufficientDiskSpace {
+            required: estimate.disk_space(),
+            available: estimate.available_disk_space().unwrap_or_default(),
+            path: estimate.rustup_home().clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// The greatest number of toolchains which may be downloaded to check the given versions.
+///
+/// A linear search may check every version, while a bisection checks at most a logarithmic
+/// number of them. Toolchains which are already installed are not downloaded.
+fn estimate_downloads(
+    config: &Config,
+    versions: &[semver::Version],
+    installed: &BTreeSet<String>,
+) -> u64 {
+    let missing = versions
+        .iter()
+        .map(|version| {
+            ToolchainSpec::new(version, config.target())
+                .spec()
+                .to_string()
+        })
+        .filter(|toolchain| !installed.contains(toolchain))
+        .count() as u64;
+
+    match config.action() {
+        Action::Find | Action::Diff if config.search_method() == SearchMethod::Bisect => {
+            missing.min(max_steps(versions.len() as u64))
+        }
+        Action::Find | Action::Diff if config.search_method() == SearchMethod::Gallop => {
+            missing.min(gallop::max_steps(versions.len() as u64))
+        }
+        _ => missing,
+    }
+}
+
+/// The directory where rustup installs toolchains.
+fn rustup_home() -> Option<PathBuf> {
+    std::env::var_os("RUSTUP_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".rustup")))
+}
+
+/// The available disk space in bytes of the file system of the given path, as reported by
+/// `df`. Unknown if `df` is not available, for example on Windows.
+fn available_disk_space(path: &Path) -> Option<u64> {
+    // The rustup home directory may not have been created yet
+    let existing = path.ancestors().find(|path| path.exists())?;
+
+    let output = Command::new("df")
+        .args([OsStr::new("-Pk"), existing.as_os_str()])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    parse_df_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the available space from the POSIX output format of `df -k`, which reports it in
+/// blocks of 1024 bytes in the fourth column.
+fn parse_df_output(output: &str) -> Option<u64> {
+    let line = output.lines().nth(1)?;
+    let available = line.split_whitespace().nth(3)?;
+
+    available.parse::<u64>().ok().map(|blocks| blocks * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_available_disk_space() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on
+/dev/nvme0n1p2   490691512 312093172 153598084      68% /
+";
+
+        assert_eq!(parse_df_output(output), Some(153_598_084 * 1024));
+    }
+
+    #[test]
+    fn parse_unexpected_df_output() {
+        assert_eq!(
+            parse_df_output("df: /nope: No such file or directory"),
+            None
+        );
+    }
+}
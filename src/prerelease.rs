@@ -0,0 +1,89 @@
+//! Pre-releases of upcoming Rust versions, as found on the beta and nightly release channels.
+//!
+//! The beta and nightly toolchains are represented by the version which they will become once
+//! they are released on the stable channel, with the name of the channel as semver pre-release
+//! identifier, e.g. `1.64.0-beta`. Since pre-release versions sort below their release, but above
+//! the previous release, they can be searched like any other release.
+
+use rust_releases::semver::{Prerelease, Version};
+use rust_releases::{Release, ReleaseIndex};
+
+use crate::config::ReleaseChannel;
+
+/// Add the pre-releases of the given channel to the index of stable releases.
+///
+/// For the `beta` channel, the beta pre-release is added; for the `nightly` channel, both the
+/// beta and nightly pre-releases are added.
+pub fn with_prereleases(index: ReleaseIndex, channel: ReleaseChannel) -> ReleaseIndex {
+    let latest_stable = match index.releases().first() {
+        Some(release) => release.version().clone(),
+        None => return index,
+    };
+
+    let prereleases = match channel {
+        ReleaseChannel::Stable => vec![],
+        ReleaseChannel::Beta => vec![prerelease(&latest_stable, 1, "beta")],
+        ReleaseChannel::Nightly => vec![
+            prerelease(&latest_stable, 2, "nightly"),
+            prerelease(&latest_stable, 1, "beta"),
+        ],
+    };
+
+    prereleases
+        .into_iter()
+        .map(Release::new_stable)
+        .chain(index.releases().iter().cloned())
+        .collect()
+}
+
+fn prerelease(latest_stable: &Version, minors_ahead: u64, channel: &str) -> Version {
+    let mut version = Version::new(latest_stable.major, latest_stable.minor + minors_ahead, 0);
+    // NB: the channel names are valid pre-release identifiers
+    version.pre = Prerelease::new(channel).unwrap();
+    version
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::iter::FromIterator;
+
+    fn index() -> ReleaseIndex {
+        ReleaseIndex::from_iter(vec![
+            Release::new_stable(Version::new(1, 63, 0)),
+            Release::new_stable(Version::new(1, 62, 1)),
+        ])
+    }
+
+    fn versions(index: &ReleaseIndex) -> Vec<String> {
+        index
+            .releases()
+            .iter()
+            .map(|release| release.version().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn stable() {
+        let index = with_prereleases(index(), ReleaseChannel::Stable);
+
+        assert_eq!(versions(&index), vec!["1.63.0", "1.62.1"]);
+    }
+
+    #[test]
+    fn beta() {
+        let index = with_prereleases(index(), ReleaseChannel::Beta);
+
+        assert_eq!(versions(&index), vec!["1.64.0-beta", "1.63.0", "1.62.1"]);
+    }
+
+    #[test]
+    fn nightly() {
+        let index = with_prereleases(index(), ReleaseChannel::Nightly);
+
+        assert_eq!(
+            versions(&index),
+            vec!["1.65.0-nightly", "1.64.0-beta", "1.63.0", "1.62.1"]
+        );
+    }
+}
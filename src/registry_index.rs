@@ -0,0 +1,186 @@
+//! Read the versions of a crate from the local cache of the Cargo registry index.
+//!
+//! Cargo stores the index entries of each crate it resolved in the `.cache` folder of the
+//! registry index, in `$CARGO_HOME/registry/index/<registry>/.cache`. Reading this cache doesn't
+//! require network access, but only contains crates which Cargo resolved before.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::semver;
+
+use crate::manifest::bare_version::BareVersion;
+
+/// A published version of a crate, as listed in the registry index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexEntry {
+    version: semver::Version,
+    rust_version: Option<BareVersion>,
+    yanked: bool,
+}
+
+impl IndexEntry {
+    pub fn new(version: semver::Version, rust_version: Option<BareVersion>, yanked: bool) -> Self {
+        Self {
+            version,
+            rust_version,
+            yanked,
+        }
+    }
+
+    pub fn version(&self) -> &semver::Version {
+        &self.version
+    }
+
+    /// The `rust-version` of this version of the crate, if it declares one.
+    pub fn rust_version(&self) -> Option<&BareVersion> {
+        self.rust_version.as_ref()
+    }
+
+    pub fn is_yanked(&self) -> bool {
+        self.yanked
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct RawIndexEntry {
+    vers: String,
+    #[serde(default)]
+    yanked: bool,
+    #[serde(default)]
+    rust_version: Option<String>,
+}
+
+/// The versions of the given crate, found in the index cache of any of the registries in the
+/// Cargo home directory.
+pub fn cached_versions(name: &str) -> Vec<IndexEntry> {
+    let registries = match cargo_home().map(|home| home.join("registry").join("index")) {
+        Some(dir) => dir,
+        None => return Vec::new(),
+    };
+
+    let read_dir = match fs::read_dir(&registries) {
+        Ok(read_dir) => read_dir,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut entries = read_dir
+        .filter_map(Result::ok)
+        .map(|registry| registry.path().join(".cache").join(index_path(name)))
+        .filter_map(|path| fs::read(path).ok())
+        .flat_map(|contents| parse_cache_file(&contents))
+        .collect::<Vec<_>>();
+
+    entries.sort_by(|lhs, rhs| lhs.version.cmp(&rhs.version));
+    entries.dedup_by(|lhs, rhs| lhs.version == rhs.version);
+    entries
+}
+
+fn cargo_home() -> Option<PathBuf> {
+    std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".cargo")))
+}
+
+/// The path of the index file of a crate, relative to the root of the index.
+///
+/// See [the Cargo book](https://doc.rust-lang.org/cargo/reference/registries.html#index-format).
+fn index_path(name: &str) -> PathBuf {
+    let name = name.to_lowercase();
+
+    match name.len() {
+        1 => Path::new("1").join(&name),
+        2 => Path::new("2").join(&name),
+        3 => Path::new("3").join(&name[..1]).join(&name),
+        _ => Path::new(&name[..2]).join(&name[2..4]).join(&name),
+    }
+}
+
+/// Parse a file of the index cache.
+///
+/// The file starts with a header, consisting of the version of the cache format, the version of
+/// the index format (since cache version 2) and the null-terminated revision of the index.
+/// The header is followed by null-terminated pairs of a crate version and its JSON index entry.
+fn parse_cache_file(contents: &[u8]) -> Vec<IndexEntry> {
+    let cache_version = match contents.first() {
+        Some(version) => *version,
+        None => return Vec::new(),
+    };
+
+    let header = if cache_version >= 2 { 5 } else { 1 };
+
+    let mut fields = contents
+        .get(header..)
+        .unwrap_or_default()
+        .split(|&byte| byte == 0)
+        .skip(1); // the index revision
+
+    let mut entries = Vec::new();
+
+    while let (Some(_version), Some(json)) = (fields.next(), fields.next()) {
+        if let Some(entry) = parse_entry(json) {
+            entries.push(entry);
+        }
+    }
+
+    entries
+}
+
+fn parse_entry(json: &[u8]) -> Option<IndexEntry> {
+    let raw = serde_json::from_slice::<RawIndexEntry>(json).ok()?;
+    let version = semver::Version::parse(&raw.vers).ok()?;
+    let rust_version = raw
+        .rust_version
+        .and_then(|rust_version| rust_version.parse().ok());
+
+    Some(IndexEntry::new(version, rust_version, raw.yanked))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        one = { "a", "1/a" },
+        two = { "ab", "2/ab" },
+        three = { "abc", "3/a/abc" },
+        four = { "Serde", "se/rd/serde" },
+    )]
+    fn path_in_index(name: &str, expected: &str) {
+        assert_eq!(index_path(name), PathBuf::from(expected));
+    }
+
+    #[test]
+    fn parse_cache() {
+        let mut contents = vec![3u8];
+        contents.extend_from_slice(&2u32.to_le_bytes());
+        contents.extend_from_slice(b"etag: abc\0");
+        contents.extend_from_slice(b"1.0.0\0");
+        contents.extend_from_slice(
+            br#"{"name":"a","vers":"1.0.0","deps":[],"cksum":"","features":{},"yanked":false}"#,
+        );
+        contents.push(0);
+        contents.extend_from_slice(b"1.1.0\0");
+        contents.extend_from_slice(br#"{"name":"a","vers":"1.1.0","deps":[],"cksum":"","features":{},"yanked":true,"rust_version":"1.60"}"#);
+        contents.push(0);
+
+        let entries = parse_cache_file(&contents);
+
+        assert_eq!(
+            entries,
+            vec![
+                IndexEntry::new(semver::Version::new(1, 0, 0), None, false),
+                IndexEntry::new(
+                    semver::Version::new(1, 1, 0),
+                    Some(BareVersion::TwoComponents(1, 60)),
+                    true
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_empty_cache() {
+        assert!(parse_cache_file(&[]).is_empty());
+    }
+}
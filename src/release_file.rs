@@ -0,0 +1,211 @@
+//! A release index from a user-provided list of Rust versions, given by
+//! `--release-source file://<path>`.
+//!
+//! This allows teams to restrict the search space to the compiler versions which were approved
+//! internally. The list is a JSON or TOML file, depending on its extension:
+//!
+//! ```json
+//! ["1.56.1", "1.60.0", "1.64.0"]
+//! ```
+//!
+//! ```toml
+//! releases = ["1.56.1", "1.60.0", "1.64.0"]
+//! ```
+//!
+//! A JSON file may also contain an object with a `releases` array, like the TOML file.
+
+use std::iter::FromIterator;
+use std::path::Path;
+
+use rust_releases::{semver, Release, ReleaseIndex};
+use toml_edit::Document;
+
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+
+/// Build a release index from the versions listed by the releases file at the given path.
+pub(crate) fn release_file_index(path: &Path) -> TResult<ReleaseIndex> {
+    let contents = std::fs::read_to_string(path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(path.to_path_buf()),
+    })?;
+
+    let entries = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => json_entries(&contents),
+        Some("toml") => toml_entries(&contents),
+        _ => Err("the file must have a .json or .toml extension".to_string()),
+    };
+
+    let versions = entries
+        .and_then(|entries| parse_versions(&entries))
+        .map_err(|reason| CargoMSRVError::InvalidReleasesFile {
+            path: path.to_path_buf(),
+            reason,
+        })?;
+
+    Ok(ReleaseIndex::from_iter(
+        versions.into_iter().map(Release::new_stable),
+    ))
+}
+
+fn json_entries(contents: &str) -> Result<Vec<String>, String> {
+    let value = serde_json::from_str::<serde_json::Value>(contents).map_err(|e| e.to_string())?;
+
+    let releases = match &value {
+        serde_json::Value::Array(releases) => releases,
+        serde_json::Value::Object(object) => match object.get("releases") {
+            Some(serde_json::Value::Array(releases)) => releases,
+            _ => return Err("expected a 'releases' array".to_string()),
+        },
+        _ => return Err("expected an array of versions".to_string()),
+    };
+
+    releases
+        .iter()
+        .map(|release| {
+            release
+                .as_str()
+                .map(String::from)
+                .ok_or_else(|| format!("expected a version string, found '{}'", release))
+        })
+        .collect()
+}
+
+fn toml_entries(contents: &str) -> Result<Vec<String>, String> {
+    let document = contents.parse::<Document>().map_err(|e| e.to_string())?;
+
+    let releases = document
+        .get("releases")
+        .and_then(|item| item.as_array())
+        .ok_or_else(|| "expected a 'releases' array".to_string())?;
+
+    releases
+        .iter()
+        .map(|release| {
+            release
+                .as_str()
+                .map(String::from)
+                .ok_or_else(|| format!("expected a version string, found '{}'", release))
+        })
+        .collect()
+}
+
+/// Parse the listed versions, most recent first. Fails if a version is invalid, or if the list
+/// is empty.
+fn parse_versions(entries: &[String]) -> Result<Vec<semver::Version>, String> {
+    let mut versions = entries
+        .iter()
+        .map(|entry| {
+            semver::Version::parse(entry.trim())
+                .map_err(|e| format!("invalid version '{}': {}", entry, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if versions.is_empty() {
+        return Err("no versions are listed".to_string());
+    }
+
+    versions.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    versions.dedup();
+
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    fn versions(index: &ReleaseIndex) -> Vec<semver::Version> {
+        index
+            .releases()
+            .iter()
+            .map(|release| release.version().clone())
+            .collect()
+    }
+
+    #[test]
+    fn json_array() {
+        let tmp = TestDir::temp().create("releases.json", FileType::EmptyFile);
+        let path = tmp.path("releases.json");
+        std::fs::write(&path, r#"["1.56.1", "1.64.0", "1.60.0"]"#).unwrap();
+
+        let index = release_file_index(&path).unwrap();
+
+        assert_eq!(
+            versions(&index),
+            vec![
+                semver::Version::new(1, 64, 0),
+                semver::Version::new(1, 60, 0),
+                semver::Version::new(1, 56, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_object() {
+        let tmp = TestDir::temp().create("releases.json", FileType::EmptyFile);
+        let path = tmp.path("releases.json");
+        std::fs::write(&path, r#"{ "releases": ["1.56.1"] }"#).unwrap();
+
+        let index = release_file_index(&path).unwrap();
+
+        assert_eq!(versions(&index), vec![semver::Version::new(1, 56, 1)]);
+    }
+
+    #[test]
+    fn toml_table() {
+        let tmp = TestDir::temp().create("releases.toml", FileType::EmptyFile);
+        let path = tmp.path("releases.toml");
+        std::fs::write(&path, "releases = [\"1.60.0\", \"1.56.1\", \"1.60.0\"]\n").unwrap();
+
+        let index = release_file_index(&path).unwrap();
+
+        assert_eq!(
+            versions(&index),
+            vec![
+                semver::Version::new(1, 60, 0),
+                semver::Version::new(1, 56, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_version() {
+        let tmp = TestDir::temp().create("releases.toml", FileType::EmptyFile);
+        let path = tmp.path("releases.toml");
+        std::fs::write(&path, "releases = [\"1.60\"]\n").unwrap();
+
+        let result = release_file_index(&path);
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::InvalidReleasesFile { .. })
+        ));
+    }
+
+    #[test]
+    fn empty_list() {
+        let tmp = TestDir::temp().create("releases.json", FileType::EmptyFile);
+        let path = tmp.path("releases.json");
+        std::fs::write(&path, "[]").unwrap();
+
+        let result = release_file_index(&path);
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::InvalidReleasesFile { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_extension() {
+        let tmp = TestDir::temp().create("releases.txt", FileType::EmptyFile);
+
+        let result = release_file_index(&tmp.path("releases.txt"));
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::InvalidReleasesFile { .. })
+        ));
+    }
+}
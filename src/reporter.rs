@@ -4,8 +4,19 @@ use crate::reporter::event::EventScope;
 use crate::TResult;
 
 pub use handler::DiscardOutputHandler;
+pub use handler::DotHandler;
+pub use handler::GithubHandler;
+pub use handler::HtmlHandler;
 pub use handler::HumanProgressHandler;
 pub use handler::JsonHandler;
+pub use handler::JunitHandler;
+pub use handler::MarkdownHandler;
+pub use handler::QuietHandler;
+pub use handler::ReportHandler;
+pub use handler::SarifHandler;
+pub use handler::TomlHandler;
+#[cfg(feature = "tui")]
+pub use handler::TuiHandler;
 
 pub use event::{
     Event, Message,
@@ -14,6 +25,7 @@ pub use event::{
 
 pub(crate) mod event;
 pub(crate) mod handler;
+pub(crate) mod schema;
 
 #[cfg(test)]
 mod testing;
@@ -37,7 +49,7 @@ pub trait Reporter:
     fn run_scoped_event<T>(
         &self,
         event: impl Into<Event>,
-        f: impl Fn() -> TResult<T>,
+        f: impl FnOnce() -> TResult<T>,
     ) -> TResult<T> {
         let event = event.into();
 
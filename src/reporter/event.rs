@@ -5,35 +5,103 @@ pub use action::ActionMessage;
 pub use auxiliary_output::{
     AuxiliaryOutput, Destination, Item as AuxiliaryOutputItem, MsrvKind, ToolchainFileKind,
 };
+pub use certification_outdated::{CertificationOutdated, CertifiedInput};
+pub use check_output::CheckOutput;
+pub use check_skipped::CheckSkipped;
+pub use check_timed_out::CheckTimedOut;
 pub use check_toolchain::CheckToolchain;
 pub use compatibility::{Compatibility, CompatibilityReport};
 pub use compatibility_check_method::{CompatibilityCheckMethod, Method};
-pub use fetch_index::FetchIndex;
+pub use dependency_msrv_verified::DependencyMsrvVerified;
+pub use doctor_report::{DoctorCheck, DoctorReport, Finding, FindingStatus};
+pub use edition_floor::EditionFloor;
+pub use feature_matrix_result::{FeatureMatrixResult, FeatureMsrv};
+pub use fetch_index::{FetchFailureReason, FetchIndex, FetchIndexFailed};
+pub use invalid_search_space::InvalidSearchSpace;
 pub use list_dep::ListDep;
+pub use lockfile_pinned::{LockfilePinned, PinnedDependency};
+pub use lower_msrv_available::LowerMsrvAvailable;
+pub use manifest_msrv_mismatch::ManifestMsrvMismatch;
 pub use meta::Meta;
+pub use msrv_causes::{MsrvCause, MsrvCauses};
+pub use msrv_clamped_to_edition::MsrvClampedToEdition;
+pub use msrv_coverage::MsrvCoverage;
+pub use msrv_diff::{DiffStatus, MsrvDiff};
 pub use msrv_result::MsrvResult;
+pub use msrv_sources::{DeclaredMsrv, MsrvSource, MsrvSources};
+pub use planned_checks::{PlannedCheck, PlannedChecks};
+pub use policy_result::{PolicyResult, PolicyRule, PolicyRuleKind};
+pub use preflight_estimate::PreflightEstimate;
 pub use progress::Progress;
+pub use search_cancelled::SearchCancelled;
 pub use search_method::FindMsrv;
+pub use search_probe::SearchProbe;
+pub use search_space_pruned::SearchSpacePruned;
 pub use set_output::SetOutputMessage;
-pub use setup_toolchain::SetupToolchain;
+pub use setup_toolchain::{
+    SetupStage, SetupToolchain, SetupToolchainProgress, SetupToolchainRetry,
+};
 pub use show_output::ShowOutputMessage;
+pub use subprocess_finished::SubprocessFinished;
+pub use subprocess_started::SubprocessStarted;
+pub use temporary_lockfile::{LockfileStatus, TemporaryLockfile};
 pub use termination::TerminateWithFailure;
+pub use toolchain_cleanup::{CleanupStatus, ToolchainCleanup};
+pub use toolchain_file_bypassed::ToolchainFileBypassed;
+pub use upgrade_suggestions::{DependencyUpgrade, UpgradeSuggestions};
+pub use verify_result::VerifyResult;
+pub use watch_iteration::{WatchIteration, WatchIterationFailed};
+pub use workspace_msrv_result::{PackageMsrv, PackageMsrvStatus, WorkspaceMsrvResult};
+pub use workspace_verify_result::{PackageVerifyResult, VerifyStatus, WorkspaceVerifyResult};
 
 mod action;
 mod auxiliary_output;
+mod certification_outdated;
+mod check_output;
+mod check_skipped;
+mod check_timed_out;
 mod check_toolchain;
 mod compatibility;
 mod compatibility_check_method;
+mod dependency_msrv_verified;
+mod doctor_report;
+mod edition_floor;
+mod feature_matrix_result;
 mod fetch_index;
+mod invalid_search_space;
 mod list_dep;
+mod lockfile_pinned;
+mod lower_msrv_available;
+mod manifest_msrv_mismatch;
 mod meta;
+mod msrv_causes;
+mod msrv_clamped_to_edition;
+mod msrv_coverage;
+mod msrv_diff;
 mod msrv_result;
+mod msrv_sources;
+mod planned_checks;
+mod policy_result;
+mod preflight_estimate;
 mod progress;
+mod search_cancelled;
 mod search_method;
+mod search_probe;
+mod search_space_pruned;
 mod set_output;
 mod setup_toolchain;
 mod show_output;
+mod subprocess_finished;
+mod subprocess_started;
+mod temporary_lockfile;
 mod termination;
+mod toolchain_cleanup;
+mod toolchain_file_bypassed;
+mod upgrade_suggestions;
+mod verify_result;
+mod watch_iteration;
+mod workspace_msrv_result;
+mod workspace_verify_result;
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -70,6 +138,8 @@ impl Event {
 }
 
 /// Messages are a kind of event which report the state of this program to the user
+///
+/// The name of each variant is listed in the event schema as well, see `reporter::schema`.
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
@@ -81,36 +151,92 @@ pub enum Message {
 
     // get rust-releases index
     FetchIndex(FetchIndex), // todo!
+    FetchIndexFailed(FetchIndexFailed),
 
     // todo: SkippedRustVersions // +reason
 
+    // estimate the toolchains to download, before the checks are run
+    PreflightEstimate(PreflightEstimate),
+
     // install toolchain
     SetupToolchain(SetupToolchain),
+    SetupToolchainProgress(SetupToolchainProgress),
+    SetupToolchainRetry(SetupToolchainRetry),
 
     // runner + pass/reject
     CheckToolchain(CheckToolchain),
     CompatibilityCheckMethod(CompatibilityCheckMethod),
     Compatibility(Compatibility),
+    CheckOutput(CheckOutput),
+    CheckSkipped(CheckSkipped),
+    CheckTimedOut(CheckTimedOut),
+    SubprocessStarted(SubprocessStarted),
+    SubprocessFinished(SubprocessFinished),
+    TemporaryLockfile(TemporaryLockfile),
+    ToolchainFileBypassed(ToolchainFileBypassed),
+
+    // the run was cancelled, before the search was completed
+    SearchCancelled(SearchCancelled),
+
+    // uninstall the toolchains which were installed for this run
+    ToolchainCleanup(ToolchainCleanup),
+
+    // dry run: the checks which would be run
+    PlannedChecks(PlannedChecks),
 
     // output written by the program
     AuxiliaryOutput(AuxiliaryOutput),
 
     // command: find
     MsrvResult(MsrvResult),
+    ManifestMsrvMismatch(ManifestMsrvMismatch),
+    MsrvClampedToEdition(MsrvClampedToEdition),
+    MsrvCauses(MsrvCauses),
+    SearchSpacePruned(SearchSpacePruned),
+    InvalidSearchSpace(InvalidSearchSpace),
     FindMsrv(FindMsrv),
+    SearchProbe(SearchProbe),
     Progress(Progress),
+    WorkspaceMsrvResult(WorkspaceMsrvResult),
+    FeatureMatrixResult(FeatureMatrixResult),
 
     // command: verify
-    // Verify
+    VerifyResult(VerifyResult),
+    WorkspaceVerifyResult(WorkspaceVerifyResult),
+    CertificationOutdated(CertificationOutdated),
+    LowerMsrvAvailable(LowerMsrvAvailable),
 
     // command: list
     ListDep(ListDep),
+    MsrvCoverage(MsrvCoverage),
+    DependencyMsrvVerified(DependencyMsrvVerified),
 
     // command: set
     SetOutput(SetOutputMessage),
 
     // command: show
     ShowOutput(ShowOutputMessage),
+    MsrvSources(MsrvSources),
+    EditionFloor(EditionFloor),
+
+    // command: doctor
+    DoctorReport(DoctorReport),
+
+    // command: policy
+    PolicyResult(PolicyResult),
+
+    // command: upgrade
+    UpgradeSuggestions(UpgradeSuggestions),
+
+    // command: pin-lockfile
+    LockfilePinned(LockfilePinned),
+
+    // command: diff
+    MsrvDiff(MsrvDiff),
+
+    // command: watch
+    WatchIteration(WatchIteration),
+    WatchIterationFailed(WatchIterationFailed),
 
     // Termination, for example when caused by an unrecoverable error
     TerminateWithFailure(TerminateWithFailure),
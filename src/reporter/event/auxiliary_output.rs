@@ -1,17 +1,49 @@
 use crate::reporter::event::Message;
 use crate::Event;
-use std::path::PathBuf;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
+/// A side effect of cargo-msrv, other than the checks: an item which was written to a
+/// destination, or which would be written, in case of a dry run.
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct AuxiliaryOutput {
     destination: Destination,
     item: Item,
+    /// Whether the item would be written, rather than that it was written, because this is a dry
+    /// run
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    planned: bool,
 }
 
 impl AuxiliaryOutput {
     pub fn new(destination: Destination, item: Item) -> Self {
-        Self { destination, item }
+        Self {
+            destination,
+            item,
+            planned: false,
+        }
+    }
+
+    /// An item which would be written to the destination, if this wasn't a dry run.
+    pub fn planned(destination: Destination, item: Item) -> Self {
+        Self {
+            destination,
+            item,
+            planned: true,
+        }
+    }
+
+    pub fn destination(&self) -> &Destination {
+        &self.destination
+    }
+
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+
+    pub fn is_planned(&self) -> bool {
+        self.planned
     }
 }
 
@@ -24,14 +56,71 @@ impl From<AuxiliaryOutput> for Event {
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Destination {
+    // A file which isn't covered by one of the more specific destinations
     File(PathBuf),
+    // A Cargo manifest, i.e. a Cargo.toml file
+    Manifest(PathBuf),
+    // A rust-toolchain or rust-toolchain.toml file
+    ToolchainFile(PathBuf),
+    // An environment file, such as the file given by $GITHUB_ENV, which is read by later steps of
+    // a CI workflow
+    Env(PathBuf),
+    // The standard output of cargo-msrv
+    Stdout,
+}
+
+impl Destination {
+    /// The path of the file which is written to, unless written to stdout.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::File(path)
+            | Self::Manifest(path)
+            | Self::ToolchainFile(path)
+            | Self::Env(path) => Some(path),
+            Self::Stdout => None,
+        }
+    }
+}
+
+impl fmt::Display for Destination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Manifest(path) => write!(f, "Cargo manifest {}", path.display()),
+            Self::ToolchainFile(path) => write!(f, "toolchain file {}", path.display()),
+            Self::Env(path) => write!(f, "environment file {}", path.display()),
+            Self::Stdout => f.write_str("stdout"),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Item {
-    Msrv { kind: MsrvKind },
-    ToolchainFile { kind: ToolchainFileKind },
+    Msrv {
+        kind: MsrvKind,
+    },
+    // The channel is the MSRV as written to the toolchain file, e.g. 1.64.0 or 1.64; it's not
+    // known yet for a planned toolchain file
+    ToolchainFile {
+        kind: ToolchainFileKind,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        channel: Option<String>,
+    },
+    // The msrv key of the Clippy configuration file, i.e. clippy.toml or .clippy.toml
+    ClippyConfig,
+    // The version requirements of the dependencies in the Cargo manifest
+    Dependencies,
+    // The locked versions of the dependencies in the Cargo lockfile
+    Lockfile,
+    // A shields.io endpoint badge which displays the MSRV
+    Badge,
+    // A JSON report with all events, written regardless of the output format
+    Report,
+    // The package.metadata.msrv-verified table, which records the inputs of the checks
+    Certification,
+    // The JSON Schema of the events, printed by the hidden __schema command
+    Schema,
 }
 
 impl Item {
@@ -39,8 +128,79 @@ impl Item {
         Self::Msrv { kind }
     }
 
-    pub fn toolchain_file(kind: ToolchainFileKind) -> Self {
-        Self::ToolchainFile { kind }
+    pub fn toolchain_file(kind: ToolchainFileKind, channel: impl Into<String>) -> Self {
+        Self::ToolchainFile {
+            kind,
+            channel: Some(channel.into()),
+        }
+    }
+
+    /// A toolchain file which would be written, with a channel which is not known yet.
+    pub fn planned_toolchain_file(kind: ToolchainFileKind) -> Self {
+        Self::ToolchainFile {
+            kind,
+            channel: None,
+        }
+    }
+
+    pub fn clippy_config() -> Self {
+        Self::ClippyConfig
+    }
+
+    pub fn dependencies() -> Self {
+        Self::Dependencies
+    }
+
+    pub fn lockfile() -> Self {
+        Self::Lockfile
+    }
+
+    pub fn badge() -> Self {
+        Self::Badge
+    }
+
+    pub fn report() -> Self {
+        Self::Report
+    }
+
+    pub fn certification() -> Self {
+        Self::Certification
+    }
+
+    pub fn schema() -> Self {
+        Self::Schema
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Msrv { kind } => match kind {
+                MsrvKind::RustVersion => f.write_str("the MSRV (package.rust-version)"),
+                MsrvKind::MetadataFallback => f.write_str("the MSRV (package.metadata.msrv)"),
+                MsrvKind::WorkspaceRustVersion => {
+                    f.write_str("the MSRV (workspace.package.rust-version)")
+                }
+                MsrvKind::InheritedRustVersion => {
+                    f.write_str("the inherited MSRV (package.rust-version.workspace)")
+                }
+                MsrvKind::EnvVariable => f.write_str("the MSRV variable"),
+            },
+            Self::ToolchainFile {
+                channel: Some(channel),
+                ..
+            } => write!(f, "the toolchain channel {}", channel),
+            Self::ToolchainFile { channel: None, .. } => f.write_str("the toolchain channel"),
+            Self::ClippyConfig => f.write_str("the msrv of the Clippy configuration"),
+            Self::Dependencies => f.write_str("the dependency requirements"),
+            Self::Lockfile => f.write_str("the locked dependencies"),
+            Self::Badge => f.write_str("the MSRV badge"),
+            Self::Report => f.write_str("the report"),
+            Self::Certification => {
+                f.write_str("the certification (package.metadata.msrv-verified)")
+            }
+            Self::Schema => f.write_str("the event schema"),
+        }
     }
 }
 
@@ -52,12 +212,21 @@ pub enum MsrvKind {
     // The package.metadata.msrv key used as fallback for crates where the Cargo Manifest format did
     // not support the package.rust-version key yet.
     MetadataFallback,
+    // The workspace.package.rust-version, which can be inherited by the members of a workspace.
+    WorkspaceRustVersion,
+    // The package.rust-version.workspace = true key, by which a member inherits the MSRV of the
+    // workspace.
+    InheritedRustVersion,
+    // A variable in an environment file, such as MSRV=1.56.1
+    EnvVariable,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ToolchainFileKind {
-    /* Legacy, : Unsupported right now */
+    // The plain-text rust-toolchain file, which only contains the name of the toolchain.
+    Legacy,
+    // The rust-toolchain(.toml) file in the TOML format.
     Toml,
 }
 
@@ -73,7 +242,17 @@ mod tests {
     #[yare::parameterized(
         rust_version_msrv = { Item::msrv(MsrvKind::RustVersion) },
         metadata_fallback_msrv = { Item::msrv(MsrvKind::MetadataFallback) },
-        toolchain_file_toml = { Item::toolchain_file(ToolchainFileKind::Toml) },
+        workspace_rust_version_msrv = { Item::msrv(MsrvKind::WorkspaceRustVersion) },
+        inherited_rust_version_msrv = { Item::msrv(MsrvKind::InheritedRustVersion) },
+        env_variable_msrv = { Item::msrv(MsrvKind::EnvVariable) },
+        toolchain_file_legacy = { Item::toolchain_file(ToolchainFileKind::Legacy, "1.64.0") },
+        toolchain_file_toml = { Item::toolchain_file(ToolchainFileKind::Toml, "1.64") },
+        clippy_config = { Item::clippy_config() },
+        badge = { Item::badge() },
+        report = { Item::report() },
+        certification = { Item::certification() },
+        planned_toolchain_file = { Item::planned_toolchain_file(ToolchainFileKind::Toml) },
+        schema = { Item::schema() },
     )]
     fn reported_action(item: Item) {
         let reporter = TestReporter::default();
@@ -86,4 +265,27 @@ mod tests {
             vec![Event::new(Message::AuxiliaryOutput(event)),]
         );
     }
+
+    #[yare::parameterized(
+        file = { Destination::File(Path::new("badge.json").to_path_buf()), true },
+        manifest = { Destination::Manifest(Path::new("Cargo.toml").to_path_buf()), true },
+        toolchain_file = { Destination::ToolchainFile(Path::new("rust-toolchain.toml").to_path_buf()), true },
+        env = { Destination::Env(Path::new("env").to_path_buf()), true },
+        stdout = { Destination::Stdout, false },
+    )]
+    fn destination_path(destination: Destination, has_path: bool) {
+        assert_eq!(destination.path().is_some(), has_path);
+    }
+
+    #[test]
+    fn planned_output_is_marked() {
+        let planned = AuxiliaryOutput::planned(Destination::Stdout, Item::schema());
+        let written = AuxiliaryOutput::new(Destination::Stdout, Item::schema());
+
+        assert_eq!(serde_json::to_value(&planned).unwrap()["planned"], true);
+        assert!(serde_json::to_value(&written)
+            .unwrap()
+            .get("planned")
+            .is_none());
+    }
 }
@@ -0,0 +1,98 @@
+use crate::reporter::event::Message;
+use crate::Event;
+use std::fmt;
+use std::path::PathBuf;
+
+/// The inputs of the checks changed since the MSRV was certified by `find --certify`, so the
+/// certification may no longer hold.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CertificationOutdated {
+    manifest_path: PathBuf,
+    certified_toolchain: String,
+    certified_on: String,
+    changed: Vec<CertifiedInput>,
+}
+
+impl CertificationOutdated {
+    pub fn new(
+        manifest_path: impl Into<PathBuf>,
+        certified_toolchain: impl Into<String>,
+        certified_on: impl Into<String>,
+        changed: Vec<CertifiedInput>,
+    ) -> Self {
+        Self {
+            manifest_path: manifest_path.into(),
+            certified_toolchain: certified_toolchain.into(),
+            certified_on: certified_on.into(),
+            changed,
+        }
+    }
+
+    pub fn manifest_path(&self) -> &PathBuf {
+        &self.manifest_path
+    }
+
+    pub fn certified_toolchain(&self) -> &str {
+        &self.certified_toolchain
+    }
+
+    pub fn certified_on(&self) -> &str {
+        &self.certified_on
+    }
+
+    pub fn changed(&self) -> &[CertifiedInput] {
+        &self.changed
+    }
+}
+
+impl From<CertificationOutdated> for Event {
+    fn from(it: CertificationOutdated) -> Self {
+        Message::CertificationOutdated(it).into()
+    }
+}
+
+/// An input of the checks which is recorded by the certification.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CertifiedInput {
+    Toolchain,
+    CheckCommand,
+    Lockfile,
+}
+
+impl fmt::Display for CertifiedInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Toolchain => write!(f, "toolchain"),
+            Self::CheckCommand => write!(f, "check command"),
+            Self::Lockfile => write!(f, "lockfile"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = CertificationOutdated::new(
+            "Cargo.toml",
+            "1.56.1",
+            "2022-08-11",
+            vec![CertifiedInput::Lockfile],
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::CertificationOutdated(event)),]
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::reporter::event::Message;
+use crate::toolchain::OwnedToolchainSpec;
+use crate::Event;
+
+/// A line of output written by the check command of a toolchain, reported as soon as it is
+/// written.
+///
+/// Only reported if the output of the check commands is shown.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckOutput {
+    toolchain: OwnedToolchainSpec,
+    line: String,
+}
+
+impl CheckOutput {
+    pub fn new(toolchain: impl Into<OwnedToolchainSpec>, line: impl Into<String>) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+            line: line.into(),
+        }
+    }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+}
+
+impl From<CheckOutput> for Event {
+    fn from(it: CheckOutput) -> Self {
+        Message::CheckOutput(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::semver;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = CheckOutput::new(
+            OwnedToolchainSpec::new(&semver::Version::new(1, 2, 3), "test_target"),
+            "    Checking example v0.1.0",
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::CheckOutput(event)),]
+        );
+    }
+}
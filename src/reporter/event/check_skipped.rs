@@ -0,0 +1,54 @@
+use crate::reporter::event::Message;
+use crate::toolchain::OwnedToolchainSpec;
+use crate::Event;
+
+/// The check of a toolchain was aborted on request of the user, and the toolchain is considered
+/// incompatible.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckSkipped {
+    toolchain: OwnedToolchainSpec,
+}
+
+impl CheckSkipped {
+    pub fn new(toolchain: impl Into<OwnedToolchainSpec>) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+        }
+    }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+}
+
+impl From<CheckSkipped> for Event {
+    fn from(it: CheckSkipped) -> Self {
+        Message::CheckSkipped(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::semver;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = CheckSkipped::new(OwnedToolchainSpec::new(
+            &semver::Version::new(1, 2, 3),
+            "test_target",
+        ));
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::CheckSkipped(event)),]
+        );
+    }
+}
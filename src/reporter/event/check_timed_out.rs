@@ -0,0 +1,70 @@
+use crate::reporter::event::Message;
+use crate::toolchain::OwnedToolchainSpec;
+use crate::Event;
+
+/// The check of a toolchain ran longer than the timeout given by `--check-timeout`, and was
+/// aborted.
+///
+/// The toolchain is considered incompatible. If the timeout is treated as a skip, the check is
+/// also reported as skipped.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckTimedOut {
+    toolchain: OwnedToolchainSpec,
+    timeout_secs: u64,
+    skipped: bool,
+}
+
+impl CheckTimedOut {
+    pub fn new(toolchain: impl Into<OwnedToolchainSpec>, timeout_secs: u64, skipped: bool) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+            timeout_secs,
+            skipped,
+        }
+    }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+
+    pub fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    pub fn skipped(&self) -> bool {
+        self.skipped
+    }
+}
+
+impl From<CheckTimedOut> for Event {
+    fn from(it: CheckTimedOut) -> Self {
+        Message::CheckTimedOut(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::semver;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = CheckTimedOut::new(
+            OwnedToolchainSpec::new(&semver::Version::new(1, 2, 3), "test_target"),
+            600,
+            false,
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::CheckTimedOut(event)),]
+        );
+    }
+}
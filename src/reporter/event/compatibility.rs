@@ -1,6 +1,8 @@
 use crate::reporter::event::Message;
 use crate::toolchain::OwnedToolchainSpec;
 use crate::Event;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -8,6 +10,25 @@ pub struct Compatibility {
     pub toolchain: OwnedToolchainSpec,
     decision: bool,
     pub compatibility_report: CompatibilityReport,
+    /// How long the check command took to run, or `None` if the outcome was not determined by
+    /// running the check command, for example when it was cached.
+    #[serde(
+        rename = "duration_secs",
+        serialize_with = "serialize_duration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    duration: Option<Duration>,
+    /// The file to which the full output of a failed check was written.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_log: Option<PathBuf>,
+    /// Whether the check passed only with a lockfile generated by the Cargo version of the
+    /// toolchain, since it didn't understand the format of the lockfile of the crate.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    lockfile_downgraded: bool,
+    /// The flag of the check command which is not supported by the Cargo version of the
+    /// toolchain, if the check failed because of it. The crate itself was not checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unsupported_flag: Option<String>,
 }
 
 impl Compatibility {
@@ -16,6 +37,10 @@ impl Compatibility {
             toolchain: toolchain.into(),
             decision: true,
             compatibility_report: CompatibilityReport::Compatible,
+            duration: None,
+            output_log: None,
+            lockfile_downgraded: false,
+            unsupported_flag: None,
         }
     }
 
@@ -26,9 +51,37 @@ impl Compatibility {
             compatibility_report: CompatibilityReport::Incompatible {
                 error: error.map(Into::into),
             },
+            duration: None,
+            output_log: None,
+            lockfile_downgraded: false,
+            unsupported_flag: None,
         }
     }
 
+    /// Set how long the check command took to run.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Set the file to which the full output of the check was written.
+    pub fn with_output_log(mut self, path: Option<PathBuf>) -> Self {
+        self.output_log = path;
+        self
+    }
+
+    /// Set whether the check passed only with a downgraded lockfile.
+    pub fn with_lockfile_downgrade(mut self, downgraded: bool) -> Self {
+        self.lockfile_downgraded = downgraded;
+        self
+    }
+
+    /// Set the flag of the check command which the toolchain doesn't support.
+    pub fn with_unsupported_flag(mut self, flag: Option<String>) -> Self {
+        self.unsupported_flag = flag;
+        self
+    }
+
     pub fn toolchain(&self) -> &OwnedToolchainSpec {
         &self.toolchain
     }
@@ -36,6 +89,32 @@ impl Compatibility {
     pub fn is_compatible(&self) -> bool {
         self.decision
     }
+
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    pub fn output_log(&self) -> Option<&Path> {
+        self.output_log.as_deref()
+    }
+
+    pub fn lockfile_downgraded(&self) -> bool {
+        self.lockfile_downgraded
+    }
+
+    pub fn unsupported_flag(&self) -> Option<&str> {
+        self.unsupported_flag.as_deref()
+    }
+}
+
+fn serialize_duration<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match duration {
+        Some(duration) => serializer.serialize_some(&duration.as_secs_f64()),
+        None => serializer.serialize_none(),
+    }
 }
 
 impl From<Compatibility> for Event {
@@ -93,4 +172,50 @@ mod tests {
             vec![Event::new(Message::Compatibility(event)),]
         );
     }
+
+    #[test]
+    fn duration_is_serialized_in_seconds() {
+        let event = Compatibility::compatible(OwnedToolchainSpec::new(
+            &semver::Version::new(1, 2, 3),
+            "test_target",
+        ))
+        .with_duration(Duration::from_millis(1500));
+
+        let value = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(value["duration_secs"], 1.5);
+    }
+
+    #[test]
+    fn lockfile_downgrade_is_only_serialized_when_set() {
+        let toolchain = OwnedToolchainSpec::new(&semver::Version::new(1, 2, 3), "test_target");
+        let compatible = Compatibility::compatible(toolchain.clone());
+        let downgraded = Compatibility::compatible(toolchain).with_lockfile_downgrade(true);
+
+        assert!(serde_json::to_value(&compatible)
+            .unwrap()
+            .get("lockfile_downgraded")
+            .is_none());
+        assert_eq!(
+            serde_json::to_value(&downgraded).unwrap()["lockfile_downgraded"],
+            true
+        );
+    }
+
+    #[test]
+    fn unsupported_flag_is_only_serialized_when_set() {
+        let toolchain = OwnedToolchainSpec::new(&semver::Version::new(1, 2, 3), "test_target");
+        let incompatible = Compatibility::incompatible(toolchain.clone(), None);
+        let unsupported = Compatibility::incompatible(toolchain, None)
+            .with_unsupported_flag(Some("--all-targets".to_string()));
+
+        assert!(serde_json::to_value(&incompatible)
+            .unwrap()
+            .get("unsupported_flag")
+            .is_none());
+        assert_eq!(
+            serde_json::to_value(&unsupported).unwrap()["unsupported_flag"],
+            "--all-targets"
+        );
+    }
 }
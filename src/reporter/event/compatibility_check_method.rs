@@ -1,6 +1,7 @@
 use crate::reporter::event::Message;
 use crate::toolchain::OwnedToolchainSpec;
 use crate::Event;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
@@ -17,6 +18,14 @@ impl CompatibilityCheckMethod {
             method,
         }
     }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
 }
 
 impl From<CompatibilityCheckMethod> for Event {
@@ -31,6 +40,13 @@ pub enum Method {
     RustupRun {
         args: Vec<String>,
         path: Option<PathBuf>,
+        /// The environment variables which are set for the check command
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        env: BTreeMap<String, String>,
+        /// The arguments which cargo-msrv added to the check command, such as the arguments which
+        /// select the enabled features; these are included in `args` as well
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        default_args: Vec<String>,
     },
     #[cfg(test)]
     TestRunner,
@@ -44,6 +60,49 @@ impl Method {
         Self::RustupRun {
             args: args.into_iter().map(|s| s.as_ref().to_string()).collect(),
             path: path.as_ref().map(|path| path.as_ref().to_path_buf()),
+            env: BTreeMap::new(),
+            default_args: Vec::new(),
+        }
+    }
+
+    /// Set the environment variables of the check command.
+    pub fn with_env(self, env: BTreeMap<String, String>) -> Self {
+        match self {
+            Self::RustupRun {
+                args,
+                path,
+                default_args,
+                ..
+            } => Self::RustupRun {
+                args,
+                path,
+                env,
+                default_args,
+            },
+            #[cfg(test)]
+            Self::TestRunner => Self::TestRunner,
+        }
+    }
+
+    /// Set the arguments which cargo-msrv added to the check command.
+    pub fn with_default_args(
+        self,
+        default_args: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        match self {
+            Self::RustupRun {
+                args, path, env, ..
+            } => Self::RustupRun {
+                args,
+                path,
+                env,
+                default_args: default_args
+                    .into_iter()
+                    .map(|arg| arg.as_ref().to_string())
+                    .collect(),
+            },
+            #[cfg(test)]
+            Self::TestRunner => Self::TestRunner,
         }
     }
 }
@@ -59,6 +118,8 @@ mod tests {
     #[yare::parameterized(
         rustup_run_without_path = { Method::rustup_run(&["hello"], Option::<&Path>::None) },
         rustup_run_with_path = { Method::rustup_run(&["hello"], Some(Path::new("haha"))) },
+        rustup_run_with_env = { Method::rustup_run(&["hello"], Option::<&Path>::None).with_env(BTreeMap::from([("A".to_string(), "b".to_string())])) },
+        rustup_run_with_default_args = { Method::rustup_run(&["hello", "--all-features"], Option::<&Path>::None).with_default_args(&["--all-features"]) },
         test_runner = { Method::TestRunner },
     )]
     fn reported_event(method: Method) {
@@ -0,0 +1,79 @@
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The MSRV of a direct dependency, found by compiling the dependency in isolation, next to the
+/// MSRV it declares, if any.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DependencyMsrvVerified {
+    name: String,
+    version: semver::Version,
+    declared_msrv: Option<semver::Version>,
+    /// The MSRV found by compiling the dependency, or `None` if no Rust version in the search
+    /// space is compatible.
+    verified_msrv: Option<semver::Version>,
+}
+
+impl DependencyMsrvVerified {
+    pub fn new(
+        name: impl Into<String>,
+        version: semver::Version,
+        declared_msrv: Option<semver::Version>,
+        verified_msrv: Option<semver::Version>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            version,
+            declared_msrv,
+            verified_msrv,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> &semver::Version {
+        &self.version
+    }
+
+    pub fn declared_msrv(&self) -> Option<&semver::Version> {
+        self.declared_msrv.as_ref()
+    }
+
+    pub fn verified_msrv(&self) -> Option<&semver::Version> {
+        self.verified_msrv.as_ref()
+    }
+}
+
+impl From<DependencyMsrvVerified> for Event {
+    fn from(it: DependencyMsrvVerified) -> Self {
+        Message::DependencyMsrvVerified(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = DependencyMsrvVerified::new(
+            "some-crate",
+            semver::Version::new(1, 0, 0),
+            None,
+            Some(semver::Version::new(1, 56, 1)),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::DependencyMsrvVerified(event)),]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::reporter::event::Message;
+use crate::Event;
+
+/// The findings of the `doctor` subcommand, which diagnoses the environment cargo-msrv runs in.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DoctorReport {
+    findings: Vec<Finding>,
+}
+
+impl DoctorReport {
+    pub fn new(findings: Vec<Finding>) -> Self {
+        Self { findings }
+    }
+
+    pub fn findings(&self) -> &[Finding] {
+        &self.findings
+    }
+
+    /// The findings which prevent cargo-msrv from working properly.
+    pub fn problems(&self) -> impl Iterator<Item = &Finding> {
+        self.findings
+            .iter()
+            .filter(|finding| finding.status() == FindingStatus::Problem)
+    }
+}
+
+impl From<DoctorReport> for Event {
+    fn from(it: DoctorReport) -> Self {
+        Message::DoctorReport(it).into()
+    }
+}
+
+/// The outcome of a single diagnostic check.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Finding {
+    check: DoctorCheck,
+    status: FindingStatus,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    advice: Option<String>,
+}
+
+impl Finding {
+    pub fn ok(check: DoctorCheck, message: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: FindingStatus::Ok,
+            message: message.into(),
+            advice: None,
+        }
+    }
+
+    pub fn warning(
+        check: DoctorCheck,
+        message: impl Into<String>,
+        advice: impl Into<String>,
+    ) -> Self {
+        Self {
+            check,
+            status: FindingStatus::Warning,
+            message: message.into(),
+            advice: Some(advice.into()),
+        }
+    }
+
+    pub fn problem(
+        check: DoctorCheck,
+        message: impl Into<String>,
+        advice: impl Into<String>,
+    ) -> Self {
+        Self {
+            check,
+            status: FindingStatus::Problem,
+            message: message.into(),
+            advice: Some(advice.into()),
+        }
+    }
+
+    pub fn check(&self) -> DoctorCheck {
+        self.check
+    }
+
+    pub fn status(&self) -> FindingStatus {
+        self.status
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// What the user can do to resolve the finding.
+    pub fn advice(&self) -> Option<&str> {
+        self.advice.as_deref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DoctorCheck {
+    Rustup,
+    InstalledToolchains,
+    ReleaseIndex,
+    Manifest,
+    RustVersion,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingStatus {
+    Ok,
+    // Does not prevent cargo-msrv from working, but may be unexpected
+    Warning,
+    // Prevents cargo-msrv from working properly
+    Problem,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = DoctorReport::new(vec![
+            Finding::ok(DoctorCheck::Rustup, "rustup 1.25.1"),
+            Finding::problem(
+                DoctorCheck::Manifest,
+                "Unable to parse the Cargo manifest",
+                "Fix the Cargo manifest",
+            ),
+        ]);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::DoctorReport(event))]);
+    }
+
+    #[test]
+    fn problems() {
+        let report = DoctorReport::new(vec![
+            Finding::ok(DoctorCheck::Rustup, "rustup 1.25.1"),
+            Finding::warning(
+                DoctorCheck::RustVersion,
+                "No MSRV specified",
+                "Run cargo msrv",
+            ),
+            Finding::problem(
+                DoctorCheck::ReleaseIndex,
+                "Unable to fetch the release index",
+                "Check your network connection",
+            ),
+        ]);
+
+        let problems = report.problems().map(Finding::check).collect::<Vec<_>>();
+        assert_eq!(problems, vec![DoctorCheck::ReleaseIndex]);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::cli::rust_releases_opts::Edition;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+use std::path::{Path, PathBuf};
+
+/// The least recent Rust version which supports the edition of the crate, which is the least
+/// recent version the MSRV can be.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct EditionFloor {
+    edition: Edition,
+    /// Whether the edition is specified in the Cargo manifest, rather than the default edition.
+    declared: bool,
+    rust_version: semver::Version,
+    manifest_path: PathBuf,
+}
+
+impl EditionFloor {
+    pub fn new(edition: Option<Edition>, manifest_path: PathBuf) -> Self {
+        let declared = edition.is_some();
+        // Cargo uses the 2015 edition if no edition is specified
+        let edition = edition.unwrap_or(Edition::Edition2015);
+
+        Self {
+            edition,
+            declared,
+            rust_version: edition.as_bare_version().to_semver_version(),
+            manifest_path,
+        }
+    }
+
+    pub fn edition(&self) -> Edition {
+        self.edition
+    }
+
+    pub fn is_declared(&self) -> bool {
+        self.declared
+    }
+
+    pub fn rust_version(&self) -> &semver::Version {
+        &self.rust_version
+    }
+
+    pub fn manifest_path(&self) -> &Path {
+        &self.manifest_path
+    }
+}
+
+impl From<EditionFloor> for Event {
+    fn from(it: EditionFloor) -> Self {
+        Message::EditionFloor(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = EditionFloor::new(Some(Edition::Edition2018), PathBuf::from("Cargo.toml"));
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::EditionFloor(event)),]
+        );
+    }
+
+    #[test]
+    fn default_edition() {
+        let event = EditionFloor::new(None, PathBuf::from("Cargo.toml"));
+
+        assert_eq!(event.edition(), Edition::Edition2015);
+        assert!(!event.is_declared());
+        assert_eq!(event.rust_version(), &semver::Version::new(1, 0, 0));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The MSRV of each combination of the features of a crate.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct FeatureMatrixResult {
+    combinations: Vec<FeatureMsrv>,
+    /// The greatest MSRV of the feature combinations, or `None` if the MSRV of one or more
+    /// combinations could not be determined.
+    msrv: Option<semver::Version>,
+}
+
+impl FeatureMatrixResult {
+    pub fn new(combinations: Vec<FeatureMsrv>) -> Self {
+        let msrv = combinations
+            .iter()
+            .map(|combination| combination.msrv.clone())
+            .collect::<Option<Vec<_>>>()
+            .and_then(|versions| versions.into_iter().max());
+
+        Self { combinations, msrv }
+    }
+
+    pub fn combinations(&self) -> &[FeatureMsrv] {
+        &self.combinations
+    }
+
+    pub fn msrv(&self) -> Option<&semver::Version> {
+        self.msrv.as_ref()
+    }
+}
+
+impl From<FeatureMatrixResult> for Event {
+    fn from(it: FeatureMatrixResult) -> Self {
+        Message::FeatureMatrixResult(it).into()
+    }
+}
+
+/// The MSRV of the crate with only the given features enabled, i.e. without its default features.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct FeatureMsrv {
+    features: Vec<String>,
+    msrv: Option<semver::Version>,
+}
+
+impl FeatureMsrv {
+    pub fn new(features: Vec<String>, msrv: Option<semver::Version>) -> Self {
+        Self { features, msrv }
+    }
+
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    pub fn msrv(&self) -> Option<&semver::Version> {
+        self.msrv.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = FeatureMatrixResult::new(vec![
+            FeatureMsrv::new(vec![], Some(semver::Version::new(1, 56, 0))),
+            FeatureMsrv::new(vec!["a".to_string()], Some(semver::Version::new(1, 60, 0))),
+        ]);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::FeatureMatrixResult(event))]);
+
+        if let Message::FeatureMatrixResult(msg) = &events[0].message {
+            assert_eq!(msg.combinations().len(), 2);
+            assert_eq!(msg.msrv(), Some(&semver::Version::new(1, 60, 0)));
+        }
+    }
+
+    #[test]
+    fn msrv_undetermined_if_any_combination_undetermined() {
+        let event = FeatureMatrixResult::new(vec![
+            FeatureMsrv::new(vec![], Some(semver::Version::new(1, 56, 0))),
+            FeatureMsrv::new(vec!["a".to_string()], None),
+        ]);
+
+        assert!(event.msrv().is_none());
+    }
+}
@@ -6,14 +6,29 @@ use crate::{Event, ReleaseSource};
 pub struct FetchIndex {
     #[serde(rename = "source")]
     from_source: ReleaseSource,
+    /// Whether the index was read from the on-disk cache, instead of being fetched.
+    cached: bool,
 }
 
 impl FetchIndex {
     pub fn new(source: ReleaseSource) -> Self {
         Self {
             from_source: source,
+            cached: false,
         }
     }
+
+    /// The index of the given source was found in the on-disk cache.
+    pub fn cached(source: ReleaseSource) -> Self {
+        Self {
+            from_source: source,
+            cached: true,
+        }
+    }
+
+    pub fn is_cached(&self) -> bool {
+        self.cached
+    }
 }
 
 impl From<FetchIndex> for Event {
@@ -22,6 +37,65 @@ impl From<FetchIndex> for Event {
     }
 }
 
+/// The release index could not be fetched, with advice on how to resolve the failure.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct FetchIndexFailed {
+    #[serde(rename = "source")]
+    from_source: ReleaseSource,
+    reason: FetchFailureReason,
+    /// The error, including its causes.
+    error: String,
+    advice: String,
+}
+
+impl FetchIndexFailed {
+    pub fn new(
+        source: ReleaseSource,
+        reason: FetchFailureReason,
+        error: impl Into<String>,
+        advice: impl Into<String>,
+    ) -> Self {
+        Self {
+            from_source: source,
+            reason,
+            error: error.into(),
+            advice: advice.into(),
+        }
+    }
+
+    pub fn reason(&self) -> FetchFailureReason {
+        self.reason
+    }
+
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+
+    pub fn advice(&self) -> &str {
+        &self.advice
+    }
+}
+
+impl From<FetchIndexFailed> for Event {
+    fn from(it: FetchIndexFailed) -> Self {
+        Message::FetchIndexFailed(it).into()
+    }
+}
+
+/// Why the release index could not be fetched.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FetchFailureReason {
+    /// The TLS certificate of the server, or of an intercepting proxy, is not trusted.
+    Certificate,
+    /// The proxy could not be reached, or refused the connection.
+    Proxy,
+    /// The server could not be reached.
+    Connection,
+    Other,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,6 +116,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reported_cached_index() {
+        let reporter = TestReporter::default();
+        let event = FetchIndex::cached(ReleaseSource::RustChangelog);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+        assert_eq!(&events, &[Event::new(Message::FetchIndex(event))]);
+
+        if let Message::FetchIndex(msg) = &events[0].message {
+            assert!(msg.is_cached());
+        }
+    }
+
+    #[test]
+    fn reported_failure() {
+        let reporter = TestReporter::default();
+        let event = FetchIndexFailed::new(
+            ReleaseSource::RustChangelog,
+            FetchFailureReason::Certificate,
+            "invalid peer certificate: UnknownIssuer",
+            "Pass the certificate bundle with --cacert",
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::FetchIndexFailed(event)),]
+        );
+    }
+
     #[cfg(feature = "rust-releases-dist-source")]
     #[test]
     fn reported_rust_dist_source() {
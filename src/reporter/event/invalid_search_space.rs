@@ -0,0 +1,75 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+use std::fmt;
+
+/// The search space given by `--min` and `--max` can't contain any Rust release.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "reason")]
+pub enum InvalidSearchSpace {
+    /// The minimum version is more recent than the maximum version.
+    MinAboveMax { min: BareVersion, max: BareVersion },
+    /// The minimum version is more recent than the latest release.
+    MinAboveLatest {
+        min: BareVersion,
+        latest: semver::Version,
+    },
+    /// The maximum version is older than the earliest release.
+    MaxBelowEarliest {
+        max: BareVersion,
+        earliest: semver::Version,
+    },
+}
+
+impl fmt::Display for InvalidSearchSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MinAboveMax { min, max } => write!(
+                f,
+                "The minimum version (--min {}) is more recent than the maximum version (--max {}). Swap the versions, or remove one of them.",
+                min, max
+            ),
+            Self::MinAboveLatest { min, latest } => write!(
+                f,
+                "The minimum version (--min {}) is more recent than the latest Rust release ({}). Lower the minimum version, or run with --refresh-index if the release index is outdated.",
+                min, latest
+            ),
+            Self::MaxBelowEarliest { max, earliest } => write!(
+                f,
+                "The maximum version (--max {}) is older than the earliest Rust release ({}). Raise the maximum version.",
+                max, earliest
+            ),
+        }
+    }
+}
+
+impl From<InvalidSearchSpace> for Event {
+    fn from(it: InvalidSearchSpace) -> Self {
+        Message::InvalidSearchSpace(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = InvalidSearchSpace::MinAboveMax {
+            min: BareVersion::TwoComponents(1, 56),
+            max: BareVersion::ThreeComponents(1, 54, 0),
+        };
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::InvalidSearchSpace(event)),]
+        );
+    }
+}
@@ -1,24 +1,124 @@
-use crate::config::list::ListMsrvVariant;
+use crate::config::list::{ListMsrvVariant, ListSort};
 use crate::dependency_graph::DependencyGraph;
-use crate::reporter::event::Message;
-use crate::Event;
+use crate::msrv_resolver::MsrvResolver;
+use crate::reporter::event::{Message, MsrvCoverage};
+use crate::{semver, Event};
+use cargo_metadata::Package;
 
 use crate::reporter::event::list_dep::ordered_by_msrv::OrderedByMsrvFormatter;
+use blame::BlameFormatter;
 use direct_deps::DirectDepsFormatter;
+use dot::DotFormatter;
 
+mod blame;
 mod direct_deps;
-mod metadata;
+mod dot;
+pub(super) mod metadata;
 mod ordered_by_msrv;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ListDep {
     variant: ListMsrvVariant,
     graph: DependencyGraph,
+    selection: Selection,
 }
 
 impl ListDep {
     pub fn new(variant: ListMsrvVariant, graph: DependencyGraph) -> Self {
-        Self { variant, graph }
+        Self {
+            variant,
+            graph,
+            selection: Selection::default(),
+        }
+    }
+
+    /// List the dependencies in the given order, instead of the default order of the variant.
+    pub fn sorted_by(mut self, sort: Option<ListSort>) -> Self {
+        self.selection.sort = sort;
+        self
+    }
+
+    /// Only list the dependencies with an MSRV greater than the given version.
+    pub fn with_min_msrv(mut self, min_msrv: Option<semver::Version>) -> Self {
+        self.selection.min_msrv = min_msrv;
+        self
+    }
+
+    /// Determine the MSRV of the dependencies with the given resolver, instead of the default one.
+    pub fn resolved_with(mut self, msrv_resolver: MsrvResolver) -> Self {
+        self.selection.msrv_resolver = msrv_resolver;
+        self
+    }
+
+    /// Leave out the dependencies with the given names, regardless of their MSRV.
+    pub fn ignoring(mut self, names: Vec<String>) -> Self {
+        self.selection.ignored = names;
+        self
+    }
+}
+
+impl ListDep {
+    /// How many dependencies declare their MSRV, and by which key, regardless of the minimum MSRV
+    /// of the listed dependencies. Ignored dependencies and the crate itself are not counted.
+    pub fn coverage(&self) -> MsrvCoverage {
+        let root = self.graph.root_crate();
+
+        let packages = self.graph.packages();
+
+        let keys = packages
+            .node_indices()
+            .map(|nx| &packages[nx])
+            .filter(|package| &package.id != root)
+            .filter(|package| !self.selection.ignores(&package.name))
+            .map(|package| {
+                self.selection
+                    .msrv_resolver
+                    .package_msrv_with_key(package)
+                    .map(|(_, key)| key)
+            });
+
+        MsrvCoverage::from_keys(keys)
+    }
+
+    /// The names of the dependencies grouped by MSRV, from the greatest to the lowest MSRV,
+    /// regardless of the variant.
+    pub fn dependencies_by_msrv(&self) -> Vec<(String, Vec<String>)> {
+        OrderedByMsrvFormatter::new(&self.graph, &self.selection).rows()
+    }
+
+    /// The dependency graph as a Graphviz DOT digraph, with the packages colored by MSRV,
+    /// regardless of the variant.
+    pub fn dot_graph(&self) -> String {
+        DotFormatter::new(&self.graph, &self.selection).to_string()
+    }
+}
+
+/// Which dependencies are listed, and in which order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Selection {
+    sort: Option<ListSort>,
+    min_msrv: Option<semver::Version>,
+    ignored: Vec<String>,
+    msrv_resolver: MsrvResolver,
+}
+
+impl Selection {
+    /// The declared MSRV of a dependency.
+    fn msrv(&self, package: &Package) -> Option<semver::Version> {
+        self.msrv_resolver.package_msrv(package)
+    }
+
+    /// Whether the dependency with the given name is left out, regardless of its MSRV.
+    fn ignores(&self, name: &str) -> bool {
+        self.ignored.iter().any(|ignored| ignored == name)
+    }
+
+    /// Whether a dependency with the given MSRV is listed.
+    fn includes(&self, msrv: Option<&semver::Version>) -> bool {
+        match &self.min_msrv {
+            Some(min_msrv) => msrv.map_or(false, |msrv| msrv > min_msrv),
+            None => true,
+        }
     }
 }
 
@@ -31,8 +131,13 @@ impl From<ListDep> for Event {
 impl ToString for ListDep {
     fn to_string(&self) -> String {
         match self.variant {
-            ListMsrvVariant::DirectDeps => DirectDepsFormatter::new(&self.graph).to_string(),
-            ListMsrvVariant::OrderedByMSRV => OrderedByMsrvFormatter::new(&self.graph).to_string(),
+            ListMsrvVariant::DirectDeps => {
+                DirectDepsFormatter::new(&self.graph, &self.selection).to_string()
+            }
+            ListMsrvVariant::OrderedByMSRV => {
+                OrderedByMsrvFormatter::new(&self.graph, &self.selection).to_string()
+            }
+            ListMsrvVariant::Blame => BlameFormatter::new(&self.graph, &self.selection).to_string(),
         }
     }
 }
@@ -44,11 +149,55 @@ impl serde::Serialize for ListDep {
     {
         match self.variant {
             ListMsrvVariant::DirectDeps => {
-                DirectDepsFormatter::new(&self.graph).serialize(serializer)
+                DirectDepsFormatter::new(&self.graph, &self.selection).serialize(serializer)
             }
             ListMsrvVariant::OrderedByMSRV => {
-                OrderedByMsrvFormatter::new(&self.graph).serialize(serializer)
+                OrderedByMsrvFormatter::new(&self.graph, &self.selection).serialize(serializer)
+            }
+            ListMsrvVariant::Blame => {
+                BlameFormatter::new(&self.graph, &self.selection).serialize(serializer)
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_includes_all_by_default() {
+        let selection = Selection::default();
+
+        assert!(selection.includes(Some(&semver::Version::new(1, 56, 0))));
+        assert!(selection.includes(None));
+    }
+
+    #[test]
+    fn selection_includes_msrv_greater_than_min_msrv() {
+        let selection = Selection {
+            sort: None,
+            min_msrv: Some(semver::Version::new(1, 56, 0)),
+            ignored: Vec::new(),
+            msrv_resolver: MsrvResolver::default(),
+        };
+
+        assert!(selection.includes(Some(&semver::Version::new(1, 57, 0))));
+        assert!(!selection.includes(Some(&semver::Version::new(1, 56, 0))));
+        assert!(!selection.includes(Some(&semver::Version::new(1, 40, 0))));
+        assert!(!selection.includes(None));
+    }
+
+    #[test]
+    fn selection_ignores_by_name() {
+        let selection = Selection {
+            sort: None,
+            min_msrv: None,
+            ignored: vec!["a".to_string()],
+            msrv_resolver: MsrvResolver::default(),
+        };
+
+        assert!(selection.ignores("a"));
+        assert!(!selection.ignores("b"));
+    }
+}
@@ -0,0 +1,161 @@
+use super::metadata::format_version;
+use super::Selection;
+use crate::config::list::{DependencyKind, ListSort, BLAME};
+use crate::dependency_graph::DependencyGraph;
+use crate::formatting::table;
+use crate::semver;
+use std::collections::HashSet;
+use tabled::{Style, Tabled};
+
+pub struct BlameFormatter<'g> {
+    graph: &'g DependencyGraph,
+    selection: &'g Selection,
+}
+
+impl<'g> BlameFormatter<'g> {
+    pub fn new(graph: &'g DependencyGraph, selection: &'g Selection) -> Self {
+        Self { graph, selection }
+    }
+}
+
+impl ToString for BlameFormatter<'_> {
+    fn to_string(&self) -> String {
+        let values = dependencies(self.graph, self.selection);
+
+        table(values).with(Style::modern()).to_string()
+    }
+}
+
+impl serde::Serialize for BlameFormatter<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let serializable = SerializableValues {
+            variant: BLAME,
+            list: dependencies(self.graph, self.selection),
+        };
+
+        serializable.serialize(serializer)
+    }
+}
+
+/// The transitive dependencies which specify an MSRV, together with the chains of dependencies
+/// which introduce them, ordered from the greatest to the lowest MSRV by default.
+///
+/// Direct dependencies are left out, since they're introduced by the root crate itself.
+fn dependencies<'g>(graph: &'g DependencyGraph, selection: &Selection) -> Vec<Values<'g>> {
+    let root_index = graph.index()[graph.root_crate()].into();
+    let direct = graph
+        .packages()
+        .neighbors(root_index)
+        .collect::<HashSet<_>>();
+
+    let mut dependencies = graph
+        .packages()
+        .node_indices()
+        .filter(|&node| node != root_index && !direct.contains(&node))
+        .filter_map(|node| {
+            let package = &graph.packages()[node];
+
+            if selection.ignores(&package.name) {
+                return None;
+            }
+
+            let msrv = selection.msrv(package)?;
+
+            if !selection.includes(Some(&msrv)) {
+                return None;
+            }
+
+            let introduced_by = graph
+                .dependency_chains(&package.id)
+                .into_iter()
+                .map(|chain| chain.iter().map(|p| p.name.clone()).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            // Not depended upon by the root crate
+            if introduced_by.is_empty() {
+                return None;
+            }
+
+            Some((package, msrv, introduced_by))
+        })
+        .collect::<Vec<_>>();
+
+    match selection.sort {
+        Some(ListSort::Name) => {
+            dependencies.sort_by(|(lhs, _, _), (rhs, _, _)| lhs.name.cmp(&rhs.name))
+        }
+        Some(ListSort::Depth) => dependencies.sort_by_key(|(_, _, chains)| shortest(chains)),
+        Some(ListSort::Msrv) | None => {
+            dependencies.sort_by(|(lhs_package, lhs, _), (rhs_package, rhs, _)| {
+                rhs.cmp(lhs)
+                    .then_with(|| lhs_package.name.cmp(&rhs_package.name))
+            })
+        }
+    }
+
+    dependencies
+        .into_iter()
+        .map(|(package, msrv, introduced_by)| Values {
+            name: &package.name,
+            version: &package.version,
+            msrv: format_version(Some(&msrv)),
+            kinds: graph.dependency_kinds(&package.id),
+            introduced_by,
+        })
+        .collect()
+}
+
+/// Length of the shortest chain, i.e. the depth of the dependency in the dependency graph.
+fn shortest(chains: &[Vec<String>]) -> usize {
+    chains.iter().map(Vec::len).min().unwrap_or_default()
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+struct Values<'a> {
+    name: &'a str,
+    version: &'a semver::Version,
+    msrv: String,
+    kinds: &'a [DependencyKind],
+    /// Chains of dependencies, from a direct dependency of the root crate to this dependency
+    introduced_by: Vec<Vec<String>>,
+}
+
+impl Tabled for Values<'_> {
+    const LENGTH: usize = 4;
+
+    fn fields(&self) -> Vec<String> {
+        let introduced_by = self
+            .introduced_by
+            .iter()
+            .map(|chain| chain.join(" -> "))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        vec![
+            self.name.to_string(),
+            self.version.to_string(),
+            self.msrv.to_string(),
+            introduced_by,
+        ]
+    }
+
+    fn headers() -> Vec<String> {
+        vec![
+            "Name".to_string(),
+            "Version".to_string(),
+            "MSRV".to_string(),
+            "Introduced by".to_string(),
+        ]
+    }
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+struct SerializableValues<'v> {
+    variant: &'static str,
+    list: Vec<Values<'v>>,
+}
@@ -1,22 +1,24 @@
 use super::metadata::*;
-use crate::config::list::DIRECT_DEPS;
+use super::Selection;
+use crate::config::list::{DependencyKind, ListSort, DIRECT_DEPS};
 use crate::dependency_graph::DependencyGraph;
 use crate::formatting::table;
 use tabled::{Style, Tabled};
 
 pub struct DirectDepsFormatter<'g> {
     graph: &'g DependencyGraph,
+    selection: &'g Selection,
 }
 
 impl<'g> DirectDepsFormatter<'g> {
-    pub fn new(graph: &'g DependencyGraph) -> Self {
-        Self { graph }
+    pub fn new(graph: &'g DependencyGraph, selection: &'g Selection) -> Self {
+        Self { graph, selection }
     }
 }
 
 impl ToString for DirectDepsFormatter<'_> {
     fn to_string(&self) -> String {
-        let values = dependencies(self.graph);
+        let values = dependencies(self.graph, self.selection);
 
         table(values).with(Style::modern()).to_string()
     }
@@ -29,36 +31,51 @@ impl serde::Serialize for DirectDepsFormatter<'_> {
     {
         let serializable = SerializableValues {
             variant: DIRECT_DEPS,
-            list: dependencies(self.graph).collect(),
+            list: dependencies(self.graph, self.selection),
         };
 
         serializable.serialize(serializer)
     }
 }
 
-fn dependencies(graph: &DependencyGraph) -> impl Iterator<Item = Values> {
+fn dependencies<'g>(graph: &'g DependencyGraph, selection: &Selection) -> Vec<Values<'g>> {
     let package_id = graph.root_crate();
     let root_index = graph.index()[package_id].into();
     let neighbors = graph
         .packages()
         .neighbors_directed(root_index, petgraph::Direction::Outgoing);
 
-    neighbors.map(move |dependency| {
-        let package = &graph.packages()[dependency];
+    let mut dependencies = neighbors
+        .map(|dependency| {
+            let package = &graph.packages()[dependency];
 
-        let msrv = super::metadata::package_msrv(package);
+            (package, selection.msrv(package))
+        })
+        .filter(|(package, _)| !selection.ignores(&package.name))
+        .filter(|(_, msrv)| selection.includes(msrv.as_ref()))
+        .collect::<Vec<_>>();
 
-        Values {
+    match selection.sort {
+        Some(ListSort::Msrv) => dependencies.sort_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs)),
+        Some(ListSort::Name) => dependencies.sort_by(|(lhs, _), (rhs, _)| lhs.name.cmp(&rhs.name)),
+        // Direct dependencies are all at the same depth
+        Some(ListSort::Depth) | None => {}
+    }
+
+    dependencies
+        .into_iter()
+        .map(|(package, msrv)| Values {
             name: &package.name,
             version: &package.version,
             msrv: format_version(msrv.as_ref()),
+            kinds: graph.dependency_kinds(&package.id),
             dependencies: package
                 .dependencies
                 .iter()
                 .map(|d| d.name.clone())
                 .collect(),
-        }
-    })
+        })
+        .collect()
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -66,6 +83,7 @@ struct Values<'a> {
     name: &'a str,
     version: &'a crate::semver::Version,
     msrv: String,
+    kinds: &'a [DependencyKind],
     dependencies: Vec<String>,
 }
 
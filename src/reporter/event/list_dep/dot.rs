@@ -0,0 +1,220 @@
+use super::Selection;
+use crate::dependency_graph::DependencyGraph;
+use crate::semver;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+/// The fill colors of the MSRV buckets, from the bucket of the greatest MSRV downwards.
+/// Dependencies with a lower MSRV than those in the last bucket share its color.
+const BUCKET_COLORS: &[&str] = &["#f4a6a6", "#f8cb8c", "#fbe79a", "#c5e3b1"];
+
+/// The fill color of packages which don't declare an MSRV, or which aren't selected.
+const UNBUCKETED_COLOR: &str = "#e8e8e8";
+
+/// Renders the dependency graph as a Graphviz DOT digraph, with the packages colored by MSRV, so
+/// the dependencies which drive the MSRV upwards, and the subtrees they belong to, stand out.
+pub struct DotFormatter<'g> {
+    graph: &'g DependencyGraph,
+    selection: &'g Selection,
+}
+
+impl<'g> DotFormatter<'g> {
+    pub fn new(graph: &'g DependencyGraph, selection: &'g Selection) -> Self {
+        Self { graph, selection }
+    }
+}
+
+impl ToString for DotFormatter<'_> {
+    fn to_string(&self) -> String {
+        let packages = self.graph.packages();
+        let root = self.graph.root_crate();
+
+        let nodes = packages
+            .node_indices()
+            .filter(|&nx| !self.selection.ignores(&packages[nx].name))
+            .collect::<Vec<_>>();
+
+        let ids = nodes
+            .iter()
+            .enumerate()
+            .map(|(id, &nx)| (nx, id))
+            .collect::<HashMap<_, _>>();
+        let ids = &ids;
+
+        let values = nodes
+            .iter()
+            .map(|&nx| {
+                let package = &packages[nx];
+                let msrv = self.selection.msrv(package);
+
+                Node {
+                    name: &package.name,
+                    version: &package.version,
+                    colored: self.selection.includes(msrv.as_ref()),
+                    msrv,
+                    root: &package.id == root,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let edges = nodes
+            .iter()
+            .flat_map(|&nx| {
+                let from = ids[&nx];
+                packages
+                    .neighbors_directed(nx, petgraph::Direction::Outgoing)
+                    .filter_map(move |dependency| ids.get(&dependency))
+                    .map(move |&to| (from, to))
+            })
+            .collect::<BTreeSet<_>>();
+
+        dot_graph(&values, &edges)
+    }
+}
+
+struct Node<'a> {
+    name: &'a str,
+    version: &'a semver::Version,
+    msrv: Option<semver::Version>,
+    /// Whether the MSRV is selected by `--min-msrv`; unselected packages aren't colored.
+    colored: bool,
+    root: bool,
+}
+
+/// Build the digraph, with an edge from each package to each of its dependencies. Each node is
+/// identified by its position in `nodes`.
+fn dot_graph(nodes: &[Node], edges: &BTreeSet<(usize, usize)>) -> String {
+    let buckets = nodes
+        .iter()
+        .filter(|node| node.colored)
+        .filter_map(|node| node.msrv.as_ref())
+        .collect::<BTreeSet<_>>();
+
+    let mut graph = String::from("digraph dependencies {\n");
+    graph.push_str("  rankdir=LR;\n");
+    graph.push_str("  node [shape=box, style=filled, fontname=\"sans-serif\"];\n");
+
+    for (id, node) in nodes.iter().enumerate() {
+        let msrv = node
+            .msrv
+            .as_ref()
+            .map_or_else(|| "N/A".to_string(), ToString::to_string);
+
+        let color = match &node.msrv {
+            Some(msrv) if node.colored => {
+                // The number of distinct MSRV's which are greater than this MSRV
+                let rank = buckets.range::<&semver::Version, _>(msrv..).count() - 1;
+                BUCKET_COLORS[rank.min(BUCKET_COLORS.len() - 1)]
+            }
+            _ => UNBUCKETED_COLOR,
+        };
+
+        let _ = write!(
+            graph,
+            "  n{} [label=\"{} {}\\nMSRV: {}\", fillcolor=\"{}\"",
+            id,
+            escape(node.name),
+            node.version,
+            msrv,
+            color
+        );
+
+        if node.root {
+            graph.push_str(", penwidth=2");
+        }
+
+        graph.push_str("];\n");
+    }
+
+    for (from, to) in edges {
+        let _ = writeln!(graph, "  n{} -> n{};", from, to);
+    }
+
+    graph.push_str("}\n");
+    graph
+}
+
+/// Escape characters which would otherwise end a quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node<'a>(name: &'a str, version: &'a semver::Version, msrv: Option<&str>) -> Node<'a> {
+        Node {
+            name,
+            version,
+            msrv: msrv.map(|msrv| semver::Version::parse(msrv).unwrap()),
+            colored: true,
+            root: false,
+        }
+    }
+
+    fn fill_colors(graph: &str) -> Vec<&str> {
+        graph
+            .lines()
+            .filter_map(|line| line.split("fillcolor=\"").nth(1))
+            .map(|rest| &rest[..7])
+            .collect()
+    }
+
+    #[test]
+    fn nodes_and_edges() {
+        let version = semver::Version::new(1, 0, 0);
+        let mut root = node("root", &version, None);
+        root.root = true;
+        let nodes = vec![root, node("a", &version, Some("1.56.0"))];
+        let edges = vec![(0, 1)].into_iter().collect();
+
+        let graph = dot_graph(&nodes, &edges);
+
+        assert!(graph.starts_with("digraph dependencies {\n"));
+        assert!(graph.contains(
+            "  n0 [label=\"root 1.0.0\\nMSRV: N/A\", fillcolor=\"#e8e8e8\", penwidth=2];\n"
+        ));
+        assert!(graph.contains("  n1 [label=\"a 1.0.0\\nMSRV: 1.56.0\", fillcolor=\"#f4a6a6\"];\n"));
+        assert!(graph.contains("  n0 -> n1;\n"));
+        assert!(graph.ends_with("}\n"));
+    }
+
+    #[test]
+    fn colored_by_msrv_bucket() {
+        let version = semver::Version::new(1, 0, 0);
+        let nodes = vec![
+            node("a", &version, Some("1.60.0")),
+            node("b", &version, Some("1.56.0")),
+            node("c", &version, Some("1.60.0")),
+            node("d", &version, Some("1.40.0")),
+            node("e", &version, Some("1.31.0")),
+            node("f", &version, Some("1.13.0")),
+            node("g", &version, None),
+        ];
+
+        let graph = dot_graph(&nodes, &BTreeSet::new());
+
+        assert_eq!(
+            fill_colors(&graph),
+            vec!["#f4a6a6", "#f8cb8c", "#f4a6a6", "#fbe79a", "#c5e3b1", "#c5e3b1", "#e8e8e8"]
+        );
+    }
+
+    #[test]
+    fn unselected_msrv_is_not_colored() {
+        let version = semver::Version::new(1, 0, 0);
+        let mut unselected = node("a", &version, Some("1.60.0"));
+        unselected.colored = false;
+        let nodes = vec![unselected, node("b", &version, Some("1.56.0"))];
+
+        let graph = dot_graph(&nodes, &BTreeSet::new());
+
+        assert_eq!(fill_colors(&graph), vec!["#e8e8e8", "#f4a6a6"]);
+    }
+
+    #[test]
+    fn escapes_quotes() {
+        assert_eq!(escape("a\"b\\c"), "a\\\"b\\\\c");
+    }
+}
@@ -1,7 +1,8 @@
-use crate::config::list::ORDERED_BY_MSRV;
+use super::Selection;
+use crate::config::list::{DependencyKind, ListSort, ORDERED_BY_MSRV};
 use crate::dependency_graph::DependencyGraph;
 use crate::formatting::table;
-use crate::reporter::event::list_dep::metadata::{format_version, package_msrv};
+use crate::reporter::event::list_dep::metadata::format_version;
 use crate::semver;
 use cargo_metadata::Package;
 use petgraph::visit::Bfs;
@@ -10,17 +11,27 @@ use tabled::{Style, Tabled};
 
 pub struct OrderedByMsrvFormatter<'g> {
     graph: &'g DependencyGraph,
+    selection: &'g Selection,
 }
 
 impl<'g> OrderedByMsrvFormatter<'g> {
-    pub fn new(graph: &'g DependencyGraph) -> Self {
-        Self { graph }
+    pub fn new(graph: &'g DependencyGraph, selection: &'g Selection) -> Self {
+        Self { graph, selection }
+    }
+}
+
+impl OrderedByMsrvFormatter<'_> {
+    /// The dependencies grouped by MSRV, from the greatest to the lowest MSRV.
+    pub fn rows(&self) -> Vec<(String, Vec<String>)> {
+        dependencies(self.graph, self.selection)
+            .map(|values| (values.msrv, values.dependencies))
+            .collect()
     }
 }
 
 impl ToString for OrderedByMsrvFormatter<'_> {
     fn to_string(&self) -> String {
-        let values = dependencies(self.graph);
+        let values = dependencies(self.graph, self.selection);
 
         table(values).with(Style::modern()).to_string()
     }
@@ -33,14 +44,21 @@ impl serde::Serialize for OrderedByMsrvFormatter<'_> {
     {
         let serializable = SerializableValues {
             variant: ORDERED_BY_MSRV,
-            list: dependencies(self.graph).collect(),
+            list: dependencies(self.graph, self.selection).collect(),
         };
 
         serializable.serialize(serializer)
     }
 }
 
-fn dependencies(graph: &DependencyGraph) -> impl Iterator<Item = Values> + '_ {
+/// The dependencies grouped by MSRV, from the greatest to the lowest MSRV.
+///
+/// Within each group, dependencies are in breadth-first order, i.e. ordered by depth, unless
+/// they're sorted by name.
+fn dependencies<'g>(
+    graph: &'g DependencyGraph,
+    selection: &Selection,
+) -> impl Iterator<Item = Values> + 'g {
     let package_id = &graph.root_crate();
     let root_index = graph.index()[package_id].into();
     let mut bfs = Bfs::new(&graph.packages(), root_index);
@@ -50,17 +68,33 @@ fn dependencies(graph: &DependencyGraph) -> impl Iterator<Item = Values> + '_ {
     while let Some(nx) = bfs.next(&graph.packages()) {
         let package = &graph.packages()[nx];
 
-        let msrv = package_msrv(package);
+        if selection.ignores(&package.name) {
+            continue;
+        }
+
+        let msrv = selection.msrv(package);
+
+        if selection.includes(msrv.as_ref()) {
+            version_map.entry(msrv).or_default().push(package);
+        }
+    }
 
-        version_map.entry(msrv).or_default().push(package);
+    if let Some(ListSort::Name) = selection.sort {
+        version_map
+            .values_mut()
+            .for_each(|packages| packages.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name)));
     }
 
     version_map
         .into_iter()
         .rev()
-        .map(|(version, packages)| Values {
+        .map(move |(version, packages)| Values {
             msrv: format_version(version.as_ref()),
             dependencies: packages.iter().map(|p| p.name.clone()).collect(),
+            kinds: packages
+                .iter()
+                .map(|p| (p.name.clone(), graph.dependency_kinds(&p.id).to_vec()))
+                .collect(),
         })
 }
 
@@ -69,6 +103,8 @@ fn dependencies(graph: &DependencyGraph) -> impl Iterator<Item = Values> + '_ {
 struct Values {
     msrv: String,
     dependencies: Vec<String>,
+    /// The kinds of dependencies through which each dependency is depended upon, by name
+    kinds: BTreeMap<String, Vec<DependencyKind>>,
 }
 
 impl Tabled for Values {
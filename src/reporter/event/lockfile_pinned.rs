@@ -0,0 +1,96 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The locked dependencies which required a newer Rust version than the MSRV, and the version
+/// they were downgraded to.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LockfilePinned {
+    msrv: BareVersion,
+    dependencies: Vec<PinnedDependency>,
+}
+
+impl LockfilePinned {
+    pub fn new(msrv: BareVersion, dependencies: Vec<PinnedDependency>) -> Self {
+        Self { msrv, dependencies }
+    }
+
+    pub fn msrv(&self) -> &BareVersion {
+        &self.msrv
+    }
+
+    pub fn dependencies(&self) -> &[PinnedDependency] {
+        &self.dependencies
+    }
+}
+
+impl From<LockfilePinned> for Event {
+    fn from(it: LockfilePinned) -> Self {
+        Message::LockfilePinned(it).into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PinnedDependency {
+    name: String,
+    from: semver::Version,
+    /// The version the dependency was downgraded to, or `None` if none of the older versions
+    /// which support the MSRV was accepted.
+    to: Option<semver::Version>,
+}
+
+impl PinnedDependency {
+    pub fn new(
+        name: impl Into<String>,
+        from: semver::Version,
+        to: Option<semver::Version>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            from,
+            to,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn from(&self) -> &semver::Version {
+        &self.from
+    }
+
+    pub fn to(&self) -> Option<&semver::Version> {
+        self.to.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = LockfilePinned::new(
+            BareVersion::TwoComponents(1, 56),
+            vec![PinnedDependency::new(
+                "serde",
+                semver::Version::new(1, 0, 190),
+                Some(semver::Version::new(1, 0, 180)),
+            )],
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::LockfilePinned(event))]);
+    }
+}
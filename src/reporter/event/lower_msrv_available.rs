@@ -0,0 +1,78 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+use std::path::{Path, PathBuf};
+
+/// The crate is compatible with an older Rust version than the verified Rust version, so the
+/// declared MSRV could be lowered.
+///
+/// Reported by `cargo msrv verify --find-lower`. This is advisory only: verification doesn't fail
+/// because of it.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LowerMsrvAvailable {
+    rust_version: BareVersion,
+    /// The Cargo manifest which declares the verified Rust version, or `None` if the Rust
+    /// version was given as argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manifest_path: Option<PathBuf>,
+    /// The least recent Rust version the crate was found to be compatible with.
+    lower_msrv: semver::Version,
+}
+
+impl LowerMsrvAvailable {
+    pub fn new(
+        rust_version: BareVersion,
+        manifest_path: Option<PathBuf>,
+        lower_msrv: semver::Version,
+    ) -> Self {
+        Self {
+            rust_version,
+            manifest_path,
+            lower_msrv,
+        }
+    }
+
+    pub fn rust_version(&self) -> &BareVersion {
+        &self.rust_version
+    }
+
+    pub fn manifest_path(&self) -> Option<&Path> {
+        self.manifest_path.as_deref()
+    }
+
+    pub fn lower_msrv(&self) -> &semver::Version {
+        &self.lower_msrv
+    }
+}
+
+impl From<LowerMsrvAvailable> for Event {
+    fn from(it: LowerMsrvAvailable) -> Self {
+        Message::LowerMsrvAvailable(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = LowerMsrvAvailable::new(
+            BareVersion::TwoComponents(1, 60),
+            None,
+            semver::Version::new(1, 56, 1),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::LowerMsrvAvailable(event)),]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+use std::path::{Path, PathBuf};
+
+/// The MSRV which was found differs from the MSRV declared in the Cargo manifest.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ManifestMsrvMismatch {
+    found: semver::Version,
+    declared: BareVersion,
+    manifest_path: PathBuf,
+}
+
+impl ManifestMsrvMismatch {
+    pub fn new(found: semver::Version, declared: BareVersion, manifest_path: PathBuf) -> Self {
+        Self {
+            found,
+            declared,
+            manifest_path,
+        }
+    }
+
+    pub fn found(&self) -> &semver::Version {
+        &self.found
+    }
+
+    pub fn declared(&self) -> &BareVersion {
+        &self.declared
+    }
+
+    pub fn manifest_path(&self) -> &Path {
+        &self.manifest_path
+    }
+}
+
+impl From<ManifestMsrvMismatch> for Event {
+    fn from(it: ManifestMsrvMismatch) -> Self {
+        Message::ManifestMsrvMismatch(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = ManifestMsrvMismatch::new(
+            semver::Version::new(1, 56, 0),
+            BareVersion::TwoComponents(1, 40),
+            Path::new("Cargo.toml").to_path_buf(),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::ManifestMsrvMismatch(event))]);
+
+        if let Message::ManifestMsrvMismatch(msg) = &events[0].message {
+            assert_eq!(msg.found(), &semver::Version::new(1, 56, 0));
+            assert_eq!(msg.declared(), &BareVersion::TwoComponents(1, 40));
+            assert_eq!(msg.manifest_path(), Path::new("Cargo.toml"));
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::diagnosis::UnsupportedFeature;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The features of the crate which require the MSRV, since the release preceding the MSRV
+/// rejects them.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MsrvCauses {
+    msrv: semver::Version,
+    /// The release preceding the MSRV, which was checked to find the causes.
+    previous: semver::Version,
+    causes: Vec<MsrvCause>,
+}
+
+impl MsrvCauses {
+    pub fn new(msrv: semver::Version, previous: semver::Version, causes: Vec<MsrvCause>) -> Self {
+        Self {
+            msrv,
+            previous,
+            causes,
+        }
+    }
+
+    pub fn msrv(&self) -> &semver::Version {
+        &self.msrv
+    }
+
+    pub fn previous(&self) -> &semver::Version {
+        &self.previous
+    }
+
+    pub fn causes(&self) -> &[MsrvCause] {
+        &self.causes
+    }
+}
+
+impl From<MsrvCauses> for Event {
+    fn from(it: MsrvCauses) -> Self {
+        Message::MsrvCauses(it).into()
+    }
+}
+
+/// A language or library feature which requires the MSRV, and where it is used.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MsrvCause {
+    feature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_rust_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u32>,
+}
+
+impl MsrvCause {
+    pub fn feature(&self) -> &str {
+        &self.feature
+    }
+
+    pub fn required_rust_version(&self) -> Option<&str> {
+        self.required_rust_version.as_deref()
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+}
+
+impl From<UnsupportedFeature> for MsrvCause {
+    fn from(feature: UnsupportedFeature) -> Self {
+        Self {
+            feature: feature.message,
+            required_rust_version: feature.required,
+            file: feature.file,
+            line: feature.line,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let cause = MsrvCause::from(UnsupportedFeature {
+            message: "use of unstable library feature 'let_else'".to_string(),
+            required: None,
+            file: Some("src/lib.rs".to_string()),
+            line: Some(2),
+        });
+
+        let event = MsrvCauses::new(
+            semver::Version::new(1, 65, 0),
+            semver::Version::new(1, 64, 0),
+            vec![cause.clone()],
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::MsrvCauses(event))]);
+
+        if let Message::MsrvCauses(msg) = &events[0].message {
+            assert_eq!(msg.msrv(), &semver::Version::new(1, 65, 0));
+            assert_eq!(msg.previous(), &semver::Version::new(1, 64, 0));
+            assert_eq!(msg.causes(), &[cause]);
+        }
+    }
+}
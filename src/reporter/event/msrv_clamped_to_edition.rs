@@ -0,0 +1,69 @@
+use crate::cli::rust_releases_opts::Edition;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The MSRV which was found is older than the least recent Rust version which supports the
+/// edition of the crate, so the MSRV was raised to that version instead.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MsrvClampedToEdition {
+    found: semver::Version,
+    edition: Edition,
+    msrv: semver::Version,
+}
+
+impl MsrvClampedToEdition {
+    pub fn new(found: semver::Version, edition: Edition) -> Self {
+        Self {
+            found,
+            edition,
+            msrv: edition.as_bare_version().to_semver_version(),
+        }
+    }
+
+    pub fn found(&self) -> &semver::Version {
+        &self.found
+    }
+
+    pub fn edition(&self) -> Edition {
+        self.edition
+    }
+
+    pub fn msrv(&self) -> &semver::Version {
+        &self.msrv
+    }
+}
+
+impl From<MsrvClampedToEdition> for Event {
+    fn from(it: MsrvClampedToEdition) -> Self {
+        Message::MsrvClampedToEdition(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = MsrvClampedToEdition::new(semver::Version::new(1, 40, 0), Edition::Edition2021);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::MsrvClampedToEdition(event)),]
+        );
+    }
+
+    #[test]
+    fn clamped_to_first_version_of_edition() {
+        let event = MsrvClampedToEdition::new(semver::Version::new(1, 40, 0), Edition::Edition2021);
+
+        assert_eq!(event.msrv(), &semver::Version::new(1, 56, 0));
+    }
+}
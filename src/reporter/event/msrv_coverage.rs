@@ -0,0 +1,93 @@
+use crate::msrv_resolver::MsrvKey;
+use crate::reporter::event::Message;
+use crate::Event;
+
+/// How many dependencies declare their MSRV, and by which key, which indicates how trustworthy an
+/// MSRV derived from the MSRV of the dependencies is.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MsrvCoverage {
+    dependencies: u64,
+    /// Dependencies which declare their MSRV with the `package.rust-version` key
+    rust_version: u64,
+    /// Dependencies which declare their MSRV with the `package.metadata.msrv` key, the fallback
+    /// for crates with an MSRV prior to Rust 1.56
+    metadata_msrv: u64,
+    /// Dependencies whose MSRV is unknown
+    unknown: u64,
+}
+
+impl MsrvCoverage {
+    /// Count the dependencies by the key which declares their MSRV, if any.
+    pub fn from_keys(keys: impl IntoIterator<Item = Option<MsrvKey>>) -> Self {
+        keys.into_iter().fold(Self::default(), |mut coverage, key| {
+            coverage.dependencies += 1;
+
+            match key {
+                Some(MsrvKey::RustVersion) => coverage.rust_version += 1,
+                Some(MsrvKey::MetadataMsrv) => coverage.metadata_msrv += 1,
+                None => coverage.unknown += 1,
+            }
+
+            coverage
+        })
+    }
+
+    pub fn dependencies(&self) -> u64 {
+        self.dependencies
+    }
+
+    pub fn rust_version(&self) -> u64 {
+        self.rust_version
+    }
+
+    pub fn metadata_msrv(&self) -> u64 {
+        self.metadata_msrv
+    }
+
+    pub fn unknown(&self) -> u64 {
+        self.unknown
+    }
+}
+
+impl From<MsrvCoverage> for Event {
+    fn from(it: MsrvCoverage) -> Self {
+        Message::MsrvCoverage(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = MsrvCoverage::from_keys(vec![Some(MsrvKey::RustVersion), None]);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::MsrvCoverage(event)),]
+        );
+    }
+
+    #[test]
+    fn counts_by_key() {
+        let coverage = MsrvCoverage::from_keys(vec![
+            Some(MsrvKey::RustVersion),
+            Some(MsrvKey::RustVersion),
+            Some(MsrvKey::MetadataMsrv),
+            None,
+        ]);
+
+        assert_eq!(coverage.dependencies(), 4);
+        assert_eq!(coverage.rust_version(), 2);
+        assert_eq!(coverage.metadata_msrv(), 1);
+        assert_eq!(coverage.unknown(), 1);
+    }
+}
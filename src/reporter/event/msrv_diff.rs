@@ -0,0 +1,129 @@
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The MSRV of the crate at two revisions, and how it changed between them.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MsrvDiff {
+    base_revision: String,
+    base_msrv: Option<semver::Version>,
+    /// The head revision, or `None` if the working tree was used instead.
+    head_revision: Option<String>,
+    head_msrv: Option<semver::Version>,
+    status: DiffStatus,
+}
+
+impl MsrvDiff {
+    pub fn new(
+        base_revision: impl Into<String>,
+        base_msrv: Option<semver::Version>,
+        head_revision: Option<String>,
+        head_msrv: Option<semver::Version>,
+    ) -> Self {
+        let status = DiffStatus::of(base_msrv.as_ref(), head_msrv.as_ref());
+
+        Self {
+            base_revision: base_revision.into(),
+            base_msrv,
+            head_revision,
+            head_msrv,
+            status,
+        }
+    }
+
+    pub fn base_revision(&self) -> &str {
+        &self.base_revision
+    }
+
+    pub fn base_msrv(&self) -> Option<&semver::Version> {
+        self.base_msrv.as_ref()
+    }
+
+    pub fn head_revision(&self) -> Option<&str> {
+        self.head_revision.as_deref()
+    }
+
+    pub fn head_msrv(&self) -> Option<&semver::Version> {
+        self.head_msrv.as_ref()
+    }
+
+    pub fn status(&self) -> DiffStatus {
+        self.status
+    }
+}
+
+impl From<MsrvDiff> for Event {
+    fn from(it: MsrvDiff) -> Self {
+        Message::MsrvDiff(it).into()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffStatus {
+    Increased,
+    Decreased,
+    Unchanged,
+}
+
+impl DiffStatus {
+    /// A revision without an MSRV is not compatible with any Rust version in the search space,
+    /// so losing the MSRV counts as an increase.
+    fn of(base: Option<&semver::Version>, head: Option<&semver::Version>) -> Self {
+        match (base, head) {
+            (Some(base), Some(head)) if head > base => Self::Increased,
+            (Some(base), Some(head)) if head < base => Self::Decreased,
+            (Some(_), None) => Self::Increased,
+            (None, Some(_)) => Self::Decreased,
+            _ => Self::Unchanged,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+    use yare::parameterized;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = MsrvDiff::new(
+            "main",
+            Some(semver::Version::new(1, 56, 0)),
+            None,
+            Some(semver::Version::new(1, 60, 0)),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::MsrvDiff(event))]);
+
+        if let Message::MsrvDiff(msg) = &events[0].message {
+            assert_eq!(msg.base_revision(), "main");
+            assert_eq!(msg.head_revision(), None);
+            assert_eq!(msg.status(), DiffStatus::Increased);
+        }
+    }
+
+    #[parameterized(
+        increased = { Some(56), Some(60), DiffStatus::Increased },
+        decreased = { Some(60), Some(56), DiffStatus::Decreased },
+        unchanged = { Some(56), Some(56), DiffStatus::Unchanged },
+        lost_msrv = { Some(56), None, DiffStatus::Increased },
+        gained_msrv = { None, Some(56), DiffStatus::Decreased },
+        neither = { None, None, DiffStatus::Unchanged },
+    )]
+    fn status(base: Option<u64>, head: Option<u64>, expected: DiffStatus) {
+        let base = base.map(|minor| semver::Version::new(1, minor, 0));
+        let head = head.map(|minor| semver::Version::new(1, minor, 0));
+
+        assert_eq!(DiffStatus::of(base.as_ref(), head.as_ref()), expected);
+    }
+}
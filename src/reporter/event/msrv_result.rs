@@ -1,3 +1,5 @@
+use crate::config::features::Features;
+use crate::config::target_selection::TargetSelection;
 use crate::config::{Config, SearchMethod};
 use crate::manifest::bare_version::BareVersion;
 use crate::reporter::event::Message;
@@ -16,6 +18,16 @@ pub struct MsrvResult {
     #[serde(skip)]
     pub search_method: SearchMethod,
 
+    #[serde(skip_serializing_if = "Features::is_default")]
+    pub features: Features,
+
+    #[serde(skip_serializing_if = "TargetSelection::is_default")]
+    pub target_selection: TargetSelection,
+
+    /// The host triple of the toolchains, if it was given with `--host`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+
     #[serde(flatten)]
     result: ResultDetails,
 }
@@ -39,6 +51,9 @@ impl MsrvResult {
                 .unwrap_or_else(|| max),
 
             search_method: config.search_method(),
+            features: config.features().clone(),
+            target_selection: config.target_selection().clone(),
+            host: config.host().map(ToString::to_string),
 
             result: ResultDetails::Determined {
                 version,
@@ -60,6 +75,9 @@ impl MsrvResult {
                 .unwrap_or_else(|| max),
 
             search_method: config.search_method(),
+            features: config.features().clone(),
+            target_selection: config.target_selection().clone(),
+            host: config.host().map(ToString::to_string),
 
             result: ResultDetails::Undetermined { success: False },
         }
@@ -144,4 +162,42 @@ mod tests {
             assert_eq!(res.msrv(), None);
         }
     }
+
+    #[test]
+    fn target_selection_is_serialized_if_not_default() {
+        let min = BareVersion::TwoComponents(1, 0);
+        let max = BareVersion::ThreeComponents(1, 4, 0);
+
+        let config = Config::new(Action::Find, "".to_string());
+        let event = MsrvResult::none(&config, min.clone(), max.clone());
+        let json = serde_json::to_value(&event).unwrap();
+        assert!(json.get("target_selection").is_none());
+
+        let config = crate::config::ConfigBuilder::new(Action::Find, "")
+            .target_selection(TargetSelection::new(false, true, false, false))
+            .build();
+        let event = MsrvResult::none(&config, min, max);
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["target_selection"]["examples"], true);
+        assert_eq!(json["target_selection"]["all_targets"], false);
+    }
+
+    #[test]
+    fn host_is_serialized_if_given() {
+        let min = BareVersion::TwoComponents(1, 0);
+        let max = BareVersion::ThreeComponents(1, 4, 0);
+
+        let config = Config::new(Action::Find, "x86_64-pc-windows-msvc".to_string());
+        let event = MsrvResult::none(&config, min.clone(), max.clone());
+        let json = serde_json::to_value(&event).unwrap();
+        assert!(json.get("host").is_none());
+
+        let config = crate::config::ConfigBuilder::new(Action::Find, "x86_64-pc-windows-msvc")
+            .host("x86_64-pc-windows-gnu")
+            .build();
+        let event = MsrvResult::none(&config, min, max);
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["host"], "x86_64-pc-windows-gnu");
+        assert_eq!(event.target, "x86_64-pc-windows-gnu");
+    }
 }
@@ -0,0 +1,171 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::Event;
+use std::path::{Path, PathBuf};
+
+/// The MSRV as declared by each of the sources which may declare it.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MsrvSources {
+    sources: Vec<DeclaredMsrv>,
+    /// Whether all declared versions agree with each other.
+    consistent: bool,
+}
+
+impl MsrvSources {
+    pub fn new(sources: Vec<DeclaredMsrv>) -> Self {
+        let mut versions = sources
+            .iter()
+            .filter_map(|source| source.version.as_ref())
+            .map(BareVersion::to_semver_version);
+
+        let consistent = match versions.next() {
+            Some(first) => versions.all(|version| version == first),
+            None => true,
+        };
+
+        Self {
+            sources,
+            consistent,
+        }
+    }
+
+    pub fn sources(&self) -> &[DeclaredMsrv] {
+        &self.sources
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.consistent
+    }
+}
+
+impl From<MsrvSources> for Event {
+    fn from(it: MsrvSources) -> Self {
+        Message::MsrvSources(it).into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DeclaredMsrv {
+    source: MsrvSource,
+    path: PathBuf,
+    /// The value as written in the source.
+    value: String,
+    /// The value as a version, or `None` if the value does not name a specific Rust version,
+    /// like the `stable` toolchain channel.
+    version: Option<BareVersion>,
+}
+
+impl DeclaredMsrv {
+    pub fn new(source: MsrvSource, path: PathBuf, value: impl Into<String>) -> Self {
+        let value = value.into();
+        let version = value.parse().ok();
+
+        Self {
+            source,
+            path,
+            value,
+            version,
+        }
+    }
+
+    pub fn source(&self) -> MsrvSource {
+        self.source
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn version(&self) -> Option<&BareVersion> {
+        self.version.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MsrvSource {
+    /// The `package.rust-version` key of the Cargo manifest
+    RustVersion,
+    /// The `package.metadata.msrv` key of the Cargo manifest
+    MetadataMsrv,
+    /// The toolchain channel of the `rust-toolchain(.toml)` file
+    ToolchainFile,
+}
+
+impl MsrvSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::RustVersion => "package.rust-version",
+            Self::MetadataMsrv => "package.metadata.msrv",
+            Self::ToolchainFile => "toolchain.channel",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = MsrvSources::new(vec![
+            DeclaredMsrv::new(MsrvSource::RustVersion, PathBuf::from("Cargo.toml"), "1.56"),
+            DeclaredMsrv::new(
+                MsrvSource::ToolchainFile,
+                PathBuf::from("rust-toolchain.toml"),
+                "1.56.0",
+            ),
+        ]);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::MsrvSources(event))]);
+
+        if let Message::MsrvSources(msg) = &events[0].message {
+            assert_eq!(msg.sources().len(), 2);
+            assert!(msg.is_consistent());
+        }
+    }
+
+    #[test]
+    fn disagreeing_sources() {
+        let event = MsrvSources::new(vec![
+            DeclaredMsrv::new(MsrvSource::RustVersion, PathBuf::from("Cargo.toml"), "1.56"),
+            DeclaredMsrv::new(
+                MsrvSource::MetadataMsrv,
+                PathBuf::from("Cargo.toml"),
+                "1.58",
+            ),
+        ]);
+
+        assert!(!event.is_consistent());
+    }
+
+    #[test]
+    fn channels_without_version_are_not_compared() {
+        let event = MsrvSources::new(vec![
+            DeclaredMsrv::new(MsrvSource::RustVersion, PathBuf::from("Cargo.toml"), "1.56"),
+            DeclaredMsrv::new(
+                MsrvSource::ToolchainFile,
+                PathBuf::from("rust-toolchain"),
+                "stable",
+            ),
+        ]);
+
+        assert!(event.is_consistent());
+        assert!(event.sources()[1].version().is_none());
+    }
+}
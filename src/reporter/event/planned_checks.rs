@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::reporter::event::Message;
+use crate::toolchain::OwnedToolchainSpec;
+use crate::Event;
+
+/// The checks which would be run, when running with `--dry-run`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PlannedChecks {
+    checks: Vec<PlannedCheck>,
+}
+
+impl PlannedChecks {
+    pub fn new(checks: Vec<PlannedCheck>) -> Self {
+        Self { checks }
+    }
+
+    pub fn checks(&self) -> &[PlannedCheck] {
+        &self.checks
+    }
+}
+
+impl From<PlannedChecks> for Event {
+    fn from(it: PlannedChecks) -> Self {
+        Message::PlannedChecks(it).into()
+    }
+}
+
+/// A check command which would be run with a toolchain.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PlannedCheck {
+    toolchain: OwnedToolchainSpec,
+    /// The program and its arguments.
+    command: Vec<String>,
+    /// The working directory, or `None` if the check is run in the current directory.
+    directory: Option<PathBuf>,
+    /// The environment variables which are set for the check, in addition to the environment of
+    /// cargo-msrv.
+    env: BTreeMap<String, String>,
+    /// The directory to which build artifacts are written, or `None` if they are not written to
+    /// the host, e.g. when the check is run in a Docker container.
+    target_dir: Option<PathBuf>,
+}
+
+impl PlannedCheck {
+    pub fn new(
+        toolchain: impl Into<OwnedToolchainSpec>,
+        command: Vec<String>,
+        directory: Option<PathBuf>,
+        env: BTreeMap<String, String>,
+        target_dir: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+            command,
+            directory,
+            env,
+            target_dir,
+        }
+    }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+
+    pub fn command(&self) -> &[String] {
+        &self.command
+    }
+
+    pub fn directory(&self) -> Option<&Path> {
+        self.directory.as_deref()
+    }
+
+    pub fn env(&self) -> &BTreeMap<String, String> {
+        &self.env
+    }
+
+    pub fn target_dir(&self) -> Option<&Path> {
+        self.target_dir.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::semver;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let mut env = BTreeMap::new();
+        env.insert("CARGO_TARGET_DIR".to_string(), "target/msrv".to_string());
+
+        let check = PlannedCheck::new(
+            OwnedToolchainSpec::new(&semver::Version::new(1, 60, 0), "test_target"),
+            vec!["rustup".to_string(), "run".to_string()],
+            None,
+            env,
+            Some(PathBuf::from("target/msrv")),
+        );
+        let event = PlannedChecks::new(vec![check.clone()]);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::PlannedChecks(event))]);
+
+        if let Message::PlannedChecks(msg) = &events[0].message {
+            assert_eq!(msg.checks(), &[check]);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// Whether the MSRV complies with each rule of the MSRV policy, relative to the current stable
+/// release.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PolicyResult {
+    msrv: semver::Version,
+    stable: semver::Version,
+    rules: Vec<PolicyRule>,
+    compliant: bool,
+}
+
+impl PolicyResult {
+    pub fn new(msrv: semver::Version, stable: semver::Version, rules: Vec<PolicyRule>) -> Self {
+        let compliant = rules.iter().all(PolicyRule::is_compliant);
+
+        Self {
+            msrv,
+            stable,
+            rules,
+            compliant,
+        }
+    }
+
+    pub fn msrv(&self) -> &semver::Version {
+        &self.msrv
+    }
+
+    /// The stable release which the rules are relative to.
+    pub fn stable(&self) -> &semver::Version {
+        &self.stable
+    }
+
+    pub fn rules(&self) -> &[PolicyRule] {
+        &self.rules
+    }
+
+    /// Whether the MSRV complies with all rules.
+    pub fn is_compliant(&self) -> bool {
+        self.compliant
+    }
+}
+
+impl From<PolicyResult> for Event {
+    fn from(it: PolicyResult) -> Self {
+        Message::PolicyResult(it).into()
+    }
+}
+
+/// A rule of the MSRV policy, and whether the MSRV complies with it.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PolicyRule {
+    kind: PolicyRuleKind,
+    /// The newest MSRV which complies with the rule.
+    newest_allowed_msrv: semver::Version,
+    compliant: bool,
+}
+
+impl PolicyRule {
+    pub fn new(
+        kind: PolicyRuleKind,
+        newest_allowed_msrv: semver::Version,
+        compliant: bool,
+    ) -> Self {
+        Self {
+            kind,
+            newest_allowed_msrv,
+            compliant,
+        }
+    }
+
+    pub fn kind(&self) -> &PolicyRuleKind {
+        &self.kind
+    }
+
+    pub fn newest_allowed_msrv(&self) -> &semver::Version {
+        &self.newest_allowed_msrv
+    }
+
+    pub fn is_compliant(&self) -> bool {
+        self.compliant
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum PolicyRuleKind {
+    /// The MSRV must be at least the given amount of minor releases behind the stable release.
+    SlidingWindow { releases: u64 },
+    /// The MSRV must not be newer than a fixed Rust version.
+    Minimum,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = PolicyResult::new(
+            semver::Version::new(1, 60, 0),
+            semver::Version::new(1, 70, 0),
+            vec![PolicyRule::new(
+                PolicyRuleKind::SlidingWindow { releases: 4 },
+                semver::Version::new(1, 66, 0),
+                true,
+            )],
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::PolicyResult(event)),]
+        );
+    }
+
+    #[test]
+    fn not_compliant_if_any_rule_is_violated() {
+        let result = PolicyResult::new(
+            semver::Version::new(1, 66, 0),
+            semver::Version::new(1, 70, 0),
+            vec![
+                PolicyRule::new(
+                    PolicyRuleKind::SlidingWindow { releases: 4 },
+                    semver::Version::new(1, 66, 0),
+                    true,
+                ),
+                PolicyRule::new(
+                    PolicyRuleKind::Minimum,
+                    semver::Version::new(1, 65, 0),
+                    false,
+                ),
+            ],
+        );
+
+        assert!(!result.is_compliant());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::reporter::event::Message;
+use crate::Event;
+use std::path::PathBuf;
+
+/// An estimate of the toolchains which will be downloaded by rustup for the checks, and of the
+/// disk space they take, made before the checks are run.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PreflightEstimate {
+    /// The greatest number of toolchains which are not yet installed, and may be downloaded
+    downloads: u64,
+    /// The approximate size of the downloads in bytes
+    download_size: u64,
+    /// The approximate disk space taken by the installed toolchains in bytes
+    disk_space: u64,
+    /// The disk space available in the rustup home directory in bytes, if it could be determined
+    available_disk_space: Option<u64>,
+    rustup_home: PathBuf,
+}
+
+impl PreflightEstimate {
+    pub fn new(
+        downloads: u64,
+        download_size: u64,
+        disk_space: u64,
+        available_disk_space: Option<u64>,
+        rustup_home: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            downloads,
+            download_size,
+            disk_space,
+            available_disk_space,
+            rustup_home: rustup_home.into(),
+        }
+    }
+
+    pub fn downloads(&self) -> u64 {
+        self.downloads
+    }
+
+    pub fn download_size(&self) -> u64 {
+        self.download_size
+    }
+
+    pub fn disk_space(&self) -> u64 {
+        self.disk_space
+    }
+
+    pub fn available_disk_space(&self) -> Option<u64> {
+        self.available_disk_space
+    }
+
+    pub fn rustup_home(&self) -> &PathBuf {
+        &self.rustup_home
+    }
+
+    /// Whether the toolchains are expected not to fit on the disk. Unknown available disk space
+    /// is assumed to be sufficient.
+    pub fn exceeds_available_disk_space(&self) -> bool {
+        self.available_disk_space
+            .map_or(false, |available| self.disk_space > available)
+    }
+}
+
+impl From<PreflightEstimate> for Event {
+    fn from(it: PreflightEstimate) -> Self {
+        Message::PreflightEstimate(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+    use yare::parameterized;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = PreflightEstimate::new(3, 300, 1200, Some(1000), "/home/user/.rustup");
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::PreflightEstimate(event)),]
+        );
+    }
+
+    #[parameterized(
+        sufficient = { Some(2000), false },
+        insufficient = { Some(1000), true },
+        unknown = { None, false },
+    )]
+    fn exceeds_available_disk_space(available: Option<u64>, expected: bool) {
+        let event = PreflightEstimate::new(3, 300, 1200, available, "/home/user/.rustup");
+
+        assert_eq!(event.exceeds_available_disk_space(), expected);
+    }
+}
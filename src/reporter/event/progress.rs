@@ -8,6 +8,8 @@ pub struct Progress {
     current: u64,
     max: u64,
     iteration: u64,
+    /// An upper bound of the number of checks which remain to be run.
+    remaining: u64,
 }
 
 impl From<Progress> for Event {
@@ -17,13 +19,18 @@ impl From<Progress> for Event {
 }
 
 impl Progress {
-    pub fn new(current: u64, max: u64, iteration: u64) -> Self {
+    pub fn new(current: u64, max: u64, iteration: u64, remaining: u64) -> Self {
         Self {
             current,
             max,
             iteration,
+            remaining,
         }
     }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
 }
 
 #[cfg(test)]
@@ -36,7 +43,7 @@ mod tests {
     #[test]
     fn reported_event() {
         let reporter = TestReporter::default();
-        let event = Progress::new(10, 100, 30);
+        let event = Progress::new(10, 100, 30, 3);
 
         reporter.reporter().report_event(event.clone()).unwrap();
 
@@ -0,0 +1,80 @@
+use crate::reporter::event::Message;
+use crate::toolchain::OwnedToolchainSpec;
+use crate::Event;
+
+/// The run was cancelled by the user, before the search was completed.
+///
+/// Lists the toolchains which were checked before the run was cancelled. If the outcomes were
+/// persisted, a subsequent run with `--resume` doesn't check these toolchains again.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchCancelled {
+    compatible: Vec<OwnedToolchainSpec>,
+    incompatible: Vec<OwnedToolchainSpec>,
+    resumable: bool,
+}
+
+impl SearchCancelled {
+    pub fn new(
+        compatible: Vec<OwnedToolchainSpec>,
+        incompatible: Vec<OwnedToolchainSpec>,
+        resumable: bool,
+    ) -> Self {
+        Self {
+            compatible,
+            incompatible,
+            resumable,
+        }
+    }
+
+    pub fn compatible(&self) -> &[OwnedToolchainSpec] {
+        &self.compatible
+    }
+
+    pub fn incompatible(&self) -> &[OwnedToolchainSpec] {
+        &self.incompatible
+    }
+
+    /// Whether the outcomes were persisted, so the search can be resumed with `--resume`.
+    pub fn resumable(&self) -> bool {
+        self.resumable
+    }
+}
+
+impl From<SearchCancelled> for Event {
+    fn from(it: SearchCancelled) -> Self {
+        Message::SearchCancelled(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::semver;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = SearchCancelled::new(
+            vec![OwnedToolchainSpec::new(
+                &semver::Version::new(1, 60, 0),
+                "test_target",
+            )],
+            vec![OwnedToolchainSpec::new(
+                &semver::Version::new(1, 40, 0),
+                "test_target",
+            )],
+            true,
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::SearchCancelled(event)),]
+        );
+    }
+}
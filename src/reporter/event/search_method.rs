@@ -1,19 +1,30 @@
 use crate::config::SearchMethod as Method;
 use crate::reporter::event::Message;
-use crate::Event;
+use crate::{semver, Event};
+use rust_releases::Release;
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct FindMsrv {
     search_method: Method,
+    /// The Rust versions which may be checked, in the order of the search space.
+    search_space: Vec<semver::Version>,
 }
 
 impl FindMsrv {
-    pub(crate) fn new(method: Method) -> Self {
+    pub(crate) fn new(method: Method, search_space: &[Release]) -> Self {
         Self {
             search_method: method,
+            search_space: search_space
+                .iter()
+                .map(|release| release.version().clone())
+                .collect(),
         }
     }
+
+    pub fn search_space(&self) -> &[semver::Version] {
+        &self.search_space
+    }
 }
 
 impl From<FindMsrv> for Event {
@@ -35,7 +46,10 @@ mod tests {
     )]
     fn reported_event(method: Method) {
         let reporter = TestReporter::default();
-        let event = FindMsrv::new(method);
+        let event = FindMsrv::new(
+            method,
+            &[Release::new_stable(semver::Version::new(1, 2, 3))],
+        );
 
         reporter.reporter().report_event(event.clone()).unwrap();
 
@@ -0,0 +1,52 @@
+use crate::reporter::event::Message;
+use crate::search_method::Heuristic;
+use crate::{semver, Event};
+
+/// A Rust version of the search space is about to be checked, and the heuristic which chose it.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchProbe {
+    version: semver::Version,
+    heuristic: Heuristic,
+}
+
+impl SearchProbe {
+    pub fn new(version: semver::Version, heuristic: Heuristic) -> Self {
+        Self { version, heuristic }
+    }
+
+    pub fn version(&self) -> &semver::Version {
+        &self.version
+    }
+
+    pub fn heuristic(&self) -> Heuristic {
+        self.heuristic
+    }
+}
+
+impl From<SearchProbe> for Event {
+    fn from(it: SearchProbe) -> Self {
+        Message::SearchProbe(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = SearchProbe::new(semver::Version::new(1, 56, 0), Heuristic::DeclaredMsrv);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::SearchProbe(event)),]
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// Rust versions were removed from the search space, because they are older than the MSRV of a
+/// dependency, and Cargo refuses to build the crate with them.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SearchSpacePruned {
+    dependency: String,
+    dependency_msrv: semver::Version,
+    /// The amount of Rust versions which were removed from the search space.
+    pruned: usize,
+}
+
+impl SearchSpacePruned {
+    pub fn new(
+        dependency: impl Into<String>,
+        dependency_msrv: semver::Version,
+        pruned: usize,
+    ) -> Self {
+        Self {
+            dependency: dependency.into(),
+            dependency_msrv,
+            pruned,
+        }
+    }
+
+    pub fn dependency(&self) -> &str {
+        &self.dependency
+    }
+
+    pub fn dependency_msrv(&self) -> &semver::Version {
+        &self.dependency_msrv
+    }
+
+    pub fn pruned(&self) -> usize {
+        self.pruned
+    }
+}
+
+impl From<SearchSpacePruned> for Event {
+    fn from(it: SearchSpacePruned) -> Self {
+        Message::SearchSpacePruned(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = SearchSpacePruned::new("serde", semver::Version::new(1, 56, 0), 12);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::SearchSpacePruned(event)),]
+        );
+    }
+}
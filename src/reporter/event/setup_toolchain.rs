@@ -14,6 +14,10 @@ impl SetupToolchain {
             toolchain: toolchain.into(),
         }
     }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
 }
 
 impl From<SetupToolchain> for Event {
@@ -22,6 +26,115 @@ impl From<SetupToolchain> for Event {
     }
 }
 
+/// A step of the installation of a toolchain, as reported by rustup.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SetupToolchainProgress {
+    toolchain: OwnedToolchainSpec,
+    stage: SetupStage,
+    /// The component which is downloaded or installed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component: Option<String>,
+}
+
+impl SetupToolchainProgress {
+    pub fn new(
+        toolchain: impl Into<OwnedToolchainSpec>,
+        stage: SetupStage,
+        component: Option<String>,
+    ) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+            stage,
+            component,
+        }
+    }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+
+    pub fn stage(&self) -> SetupStage {
+        self.stage
+    }
+
+    pub fn component(&self) -> Option<&str> {
+        self.component.as_deref()
+    }
+}
+
+impl From<SetupToolchainProgress> for Event {
+    fn from(it: SetupToolchainProgress) -> Self {
+        Message::SetupToolchainProgress(it).into()
+    }
+}
+
+/// The installation of a toolchain failed, and is retried after a delay.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SetupToolchainRetry {
+    toolchain: OwnedToolchainSpec,
+    /// The number of the upcoming retry, starting at 1
+    retry: u32,
+    max_retries: u32,
+    delay_secs: u64,
+    /// Why the previous attempt failed
+    error: String,
+}
+
+impl SetupToolchainRetry {
+    pub fn new(
+        toolchain: impl Into<OwnedToolchainSpec>,
+        retry: u32,
+        max_retries: u32,
+        delay_secs: u64,
+        error: impl Into<String>,
+    ) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+            retry,
+            max_retries,
+            delay_secs,
+            error: error.into(),
+        }
+    }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+
+    pub fn retry(&self) -> u32 {
+        self.retry
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn delay_secs(&self) -> u64 {
+        self.delay_secs
+    }
+
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+impl From<SetupToolchainRetry> for Event {
+    fn from(it: SetupToolchainRetry) -> Self {
+        Message::SetupToolchainRetry(it).into()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupStage {
+    /// The channel manifest of the toolchain is fetched
+    SyncingChannel,
+    Downloading,
+    Installing,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,4 +158,47 @@ mod tests {
             vec![Event::new(Message::SetupToolchain(event)),]
         );
     }
+
+    #[test]
+    fn reported_progress_event() {
+        let reporter = TestReporter::default();
+        let event = SetupToolchainProgress::new(
+            OwnedToolchainSpec::new(&semver::Version::new(1, 2, 3), "test_target"),
+            SetupStage::Downloading,
+            Some("cargo".to_string()),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(
+            &events,
+            &[Event::new(Message::SetupToolchainProgress(event))]
+        );
+
+        if let Message::SetupToolchainProgress(msg) = &events[0].message {
+            assert_eq!(msg.stage(), SetupStage::Downloading);
+            assert_eq!(msg.component(), Some("cargo"));
+        }
+    }
+
+    #[test]
+    fn reported_retry_event() {
+        let reporter = TestReporter::default();
+        let event = SetupToolchainRetry::new(
+            OwnedToolchainSpec::new(&semver::Version::new(1, 2, 3), "test_target"),
+            1,
+            2,
+            2,
+            "Unable to install toolchain with `rustup install 1.2.3`.",
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::SetupToolchainRetry(event)),]
+        );
+    }
 }
@@ -0,0 +1,127 @@
+use crate::reporter::event::Message;
+use crate::Event;
+use std::time::Duration;
+
+/// A process which was spawned by cargo-msrv, and reported by a
+/// [`SubprocessStarted`] event with the same `id`, has exited, or was killed.
+///
+/// [`SubprocessStarted`]: crate::reporter::event::SubprocessStarted
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SubprocessFinished {
+    id: u64,
+    argv: Vec<String>,
+    /// The exit code of the process, which is absent when the process was killed, e.g. by a
+    /// timeout, or terminated by a signal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exit_code: Option<i32>,
+    success: bool,
+    /// Whether the process was killed by cargo-msrv, because its check was skipped or timed out
+    killed: bool,
+    #[serde(rename = "duration_secs", serialize_with = "serialize_duration")]
+    duration: Duration,
+}
+
+impl SubprocessFinished {
+    pub fn exited(
+        id: u64,
+        argv: Vec<String>,
+        exit_code: Option<i32>,
+        success: bool,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            id,
+            argv,
+            exit_code,
+            success,
+            killed: false,
+            duration,
+        }
+    }
+
+    pub fn killed(id: u64, argv: Vec<String>, duration: Duration) -> Self {
+        Self {
+            id,
+            argv,
+            exit_code: None,
+            success: false,
+            killed: true,
+            duration,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn argv(&self) -> &[String] {
+        &self.argv
+    }
+
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.killed
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+fn serialize_duration<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+impl From<SubprocessFinished> for Event {
+    fn from(it: SubprocessFinished) -> Self {
+        Message::SubprocessFinished(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = SubprocessFinished::exited(
+            1,
+            vec!["rustup".to_string(), "run".to_string()],
+            Some(101),
+            false,
+            Duration::from_millis(1500),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::SubprocessFinished(event)),]
+        );
+    }
+
+    #[test]
+    fn killed_process_has_no_exit_code() {
+        let event = SubprocessFinished::killed(2, vec!["cargo".to_string()], Duration::ZERO);
+        let value = serde_json::to_value(&event).unwrap();
+
+        assert!(value.get("exit_code").is_none());
+        assert_eq!(value["killed"], true);
+        assert_eq!(value["duration_secs"], 0.0);
+    }
+}
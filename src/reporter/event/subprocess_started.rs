@@ -0,0 +1,83 @@
+use crate::reporter::event::Message;
+use crate::Event;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// A rustup, cargo or other process was spawned by cargo-msrv.
+///
+/// Together with [`SubprocessFinished`], these events are an audit trail of every process which
+/// was run. The `id` of a process is unique within a run of cargo-msrv.
+///
+/// [`SubprocessFinished`]: crate::reporter::event::SubprocessFinished
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SubprocessStarted {
+    id: u64,
+    argv: Vec<String>,
+    /// The environment variables which were set for the process, in addition to the inherited
+    /// environment
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    env: BTreeMap<String, String>,
+    /// The working directory of the process, if it's not the current directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dir: Option<PathBuf>,
+}
+
+impl SubprocessStarted {
+    pub fn new(
+        id: u64,
+        argv: Vec<String>,
+        env: BTreeMap<String, String>,
+        dir: Option<PathBuf>,
+    ) -> Self {
+        Self { id, argv, env, dir }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn argv(&self) -> &[String] {
+        &self.argv
+    }
+
+    pub fn env(&self) -> &BTreeMap<String, String> {
+        &self.env
+    }
+
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+}
+
+impl From<SubprocessStarted> for Event {
+    fn from(it: SubprocessStarted) -> Self {
+        Message::SubprocessStarted(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = SubprocessStarted::new(
+            1,
+            vec!["rustup".to_string(), "run".to_string()],
+            BTreeMap::from([("CARGO_TARGET_DIR".to_string(), "target".to_string())]),
+            Some(PathBuf::from("crate")),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::SubprocessStarted(event)),]
+        );
+    }
+}
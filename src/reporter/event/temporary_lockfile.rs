@@ -0,0 +1,110 @@
+use crate::reporter::event::Message;
+use crate::toolchain::OwnedToolchainSpec;
+use crate::Event;
+use std::path::{Path, PathBuf};
+
+/// The lockfile was temporarily rewritten for a toolchain check, or the original lockfile was
+/// restored afterwards.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct TemporaryLockfile {
+    path: PathBuf,
+    toolchain: OwnedToolchainSpec,
+    status: LockfileStatus,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockfileStatus {
+    /// A fresh lockfile was generated by the Cargo version of the toolchain, while the original
+    /// lockfile is stashed.
+    Regenerated,
+    /// A lockfile with the minimal versions allowed by the dependency requirements was generated
+    /// by a nightly toolchain, while the original lockfile is stashed.
+    MinimalVersions,
+    /// The original lockfile was restored.
+    Restored,
+    /// The Cargo version of the toolchain didn't understand the lockfile, so a lockfile was
+    /// generated by it in a temporary copy of the crate. The original lockfile is untouched.
+    Downgraded,
+}
+
+impl TemporaryLockfile {
+    pub fn regenerated(path: impl Into<PathBuf>, toolchain: impl Into<OwnedToolchainSpec>) -> Self {
+        Self {
+            path: path.into(),
+            toolchain: toolchain.into(),
+            status: LockfileStatus::Regenerated,
+        }
+    }
+
+    pub fn minimal_versions(
+        path: impl Into<PathBuf>,
+        toolchain: impl Into<OwnedToolchainSpec>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            toolchain: toolchain.into(),
+            status: LockfileStatus::MinimalVersions,
+        }
+    }
+
+    pub fn downgraded(path: impl Into<PathBuf>, toolchain: impl Into<OwnedToolchainSpec>) -> Self {
+        Self {
+            path: path.into(),
+            toolchain: toolchain.into(),
+            status: LockfileStatus::Downgraded,
+        }
+    }
+
+    pub fn restored(path: impl Into<PathBuf>, toolchain: impl Into<OwnedToolchainSpec>) -> Self {
+        Self {
+            path: path.into(),
+            toolchain: toolchain.into(),
+            status: LockfileStatus::Restored,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn toolchain(&self) -> &OwnedToolchainSpec {
+        &self.toolchain
+    }
+
+    pub fn status(&self) -> LockfileStatus {
+        self.status
+    }
+}
+
+impl From<TemporaryLockfile> for Event {
+    fn from(it: TemporaryLockfile) -> Self {
+        Message::TemporaryLockfile(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::semver;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = TemporaryLockfile::regenerated(
+            "Cargo.lock",
+            OwnedToolchainSpec::new(&semver::Version::new(1, 2, 3), "test_target"),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::TemporaryLockfile(event)),]
+        );
+    }
+}
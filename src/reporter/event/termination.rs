@@ -1,3 +1,4 @@
+use crate::error::ErrorCode;
 use crate::reporter::event::Message;
 use crate::{CargoMSRVError, Event};
 
@@ -9,6 +10,7 @@ pub struct TerminateWithFailure {
     // Not all failure terminations are errors, for example, if we fail to verify we want to exit
     // with a non-zero exit code i.e. 'Terminate with failure',
     is_error: bool,
+    code: ErrorCode,
     reason: SerializableReason,
 }
 
@@ -18,6 +20,7 @@ impl TerminateWithFailure {
 
         Self {
             is_error,
+            code: error.code(),
             reason: SerializableReason {
                 description: format!("{}", &error),
             },
@@ -28,6 +31,11 @@ impl TerminateWithFailure {
         self.is_error
     }
 
+    /// The machine-readable cause of the failure.
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
     pub fn as_message(&self) -> &str {
         &self.reason.description
     }
@@ -65,6 +73,7 @@ mod tests {
 
         if let Message::TerminateWithFailure(msg) = &events[0].message {
             assert!(!msg.is_error());
+            assert_eq!(msg.code(), ErrorCode::Internal);
             assert_eq!(msg.as_message(), "Unable to print event output");
         }
     }
@@ -84,9 +93,21 @@ mod tests {
 
         if let Message::TerminateWithFailure(msg) = &events[0].message {
             assert!(msg.is_error());
+            assert_eq!(msg.code(), ErrorCode::NoCompatibleVersion);
             assert!(msg
                 .as_message()
                 .starts_with("Unable to find a Minimum Supported Rust Version (MSRV)"));
         }
     }
+
+    #[test]
+    fn serialized_code() {
+        let event = TerminateWithFailure::new(CargoMSRVError::RustupInstallFailed(
+            "1.56.0-x86_64-unknown-linux-gnu".to_string(),
+        ));
+
+        let value = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(value["code"], "toolchain-install-failed");
+    }
 }
@@ -0,0 +1,71 @@
+use crate::reporter::event::Message;
+use crate::Event;
+
+/// A toolchain which was installed for this run, and which was uninstalled afterwards because
+/// of `--uninstall-after`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ToolchainCleanup {
+    toolchain: String,
+    status: CleanupStatus,
+}
+
+impl ToolchainCleanup {
+    pub fn uninstalled(toolchain: impl Into<String>) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+            status: CleanupStatus::Uninstalled,
+        }
+    }
+
+    pub fn failed(toolchain: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            toolchain: toolchain.into(),
+            status: CleanupStatus::Failed {
+                reason: reason.into(),
+            },
+        }
+    }
+
+    pub fn toolchain(&self) -> &str {
+        &self.toolchain
+    }
+
+    pub fn status(&self) -> &CleanupStatus {
+        &self.status
+    }
+}
+
+impl From<ToolchainCleanup> for Event {
+    fn from(it: ToolchainCleanup) -> Self {
+        Message::ToolchainCleanup(it).into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum CleanupStatus {
+    Uninstalled,
+    Failed { reason: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = ToolchainCleanup::uninstalled("1.56.1-x86_64-unknown-linux-gnu");
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::ToolchainCleanup(event)),]
+        );
+    }
+}
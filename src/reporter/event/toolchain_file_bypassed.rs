@@ -0,0 +1,61 @@
+use crate::reporter::event::Message;
+use crate::Event;
+use std::path::{Path, PathBuf};
+
+/// A rust-toolchain file applies to the crate, but is bypassed while checking, so each check
+/// runs with the toolchain which is being checked, instead of the pinned toolchain.
+///
+/// The toolchain is selected explicitly, e.g. by setting `RUSTUP_TOOLCHAIN`, which takes
+/// precedence over the toolchain file. The file itself is left untouched.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ToolchainFileBypassed {
+    path: PathBuf,
+    /// The channel the toolchain file pins, if it specifies one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel: Option<String>,
+}
+
+impl ToolchainFileBypassed {
+    pub fn new(path: impl Into<PathBuf>, channel: Option<String>) -> Self {
+        Self {
+            path: path.into(),
+            channel,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn channel(&self) -> Option<&str> {
+        self.channel.as_deref()
+    }
+}
+
+impl From<ToolchainFileBypassed> for Event {
+    fn from(it: ToolchainFileBypassed) -> Self {
+        Message::ToolchainFileBypassed(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = ToolchainFileBypassed::new("rust-toolchain.toml", Some("1.64.0".to_string()));
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::ToolchainFileBypassed(event)),]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The newest versions of the dependencies which are compatible with the MSRV.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct UpgradeSuggestions {
+    msrv: BareVersion,
+    dependencies: Vec<DependencyUpgrade>,
+}
+
+impl UpgradeSuggestions {
+    pub fn new(msrv: BareVersion, dependencies: Vec<DependencyUpgrade>) -> Self {
+        Self { msrv, dependencies }
+    }
+
+    pub fn msrv(&self) -> &BareVersion {
+        &self.msrv
+    }
+
+    pub fn upgrades(&self) -> &[DependencyUpgrade] {
+        &self.dependencies
+    }
+}
+
+impl From<UpgradeSuggestions> for Event {
+    fn from(it: UpgradeSuggestions) -> Self {
+        Message::UpgradeSuggestions(it).into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DependencyUpgrade {
+    name: String,
+    /// The dependency table of the Cargo manifest, e.g. `dev-dependencies`.
+    table: String,
+    requirement: String,
+    /// The newest version which matches the requirement and supports the MSRV, or `None` if no
+    /// such version was found in the registry index.
+    suggested: Option<semver::Version>,
+    /// The newest version which matches the requirement, regardless of its MSRV.
+    latest: Option<semver::Version>,
+}
+
+impl DependencyUpgrade {
+    pub fn new(
+        name: impl Into<String>,
+        table: impl Into<String>,
+        requirement: &semver::VersionReq,
+        suggested: Option<semver::Version>,
+        latest: Option<semver::Version>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            table: table.into(),
+            requirement: requirement.to_string(),
+            suggested,
+            latest,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    pub fn requirement(&self) -> &str {
+        &self.requirement
+    }
+
+    pub fn suggested(&self) -> Option<&semver::Version> {
+        self.suggested.as_ref()
+    }
+
+    pub fn latest(&self) -> Option<&semver::Version> {
+        self.latest.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = UpgradeSuggestions::new(
+            BareVersion::TwoComponents(1, 56),
+            vec![DependencyUpgrade::new(
+                "serde",
+                "dependencies",
+                &semver::VersionReq::parse("1.0").unwrap(),
+                Some(semver::Version::new(1, 0, 180)),
+                Some(semver::Version::new(1, 0, 190)),
+            )],
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::UpgradeSuggestions(event))]);
+    }
+}
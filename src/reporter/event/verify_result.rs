@@ -0,0 +1,148 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::{Message, VerifyStatus};
+use crate::{semver, Event};
+use std::path::{Path, PathBuf};
+
+/// The result of verifying a crate against a Rust version.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifyResult {
+    rust_version: BareVersion,
+    /// The Cargo manifest which declares the verified Rust version, or `None` if the Rust
+    /// version was given as argument.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manifest_path: Option<PathBuf>,
+    status: VerifyStatus,
+    /// Why the crate is incompatible, if it could be determined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diagnosis: Option<String>,
+    /// The Rust release preceding the verified Rust version, if the crate was found to be
+    /// compatible with it as well.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preceding_release: Option<semver::Version>,
+    /// The host triple of the toolchain, if it was given with `--host`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    host: Option<String>,
+}
+
+impl VerifyResult {
+    pub fn compatible(rust_version: BareVersion, manifest_path: Option<PathBuf>) -> Self {
+        Self {
+            rust_version,
+            manifest_path,
+            status: VerifyStatus::Compatible,
+            diagnosis: None,
+            preceding_release: None,
+            host: None,
+        }
+    }
+
+    pub fn incompatible(
+        rust_version: BareVersion,
+        manifest_path: Option<PathBuf>,
+        diagnosis: Option<String>,
+    ) -> Self {
+        Self {
+            rust_version,
+            manifest_path,
+            status: VerifyStatus::Incompatible,
+            diagnosis,
+            preceding_release: None,
+            host: None,
+        }
+    }
+
+    /// The crate is compatible with the Rust version, but also with the Rust release preceding it.
+    pub fn too_conservative(
+        rust_version: BareVersion,
+        manifest_path: Option<PathBuf>,
+        preceding_release: semver::Version,
+    ) -> Self {
+        Self {
+            rust_version,
+            manifest_path,
+            status: VerifyStatus::TooConservative,
+            diagnosis: None,
+            preceding_release: Some(preceding_release),
+            host: None,
+        }
+    }
+
+    /// Record the host triple of the toolchain the crate was verified with, if it was given
+    /// explicitly.
+    pub fn with_host(mut self, host: Option<&str>) -> Self {
+        self.host = host.map(ToString::to_string);
+        self
+    }
+
+    pub fn rust_version(&self) -> &BareVersion {
+        &self.rust_version
+    }
+
+    pub fn manifest_path(&self) -> Option<&Path> {
+        self.manifest_path.as_deref()
+    }
+
+    pub fn status(&self) -> VerifyStatus {
+        self.status
+    }
+
+    pub fn diagnosis(&self) -> Option<&str> {
+        self.diagnosis.as_deref()
+    }
+
+    pub fn preceding_release(&self) -> Option<&semver::Version> {
+        self.preceding_release.as_ref()
+    }
+
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+}
+
+impl From<VerifyResult> for Event {
+    fn from(it: VerifyResult) -> Self {
+        Message::VerifyResult(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = VerifyResult::incompatible(
+            BareVersion::TwoComponents(1, 56),
+            Some(PathBuf::from("Cargo.toml")),
+            Some("the crate uses an unstable feature".to_string()),
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::VerifyResult(event))]);
+
+        if let Message::VerifyResult(msg) = &events[0].message {
+            assert_eq!(msg.status(), VerifyStatus::Incompatible);
+            assert_eq!(msg.manifest_path(), Some(Path::new("Cargo.toml")));
+        }
+    }
+
+    #[test]
+    fn host_is_serialized_if_given() {
+        let event = VerifyResult::compatible(BareVersion::TwoComponents(1, 56), None);
+        let json = serde_json::to_value(&event).unwrap();
+        assert!(json.get("host").is_none());
+
+        let event = event.with_host(Some("x86_64-pc-windows-gnu"));
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["host"], "x86_64-pc-windows-gnu");
+    }
+}
@@ -0,0 +1,120 @@
+use crate::reporter::event::Message;
+use crate::Event;
+use std::path::PathBuf;
+
+/// The MSRV is verified (again) by `cargo msrv watch`, because it started, or because files of
+/// the crate changed.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct WatchIteration {
+    /// Counts the runs of verify, starting at 0 for the initial run.
+    iteration: u32,
+    /// The directory which is watched.
+    root: PathBuf,
+    /// The files which changed since the previous run; empty for the initial run.
+    changed: Vec<PathBuf>,
+}
+
+impl WatchIteration {
+    pub fn initial(root: impl Into<PathBuf>) -> Self {
+        Self {
+            iteration: 0,
+            root: root.into(),
+            changed: Vec::new(),
+        }
+    }
+
+    pub fn new(iteration: u32, root: impl Into<PathBuf>, changed: Vec<PathBuf>) -> Self {
+        Self {
+            iteration,
+            root: root.into(),
+            changed,
+        }
+    }
+
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    pub fn root(&self) -> &PathBuf {
+        &self.root
+    }
+
+    pub fn changed(&self) -> &[PathBuf] {
+        &self.changed
+    }
+}
+
+impl From<WatchIteration> for Event {
+    fn from(it: WatchIteration) -> Self {
+        Message::WatchIteration(it).into()
+    }
+}
+
+/// A run of verify by `cargo msrv watch` failed for another reason than an incompatible MSRV,
+/// for example because the Cargo manifest could not be parsed while it was being edited.
+///
+/// Watching continues regardless.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct WatchIterationFailed {
+    iteration: u32,
+    error: String,
+}
+
+impl WatchIterationFailed {
+    pub fn new(iteration: u32, error: impl Into<String>) -> Self {
+        Self {
+            iteration,
+            error: error.into(),
+        }
+    }
+
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+impl From<WatchIterationFailed> for Event {
+    fn from(it: WatchIterationFailed) -> Self {
+        Message::WatchIterationFailed(it).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+        let event = WatchIteration::new(1, "crate", vec![PathBuf::from("crate/src/lib.rs")]);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::WatchIteration(event)),]
+        );
+    }
+
+    #[test]
+    fn reported_failure_event() {
+        let reporter = TestReporter::default();
+        let event = WatchIterationFailed::new(2, "Unable to parse Cargo.toml");
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::WatchIterationFailed(event)),]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::{semver, Event};
+
+/// The MSRV of each member of a workspace, and of the workspace as a whole.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct WorkspaceMsrvResult {
+    packages: Vec<PackageMsrv>,
+    /// The greatest MSRV of the workspace members, or `None` if the MSRV of one or more members
+    /// could not be determined.
+    workspace_msrv: Option<semver::Version>,
+}
+
+impl WorkspaceMsrvResult {
+    pub fn new(packages: Vec<PackageMsrv>) -> Self {
+        let workspace_msrv = packages
+            .iter()
+            .map(|package| package.msrv.clone())
+            .collect::<Option<Vec<_>>>()
+            .and_then(|versions| versions.into_iter().max());
+
+        Self {
+            packages,
+            workspace_msrv,
+        }
+    }
+
+    pub fn packages(&self) -> &[PackageMsrv] {
+        &self.packages
+    }
+
+    pub fn workspace_msrv(&self) -> Option<&semver::Version> {
+        self.workspace_msrv.as_ref()
+    }
+}
+
+impl From<WorkspaceMsrvResult> for Event {
+    fn from(it: WorkspaceMsrvResult) -> Self {
+        Message::WorkspaceMsrvResult(it).into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PackageMsrv {
+    name: String,
+    /// The MSRV declared by the `rust-version` (or `package.metadata.msrv`) field of the
+    /// Cargo manifest of the package, if any.
+    rust_version: Option<BareVersion>,
+    msrv: Option<semver::Version>,
+    status: PackageMsrvStatus,
+}
+
+impl PackageMsrv {
+    pub fn new(
+        name: impl Into<String>,
+        rust_version: Option<BareVersion>,
+        msrv: Option<semver::Version>,
+    ) -> Self {
+        let status = PackageMsrvStatus::new(rust_version.as_ref(), msrv.as_ref());
+
+        Self {
+            name: name.into(),
+            rust_version,
+            msrv,
+            status,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn rust_version(&self) -> Option<&BareVersion> {
+        self.rust_version.as_ref()
+    }
+
+    pub fn msrv(&self) -> Option<&semver::Version> {
+        self.msrv.as_ref()
+    }
+
+    pub fn status(&self) -> PackageMsrvStatus {
+        self.status
+    }
+}
+
+/// How the MSRV found for a package relates to the MSRV declared in its Cargo manifest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PackageMsrvStatus {
+    /// The declared MSRV is the MSRV which was found.
+    Matches,
+    /// The declared MSRV differs from the MSRV which was found.
+    Differs,
+    /// The package does not declare an MSRV.
+    Undeclared,
+    /// The MSRV of the package could not be determined.
+    NotFound,
+}
+
+impl PackageMsrvStatus {
+    fn new(rust_version: Option<&BareVersion>, msrv: Option<&semver::Version>) -> Self {
+        match (rust_version, msrv) {
+            (_, None) => Self::NotFound,
+            (None, Some(_)) => Self::Undeclared,
+            (Some(declared), Some(msrv)) if &declared.to_semver_version() == msrv => Self::Matches,
+            (Some(_), Some(_)) => Self::Differs,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Matches => "matches",
+            Self::Differs => "differs",
+            Self::Undeclared => "undeclared",
+            Self::NotFound => "not found",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = WorkspaceMsrvResult::new(vec![
+            PackageMsrv::new("a", None, Some(semver::Version::new(1, 56, 0))),
+            PackageMsrv::new("b", None, Some(semver::Version::new(1, 60, 0))),
+        ]);
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(&events, &[Event::new(Message::WorkspaceMsrvResult(event))]);
+
+        if let Message::WorkspaceMsrvResult(msg) = &events[0].message {
+            assert_eq!(msg.packages().len(), 2);
+            assert_eq!(msg.workspace_msrv(), Some(&semver::Version::new(1, 60, 0)));
+        }
+    }
+
+    #[test]
+    fn workspace_msrv_undetermined_if_any_package_undetermined() {
+        let event = WorkspaceMsrvResult::new(vec![
+            PackageMsrv::new("a", None, Some(semver::Version::new(1, 56, 0))),
+            PackageMsrv::new("b", None, None),
+        ]);
+
+        assert!(event.workspace_msrv().is_none());
+    }
+
+    #[yare::parameterized(
+        matches = { Some(BareVersion::TwoComponents(1, 56)), Some(semver::Version::new(1, 56, 0)), PackageMsrvStatus::Matches },
+        matches_patch = { Some(BareVersion::ThreeComponents(1, 56, 1)), Some(semver::Version::new(1, 56, 1)), PackageMsrvStatus::Matches },
+        differs = { Some(BareVersion::TwoComponents(1, 56)), Some(semver::Version::new(1, 58, 0)), PackageMsrvStatus::Differs },
+        undeclared = { None, Some(semver::Version::new(1, 58, 0)), PackageMsrvStatus::Undeclared },
+        not_found = { Some(BareVersion::TwoComponents(1, 56)), None, PackageMsrvStatus::NotFound },
+    )]
+    fn package_status(
+        rust_version: Option<BareVersion>,
+        msrv: Option<semver::Version>,
+        expected: PackageMsrvStatus,
+    ) {
+        let package = PackageMsrv::new("a", rust_version, msrv);
+
+        assert_eq!(package.status(), expected);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::config::verify::FailOn;
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::Message;
+use crate::Event;
+
+/// The outcome of verifying the MSRV of each member of a workspace.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct WorkspaceVerifyResult {
+    packages: Vec<PackageVerifyResult>,
+    success: bool,
+}
+
+impl WorkspaceVerifyResult {
+    /// The workspace is verified successfully if none of its members fail verification according
+    /// to the given policy.
+    pub fn new(packages: Vec<PackageVerifyResult>, fail_on: FailOn) -> Self {
+        let success = packages
+            .iter()
+            .all(|package| !fail_on.fails_on(package.status));
+
+        Self { packages, success }
+    }
+
+    pub fn packages(&self) -> &[PackageVerifyResult] {
+        &self.packages
+    }
+
+    /// The workspace members which are not compatible with their declared MSRV.
+    pub fn incompatible_packages(&self) -> impl Iterator<Item = &PackageVerifyResult> {
+        self.packages
+            .iter()
+            .filter(|package| package.status == VerifyStatus::Incompatible)
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.success
+    }
+}
+
+impl From<WorkspaceVerifyResult> for Event {
+    fn from(it: WorkspaceVerifyResult) -> Self {
+        Message::WorkspaceVerifyResult(it).into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PackageVerifyResult {
+    name: String,
+    rust_version: Option<BareVersion>,
+    status: VerifyStatus,
+}
+
+impl PackageVerifyResult {
+    pub fn new(name: impl Into<String>, rust_version: BareVersion, status: VerifyStatus) -> Self {
+        Self {
+            name: name.into(),
+            rust_version: Some(rust_version),
+            status,
+        }
+    }
+
+    /// A workspace member which does not declare an MSRV, and was therefore not verified.
+    pub fn without_rust_version(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            rust_version: None,
+            status: VerifyStatus::Skipped,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn rust_version(&self) -> Option<&BareVersion> {
+        self.rust_version.as_ref()
+    }
+
+    pub fn status(&self) -> VerifyStatus {
+        self.status
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerifyStatus {
+    Compatible,
+    Incompatible,
+    /// The package is also compatible with the Rust release preceding its MSRV
+    TooConservative,
+    /// The package does not declare an MSRV
+    Skipped,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use storyteller::Reporter;
+    use yare::parameterized;
+
+    #[test]
+    fn reported_event() {
+        let reporter = TestReporter::default();
+
+        let event = WorkspaceVerifyResult::new(
+            vec![
+                PackageVerifyResult::new(
+                    "a",
+                    BareVersion::TwoComponents(1, 56),
+                    VerifyStatus::Compatible,
+                ),
+                PackageVerifyResult::without_rust_version("b"),
+            ],
+            FailOn::Lower,
+        );
+
+        reporter.reporter().report_event(event.clone()).unwrap();
+
+        let events = reporter.wait_for_events();
+
+        assert_eq!(
+            &events,
+            &[Event::new(Message::WorkspaceVerifyResult(event))]
+        );
+
+        if let Message::WorkspaceVerifyResult(msg) = &events[0].message {
+            assert!(msg.is_success());
+            assert_eq!(msg.packages().len(), 2);
+            assert_eq!(msg.incompatible_packages().count(), 0);
+        }
+    }
+
+    #[test]
+    fn incompatible_member_fails_workspace() {
+        let event = WorkspaceVerifyResult::new(
+            vec![
+                PackageVerifyResult::new(
+                    "a",
+                    BareVersion::TwoComponents(1, 56),
+                    VerifyStatus::Compatible,
+                ),
+                PackageVerifyResult::new(
+                    "b",
+                    BareVersion::ThreeComponents(1, 40, 0),
+                    VerifyStatus::Incompatible,
+                ),
+            ],
+            FailOn::Lower,
+        );
+
+        assert!(!event.is_success());
+
+        let incompatible = event
+            .incompatible_packages()
+            .map(PackageVerifyResult::name)
+            .collect::<Vec<_>>();
+        assert_eq!(incompatible, vec!["b"]);
+    }
+
+    #[parameterized(
+        lower = { FailOn::Lower, false, true },
+        higher = { FailOn::Higher, true, false },
+        both = { FailOn::Both, false, false },
+        never = { FailOn::Never, true, true },
+    )]
+    fn success_depends_on_policy(
+        fail_on: FailOn,
+        success_if_incompatible: bool,
+        success_if_too_conservative: bool,
+    ) {
+        let incompatible = WorkspaceVerifyResult::new(
+            vec![PackageVerifyResult::new(
+                "a",
+                BareVersion::TwoComponents(1, 56),
+                VerifyStatus::Incompatible,
+            )],
+            fail_on,
+        );
+        assert_eq!(incompatible.is_success(), success_if_incompatible);
+
+        let too_conservative = WorkspaceVerifyResult::new(
+            vec![PackageVerifyResult::new(
+                "a",
+                BareVersion::TwoComponents(1, 56),
+                VerifyStatus::TooConservative,
+            )],
+            fail_on,
+        );
+        assert_eq!(too_conservative.is_success(), success_if_too_conservative);
+    }
+}
@@ -8,15 +8,37 @@ use std::{io, marker};
 use storyteller::{EventHandler, Reporter};
 
 mod discard_output_handler;
+mod dot_handler;
+mod github_handler;
+mod html_handler;
 mod human_progress_handler;
 mod json_handler;
+mod junit_handler;
+mod markdown_handler;
+mod quiet_handler;
+mod report_handler;
+mod sarif_handler;
+mod toml_handler;
+#[cfg(feature = "tui")]
+mod tui_handler;
 
 #[cfg(test)]
 mod testing;
 
 pub use discard_output_handler::DiscardOutputHandler;
+pub use dot_handler::DotHandler;
+pub use github_handler::GithubHandler;
+pub use html_handler::HtmlHandler;
 pub use human_progress_handler::HumanProgressHandler;
 pub use json_handler::JsonHandler;
+pub use junit_handler::JunitHandler;
+pub use markdown_handler::MarkdownHandler;
+pub use quiet_handler::QuietHandler;
+pub use report_handler::ReportHandler;
+pub use sarif_handler::SarifHandler;
+pub use toml_handler::TomlHandler;
+#[cfg(feature = "tui")]
+pub use tui_handler::TuiHandler;
 
 #[cfg(test)]
 pub use testing::TestingHandler;
@@ -0,0 +1,50 @@
+use crate::reporter::event::Message;
+use crate::reporter::handler::json_handler::SendWriter;
+use std::io;
+use std::io::Stdout;
+use std::sync::Mutex;
+use storyteller::EventHandler;
+
+/// Records the dependency graph listed by the `list` subcommand, and prints it as a Graphviz DOT
+/// digraph, once all events have been handled.
+///
+/// The packages are colored by MSRV, so the subtree which drives the MSRV upwards stands out when
+/// the graph is rendered, e.g. with `dot -Tsvg`. Nothing is printed if no dependencies were listed.
+pub struct DotHandler<W: SendWriter> {
+    writer: Mutex<W>,
+    graph: Mutex<Option<String>>,
+}
+
+impl<W: SendWriter> DotHandler<W> {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock state for DotHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write DOT output for DotHandler";
+}
+
+impl DotHandler<Stdout> {
+    pub fn stdout() -> Self {
+        Self {
+            writer: Mutex::new(io::stdout()),
+            graph: Mutex::new(None),
+        }
+    }
+}
+
+impl<W: SendWriter> EventHandler for DotHandler<W> {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        if let Message::ListDep(list) = event.message() {
+            let mut graph = self.graph.lock().expect(Self::LOCK_FAILURE_MSG);
+            *graph = Some(list.dot_graph());
+        }
+    }
+
+    fn finish(&self) {
+        let graph = self.graph.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        if let Some(graph) = graph.as_deref() {
+            let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
+            write!(&mut w, "{}", graph).expect(Self::WRITE_FAILURE_MSG);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::reporter::event::{DiffStatus, Message};
+use crate::reporter::handler::json_handler::SendWriter;
+use crate::semver;
+use std::io;
+use std::io::Stdout;
+use std::sync::{Arc, Mutex};
+use storyteller::EventHandler;
+
+/// Prints GitHub Actions [workflow commands], which GitHub renders as annotations in the
+/// summary of a workflow run and on pull requests.
+///
+/// Only events which are relevant as annotations are printed.
+///
+/// [workflow commands]: https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions
+pub struct GithubHandler<W: SendWriter> {
+    writer: Arc<Mutex<W>>,
+}
+
+impl<W: SendWriter> GithubHandler<W> {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock writer for GithubHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write workflow command for GithubHandler";
+}
+
+impl SendWriter for Stdout {}
+
+impl GithubHandler<Stdout> {
+    pub fn stdout() -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(io::stdout())),
+        }
+    }
+}
+
+impl<W: SendWriter> EventHandler for GithubHandler<W> {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        if let Some(command) = workflow_command(event.message()) {
+            let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
+            writeln!(&mut w, "{}", command).expect(Self::WRITE_FAILURE_MSG);
+        }
+    }
+}
+
+fn workflow_command(message: &Message) -> Option<String> {
+    match message {
+        Message::MsrvResult(result) => result.msrv().map(|version| {
+            WorkflowCommand::Notice.format(
+                &[("title", "MSRV")],
+                &format!("The MSRV is Rust {}", version),
+            )
+        }),
+        Message::ManifestMsrvMismatch(mismatch) => {
+            let file = mismatch.manifest_path().display().to_string();
+
+            Some(WorkflowCommand::Warning.format(
+                &[("file", file.as_str()), ("title", "MSRV mismatch")],
+                &format!(
+                    "The MSRV which was found (Rust {}) differs from the MSRV specified in the Cargo manifest (Rust {})",
+                    mismatch.found(),
+                    mismatch.declared()
+                ),
+            ))
+        }
+        Message::LowerMsrvAvailable(lower) => {
+            let file = lower
+                .manifest_path()
+                .map(|path| path.display().to_string());
+
+            let mut properties = vec![("title", "MSRV could be lowered")];
+            if let Some(file) = &file {
+                properties.insert(0, ("file", file.as_str()));
+            }
+
+            Some(WorkflowCommand::Notice.format(
+                &properties,
+                &format!(
+                    "The crate is also compatible with Rust {}, so its MSRV could be lowered from Rust {}",
+                    lower.lower_msrv(),
+                    lower.rust_version()
+                ),
+            ))
+        }
+        Message::MsrvClampedToEdition(clamped) => Some(WorkflowCommand::Warning.format(
+            &[("title", "MSRV raised to edition")],
+            &format!(
+                "The MSRV which was found (Rust {}) is older than the first version which supports the {} edition, so the MSRV was raised to Rust {}",
+                clamped.found(),
+                clamped.edition(),
+                clamped.msrv()
+            ),
+        )),
+        Message::WorkspaceMsrvResult(result) => result.workspace_msrv().map(|version| {
+            WorkflowCommand::Notice.format(
+                &[("title", "Workspace MSRV")],
+                &format!("The MSRV of the workspace is Rust {}", version),
+            )
+        }),
+        Message::FeatureMatrixResult(result) => result.msrv().map(|version| {
+            WorkflowCommand::Notice.format(
+                &[("title", "Feature matrix MSRV")],
+                &format!("The MSRV of all feature combinations is Rust {}", version),
+            )
+        }),
+        Message::MsrvDiff(diff) if diff.status() == DiffStatus::Increased => {
+            let describe = |msrv: Option<&semver::Version>| {
+                msrv.map_or_else(|| "none".to_string(), |version| format!("Rust {}", version))
+            };
+
+            Some(WorkflowCommand::Warning.format(
+                &[("title", "MSRV increased")],
+                &format!(
+                    "The MSRV increased from {} to {}",
+                    describe(diff.base_msrv()),
+                    describe(diff.head_msrv())
+                ),
+            ))
+        }
+        Message::TerminateWithFailure(termination) => Some(
+            WorkflowCommand::Error.format(&[("title", "cargo-msrv")], termination.as_message()),
+        ),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum WorkflowCommand {
+    Notice,
+    Warning,
+    Error,
+}
+
+impl WorkflowCommand {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Notice => "notice",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    fn format(self, properties: &[(&str, &str)], message: &str) -> String {
+        let properties = properties
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, escape_property(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("::{} {}::{}", self.name(), properties, escape_data(message))
+    }
+}
+
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(property: &str) -> String {
+    escape_data(property)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::bare_version::BareVersion;
+    use crate::reporter::event::{ManifestMsrvMismatch, TerminateWithFailure};
+    use crate::{semver, CargoMSRVError};
+    use std::path::Path;
+
+    #[test]
+    fn format_command() {
+        let command = WorkflowCommand::Notice.format(&[("title", "a:b,c")], "hello\nworld 100%");
+
+        assert_eq!(command, "::notice title=a%3Ab%2Cc::hello%0Aworld 100%25");
+    }
+
+    #[test]
+    fn mismatch_is_warning() {
+        let message = Message::ManifestMsrvMismatch(ManifestMsrvMismatch::new(
+            semver::Version::new(1, 56, 0),
+            BareVersion::TwoComponents(1, 40),
+            Path::new("Cargo.toml").to_path_buf(),
+        ));
+
+        let command = workflow_command(&message).unwrap();
+
+        assert!(command.starts_with("::warning file=Cargo.toml,title=MSRV mismatch::"));
+        assert!(command.contains("Rust 1.56.0"));
+        assert!(command.contains("Rust 1.40"));
+    }
+
+    #[test]
+    fn failure_is_error() {
+        let message = Message::TerminateWithFailure(TerminateWithFailure::new(
+            CargoMSRVError::UnableToRunCheck,
+        ));
+
+        let command = workflow_command(&message).unwrap();
+
+        assert!(command.starts_with("::error title=cargo-msrv::"));
+    }
+}
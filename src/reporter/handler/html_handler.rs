@@ -0,0 +1,174 @@
+use crate::reporter::event::Message;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use storyteller::EventHandler;
+
+/// Records the dependencies listed by the `list` subcommand, and writes them as a standalone
+/// HTML document with a sortable table, once all events have been handled.
+///
+/// The document has no external dependencies, so it can be attached as a CI artifact. Nothing
+/// is written if no dependencies were listed.
+pub struct HtmlHandler {
+    path: PathBuf,
+    dependencies: Mutex<Option<Vec<(String, Vec<String>)>>>,
+}
+
+impl HtmlHandler {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock state for HtmlHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write HTML report for HtmlHandler";
+
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            dependencies: Mutex::new(None),
+        }
+    }
+}
+
+impl EventHandler for HtmlHandler {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        if let Message::ListDep(list) = event.message() {
+            let mut dependencies = self.dependencies.lock().expect(Self::LOCK_FAILURE_MSG);
+            *dependencies = Some(list.dependencies_by_msrv());
+        }
+    }
+
+    fn finish(&self) {
+        let dependencies = self.dependencies.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        if let Some(dependencies) = dependencies.as_deref() {
+            let document = html_report(dependencies);
+            std::fs::write(&self.path, document).expect(Self::WRITE_FAILURE_MSG);
+        }
+    }
+}
+
+/// Sorts the rows of the table by the clicked column. Cells which are Rust versions are
+/// compared by their numeric components; cells without an MSRV are sorted last.
+const SORT_SCRIPT: &str = r#"function compareCells(lhs, rhs) {
+  if (lhs === rhs) return 0;
+  if (lhs === "N/A") return 1;
+  if (rhs === "N/A") return -1;
+  const version = /^\d+(\.\d+)*$/;
+  if (version.test(lhs) && version.test(rhs)) {
+    const l = lhs.split(".").map(Number);
+    const r = rhs.split(".").map(Number);
+    for (let i = 0; i < Math.max(l.length, r.length); i++) {
+      const diff = (l[i] || 0) - (r[i] || 0);
+      if (diff !== 0) return diff;
+    }
+    return 0;
+  }
+  return lhs.localeCompare(rhs);
+}
+document.querySelectorAll("th").forEach(function (header, column) {
+  header.addEventListener("click", function () {
+    const body = header.closest("table").tBodies[0];
+    const ascending = header.getAttribute("aria-sort") !== "ascending";
+    document.querySelectorAll("th").forEach(function (th) { th.removeAttribute("aria-sort"); });
+    header.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+    Array.from(body.rows)
+      .sort(function (a, b) {
+        const order = compareCells(a.cells[column].textContent, b.cells[column].textContent);
+        return ascending ? order : -order;
+      })
+      .forEach(function (row) { body.appendChild(row); });
+  });
+});
+"#;
+
+const STYLE: &str = r#"body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.8em; text-align: left; }
+th { cursor: pointer; background: #f3f3f3; user-select: none; }
+th[aria-sort="ascending"]::after { content: " \25B2"; }
+th[aria-sort="descending"]::after { content: " \25BC"; }
+"#;
+
+/// Build the HTML document, with one table row per dependency. The dependencies are given
+/// grouped by MSRV, from the greatest to the lowest MSRV, which is the initial order of the rows.
+fn html_report(dependencies: &[(String, Vec<String>)]) -> String {
+    let mut document = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    document
+        .push_str("<meta charset=\"utf-8\">\n<title>cargo-msrv: MSRV of dependencies</title>\n");
+    let _ = write!(document, "<style>\n{}</style>\n", STYLE);
+    document.push_str("</head>\n<body>\n<h1>MSRV of dependencies</h1>\n");
+    document.push_str(
+        "<table>\n<thead>\n<tr><th>Dependency</th><th>MSRV</th></tr>\n</thead>\n<tbody>\n",
+    );
+
+    for (msrv, names) in dependencies {
+        // Dependencies which don't specify an MSRV are grouped under an empty version
+        let msrv = if msrv.is_empty() { "N/A" } else { msrv };
+
+        for name in names {
+            let _ = writeln!(
+                document,
+                "<tr><td>{}</td><td>{}</td></tr>",
+                escape(name),
+                escape(msrv)
+            );
+        }
+    }
+
+    document.push_str("</tbody>\n</table>\n");
+    let _ = write!(document, "<script>\n{}</script>\n", SORT_SCRIPT);
+    document.push_str("</body>\n</html>\n");
+
+    document
+}
+
+/// Escape characters which would otherwise be interpreted as markup.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_row_per_dependency() {
+        let dependencies = vec![
+            ("1.56.0".to_string(), vec!["a".to_string(), "b".to_string()]),
+            (String::new(), vec!["c".to_string()]),
+        ];
+
+        let document = html_report(&dependencies);
+
+        let rows = document
+            .lines()
+            .filter(|line| line.starts_with("<tr><td>"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            vec![
+                "<tr><td>a</td><td>1.56.0</td></tr>",
+                "<tr><td>b</td><td>1.56.0</td></tr>",
+                "<tr><td>c</td><td>N/A</td></tr>",
+            ]
+        );
+    }
+
+    #[test]
+    fn standalone_document() {
+        let document = html_report(&[]);
+
+        assert!(document.starts_with("<!DOCTYPE html>"));
+        assert!(document.contains("<script>"));
+        assert!(!document.contains("src="));
+        assert!(!document.contains("href="));
+    }
+
+    #[test]
+    fn escapes_markup() {
+        assert_eq!(escape("<a href=\"&\">"), "&lt;a href=&quot;&amp;&quot;&gt;");
+    }
+}
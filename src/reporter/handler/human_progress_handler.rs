@@ -1,10 +1,19 @@
-use crate::formatting::TermWidth;
+use crate::config::Verbosity;
+use crate::formatting::{byte_size, TermWidth};
+use crate::manifest::bare_version::BareVersion;
 use crate::reporter::event::{
-    CheckToolchain, Compatibility, CompatibilityReport, Message, MsrvResult,
+    CheckToolchain, CleanupStatus, Compatibility, CompatibilityCheckMethod, CompatibilityReport,
+    DependencyMsrvVerified, DiffStatus, DoctorReport, FeatureMatrixResult, FindingStatus,
+    LockfilePinned, LockfileStatus, Message, Method, MsrvCauses, MsrvCoverage, MsrvDiff,
+    MsrvResult, MsrvSources, PackageMsrvStatus, PlannedChecks, PolicyResult, PolicyRuleKind,
+    PreflightEstimate, SearchCancelled, SearchSpacePruned, SetupStage, SetupToolchainProgress,
+    UpgradeSuggestions, VerifyResult, VerifyStatus, WorkspaceMsrvResult, WorkspaceVerifyResult,
 };
+use crate::toolchain::OwnedToolchainSpec;
 use crate::{semver, Action, Event};
 use owo_colors::OwoColorize;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt::Display;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
@@ -18,30 +27,110 @@ use thiserror::private::PathAsDisplay;
 pub struct HumanProgressHandler {
     pb: indicatif::ProgressBar,
     sequence_number: AtomicU32,
+    timings: Mutex<Vec<CheckTiming>>,
+    /// An upper bound of the number of checks remaining in the current search, if known.
+    remaining_checks: Mutex<Option<u64>>,
+    verbosity: Verbosity,
+}
+
+/// The outcome of a toolchain check, and how long it took.
+struct CheckTiming {
+    toolchain: String,
+    compatible: bool,
+    duration: Option<Duration>,
 }
 
 impl Default for HumanProgressHandler {
     fn default() -> Self {
+        Self::new(Verbosity::Normal)
+    }
+}
+
+impl HumanProgressHandler {
+    pub fn new(verbosity: Verbosity) -> Self {
         let mp = Self::styled_progress_bar();
 
         Self {
             pb: mp,
             sequence_number: AtomicU32::new(1),
+            timings: Mutex::new(Vec::new()),
+            remaining_checks: Mutex::new(None),
+            verbosity,
         }
     }
-}
 
-impl HumanProgressHandler {
     fn start_runner_progress(&self, version: &semver::Version) {
         self.sequence_number.fetch_add(1, Ordering::SeqCst);
         self.pb.reset();
-        self.pb.set_message(format!("Rust {}", version));
+        self.pb.set_message(self.runner_message(version));
+    }
+
+    /// The spinner message of a check, which includes the number of remaining checks and an
+    /// estimate of the time they will take, when a search is in progress.
+    fn runner_message(&self, version: &semver::Version) -> String {
+        let remaining = self.remaining_checks.lock().ok().and_then(|it| *it);
+
+        match (remaining, self.average_check_duration()) {
+            (Some(remaining), Some(average)) => format!(
+                "Rust {} ({} checks left, ETA ~{:.0}s)",
+                version,
+                remaining,
+                average.as_secs_f64() * remaining as f64,
+            ),
+            (Some(remaining), None) => format!("Rust {} ({} checks left)", version, remaining),
+            (None, _) => format!("Rust {}", version),
+        }
+    }
+
+    fn set_remaining_checks(&self, remaining: Option<u64>) {
+        if let Ok(mut it) = self.remaining_checks.lock() {
+            *it = remaining;
+        }
+    }
+
+    /// The average duration of the checks which were run (and not cached) since the previous
+    /// result.
+    fn average_check_duration(&self) -> Option<Duration> {
+        let timings = self.timings.lock().ok()?;
+        let durations = timings
+            .iter()
+            .filter_map(|timing| timing.duration)
+            .collect::<Vec<_>>();
+
+        let count = u32::try_from(durations.len()).ok().filter(|&n| n > 0)?;
+
+        Some(durations.iter().sum::<Duration>() / count)
     }
 
     fn finish_runner_progress(&self) {
         self.pb.finish_and_clear();
     }
 
+    fn record_timing(&self, compatibility: &Compatibility) {
+        if let Ok(mut timings) = self.timings.lock() {
+            timings.push(CheckTiming {
+                toolchain: compatibility.toolchain().version().to_string(),
+                compatible: compatibility.is_compatible(),
+                duration: compatibility.duration(),
+            });
+        }
+    }
+
+    /// The timing table of the checks since the previous result, if any checks were run.
+    fn take_timings(&self) -> Option<String> {
+        let timings = self
+            .timings
+            .lock()
+            .map(|mut timings| std::mem::take(&mut *timings))
+            .unwrap_or_default();
+
+        if timings.is_empty() {
+            None
+        } else {
+            Some(timing_table(&timings))
+        }
+    }
+
     fn styled_progress_bar() -> indicatif::ProgressBar {
         let pb = indicatif::ProgressBar::new_spinner();
         pb.set_style(
@@ -59,6 +148,10 @@ impl EventHandler for HumanProgressHandler {
     type Event = super::Event;
 
     fn handle(&self, event: Self::Event) {
+        if let Message::Compatibility(compatibility) = event.message() {
+            self.record_timing(compatibility);
+        }
+
         #[allow(unused_must_use)]
         match event.message() {
             Message::Meta(it) => {
@@ -74,6 +167,37 @@ impl EventHandler for HumanProgressHandler {
                 self.pb.reset(); // We'll reset here to ensure the steady tick call below works
                 self.pb.enable_steady_tick(Duration::from_millis(150));
             }
+            Message::FindMsrv(_) if event.is_scope_start() => {
+                self.set_remaining_checks(None);
+            }
+            Message::SearchProbe(probe) if self.verbosity >= Verbosity::Verbose => {
+                let message = Status::with_lead("Probing".dimmed(), format_args!(
+                    "Rust {}, chosen by {}",
+                    probe.version(),
+                    probe.heuristic(),
+                ));
+                self.pb.println(message);
+            }
+            Message::Progress(progress) => {
+                self.set_remaining_checks(Some(progress.remaining()));
+            }
+            Message::SetupToolchainProgress(progress) => {
+                self.pb.set_message(progress.spinner_message());
+            }
+            Message::SetupToolchainRetry(retry) => {
+                let message = Status::with_lead("Retry".bright_yellow(), format_args!(
+                    "Installing Rust {} failed, retrying in {} seconds ({}/{}): {}",
+                    retry.toolchain().version(),
+                    retry.delay_secs(),
+                    retry.retry(),
+                    retry.max_retries(),
+                    retry.error(),
+                ));
+                self.pb.println(message);
+            }
+            Message::SetupToolchain(setup) if !event.is_scope_start() => {
+                self.pb.set_message(self.runner_message(setup.toolchain().version()));
+            }
             Message::CheckToolchain(it) if event.is_scope_start() => {
                 self.pb.println(it.header(self.sequence_number.load(Ordering::SeqCst)));
                 self.start_runner_progress(it.toolchain.version());
@@ -82,26 +206,202 @@ impl EventHandler for HumanProgressHandler {
                 let version = it.toolchain.version();
                 self.finish_runner_progress();
             }
-            Message::Compatibility(Compatibility {  compatibility_report: CompatibilityReport::Compatible, toolchain, .. }) => {
+            Message::CompatibilityCheckMethod(method) if self.verbosity >= Verbosity::Verbose => {
+                self.pb.println(method.details(self.verbosity));
+            }
+            Message::Compatibility(compatibility @ Compatibility {  compatibility_report: CompatibilityReport::Compatible, toolchain, .. }) => {
                 let version = toolchain.version();
-                let message = Status::ok("Is compatible");
+                let message = if compatibility.lockfile_downgraded() {
+                    Status::ok("Is compatible, with a downgraded lockfile")
+                } else {
+                    Status::ok("Is compatible")
+                };
                 self.pb.println(message);
             }
-            Message::Compatibility(Compatibility {  compatibility_report: CompatibilityReport::Incompatible { error }, toolchain, .. }) => {
+            Message::Compatibility(compatibility @ Compatibility {  compatibility_report: CompatibilityReport::Incompatible { error }, toolchain, .. }) => {
                 let version = toolchain.version();
-                let message = Status::fail("Is Incompatible");
+                let message = match compatibility.unsupported_flag() {
+                    Some(flag) => Status::fail(format_args!("Is Incompatible, since its Cargo doesn't support '{}' of the check command", flag)),
+                    None => Status::fail("Is Incompatible"),
+                };
                 self.pb.println(message);
 
                 if let Some(error_report) = error.as_deref() {
                     self.pb.println(message_box(error_report));
                 }
+
+                if let Some(path) = compatibility.output_log() {
+                    let message = Status::with_lead("Note".bright_yellow(), format_args!("The full output of the check was written to {}", path.display()));
+                    self.pb.println(message);
+                }
+            }
+            Message::PreflightEstimate(estimate) if estimate.downloads() > 0 => {
+                self.pb.println(preflight_estimate(estimate));
+            }
+            Message::FetchIndexFailed(failure) => {
+                let message = Status::with_lead("Note".bright_yellow(), format_args!(
+                    "Unable to fetch the release index: {}",
+                    failure.advice(),
+                ));
+                self.pb.println(message);
+            }
+            Message::CheckOutput(output) => {
+                self.pb.println(format!("{}", output.line().dimmed()));
+            }
+            Message::CheckTimedOut(timed_out) => {
+                let consequence = if timed_out.skipped() { "skipped" } else { "considered incompatible" };
+                let message = Status::with_lead("Timeout".bright_yellow(), format_args!(
+                    "The check of Rust {} was aborted after {} seconds, and is {}",
+                    timed_out.toolchain().version(),
+                    timed_out.timeout_secs(),
+                    consequence,
+                ));
+                self.pb.println(message);
+            }
+            Message::TemporaryLockfile(lockfile) => {
+                let action = match lockfile.status() {
+                    LockfileStatus::Regenerated => "Regenerated",
+                    LockfileStatus::MinimalVersions => "Minimized",
+                    LockfileStatus::Restored => "Restored",
+                    LockfileStatus::Downgraded => "Downgraded",
+                };
+                let message = Status::with_lead(action.dimmed(), format_args!("lockfile {}", lockfile.path().display()));
+                self.pb.println(message);
+            }
+            Message::ToolchainFileBypassed(bypassed) => {
+                let pinned = bypassed.channel().map(|channel| format!(", which pins Rust {},", channel)).unwrap_or_default();
+                let message = Status::with_lead("Bypassing".dimmed(), format_args!(
+                    "toolchain file {}{} so each check uses the toolchain which is being checked",
+                    bypassed.path().display(),
+                    pinned,
+                ));
+                self.pb.println(message);
+            }
+            Message::SearchCancelled(cancelled) => {
+                self.pb.println(cancelled.summary());
+            }
+            Message::ToolchainCleanup(cleanup) => {
+                let message = match cleanup.status() {
+                    CleanupStatus::Uninstalled => Status::with_lead("Uninstalled".dimmed(), format_args!("toolchain {}", cleanup.toolchain())),
+                    CleanupStatus::Failed { reason } => Status::with_lead("Note".bright_yellow(), format_args!(
+                        "Unable to uninstall toolchain {}: {}",
+                        cleanup.toolchain(),
+                        reason,
+                    )),
+                };
+                self.pb.println(message);
             }
             Message::MsrvResult(result) => {
                 self.pb.println(format!("\n{}\n", result.summary()));
+
+                if let Some(timings) = self.take_timings() {
+                    self.pb.println(format!("{}\n", timings));
+                }
+            }
+            Message::ManifestMsrvMismatch(mismatch) => {
+                let message = Status::with_lead("Note".bright_yellow(), format_args!(
+                    "The MSRV which was found (Rust {}) differs from the MSRV specified in the Cargo manifest (Rust {})",
+                    mismatch.found(),
+                    mismatch.declared(),
+                ));
+                self.pb.println(message);
+            }
+            Message::MsrvClampedToEdition(clamped) => {
+                let message = Status::with_lead("Note".bright_yellow(), format_args!(
+                    "The MSRV which was found (Rust {}) is older than Rust {}, the first version which supports the {} edition, so the MSRV was raised to Rust {}",
+                    clamped.found(),
+                    clamped.msrv(),
+                    clamped.edition(),
+                    clamped.msrv(),
+                ));
+                self.pb.println(message);
+            }
+            Message::SearchSpacePruned(pruned) => {
+                let message = Status::with_lead("Note".bright_yellow(), format_args!(
+                    "Skipping {} Rust version(s) older than Rust {}, the MSRV of dependency '{}', since Cargo refuses to build the crate with them",
+                    pruned.pruned(),
+                    pruned.dependency_msrv(),
+                    pruned.dependency(),
+                ));
+                self.pb.println(message);
+            }
+            Message::CertificationOutdated(outdated) => {
+                let changed = outdated
+                    .changed()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let message = Status::with_lead("Note".bright_yellow(), format_args!(
+                    "The MSRV was certified with Rust {} on {}, but the following inputs changed since: {}. Run cargo msrv --certify to certify it again",
+                    outdated.certified_toolchain(),
+                    outdated.certified_on(),
+                    changed,
+                ));
+                self.pb.println(message);
+            }
+            Message::WatchIteration(watch) if watch.iteration() == 0 => {
+                let message = Status::with_lead("Watch".bright_cyan(), format_args!(
+                    "Watching '{}' for changes, press Ctrl+C to stop",
+                    watch.root().display(),
+                ));
+                self.pb.println(message);
+            }
+            Message::WatchIteration(watch) => {
+                let changed = watch
+                    .changed()
+                    .iter()
+                    .map(|path| path.strip_prefix(watch.root()).unwrap_or(path).display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let message = Status::with_lead("Watch".bright_cyan(), format_args!(
+                    "Changed: {}, verifying again (#{})",
+                    changed,
+                    watch.iteration(),
+                ));
+                self.pb.println(message);
+            }
+            Message::WatchIterationFailed(failed) => {
+                self.pb.println(Status::fail(failed.error()));
+            }
+            Message::InvalidSearchSpace(invalid) => {
+                self.pb.println(Status::fail(invalid));
+            }
+            Message::MsrvCauses(causes) => {
+                self.pb.println(causes.summary());
+            }
+            Message::WorkspaceMsrvResult(result) => {
+                self.pb.println(format!("\n{}\n", result.summary()));
+            }
+            Message::VerifyResult(result) => {
+                self.pb.println(result.summary());
+            }
+            Message::LowerMsrvAvailable(lower) => {
+                let message = Status::with_lead("Advisory".bright_cyan(), format_args!(
+                    "The crate is also compatible with Rust {}, so its MSRV could be lowered from Rust {} to Rust {}",
+                    lower.lower_msrv(),
+                    lower.rust_version(),
+                    lower.lower_msrv(),
+                ));
+                self.pb.println(message);
+            }
+            Message::WorkspaceVerifyResult(result) => {
+                self.pb.println(format!("\n{}\n", result.summary()));
+            }
+            Message::FeatureMatrixResult(result) => {
+                self.pb.println(format!("\n{}\n", result.summary()));
             }
             Message::ListDep(list) => {
                 self.pb.println(list.to_string());
             }
+            Message::MsrvCoverage(coverage) => {
+                self.pb.println(coverage.summary());
+            }
+            Message::DependencyMsrvVerified(verified) => {
+                self.pb.println(verified.summary());
+            }
             Message::SetOutput(output) => {
                 let message = Status::with_lead("Set".bright_green(), format_args!("Rust {}", output.version()));
                 self.pb.println(message);
@@ -110,6 +410,41 @@ impl EventHandler for HumanProgressHandler {
                 let message = Status::with_lead("Show".bright_green(), format_args!("MSRV is Rust {}", output.version()));
                 self.pb.println(message);
             }
+            Message::EditionFloor(floor) => {
+                let default = if floor.is_declared() { "" } else { " (default)" };
+                let message = Status::with_lead("Show".bright_green(), format_args!(
+                    "Edition {}{} requires at least Rust {}",
+                    floor.edition(),
+                    default,
+                    floor.rust_version(),
+                ));
+                self.pb.println(message);
+            }
+            Message::MsrvSources(sources) => {
+                self.pb.println(sources.summary());
+            }
+            Message::DoctorReport(report) => {
+                self.pb.println(report.summary());
+            }
+            Message::UpgradeSuggestions(suggestions) => {
+                self.pb.println(suggestions.summary());
+            }
+            Message::LockfilePinned(pinned) => {
+                self.pb.println(pinned.summary());
+            }
+            Message::MsrvDiff(diff) => {
+                self.pb.println(format!("\n{}", diff.summary()));
+            }
+            Message::PolicyResult(result) => {
+                self.pb.println(format!("\n{}", result.summary()));
+            }
+            Message::PlannedChecks(planned) => {
+                self.pb.println(planned.summary());
+            }
+            Message::AuxiliaryOutput(output) if output.is_planned() => {
+                let message = Status::with_lead("Would write".dimmed(), format_args!("{} to {}", output.item(), output.destination()));
+                self.pb.println(message);
+            }
             Message::TerminateWithFailure(termination) if termination.is_error() => {
                 self.pb.println(format!("\n\n{}", termination.as_message().red()));
             }
@@ -123,7 +458,7 @@ impl EventHandler for HumanProgressHandler {
 
 impl Action {
     pub fn should_enable_spinner(&self) -> bool {
-        matches!(self, Self::Find | Self::Verify)
+        matches!(self, Self::Find | Self::Verify | Self::Diff)
     }
 }
 
@@ -140,12 +475,468 @@ impl CheckToolchain {
     }
 }
 
+impl CompatibilityCheckMethod {
+    /// The command line of the check, and with `-vv`, its working directory and environment.
+    fn details(&self, verbosity: Verbosity) -> String {
+        match self.method() {
+            Method::RustupRun {
+                args,
+                path,
+                env,
+                default_args,
+            } => {
+                let mut lines = vec![Status::with_lead(
+                    "Running".dimmed(),
+                    format_args!("rustup run {}", args.join(" ")),
+                )];
+
+                if verbosity >= Verbosity::VeryVerbose {
+                    if let Some(path) = path {
+                        lines.push(Status::with_lead(
+                            "In".dimmed(),
+                            format_args!("{}", path.display()),
+                        ));
+                    }
+
+                    lines.extend(env.iter().map(|(key, value)| {
+                        Status::with_lead("Env".dimmed(), format_args!("{}={}", key, value))
+                    }));
+
+                    if !default_args.is_empty() {
+                        lines.push(Status::with_lead(
+                            "Added".dimmed(),
+                            format_args!("{} (default check arguments)", default_args.join(" ")),
+                        ));
+                    }
+                }
+
+                lines.join("\n")
+            }
+            #[cfg(test)]
+            Method::TestRunner => Status::with_lead("Running".dimmed(), "test runner"),
+        }
+    }
+}
+
+impl SetupToolchainProgress {
+    fn spinner_message(&self) -> String {
+        let version = self.toolchain().version();
+
+        match (self.stage(), self.component()) {
+            (SetupStage::SyncingChannel, _) => format!("Rust {}: syncing channel", version),
+            (SetupStage::Downloading, Some(component)) => {
+                format!("Rust {}: downloading component '{}'", version, component)
+            }
+            (SetupStage::Installing, Some(component)) => {
+                format!("Rust {}: installing component '{}'", version, component)
+            }
+            (_, None) => format!("Rust {}: installing", version),
+        }
+    }
+}
+
 impl MsrvResult {
     fn summary(&self) -> String {
         result_table(self)
     }
 }
 
+impl WorkspaceMsrvResult {
+    fn summary(&self) -> String {
+        workspace_result_table(self)
+    }
+}
+
+impl VerifyResult {
+    fn summary(&self) -> String {
+        let version = match self.manifest_path() {
+            Some(_) => format!("its MSRV, Rust {}", self.rust_version()),
+            None => format!("Rust {}", self.rust_version()),
+        };
+
+        match self.status() {
+            VerifyStatus::Compatible => {
+                Status::ok(format_args!("The crate is compatible with {}", version))
+            }
+            VerifyStatus::TooConservative => Status::fail(format_args!(
+                "The crate is compatible with {}, but also with the preceding Rust {}",
+                version,
+                self.preceding_release()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            )),
+            _ => Status::fail(format_args!("The crate is not compatible with {}", version)),
+        }
+    }
+}
+
+impl SearchCancelled {
+    fn summary(&self) -> String {
+        let versions = |toolchains: &[OwnedToolchainSpec]| {
+            if toolchains.is_empty() {
+                "none".to_string()
+            } else {
+                toolchains
+                    .iter()
+                    .map(|toolchain| toolchain.version().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+
+        let mut summary = Status::with_lead(
+            "Cancelled".bright_red(),
+            format_args!(
+                "Compatible: {}; incompatible: {}",
+                versions(self.compatible()),
+                versions(self.incompatible()),
+            ),
+        );
+
+        if self.resumable() {
+            summary.push_str(&format!(
+                "\n{}",
+                "Run cargo-msrv again with --resume to continue the search".dimmed()
+            ));
+        }
+
+        summary
+    }
+}
+
+impl WorkspaceVerifyResult {
+    fn summary(&self) -> String {
+        workspace_verify_table(self)
+    }
+}
+
+impl FeatureMatrixResult {
+    fn summary(&self) -> String {
+        feature_matrix_table(self)
+    }
+}
+
+impl MsrvSources {
+    fn summary(&self) -> String {
+        let content = self
+            .sources()
+            .iter()
+            .map(|source| {
+                [
+                    format!("{}:", source.source().name()),
+                    format!("{} ({})", source.value(), source.path().display()),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let table = Table::new(&content)
+            .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
+            .with(Header(format!("{}", "Declared MSRV:".bold())))
+            .with(Modify::new(Segment::all()).with(Alignment::left()))
+            .with(Style::blank())
+            .to_string();
+
+        if self.is_consistent() {
+            table
+        } else {
+            let warning = Status::with_lead(
+                "Warning".bright_yellow(),
+                "The MSRV declared by these sources does not agree",
+            );
+
+            format!("{}\n{}", table, warning)
+        }
+    }
+}
+
+impl DoctorReport {
+    fn summary(&self) -> String {
+        self.findings()
+            .iter()
+            .map(|finding| {
+                let status = match finding.status() {
+                    FindingStatus::Ok => Status::ok(finding.message()),
+                    FindingStatus::Warning => {
+                        Status::with_lead("WARN".bright_yellow(), finding.message())
+                    }
+                    FindingStatus::Problem => Status::fail(finding.message()),
+                };
+
+                match finding.advice() {
+                    Some(advice) => format!("{}\n  {:>7}{}", status, "", advice.dimmed()),
+                    None => status,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl UpgradeSuggestions {
+    fn summary(&self) -> String {
+        fn version(version: Option<&semver::Version>) -> String {
+            version
+                .map(ToString::to_string)
+                .unwrap_or_else(|| format!("{}", "N/A".red()))
+        }
+
+        let mut content = vec![[
+            "Dependency".to_string(),
+            "Requirement".to_string(),
+            "Suggested".to_string(),
+            "Latest".to_string(),
+        ]];
+
+        content.extend(self.upgrades().iter().map(|dependency| {
+            [
+                dependency.name().to_string(),
+                dependency.requirement().to_string(),
+                format!("{}", version(dependency.suggested()).green()),
+                version(dependency.latest()),
+            ]
+        }));
+
+        Table::new(&content)
+            .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
+            .with(Header(format!(
+                "{}",
+                format_args!("Compatible with Rust {}:", self.msrv()).bold()
+            )))
+            .with(Modify::new(Segment::all()).with(Alignment::left()))
+            .with(Style::blank())
+            .to_string()
+    }
+}
+
+impl LockfilePinned {
+    fn summary(&self) -> String {
+        if self.dependencies().is_empty() {
+            return Status::ok(format_args!(
+                "All locked dependencies are compatible with Rust {}",
+                self.msrv()
+            ));
+        }
+
+        self.dependencies()
+            .iter()
+            .map(|dependency| match dependency.to() {
+                Some(to) => Status::with_lead(
+                    "Pinned".bright_green(),
+                    format_args!("{} {} -> {}", dependency.name(), dependency.from(), to),
+                ),
+                None => Status::fail(format_args!(
+                    "{} {} has no older version compatible with Rust {}",
+                    dependency.name(),
+                    dependency.from(),
+                    self.msrv()
+                )),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl MsrvCauses {
+    fn summary(&self) -> String {
+        if self.causes().is_empty() {
+            return Status::with_lead(
+                "Note".bright_yellow(),
+                format_args!(
+                    "Unable to determine why Rust {} is required, Rust {} did not report unsupported features",
+                    self.msrv(),
+                    self.previous(),
+                ),
+            );
+        }
+
+        let header = Status::with_lead(
+            "Explain".bright_green(),
+            format_args!(
+                "Rust {} is required, since Rust {} does not support:",
+                self.msrv(),
+                self.previous()
+            ),
+        );
+
+        let causes = self.causes().iter().map(|cause| {
+            let required = cause
+                .required_rust_version()
+                .map(|version| format!(" (requires Rust {})", version))
+                .unwrap_or_default();
+
+            let location = match (cause.file(), cause.line()) {
+                (Some(file), Some(line)) => format!(" at {}:{}", file, line),
+                (Some(file), None) => format!(" in {}", file),
+                _ => String::new(),
+            };
+
+            format!(
+                "  {:>16}  {}{}{}",
+                "",
+                cause.feature(),
+                required,
+                location.dimmed()
+            )
+        });
+
+        std::iter::once(header)
+            .chain(causes)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl PlannedChecks {
+    fn summary(&self) -> String {
+        let header = Status::with_lead(
+            "Dry run".bright_green(),
+            format_args!(
+                "{} check(s) would be run, nothing was compiled",
+                self.checks().len()
+            ),
+        );
+
+        let checks = self.checks().iter().map(|check| {
+            let mut lines = vec![format!(
+                "  {:>16}  Rust {}: {}",
+                "",
+                check.toolchain().version(),
+                check.command().join(" "),
+            )];
+
+            if let Some(dir) = check.directory() {
+                lines.push(
+                    format!("  {:>16}    in {}", "", dir.display())
+                        .dimmed()
+                        .to_string(),
+                );
+            }
+
+            if let Some(target_dir) = check.target_dir() {
+                lines.push(
+                    format!("  {:>16}    target directory {}", "", target_dir.display())
+                        .dimmed()
+                        .to_string(),
+                );
+            }
+
+            for (key, value) in check.env() {
+                lines.push(
+                    format!("  {:>16}    {}={}", "", key, value)
+                        .dimmed()
+                        .to_string(),
+                );
+            }
+
+            lines.join("\n")
+        });
+
+        std::iter::once(header)
+            .chain(checks)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl MsrvCoverage {
+    fn summary(&self) -> String {
+        Status::with_lead(
+            "Coverage".dimmed(),
+            format_args!(
+                "{} of {} dependencies declare `rust-version`, {} declare `package.metadata.msrv`, and the MSRV of {} is unknown",
+                self.rust_version(),
+                self.dependencies(),
+                self.metadata_msrv(),
+                self.unknown(),
+            ),
+        )
+    }
+}
+
+impl DependencyMsrvVerified {
+    fn summary(&self) -> String {
+        let verified = match self.verified_msrv() {
+            Some(version) => format!("MSRV is Rust {}", version),
+            None => "no compatible Rust version".to_string(),
+        };
+        let declared = match self.declared_msrv() {
+            Some(version) => format!("declared {}", version),
+            None => "not declared".to_string(),
+        };
+
+        let message = format!(
+            "{} {}: {} ({})",
+            self.name(),
+            self.version(),
+            verified,
+            declared
+        );
+
+        match (self.verified_msrv(), self.declared_msrv()) {
+            (Some(verified), Some(declared)) if verified > declared => Status::fail(message),
+            (None, _) => Status::fail(message),
+            _ => Status::with_lead("Verified".bright_green(), message),
+        }
+    }
+}
+
+impl MsrvDiff {
+    fn summary(&self) -> String {
+        let describe = |msrv: Option<&semver::Version>| match msrv {
+            Some(version) => format!("Rust {}", version),
+            None => "no compatible Rust version".to_string(),
+        };
+
+        let base = format!("{} ({})", describe(self.base_msrv()), self.base_revision());
+        let head = match self.head_revision() {
+            Some(revision) => format!("{} ({})", describe(self.head_msrv()), revision),
+            None => format!("{} (working tree)", describe(self.head_msrv())),
+        };
+
+        match self.status() {
+            DiffStatus::Increased => {
+                Status::fail(format_args!("The MSRV increased from {} to {}", base, head))
+            }
+            DiffStatus::Decreased => {
+                Status::ok(format_args!("The MSRV decreased from {} to {}", base, head))
+            }
+            DiffStatus::Unchanged => Status::ok(format_args!("The MSRV is unchanged at {}", head)),
+        }
+    }
+}
+
+impl PolicyResult {
+    fn summary(&self) -> String {
+        self.rules()
+            .iter()
+            .map(|rule| {
+                let name = match rule.kind() {
+                    PolicyRuleKind::SlidingWindow { releases } => format!(
+                        "at least {} releases behind Rust {}",
+                        releases,
+                        self.stable()
+                    ),
+                    PolicyRuleKind::Minimum => {
+                        format!("no newer than Rust {}", rule.newest_allowed_msrv())
+                    }
+                };
+
+                if rule.is_compliant() {
+                    Status::ok(format_args!("The MSRV, Rust {}, is {}", self.msrv(), name))
+                } else {
+                    Status::fail(format_args!(
+                        "The MSRV, Rust {}, must be {}, i.e. at most Rust {}",
+                        self.msrv(),
+                        name,
+                        rule.newest_allowed_msrv()
+                    ))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 struct Status;
 
 impl Status {
@@ -170,6 +961,76 @@ impl Status {
     }
 }
 
+fn preflight_estimate(estimate: &PreflightEstimate) -> String {
+    let message = Status::with_lead(
+        "Estimate".dimmed(),
+        format_args!(
+            "Up to {} toolchain(s) will be downloaded (about {}), taking about {} of disk space",
+            estimate.downloads(),
+            byte_size(estimate.download_size()),
+            byte_size(estimate.disk_space()),
+        ),
+    );
+
+    match estimate.available_disk_space() {
+        Some(available) if estimate.exceeds_available_disk_space() => {
+            let note = Status::with_lead(
+                "Note".bright_yellow(),
+                format_args!(
+                    "Only {} of disk space is available in '{}', consider narrowing the search space with --min and --max",
+                    byte_size(available),
+                    estimate.rustup_home().display(),
+                ),
+            );
+
+            format!("{}\n{}", message, note)
+        }
+        _ => message,
+    }
+}
+
+fn timing_table(timings: &[CheckTiming]) -> String {
+    fn format_duration(duration: Option<Duration>) -> String {
+        duration
+            .map(|duration| format!("{:.1}s", duration.as_secs_f64()))
+            .unwrap_or_else(|| "cached".to_string())
+    }
+
+    let mut content = vec![[
+        "Toolchain".to_string(),
+        "Result".to_string(),
+        "Time".to_string(),
+    ]];
+
+    content.extend(timings.iter().map(|timing| {
+        let result = if timing.compatible {
+            format!("{}", "compatible".green())
+        } else {
+            format!("{}", "incompatible".red())
+        };
+
+        [
+            format!("Rust {}", timing.toolchain),
+            result,
+            format_duration(timing.duration),
+        ]
+    }));
+
+    let total: Duration = timings.iter().filter_map(|timing| timing.duration).sum();
+    content.push([
+        "Total".to_string(),
+        String::new(),
+        format_duration(Some(total)),
+    ]);
+
+    Table::new(&content)
+        .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
+        .with(Header(format!("{}", "Checked toolchains:".bold())))
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Style::blank())
+        .to_string()
+}
+
 fn message_box(message: &str) -> String {
     Table::new(&[format!("{}", message.dimmed())])
         .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
@@ -189,8 +1050,8 @@ fn result_table(result: &MsrvResult) -> String {
     let target = result.target.as_str();
     let search_method: &str = result.search_method.into();
 
-    let content = &[
-        &[
+    let mut content = vec![
+        [
             format!("Considered ({} … {}):", "min".cyan(), "max".yellow()),
             format!(
                 "Rust {} … Rust {}",
@@ -198,21 +1059,150 @@ fn result_table(result: &MsrvResult) -> String {
                 result.maximum_version.yellow()
             ),
         ],
-        &[
+        [
             "Search method:".to_string(),
             format!("{}", search_method.bright_purple()),
         ],
-        &["MSRV:".to_string(), msrv(result)],
-        &[
+        ["MSRV:".to_string(), msrv(result)],
+        [
             format!("{}", "Target:".dimmed()),
             format!("{}", target.dimmed()),
         ],
     ];
 
-    Table::new(content)
+    if !result.features.is_default() {
+        content.push([
+            format!("{}", "Features:".dimmed()),
+            format!("{}", result.features.dimmed()),
+        ]);
+    }
+
+    if !result.target_selection.is_default() {
+        content.push([
+            format!("{}", "Targets:".dimmed()),
+            format!("{}", result.target_selection.dimmed()),
+        ]);
+    }
+
+    Table::new(&content)
         .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
         .with(Header(format!("{}", "Result:".bold())))
         .with(Modify::new(Segment::all()).with(Alignment::left()))
         .with(Style::blank())
         .to_string()
 }
+
+fn workspace_result_table(result: &WorkspaceMsrvResult) -> String {
+    fn msrv(version: Option<&semver::Version>) -> String {
+        version
+            .map(|version| format!("{}", version.green().bold().underline()))
+            .unwrap_or_else(|| format!("{}", "N/A".red()))
+    }
+
+    fn rust_version(version: Option<&BareVersion>) -> String {
+        version
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("{}", "N/A".dimmed()))
+    }
+
+    fn status(status: PackageMsrvStatus) -> String {
+        match status {
+            PackageMsrvStatus::Matches => format!("{}", status.as_str().green()),
+            PackageMsrvStatus::Differs => format!("{}", status.as_str().yellow()),
+            PackageMsrvStatus::Undeclared => format!("{}", status.as_str().dimmed()),
+            PackageMsrvStatus::NotFound => format!("{}", status.as_str().red()),
+        }
+    }
+
+    let mut content = vec![[
+        format!("{}", "Package".bold()),
+        format!("{}", "Declared".bold()),
+        format!("{}", "MSRV".bold()),
+        format!("{}", "Status".bold()),
+    ]];
+
+    content.extend(result.packages().iter().map(|package| {
+        [
+            package.name().to_string(),
+            rust_version(package.rust_version()),
+            msrv(package.msrv()),
+            status(package.status()),
+        ]
+    }));
+
+    content.push([
+        format!("{}", "Workspace".bold()),
+        String::new(),
+        msrv(result.workspace_msrv()),
+        String::new(),
+    ]);
+
+    Table::new(&content)
+        .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
+        .with(Header(format!("{}", "Workspace result:".bold())))
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Style::blank())
+        .to_string()
+}
+
+fn feature_matrix_table(result: &FeatureMatrixResult) -> String {
+    fn msrv(version: Option<&semver::Version>) -> String {
+        version
+            .map(|version| format!("{}", version.green().bold().underline()))
+            .unwrap_or_else(|| format!("{}", "N/A".red()))
+    }
+
+    fn features(features: &[String]) -> String {
+        if features.is_empty() {
+            "(none):".to_string()
+        } else {
+            format!("{}:", features.join(", "))
+        }
+    }
+
+    let mut content = result
+        .combinations()
+        .iter()
+        .map(|combination| [features(combination.features()), msrv(combination.msrv())])
+        .collect::<Vec<_>>();
+
+    content.push([format!("{}", "MSRV:".bold()), msrv(result.msrv())]);
+
+    Table::new(&content)
+        .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
+        .with(Header(format!("{}", "Feature matrix result:".bold())))
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Style::blank())
+        .to_string()
+}
+
+fn workspace_verify_table(result: &WorkspaceVerifyResult) -> String {
+    let content = result
+        .packages()
+        .iter()
+        .map(|package| {
+            let version = package
+                .rust_version()
+                .map(|version| version.to_string())
+                .unwrap_or_else(|| "N/A".to_string());
+
+            let status = match package.status() {
+                VerifyStatus::Compatible => format!("{}", "Compatible".green()),
+                VerifyStatus::Incompatible => format!("{}", "Incompatible".red()),
+                VerifyStatus::TooConservative => {
+                    format!("{}", "Compatible with preceding release".yellow())
+                }
+                VerifyStatus::Skipped => format!("{}", "Skipped (no MSRV)".dimmed()),
+            };
+
+            [format!("{}:", package.name()), version, status]
+        })
+        .collect::<Vec<_>>();
+
+    Table::new(&content)
+        .with(Disable::Row(..1)) // Disables the header; Style::header_off doesn't work! ordering matters!
+        .with(Header(format!("{}", "Workspace verify result:".bold())))
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Style::blank())
+        .to_string()
+}
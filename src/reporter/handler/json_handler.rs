@@ -1,12 +1,33 @@
+use crate::reporter::event::Event;
 use std::io;
 use std::io::Stderr;
 use std::sync::{Arc, Mutex};
 use storyteller::EventHandler;
 
+/// The version of the schema of the JSON output.
+///
+/// Each serialized event carries this version in its `schema_version` field. The version is
+/// incremented when a change is made to the events which may break existing parsers, such as
+/// the removal or renaming of a field. Adding events or fields does not increment the version.
+pub const SCHEMA_VERSION: u32 = 1;
+
 pub trait SendWriter: io::Write + Send + 'static {}
 
+/// Prints the events as JSON.
+///
+/// In the `json` output format, the events are collected and printed as a single JSON document,
+/// once all events have been handled. In the `json-lines` output format, each event is printed
+/// as a JSON object on its own line, as it happens.
 pub struct JsonHandler<W: SendWriter> {
     writer: Arc<Mutex<W>>,
+    mode: Mode,
+    events: Mutex<Vec<Event>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Document,
+    Lines,
 }
 
 impl<W: SendWriter> JsonHandler<W> {
@@ -16,15 +37,27 @@ impl<W: SendWriter> JsonHandler<W> {
         "{ \"panic\": true, \"cause\": \"Unable to serialize event for JsonHandle\", \"experimental\": true }";
     const WRITE_FAILURE_MSG: &'static str =
         "{ \"panic\": true, \"cause\": \"Unable to write serialized event for JsonHandle\", \"experimental\": true }";
+
+    fn new(writer: W, mode: Mode) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(writer)),
+            mode,
+            events: Mutex::new(Vec::new()),
+        }
+    }
 }
 
 impl SendWriter for Stderr {}
 
 impl JsonHandler<Stderr> {
+    /// Print all events as a single JSON document to stderr.
     pub fn stderr() -> Self {
-        Self {
-            writer: Arc::new(Mutex::new(io::stderr())),
-        }
+        Self::new(io::stderr(), Mode::Document)
+    }
+
+    /// Print each event as a JSON object on its own line to stderr.
+    pub fn stderr_lines() -> Self {
+        Self::new(io::stderr(), Mode::Lines)
     }
 }
 
@@ -32,9 +65,95 @@ impl<W: SendWriter> EventHandler for JsonHandler<W> {
     type Event = super::Event;
 
     fn handle(&self, event: Self::Event) {
-        let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
-        let serialized_event = serde_json::to_string(&event).expect(Self::SERIALIZE_FAILURE_MSG);
+        match self.mode {
+            Mode::Document => {
+                let mut events = self.events.lock().expect(Self::LOCK_FAILURE_MSG);
+                events.push(event);
+            }
+            Mode::Lines => {
+                let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
+                let serialized_event = serde_json::to_string(&VersionedEvent::from(&event))
+                    .expect(Self::SERIALIZE_FAILURE_MSG);
+
+                writeln!(&mut w, "{}", &serialized_event).expect(Self::WRITE_FAILURE_MSG);
+            }
+        }
+    }
+
+    fn finish(&self) {
+        if self.mode == Mode::Document {
+            let events = self.events.lock().expect(Self::LOCK_FAILURE_MSG);
+            let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
+            let serialized_document =
+                serde_json::to_string(&Document::new(&events)).expect(Self::SERIALIZE_FAILURE_MSG);
+
+            writeln!(&mut w, "{}", &serialized_document).expect(Self::WRITE_FAILURE_MSG);
+        }
+    }
+}
+
+/// An event, as printed in the `json-lines` output format.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+struct VersionedEvent<'e> {
+    schema_version: u32,
+    #[serde(flatten)]
+    event: &'e Event,
+}
+
+impl<'e> From<&'e Event> for VersionedEvent<'e> {
+    fn from(event: &'e Event) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event,
+        }
+    }
+}
+
+/// The document printed in the `json` output format.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) struct Document<'e> {
+    schema_version: u32,
+    events: Vec<VersionedEvent<'e>>,
+}
+
+impl<'e> Document<'e> {
+    pub(super) fn new(events: &'e [Event]) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            events: events.iter().map(VersionedEvent::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::ActionMessage;
+    use crate::Action;
+
+    #[test]
+    fn event_carries_schema_version() {
+        let event = Event::from(ActionMessage::new(Action::Find));
+
+        let value = serde_json::to_value(&VersionedEvent::from(&event)).unwrap();
+
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["type"], "action");
+    }
+
+    #[test]
+    fn document_contains_each_event() {
+        let events = vec![
+            Event::from(ActionMessage::new(Action::Find)),
+            Event::from(ActionMessage::new(Action::Verify)),
+        ];
+
+        let value = serde_json::to_value(&Document::new(&events)).unwrap();
 
-        writeln!(&mut w, "{}", &serialized_event).expect(Self::WRITE_FAILURE_MSG);
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["events"].as_array().unwrap().len(), 2);
+        assert_eq!(value["events"][1]["schema_version"], SCHEMA_VERSION);
     }
 }
@@ -0,0 +1,201 @@
+use crate::reporter::event::{CompatibilityReport, Message};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use storyteller::EventHandler;
+
+/// Records each toolchain check as a JUnit test case, and writes the test report as JUnit XML
+/// to a file, once all events have been handled.
+pub struct JunitHandler {
+    path: PathBuf,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The start of the currently running check
+    started: Option<Instant>,
+    cases: Vec<TestCase>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct TestCase {
+    name: String,
+    duration: Duration,
+    failure: Option<String>,
+}
+
+impl JunitHandler {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock state for JunitHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write JUnit report for JunitHandler";
+
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            state: Mutex::new(State::default()),
+        }
+    }
+}
+
+impl EventHandler for JunitHandler {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        let mut state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        match event.message() {
+            Message::CheckToolchain(_) if event.is_scope_start() => {
+                state.started = Some(Instant::now());
+            }
+            Message::Compatibility(compatibility) => {
+                let duration = state
+                    .started
+                    .take()
+                    .map(|started| started.elapsed())
+                    .unwrap_or_default();
+
+                let failure = match &compatibility.compatibility_report {
+                    CompatibilityReport::Compatible => None,
+                    CompatibilityReport::Incompatible { error } => {
+                        Some(error.clone().unwrap_or_default())
+                    }
+                };
+
+                state.cases.push(TestCase {
+                    name: compatibility.toolchain().to_string(),
+                    duration,
+                    failure,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(&self) {
+        let state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+        let report = junit_xml(&state.cases);
+
+        std::fs::write(&self.path, report).expect(Self::WRITE_FAILURE_MSG);
+    }
+}
+
+fn junit_xml(cases: &[TestCase]) -> String {
+    let failures = cases.iter().filter(|case| case.failure.is_some()).count();
+    let time: Duration = cases.iter().map(|case| case.duration).sum();
+
+    let mut xml = String::new();
+
+    // Writing to a String can't fail
+    let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        xml,
+        r#"<testsuites name="cargo-msrv" tests="{}" failures="{}" time="{:.3}">"#,
+        cases.len(),
+        failures,
+        time.as_secs_f64()
+    );
+    let _ = writeln!(
+        xml,
+        r#"  <testsuite name="cargo-msrv" tests="{}" failures="{}" time="{:.3}">"#,
+        cases.len(),
+        failures,
+        time.as_secs_f64()
+    );
+
+    for case in cases {
+        let _ = write!(
+            xml,
+            r#"    <testcase name="{}" classname="cargo-msrv" time="{:.3}""#,
+            escape(&case.name),
+            case.duration.as_secs_f64()
+        );
+
+        match &case.failure {
+            None => {
+                let _ = writeln!(xml, " />");
+            }
+            Some(output) => {
+                let _ = writeln!(xml, ">");
+                let _ = writeln!(
+                    xml,
+                    r#"      <failure message="Toolchain is incompatible">{}</failure>"#,
+                    escape(output)
+                );
+                let _ = writeln!(xml, "    </testcase>");
+            }
+        }
+    }
+
+    let _ = writeln!(xml, "  </testsuite>");
+    let _ = writeln!(xml, "</testsuites>");
+
+    xml
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::{CheckToolchain, Compatibility};
+    use crate::reporter::Event;
+    use crate::semver;
+    use crate::toolchain::OwnedToolchainSpec;
+    use test_dir::{DirBuilder, TestDir};
+
+    #[test]
+    fn escape_xml() {
+        assert_eq!(
+            escape(r#"<a href="x">&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&apos;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn report_with_passed_and_failed_case() {
+        let cases = vec![
+            TestCase {
+                name: "1.56.0-x".to_string(),
+                duration: Duration::from_millis(1500),
+                failure: None,
+            },
+            TestCase {
+                name: "1.55.0-x".to_string(),
+                duration: Duration::from_millis(500),
+                failure: Some("error[E0658]: <edition2021>".to_string()),
+            },
+        ];
+
+        let xml = junit_xml(&cases);
+
+        assert!(
+            xml.contains(r#"<testsuite name="cargo-msrv" tests="2" failures="1" time="2.000">"#)
+        );
+        assert!(xml.contains(r#"<testcase name="1.56.0-x" classname="cargo-msrv" time="1.500" />"#));
+        assert!(xml.contains("error[E0658]: &lt;edition2021&gt;</failure>"));
+    }
+
+    #[test]
+    fn writes_report_on_finish() {
+        let tmp = TestDir::temp();
+        let path = tmp.path("junit.xml");
+        let handler = JunitHandler::new(&path);
+
+        let toolchain = OwnedToolchainSpec::new(&semver::Version::new(1, 56, 0), "x");
+        let event: Event = CheckToolchain::new(toolchain.clone()).into();
+
+        handler.handle(event);
+        handler.handle(Compatibility::compatible(toolchain).into());
+        handler.finish();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains(r#"<testcase name="1.56.0-x" classname="cargo-msrv""#));
+    }
+}
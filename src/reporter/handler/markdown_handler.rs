@@ -0,0 +1,401 @@
+use crate::reporter::event::{Message, VerifyStatus};
+use crate::reporter::handler::json_handler::SendWriter;
+use std::fmt::Write;
+use std::io;
+use std::io::Stdout;
+use std::sync::Mutex;
+use storyteller::EventHandler;
+
+/// Collects the search trace and the result of cargo-msrv, and prints it as a GitHub flavored
+/// Markdown document, once all events have been handled.
+///
+/// The document is meant to be posted as a comment on a pull request, for example by a bot.
+pub struct MarkdownHandler<W: SendWriter> {
+    writer: Mutex<W>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct State {
+    /// `Some` if a result was reported; the inner value is `None` if no MSRV was found.
+    msrv: Option<Option<String>>,
+    verified: Option<Verified>,
+    error: Option<String>,
+    checks: Vec<Check>,
+    packages: Vec<Package>,
+    dependencies: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Verified {
+    rust_version: String,
+    compatible: bool,
+    /// Whether the Rust version is the MSRV declared in the Cargo manifest, rather than a version
+    /// given as argument.
+    declared: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct Check {
+    version: String,
+    compatible: bool,
+    lockfile_downgraded: bool,
+    unsupported_flag: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Package {
+    name: String,
+    rust_version: Option<String>,
+    msrv: Option<String>,
+    status: &'static str,
+}
+
+impl<W: SendWriter> MarkdownHandler<W> {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock state for MarkdownHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write Markdown output for MarkdownHandler";
+}
+
+impl MarkdownHandler<Stdout> {
+    pub fn stdout() -> Self {
+        Self {
+            writer: Mutex::new(io::stdout()),
+            state: Mutex::new(State::default()),
+        }
+    }
+}
+
+impl<W: SendWriter> EventHandler for MarkdownHandler<W> {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        let mut state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        match event.message() {
+            Message::Compatibility(compatibility) => {
+                state.checks.push(Check {
+                    version: compatibility.toolchain().version().to_string(),
+                    compatible: compatibility.is_compatible(),
+                    lockfile_downgraded: compatibility.lockfile_downgraded(),
+                    unsupported_flag: compatibility.unsupported_flag().map(String::from),
+                });
+            }
+            Message::MsrvResult(result) => {
+                state.msrv = Some(result.msrv().map(ToString::to_string));
+            }
+            Message::WorkspaceMsrvResult(result) => {
+                state.msrv = Some(result.workspace_msrv().map(ToString::to_string));
+                state.packages = result
+                    .packages()
+                    .iter()
+                    .map(|package| Package {
+                        name: package.name().to_string(),
+                        rust_version: package.rust_version().map(ToString::to_string),
+                        msrv: package.msrv().map(ToString::to_string),
+                        status: package.status().as_str(),
+                    })
+                    .collect();
+            }
+            Message::FeatureMatrixResult(result) => {
+                state.msrv = Some(result.msrv().map(ToString::to_string));
+            }
+            Message::VerifyResult(result) => {
+                state.verified = Some(Verified {
+                    rust_version: result.rust_version().to_string(),
+                    compatible: result.status() != VerifyStatus::Incompatible,
+                    declared: result.manifest_path().is_some(),
+                });
+            }
+            Message::ListDep(list) => {
+                state.dependencies = list.dependencies_by_msrv();
+            }
+            Message::TerminateWithFailure(termination) if termination.is_error() => {
+                state.error = Some(termination.as_message().to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(&self) {
+        let state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+        let document = markdown_report(&state);
+
+        let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
+        write!(&mut w, "{}", document).expect(Self::WRITE_FAILURE_MSG);
+    }
+}
+
+fn markdown_report(state: &State) -> String {
+    let mut document = String::from("## cargo-msrv\n");
+
+    match &state.msrv {
+        Some(Some(msrv)) => {
+            let _ = write!(document, "\n**MSRV:** `{}`\n", msrv);
+        }
+        Some(None) => {
+            document.push_str("\n**MSRV:** not found\n");
+        }
+        None => {}
+    }
+
+    if let Some(verified) = &state.verified {
+        let outcome = if verified.compatible {
+            "is compatible"
+        } else {
+            "is not compatible"
+        };
+
+        let version = if verified.declared {
+            "its MSRV, Rust"
+        } else {
+            "Rust"
+        };
+
+        let _ = write!(
+            document,
+            "\n{} The crate {} with {} `{}`\n",
+            status_icon(verified.compatible),
+            outcome,
+            version,
+            verified.rust_version
+        );
+    }
+
+    if let Some(error) = &state.error {
+        let _ = write!(document, "\n> **Error:** {}\n", escape(error));
+    }
+
+    if !state.packages.is_empty() {
+        document.push_str(
+            "\n### Workspace members\n\n| Package | Declared | MSRV | Status |\n| --- | --- | --- | --- |\n",
+        );
+
+        for package in &state.packages {
+            let _ = writeln!(
+                document,
+                "| {} | {} | {} | {} |",
+                escape(&package.name),
+                package.rust_version.as_deref().unwrap_or("N/A"),
+                package.msrv.as_deref().unwrap_or("N/A"),
+                package.status
+            );
+        }
+
+        let workspace_msrv = state.msrv.as_ref().and_then(Option::as_deref);
+        let _ = writeln!(
+            document,
+            "| **Workspace** | | **{}** | |",
+            workspace_msrv.unwrap_or("N/A")
+        );
+    }
+
+    if !state.checks.is_empty() {
+        document.push_str(
+            "\n<details>\n<summary>Checked toolchains</summary>\n\n| Rust version | Compatible |\n| --- | --- |\n",
+        );
+
+        for check in &state.checks {
+            let note = match &check.unsupported_flag {
+                Some(flag) => format!(" (`{}` is not supported by this toolchain)", flag),
+                None if check.lockfile_downgraded => " (with a downgraded lockfile)".to_string(),
+                None => String::new(),
+            };
+
+            let _ = writeln!(
+                document,
+                "| {} | {}{} |",
+                check.version,
+                status_icon(check.compatible),
+                note
+            );
+        }
+
+        document.push_str("\n</details>\n");
+    }
+
+    if !state.dependencies.is_empty() {
+        document.push_str("\n### MSRV of dependencies\n\n| MSRV | Dependency |\n| --- | --- |\n");
+
+        for (msrv, dependencies) in &state.dependencies {
+            // Dependencies which don't specify an MSRV are grouped under an empty version
+            let msrv = if msrv.is_empty() { "N/A" } else { msrv };
+
+            let _ = writeln!(
+                document,
+                "| {} | {} |",
+                escape(msrv),
+                escape(&dependencies.join(", "))
+            );
+        }
+    }
+
+    document
+}
+
+fn status_icon(compatible: bool) -> &'static str {
+    if compatible {
+        "✅"
+    } else {
+        "❌"
+    }
+}
+
+/// Escape characters which would otherwise break a table cell.
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_report() {
+        let state = State {
+            msrv: Some(Some("1.56.0".to_string())),
+            checks: vec![
+                Check {
+                    version: "1.55.0".to_string(),
+                    compatible: false,
+                    lockfile_downgraded: false,
+                    unsupported_flag: None,
+                },
+                Check {
+                    version: "1.56.0".to_string(),
+                    compatible: true,
+                    lockfile_downgraded: false,
+                    unsupported_flag: None,
+                },
+            ],
+            ..State::default()
+        };
+
+        let expected = r#"## cargo-msrv
+
+**MSRV:** `1.56.0`
+
+<details>
+<summary>Checked toolchains</summary>
+
+| Rust version | Compatible |
+| --- | --- |
+| 1.55.0 | ❌ |
+| 1.56.0 | ✅ |
+
+</details>
+"#;
+
+        assert_eq!(markdown_report(&state), expected);
+    }
+
+    #[test]
+    fn downgraded_lockfile_is_noted() {
+        let state = State {
+            msrv: Some(Some("1.50.0".to_string())),
+            checks: vec![Check {
+                version: "1.50.0".to_string(),
+                compatible: true,
+                lockfile_downgraded: true,
+                unsupported_flag: None,
+            }],
+            ..State::default()
+        };
+
+        assert!(markdown_report(&state).contains("| 1.50.0 | ✅ (with a downgraded lockfile) |"));
+    }
+
+    #[test]
+    fn unsupported_flag_is_noted() {
+        let state = State {
+            checks: vec![Check {
+                version: "1.20.0".to_string(),
+                compatible: false,
+                lockfile_downgraded: false,
+                unsupported_flag: Some("--all-targets".to_string()),
+            }],
+            ..State::default()
+        };
+
+        assert!(markdown_report(&state)
+            .contains("| 1.20.0 | ❌ (`--all-targets` is not supported by this toolchain) |"));
+    }
+
+    #[test]
+    fn verify_report() {
+        let state = State {
+            verified: Some(Verified {
+                rust_version: "1.56".to_string(),
+                compatible: false,
+                declared: true,
+            }),
+            ..State::default()
+        };
+
+        let expected = r#"## cargo-msrv
+
+❌ The crate is not compatible with its MSRV, Rust `1.56`
+"#;
+
+        assert_eq!(markdown_report(&state), expected);
+    }
+
+    #[test]
+    fn workspace_report() {
+        let state = State {
+            msrv: Some(Some("1.58.0".to_string())),
+            packages: vec![
+                Package {
+                    name: "a".to_string(),
+                    rust_version: Some("1.56".to_string()),
+                    msrv: Some("1.56.0".to_string()),
+                    status: "matches",
+                },
+                Package {
+                    name: "b".to_string(),
+                    rust_version: None,
+                    msrv: Some("1.58.0".to_string()),
+                    status: "undeclared",
+                },
+            ],
+            ..State::default()
+        };
+
+        let expected = r#"## cargo-msrv
+
+**MSRV:** `1.58.0`
+
+### Workspace members
+
+| Package | Declared | MSRV | Status |
+| --- | --- | --- | --- |
+| a | 1.56 | 1.56.0 | matches |
+| b | N/A | 1.58.0 | undeclared |
+| **Workspace** | | **1.58.0** | |
+"#;
+
+        assert_eq!(markdown_report(&state), expected);
+    }
+
+    #[test]
+    fn list_report() {
+        let state = State {
+            dependencies: vec![
+                ("1.56.0".to_string(), vec!["a".to_string(), "b".to_string()]),
+                (String::new(), vec!["c".to_string()]),
+            ],
+            ..State::default()
+        };
+
+        let expected = r#"## cargo-msrv
+
+### MSRV of dependencies
+
+| MSRV | Dependency |
+| --- | --- |
+| 1.56.0 | a, b |
+| N/A | c |
+"#;
+
+        assert_eq!(markdown_report(&state), expected);
+    }
+}
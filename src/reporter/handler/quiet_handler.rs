@@ -0,0 +1,135 @@
+use crate::reporter::event::{Message, VerifyStatus};
+use crate::reporter::handler::json_handler::SendWriter;
+use std::io;
+use std::io::Stdout;
+use std::sync::{Arc, Mutex};
+use storyteller::EventHandler;
+
+/// Prints only the final result, such as the MSRV, without any decoration, so the output can be
+/// used by scripts, e.g. `$(cargo msrv find -q)`. Used for the `minimal` output format, and for
+/// the `human` output format when `--quiet` is given.
+///
+/// Nothing is printed to stdout if no result was found. Errors are printed to stderr, so they
+/// don't end up in the captured result.
+pub struct QuietHandler<W: SendWriter> {
+    writer: Arc<Mutex<W>>,
+}
+
+impl<W: SendWriter> QuietHandler<W> {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock writer for QuietHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write result for QuietHandler";
+}
+
+impl QuietHandler<Stdout> {
+    pub fn stdout() -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(io::stdout())),
+        }
+    }
+}
+
+impl<W: SendWriter> EventHandler for QuietHandler<W> {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        if let Message::TerminateWithFailure(termination) = event.message() {
+            if termination.is_error() {
+                eprintln!("{}", termination.as_message());
+            }
+        }
+
+        if let Some(output) = quiet_output(event.message()) {
+            let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
+            writeln!(&mut w, "{}", output).expect(Self::WRITE_FAILURE_MSG);
+        }
+    }
+}
+
+/// The final result contained in the message, if any.
+fn quiet_output(message: &Message) -> Option<String> {
+    match message {
+        Message::MsrvResult(result) => result.msrv().map(ToString::to_string),
+        Message::WorkspaceMsrvResult(result) => {
+            let lines = result
+                .packages()
+                .iter()
+                .filter_map(|package| {
+                    package
+                        .msrv()
+                        .map(|msrv| format!("{} {}", package.name(), msrv))
+                })
+                .collect::<Vec<_>>();
+
+            Some(lines.join("\n")).filter(|it| !it.is_empty())
+        }
+        Message::FeatureMatrixResult(result) => result.msrv().map(ToString::to_string),
+        Message::ShowOutput(output) => Some(output.version().to_string()),
+        Message::SetOutput(output) => Some(output.version().to_string()),
+        Message::VerifyResult(result) if result.status() == VerifyStatus::Compatible => {
+            Some(result.rust_version().to_string())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::bare_version::BareVersion;
+    use crate::reporter::event::{
+        PackageMsrv, ShowOutputMessage, TerminateWithFailure, VerifyResult, WorkspaceMsrvResult,
+    };
+    use crate::{semver, CargoMSRVError};
+    use std::path::PathBuf;
+
+    #[test]
+    fn show_prints_version() {
+        let message = Message::ShowOutput(ShowOutputMessage::new(
+            BareVersion::TwoComponents(1, 56),
+            PathBuf::from("Cargo.toml"),
+        ));
+
+        assert_eq!(quiet_output(&message).unwrap(), "1.56");
+    }
+
+    #[test]
+    fn workspace_prints_package_per_line() {
+        let message = Message::WorkspaceMsrvResult(WorkspaceMsrvResult::new(vec![
+            PackageMsrv::new("a", None, Some(semver::Version::new(1, 56, 0))),
+            PackageMsrv::new("b", None, None),
+            PackageMsrv::new("c", None, Some(semver::Version::new(1, 60, 0))),
+        ]));
+
+        assert_eq!(quiet_output(&message).unwrap(), "a 1.56.0\nc 1.60.0");
+    }
+
+    #[test]
+    fn compatible_verify_prints_version() {
+        let message = Message::VerifyResult(VerifyResult::compatible(
+            BareVersion::ThreeComponents(1, 60, 0),
+            None,
+        ));
+
+        assert_eq!(quiet_output(&message).unwrap(), "1.60.0");
+    }
+
+    #[test]
+    fn incompatible_verify_prints_nothing() {
+        let message = Message::VerifyResult(VerifyResult::incompatible(
+            BareVersion::ThreeComponents(1, 60, 0),
+            None,
+            None,
+        ));
+
+        assert!(quiet_output(&message).is_none());
+    }
+
+    #[test]
+    fn failure_is_not_printed_to_stdout() {
+        let message = Message::TerminateWithFailure(TerminateWithFailure::new(
+            CargoMSRVError::UnableToRunCheck,
+        ));
+
+        assert!(quiet_output(&message).is_none());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::reporter::event::{AuxiliaryOutput, AuxiliaryOutputItem, Destination, Event};
+use crate::reporter::handler::json_handler::Document;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use storyteller::EventHandler;
+
+/// Writes all events as a JSON document to a file, independent of the output format, while
+/// passing each event on to the handler of the output format.
+///
+/// Once the report has been written, its creation is passed on as an `AuxiliaryOutput` event.
+pub struct ReportHandler<H: EventHandler<Event = Event>> {
+    path: PathBuf,
+    inner: H,
+    events: Mutex<Vec<Event>>,
+}
+
+impl<H: EventHandler<Event = Event>> ReportHandler<H> {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock events for ReportHandler";
+    const SERIALIZE_FAILURE_MSG: &'static str = "Unable to serialize events for ReportHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write report for ReportHandler";
+
+    pub fn new(path: impl AsRef<Path>, inner: H) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            inner,
+            events: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl<H: EventHandler<Event = Event>> EventHandler for ReportHandler<H> {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        self.events
+            .lock()
+            .expect(Self::LOCK_FAILURE_MSG)
+            .push(event.clone());
+
+        self.inner.handle(event);
+    }
+
+    fn finish(&self) {
+        {
+            let events = self.events.lock().expect(Self::LOCK_FAILURE_MSG);
+            let report = serde_json::to_string_pretty(&Document::new(&events))
+                .expect(Self::SERIALIZE_FAILURE_MSG);
+
+            std::fs::write(&self.path, report).expect(Self::WRITE_FAILURE_MSG);
+        }
+
+        self.inner.handle(Event::from(AuxiliaryOutput::new(
+            Destination::File(self.path.clone()),
+            AuxiliaryOutputItem::report(),
+        )));
+
+        self.inner.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::{ActionMessage, Message};
+    use crate::reporter::handler::TestingHandler;
+    use crate::Action;
+    use test_dir::{DirBuilder, TestDir};
+
+    #[test]
+    fn writes_report_and_passes_on_events() {
+        let dir = TestDir::temp();
+        let path = dir.path("report.json");
+        let inner = TestingHandler::default();
+
+        let handler = ReportHandler::new(&path, inner.clone());
+        handler.handle(Event::from(ActionMessage::new(Action::Find)));
+        handler.finish();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+        assert_eq!(report["events"].as_array().unwrap().len(), 1);
+        assert_eq!(report["events"][0]["type"], "action");
+
+        let events = inner.events();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[1],
+            Event::new(Message::AuxiliaryOutput(AuxiliaryOutput::new(
+                Destination::File(path.clone()),
+                AuxiliaryOutputItem::report(),
+            )))
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::reporter::event::{Message, VerifyResult, VerifyStatus};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use storyteller::EventHandler;
+
+/// The id of the only rule reported by cargo-msrv.
+const RULE_ID: &str = "msrv-incorrect";
+
+/// Records the verification results, and writes a SARIF report with a finding for each crate
+/// which is incompatible with its declared MSRV, once all events have been handled.
+///
+/// SARIF is the format consumed by GitHub code scanning, amongst others. Findings are located
+/// at the line of the Cargo manifest which declares the MSRV.
+pub struct SarifHandler {
+    path: PathBuf,
+    results: Mutex<Vec<VerifyResult>>,
+}
+
+impl SarifHandler {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock state for SarifHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write SARIF report for SarifHandler";
+
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            results: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl EventHandler for SarifHandler {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        if let Message::VerifyResult(result) = event.message() {
+            let mut results = self.results.lock().expect(Self::LOCK_FAILURE_MSG);
+            results.push(result.clone());
+        }
+    }
+
+    fn finish(&self) {
+        let results = self.results.lock().expect(Self::LOCK_FAILURE_MSG);
+        let report = sarif_report(&results, manifest_msrv_line);
+
+        let contents = serde_json::to_string_pretty(&report).expect(Self::WRITE_FAILURE_MSG);
+        std::fs::write(&self.path, contents).expect(Self::WRITE_FAILURE_MSG);
+    }
+}
+
+/// Build the SARIF report. The `line` function locates the line declaring the MSRV within the
+/// given Cargo manifest.
+fn sarif_report(
+    results: &[VerifyResult],
+    line: impl Fn(&Path) -> Option<usize>,
+) -> serde_json::Value {
+    let findings = results
+        .iter()
+        .filter(|result| result.status() == VerifyStatus::Incompatible)
+        .map(|result| finding(result, &line))
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "cargo-msrv",
+                    "informationUri": "https://github.com/foresterre/cargo-msrv",
+                    "version": option_env!("CARGO_PKG_VERSION").unwrap_or("unknown"),
+                    "rules": [{
+                        "id": RULE_ID,
+                        "name": "IncorrectMsrv",
+                        "shortDescription": {
+                            "text": "The declared MSRV is incorrect"
+                        },
+                        "fullDescription": {
+                            "text": "The crate does not compile with the Rust version declared as its MSRV."
+                        },
+                        "defaultConfiguration": {
+                            "level": "error"
+                        }
+                    }]
+                }
+            },
+            "results": findings
+        }]
+    })
+}
+
+fn finding(result: &VerifyResult, line: impl Fn(&Path) -> Option<usize>) -> serde_json::Value {
+    let mut message = format!(
+        "The crate is not compatible with its declared MSRV (Rust {})",
+        result.rust_version()
+    );
+
+    if let Some(diagnosis) = result.diagnosis() {
+        message.push_str(&format!(", because {}", diagnosis));
+    }
+
+    let locations = result
+        .manifest_path()
+        .map(|path| {
+            let mut location = serde_json::json!({
+                "physicalLocation": {
+                    "artifactLocation": {
+                        "uri": artifact_uri(path)
+                    }
+                }
+            });
+
+            if let Some(line) = line(path) {
+                location["physicalLocation"]["region"] = serde_json::json!({ "startLine": line });
+            }
+
+            vec![location]
+        })
+        .unwrap_or_default();
+
+    serde_json::json!({
+        "ruleId": RULE_ID,
+        "level": "error",
+        "message": {
+            "text": message
+        },
+        "locations": locations
+    })
+}
+
+/// The path of the artifact, relative to the current directory if possible, with forward slashes
+/// as required by the URI format.
+fn artifact_uri(path: &Path) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf());
+
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// The (one-based) line of the Cargo manifest which declares the MSRV.
+fn manifest_msrv_line(path: &Path) -> Option<usize> {
+    let contents = std::fs::read_to_string(path).ok()?;
+
+    msrv_line(&contents)
+}
+
+/// Find the `rust-version` key, or else the `msrv` key of the `package.metadata` table.
+fn msrv_line(contents: &str) -> Option<usize> {
+    let find_key = |key: &str| {
+        contents.lines().position(|line| {
+            line.trim_start()
+                .strip_prefix(key)
+                .map_or(false, |rest| rest.trim_start().starts_with('='))
+        })
+    };
+
+    find_key("rust-version")
+        .or_else(|| find_key("msrv"))
+        .or_else(|| find_key("metadata"))
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::bare_version::BareVersion;
+    use crate::reporter::Event;
+    use test_dir::{DirBuilder, TestDir};
+
+    #[yare::parameterized(
+        rust_version = { "[package]\nname = \"a\"\nrust-version = \"1.56\"\n", Some(3) },
+        metadata = { "[package]\nname = \"a\"\n\n[package.metadata]\nmsrv = \"1.56\"\n", Some(5) },
+        inline_metadata = { "[package]\nname = \"a\"\nmetadata = { msrv = \"1.56\" }\n", Some(3) },
+        none = { "[package]\nname = \"a\"\n", None },
+    )]
+    fn line_of_msrv(contents: &str, expected: Option<usize>) {
+        assert_eq!(msrv_line(contents), expected);
+    }
+
+    #[test]
+    fn report_only_contains_incompatible_results() {
+        let results = vec![
+            VerifyResult::compatible(
+                BareVersion::TwoComponents(1, 60),
+                Some(PathBuf::from("a/Cargo.toml")),
+            ),
+            VerifyResult::incompatible(
+                BareVersion::TwoComponents(1, 56),
+                Some(PathBuf::from("b/Cargo.toml")),
+                None,
+            ),
+        ];
+
+        let report = sarif_report(&results, |_| Some(4));
+        let findings = report["runs"][0]["results"].as_array().unwrap();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0]["ruleId"], RULE_ID);
+        assert_eq!(
+            findings[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "b/Cargo.toml"
+        );
+        assert_eq!(
+            findings[0]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            4
+        );
+    }
+
+    #[test]
+    fn writes_report_on_finish() {
+        let tmp = TestDir::temp();
+        let path = tmp.path("cargo-msrv.sarif");
+        let handler = SarifHandler::new(&path);
+
+        let event: Event = VerifyResult::incompatible(
+            BareVersion::TwoComponents(1, 56),
+            None,
+            Some("it uses an unstable feature".to_string()),
+        )
+        .into();
+
+        handler.handle(event);
+        handler.finish();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("Rust 1.56"));
+        assert!(contents.contains("it uses an unstable feature"));
+    }
+}
@@ -0,0 +1,252 @@
+use crate::reporter::event::Message;
+use crate::reporter::handler::json_handler::SendWriter;
+use std::io;
+use std::io::Stdout;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use storyteller::EventHandler;
+use toml_edit::{value, ArrayOfTables, Document, Item, Table};
+
+/// Collects the result of cargo-msrv, and prints it as a TOML document, once all events have been
+/// handled.
+///
+/// Unlike the `json-lines` output format, which prints each event as it happens, only the final
+/// result is printed, so it can easily be spliced into other configuration files.
+pub struct TomlHandler<W: SendWriter> {
+    writer: Mutex<W>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct State {
+    msrv: Option<String>,
+    manifest_path: Option<PathBuf>,
+    error: Option<String>,
+    checks: Vec<Check>,
+    packages: Vec<Package>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Check {
+    toolchain: String,
+    version: String,
+    compatible: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct Package {
+    name: String,
+    msrv: Option<String>,
+}
+
+impl<W: SendWriter> TomlHandler<W> {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock state for TomlHandler";
+    const WRITE_FAILURE_MSG: &'static str = "Unable to write TOML output for TomlHandler";
+}
+
+impl TomlHandler<Stdout> {
+    /// Print the result to stdout. The `manifest_path` is included in the output, if given.
+    pub fn stdout(manifest_path: Option<&Path>) -> Self {
+        Self {
+            writer: Mutex::new(io::stdout()),
+            state: Mutex::new(State {
+                manifest_path: manifest_path.map(Path::to_path_buf),
+                ..State::default()
+            }),
+        }
+    }
+}
+
+impl<W: SendWriter> EventHandler for TomlHandler<W> {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        let mut state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        match event.message() {
+            Message::Compatibility(compatibility) => {
+                let toolchain = compatibility.toolchain();
+
+                state.checks.push(Check {
+                    toolchain: toolchain.spec().to_string(),
+                    version: toolchain.version().to_string(),
+                    compatible: compatibility.is_compatible(),
+                });
+            }
+            Message::MsrvResult(result) => {
+                state.msrv = result.msrv().map(ToString::to_string);
+            }
+            Message::WorkspaceMsrvResult(result) => {
+                state.msrv = result.workspace_msrv().map(ToString::to_string);
+                state.packages = result
+                    .packages()
+                    .iter()
+                    .map(|package| Package {
+                        name: package.name().to_string(),
+                        msrv: package.msrv().map(ToString::to_string),
+                    })
+                    .collect();
+            }
+            Message::FeatureMatrixResult(result) => {
+                state.msrv = result.msrv().map(ToString::to_string);
+            }
+            Message::SetOutput(output) => {
+                state.msrv = Some(output.version().to_string());
+                state.manifest_path = Some(output.manifest_path().to_path_buf());
+            }
+            Message::ShowOutput(output) => {
+                state.msrv = Some(output.version().to_string());
+                state.manifest_path = Some(output.manifest_path().to_path_buf());
+            }
+            Message::TerminateWithFailure(termination) => {
+                state.error = Some(termination.as_message().to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(&self) {
+        let state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+        let document = toml_report(&state);
+
+        let mut w = self.writer.lock().expect(Self::LOCK_FAILURE_MSG);
+        write!(&mut w, "{}", document).expect(Self::WRITE_FAILURE_MSG);
+    }
+}
+
+fn toml_report(state: &State) -> Document {
+    let mut document = Document::new();
+
+    if let Some(msrv) = &state.msrv {
+        document["msrv"] = value(msrv.as_str());
+    }
+
+    if let Some(path) = &state.manifest_path {
+        document["manifest_path"] = value(path.display().to_string());
+    }
+
+    if let Some(error) = &state.error {
+        document["error"] = value(error.as_str());
+    }
+
+    if !state.checks.is_empty() {
+        let mut checks = ArrayOfTables::new();
+
+        for check in &state.checks {
+            let mut table = Table::new();
+            table["toolchain"] = value(check.toolchain.as_str());
+            table["version"] = value(check.version.as_str());
+            table["compatible"] = value(check.compatible);
+            checks.push(table);
+        }
+
+        document["checks"] = Item::ArrayOfTables(checks);
+    }
+
+    if !state.packages.is_empty() {
+        let mut packages = ArrayOfTables::new();
+
+        for package in &state.packages {
+            let mut table = Table::new();
+            table["name"] = value(package.name.as_str());
+
+            if let Some(msrv) = &package.msrv {
+                table["msrv"] = value(msrv.as_str());
+            }
+
+            packages.push(table);
+        }
+
+        document["packages"] = Item::ArrayOfTables(packages);
+    }
+
+    document
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report() {
+        let state = State {
+            msrv: Some("1.56.0".to_string()),
+            manifest_path: Some(Path::new("crate").join("Cargo.toml")),
+            error: None,
+            checks: vec![
+                Check {
+                    toolchain: "1.55.0-x86_64-unknown-linux-gnu".to_string(),
+                    version: "1.55.0".to_string(),
+                    compatible: false,
+                },
+                Check {
+                    toolchain: "1.56.0-x86_64-unknown-linux-gnu".to_string(),
+                    version: "1.56.0".to_string(),
+                    compatible: true,
+                },
+            ],
+            packages: vec![],
+        };
+
+        let expected = format!(
+            r#"msrv = "1.56.0"
+manifest_path = "{}"
+
+[[checks]]
+toolchain = "1.55.0-x86_64-unknown-linux-gnu"
+version = "1.55.0"
+compatible = false
+
+[[checks]]
+toolchain = "1.56.0-x86_64-unknown-linux-gnu"
+version = "1.56.0"
+compatible = true
+"#,
+            Path::new("crate").join("Cargo.toml").display()
+        );
+
+        assert_eq!(toml_report(&state).to_string(), expected);
+    }
+
+    #[test]
+    fn workspace_report() {
+        let state = State {
+            msrv: Some("1.58.0".to_string()),
+            packages: vec![
+                Package {
+                    name: "a".to_string(),
+                    msrv: Some("1.58.0".to_string()),
+                },
+                Package {
+                    name: "b".to_string(),
+                    msrv: None,
+                },
+            ],
+            ..State::default()
+        };
+
+        let expected = r#"msrv = "1.58.0"
+
+[[packages]]
+name = "a"
+msrv = "1.58.0"
+
+[[packages]]
+name = "b"
+"#;
+
+        assert_eq!(toml_report(&state).to_string(), expected);
+    }
+
+    #[test]
+    fn error_report() {
+        let state = State {
+            error: Some("Unable to find a Minimum Supported Rust Version (MSRV)".to_string()),
+            ..State::default()
+        };
+
+        let expected = "error = \"Unable to find a Minimum Supported Rust Version (MSRV)\"\n";
+
+        assert_eq!(toml_report(&state).to_string(), expected);
+    }
+}
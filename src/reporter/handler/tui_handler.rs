@@ -0,0 +1,319 @@
+use crate::check::skip;
+use crate::reporter::event::Message;
+use crate::semver;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::{cursor, event, queue, style, terminal};
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::{self, Stderr, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use storyteller::EventHandler;
+
+/// The width of a single Rust version in the grid of the search space.
+const CELL_WIDTH: usize = 12;
+
+/// Shows a live view of the search in the alternate screen of the terminal, and listens for key
+/// presses, to skip the running check or to quit.
+///
+/// Once finished, the terminal is restored, and the result is printed to stderr.
+pub struct TuiHandler {
+    terminal: Mutex<Stderr>,
+    state: Mutex<State>,
+    stop_input: Arc<AtomicBool>,
+    input: Mutex<Option<thread::JoinHandle<()>>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    search_space: Vec<semver::Version>,
+    checks: HashMap<semver::Version, CheckState>,
+    current: Option<semver::Version>,
+    remaining: Option<u64>,
+    result: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CheckState {
+    Compatible,
+    Incompatible,
+    Skipped,
+}
+
+impl TuiHandler {
+    const LOCK_FAILURE_MSG: &'static str = "Unable to lock state for TuiHandler";
+
+    pub fn stderr() -> Self {
+        let mut terminal = io::stderr();
+
+        let _ = terminal::enable_raw_mode();
+        let _ = crossterm::execute!(terminal, terminal::EnterAlternateScreen, cursor::Hide);
+
+        let stop_input = Arc::new(AtomicBool::new(false));
+        let input = {
+            let stop_input = Arc::clone(&stop_input);
+            thread::spawn(move || listen_for_keys(&stop_input))
+        };
+
+        Self {
+            terminal: Mutex::new(terminal),
+            state: Mutex::new(State::default()),
+            stop_input,
+            input: Mutex::new(Some(input)),
+        }
+    }
+
+    fn draw(&self, state: &State) {
+        let width = terminal::size()
+            .map(|(columns, _)| usize::from(columns))
+            .unwrap_or(80);
+
+        let mut terminal = self.terminal.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        let _ = queue!(terminal, terminal::Clear(terminal::ClearType::All));
+        for (row, line) in render(state, width).iter().enumerate() {
+            let row = u16::try_from(row).unwrap_or(u16::MAX);
+            let _ = queue!(terminal, cursor::MoveTo(0, row), style::Print(line));
+        }
+        let _ = terminal.flush();
+    }
+}
+
+impl EventHandler for TuiHandler {
+    type Event = super::Event;
+
+    fn handle(&self, event: Self::Event) {
+        let mut state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        match event.message() {
+            Message::FindMsrv(find) if event.is_scope_start() => {
+                state.search_space = find.search_space().to_vec();
+                state.checks.clear();
+                state.remaining = None;
+            }
+            Message::CheckToolchain(it) if event.is_scope_start() => {
+                state.current = Some(it.toolchain.version().clone());
+            }
+            Message::CheckToolchain(_) /* is scope end */ => {
+                state.current = None;
+            }
+            Message::CheckSkipped(skipped) => {
+                let version = skipped.toolchain().version().clone();
+                state.checks.insert(version, CheckState::Skipped);
+            }
+            Message::Compatibility(compatibility) => {
+                let version = compatibility.toolchain().version().clone();
+                let check = if compatibility.is_compatible() {
+                    CheckState::Compatible
+                } else {
+                    CheckState::Incompatible
+                };
+
+                // A skipped check is also reported as incompatible
+                state.checks.entry(version).or_insert(check);
+            }
+            Message::Progress(progress) => {
+                state.remaining = Some(progress.remaining());
+            }
+            Message::MsrvResult(result) => {
+                state.result = Some(match result.msrv() {
+                    Some(msrv) => format!("The MSRV is Rust {}", msrv),
+                    None => "Unable to find a Minimum Supported Rust Version (MSRV)".to_string(),
+                });
+            }
+            Message::TerminateWithFailure(termination) => {
+                state.result = Some(termination.as_message().to_string());
+            }
+            _ => return,
+        }
+
+        self.draw(&state);
+    }
+
+    fn finish(&self) {
+        self.stop_input.store(true, Ordering::SeqCst);
+        if let Some(input) = self.input.lock().expect(Self::LOCK_FAILURE_MSG).take() {
+            let _ = input.join();
+        }
+
+        restore_terminal();
+
+        let state = self.state.lock().expect(Self::LOCK_FAILURE_MSG);
+        let mut terminal = self.terminal.lock().expect(Self::LOCK_FAILURE_MSG);
+
+        let mut skipped = state
+            .checks
+            .iter()
+            .filter(|(_, check)| **check == CheckState::Skipped)
+            .map(|(version, _)| version.to_string())
+            .collect::<Vec<_>>();
+        skipped.sort();
+
+        if !skipped.is_empty() {
+            let _ = writeln!(terminal, "Skipped checks: Rust {}", skipped.join(", "));
+        }
+
+        if let Some(result) = &state.result {
+            let _ = writeln!(terminal, "{}", result);
+        }
+    }
+}
+
+/// Listen for key presses until `stop` is set: `s` skips the running check, and `q` (or
+/// `ctrl+c`, which doesn't raise a signal in raw mode) quits.
+fn listen_for_keys(stop: &AtomicBool) {
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    while !stop.load(Ordering::SeqCst) {
+        if !event::poll(POLL_INTERVAL).unwrap_or(false) {
+            continue;
+        }
+
+        match event::read() {
+            Ok(event::Event::Key(KeyEvent {
+                code: KeyCode::Char('s'),
+                ..
+            })) => skip::request_skip(),
+            Ok(event::Event::Key(KeyEvent {
+                code: KeyCode::Char('q'),
+                ..
+            }))
+            | Ok(event::Event::Key(KeyEvent {
+                code: KeyCode::Char('c'),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            })) => {
+                restore_terminal();
+                std::process::exit(130);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn restore_terminal() {
+    let _ = crossterm::execute!(io::stderr(), cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+}
+
+/// The lines of the view of the search, for a terminal of the given width.
+fn render(state: &State, width: usize) -> Vec<String> {
+    let mut lines = vec![format!("{}", "cargo-msrv".bold())];
+
+    lines.push(match &state.current {
+        Some(version) => format!(
+            "Checking Rust {} {}",
+            version,
+            "(press 's' to skip this check, 'q' to quit)".dimmed()
+        ),
+        None => format!("{}", "Waiting for the next check ('q' to quit)".dimmed()),
+    });
+
+    if let Some(remaining) = state.remaining {
+        lines.push(format!("At most {} checks left", remaining));
+    }
+
+    lines.push(String::new());
+
+    let per_row = (width / CELL_WIDTH).max(1);
+    for row in state.search_space.chunks(per_row) {
+        let line = row
+            .iter()
+            .map(|version| cell(version, state))
+            .collect::<String>();
+
+        lines.push(line);
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "{} compatible  {} incompatible  {} skipped  {} checking  {} not checked",
+        "✓".green(),
+        "✗".red(),
+        "↷".yellow(),
+        "▶".blue(),
+        "·".dimmed(),
+    ));
+
+    if let Some(result) = &state.result {
+        lines.push(String::new());
+        lines.push(format!("{}", result.bold()));
+    }
+
+    lines
+}
+
+/// A single Rust version in the grid of the search space, padded to the cell width.
+fn cell(version: &semver::Version, state: &State) -> String {
+    let label = format!("{:<width$}", version, width = CELL_WIDTH - 2);
+
+    if state.current.as_ref() == Some(version) {
+        return format!("{} {}", "▶".blue(), label.blue());
+    }
+
+    match state.checks.get(version) {
+        Some(CheckState::Compatible) => format!("{} {}", "✓".green(), label),
+        Some(CheckState::Incompatible) => format!("{} {}", "✗".red(), label),
+        Some(CheckState::Skipped) => format!("{} {}", "↷".yellow(), label),
+        None => format!("{} {}", "·".dimmed(), label.dimmed()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> State {
+        let search_space = vec![
+            semver::Version::new(1, 3, 0),
+            semver::Version::new(1, 2, 0),
+            semver::Version::new(1, 1, 0),
+        ];
+
+        let mut checks = HashMap::new();
+        checks.insert(semver::Version::new(1, 3, 0), CheckState::Compatible);
+        checks.insert(semver::Version::new(1, 1, 0), CheckState::Skipped);
+
+        State {
+            search_space,
+            checks,
+            current: Some(semver::Version::new(1, 2, 0)),
+            remaining: Some(1),
+            result: None,
+        }
+    }
+
+    #[test]
+    fn render_wraps_search_space_to_width() {
+        let lines = render(&state(), CELL_WIDTH * 2);
+
+        let grid = lines
+            .iter()
+            .filter(|line| line.contains("1.3.0") || line.contains("1.1.0"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(grid.len(), 2);
+        assert!(grid[0].contains("1.2.0"));
+    }
+
+    #[test]
+    fn render_shows_current_check_and_remaining() {
+        let lines = render(&state(), 80);
+
+        assert!(lines[1].contains("Checking Rust 1.2.0"));
+        assert_eq!(lines[2], "At most 1 checks left");
+    }
+
+    #[test]
+    fn render_shows_result() {
+        let mut state = state();
+        state.result = Some("The MSRV is Rust 1.3.0".to_string());
+
+        let lines = render(&state, 80);
+
+        assert!(lines.last().unwrap().contains("The MSRV is Rust 1.3.0"));
+    }
+}
@@ -0,0 +1,167 @@
+/// The value of the `type` field of each kind of event, i.e. the name of each variant of
+/// `Message`, in snake case.
+///
+/// This list must be kept in sync with the variants of `Message`.
+pub(crate) const MESSAGE_TYPES: &[&str] = &[
+    "action",
+    "meta",
+    "fetch_index",
+    "fetch_index_failed",
+    "preflight_estimate",
+    "setup_toolchain",
+    "setup_toolchain_progress",
+    "setup_toolchain_retry",
+    "check_toolchain",
+    "compatibility_check_method",
+    "compatibility",
+    "check_output",
+    "check_skipped",
+    "check_timed_out",
+    "subprocess_started",
+    "subprocess_finished",
+    "temporary_lockfile",
+    "toolchain_file_bypassed",
+    "search_cancelled",
+    "toolchain_cleanup",
+    "planned_checks",
+    "auxiliary_output",
+    "msrv_result",
+    "manifest_msrv_mismatch",
+    "msrv_clamped_to_edition",
+    "msrv_causes",
+    "search_space_pruned",
+    "invalid_search_space",
+    "find_msrv",
+    "search_probe",
+    "progress",
+    "workspace_msrv_result",
+    "feature_matrix_result",
+    "verify_result",
+    "workspace_verify_result",
+    "certification_outdated",
+    "lower_msrv_available",
+    "list_dep",
+    "msrv_coverage",
+    "dependency_msrv_verified",
+    "set_output",
+    "show_output",
+    "msrv_sources",
+    "edition_floor",
+    "doctor_report",
+    "policy_result",
+    "upgrade_suggestions",
+    "lockfile_pinned",
+    "msrv_diff",
+    "watch_iteration",
+    "watch_iteration_failed",
+    "terminate_with_failure",
+];
+
+/// A JSON Schema (draft 2020-12) which describes a single event, as printed by the `json-lines`
+/// output format, and as an element of the array printed by the `json` output format.
+///
+/// Each event is an object with a `type` field, which identifies the kind of event, and an
+/// optional `scope` field, which is present for events which mark the start and end of an
+/// action. The remaining fields depend on the kind of event.
+pub(crate) fn event_schema() -> serde_json::Value {
+    let kinds = MESSAGE_TYPES
+        .iter()
+        .map(|kind| {
+            serde_json::json!({
+                "if": { "properties": { "type": { "const": kind } } },
+                "then": { "$ref": format!("#/$defs/{}", kind) },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let definitions = MESSAGE_TYPES
+        .iter()
+        .map(|kind| {
+            let definition = serde_json::json!({
+                "type": "object",
+                "properties": { "type": { "const": kind } },
+                "required": ["type"],
+            });
+
+            (kind.to_string(), definition)
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "https://github.com/foresterre/cargo-msrv/event.schema.json",
+        "title": "cargo-msrv event",
+        "description": "An event reported by cargo-msrv, as printed by the json and json-lines output formats",
+        "version": option_env!("CARGO_PKG_VERSION").unwrap_or("unknown"),
+        "type": "object",
+        "properties": {
+            "type": {
+                "description": "The kind of event",
+                "enum": MESSAGE_TYPES,
+            },
+            "scope": {
+                "description": "Whether the event marks the start or the end of an action",
+                "enum": ["start", "end"],
+            },
+        },
+        "required": ["type"],
+        "allOf": kinds,
+        "$defs": definitions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::{
+        ActionMessage, Message, Meta, MsrvCoverage, Progress, SearchCancelled, SearchProbe,
+        SubprocessFinished, TerminateWithFailure,
+    };
+    use crate::search_method::Heuristic;
+    use crate::{semver, Action, CargoMSRVError, Event};
+    use std::collections::HashSet;
+
+    /// The `type` of the given message, as it's serialized.
+    fn message_type(message: Message) -> String {
+        let value = serde_json::to_value(Event::from(message)).unwrap();
+        value["type"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn message_types_are_unique() {
+        let unique = MESSAGE_TYPES.iter().collect::<HashSet<_>>();
+
+        assert_eq!(unique.len(), MESSAGE_TYPES.len());
+    }
+
+    #[yare::parameterized(
+        action = { Message::Action(ActionMessage::new(Action::Find)) },
+        meta = { Message::Meta(Meta::default()) },
+        progress = { Message::Progress(Progress::new(1, 10, 1, 3)) },
+        msrv_coverage = { Message::MsrvCoverage(MsrvCoverage::default()) },
+        search_probe = { Message::SearchProbe(SearchProbe::new(semver::Version::new(1, 56, 0), Heuristic::Edition)) },
+        search_cancelled = { Message::SearchCancelled(SearchCancelled::new(Vec::new(), Vec::new(), false)) },
+        subprocess_finished = { Message::SubprocessFinished(SubprocessFinished::killed(1, Vec::new(), std::time::Duration::ZERO)) },
+        terminate_with_failure = { Message::TerminateWithFailure(TerminateWithFailure::new(CargoMSRVError::Cancelled)) },
+    )]
+    fn serialized_type_is_described(message: Message) {
+        assert!(MESSAGE_TYPES.contains(&message_type(message).as_str()));
+    }
+
+    #[test]
+    fn schema_describes_each_type() {
+        let schema = event_schema();
+
+        assert_eq!(
+            schema["properties"]["type"]["enum"]
+                .as_array()
+                .unwrap()
+                .len(),
+            MESSAGE_TYPES.len()
+        );
+
+        for kind in MESSAGE_TYPES {
+            assert_eq!(schema["$defs"][kind]["properties"]["type"]["const"], *kind);
+        }
+    }
+}
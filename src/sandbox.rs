@@ -0,0 +1,165 @@
+//! A copy of the workspace in a temporary directory, from which the checks are run when
+//! sandboxed, so the toolchains can't modify the lockfile, the target directory or files
+//! generated by build scripts in the working tree.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::workspace::workspace_root_manifest;
+
+/// Directories which are not copied, since they're not needed to check the project, and can be
+/// large.
+const SKIPPED_DIRS: &[&str] = &["target", ".git"];
+
+/// Distinguishes the sandboxes created by a single run of cargo-msrv.
+static SANDBOXES: AtomicUsize = AtomicUsize::new(0);
+
+/// A copy of a workspace in a temporary directory. The directory is removed when dropped.
+pub(crate) struct Sandbox {
+    source: PathBuf,
+    dir: PathBuf,
+}
+
+impl Sandbox {
+    /// Copy the workspace to which the crate with the given Cargo manifest belongs, or the crate
+    /// itself if it's not part of a workspace.
+    pub(crate) fn new(manifest_path: &Path) -> TResult<Self> {
+        let root_manifest = workspace_root_manifest(manifest_path)?;
+        let root = root_manifest.parent().unwrap_or_else(|| Path::new("."));
+
+        Self::copy(root)
+    }
+
+    fn copy(root: &Path) -> TResult<Self> {
+        let source = root.canonicalize().map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(root.to_path_buf()),
+        })?;
+
+        let dir = std::env::temp_dir().join(format!(
+            "cargo-msrv-sandbox-{}-{}",
+            std::process::id(),
+            SANDBOXES.fetch_add(1, Ordering::SeqCst)
+        ));
+
+        debug!(source = %source.display(), sandbox = %dir.display(), "copying into sandbox");
+
+        // Create the guard first, so a partial copy is removed as well
+        let sandbox = Self { source, dir };
+        copy_dir(&sandbox.source, &sandbox.dir)?;
+
+        Ok(sandbox)
+    }
+
+    /// The path within the sandbox which corresponds to the given path, or `None` if the path
+    /// isn't part of the sandboxed workspace.
+    pub(crate) fn path_of(&self, path: &Path) -> Option<PathBuf> {
+        let path = path.canonicalize().ok()?;
+        let relative = path.strip_prefix(&self.source).ok()?;
+
+        Some(self.dir.join(relative))
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        if let Err(error) = fs::remove_dir_all(&self.dir) {
+            warn!(%error, path = %self.dir.display(), "unable to remove sandbox");
+        }
+    }
+}
+
+/// Recursively copy the contents of the `from` directory into the `to` directory, skipping the
+/// [`SKIPPED_DIRS`].
+pub(crate) fn copy_dir(from: &Path, to: &Path) -> TResult<()> {
+    fs::create_dir_all(to).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(to.to_path_buf()),
+    })?;
+
+    let entries = fs::read_dir(from).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(from.to_path_buf()),
+    })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(from.to_path_buf()),
+        })?;
+
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+
+        if path.is_dir() {
+            let skipped = entry
+                .file_name()
+                .to_str()
+                .map_or(false, |name| SKIPPED_DIRS.contains(&name));
+
+            if !skipped {
+                copy_dir(&path, &target)?;
+            }
+        } else {
+            fs::copy(&path, &target).map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::WriteFile(target.clone()),
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[test]
+    fn copies_workspace_without_skipped_dirs() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("Cargo.lock", FileType::EmptyFile)
+            .create("member", FileType::Dir)
+            .create("member/Cargo.toml", FileType::EmptyFile)
+            .create("target", FileType::Dir)
+            .create("target/out", FileType::EmptyFile);
+
+        let sandbox = Sandbox::copy(tmp.root()).unwrap();
+        let member = sandbox.path_of(&tmp.path("member")).unwrap();
+
+        assert!(member.join("Cargo.toml").is_file());
+        assert!(sandbox
+            .path_of(tmp.root())
+            .unwrap()
+            .join("Cargo.lock")
+            .is_file());
+        assert!(!sandbox.path_of(tmp.root()).unwrap().join("target").exists());
+    }
+
+    #[test]
+    fn path_outside_of_sandbox() {
+        let tmp = TestDir::temp()
+            .create("crate", FileType::Dir)
+            .create("crate/Cargo.toml", FileType::EmptyFile)
+            .create("other", FileType::Dir);
+
+        let sandbox = Sandbox::copy(&tmp.path("crate")).unwrap();
+
+        assert!(sandbox.path_of(&tmp.path("other")).is_none());
+    }
+
+    #[test]
+    fn sandbox_is_removed_on_drop() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+
+        let sandbox = Sandbox::copy(tmp.root()).unwrap();
+        let root = sandbox.path_of(tmp.root()).unwrap();
+        drop(sandbox);
+
+        assert!(!root.exists());
+    }
+}
@@ -1,6 +1,11 @@
 use rust_releases::Release;
 
-pub use {bisect::Bisect, linear::Linear};
+pub use {
+    bisect::Bisect,
+    gallop::Gallop,
+    linear::Linear,
+    prior::{Heuristic, Prior},
+};
 
 use crate::msrv::MinimumSupportedRustVersion;
 use crate::reporter::Reporter;
@@ -9,12 +14,21 @@ use crate::{Config, TResult};
 /// Use a bisection method to find the MSRV. By using a binary search, we halve our search space each
 /// step, making this an efficient search function.
 pub(crate) mod bisect;
+/// Find the MSRV by stepping back from the most-recent version, with steps which double in size,
+/// until an incompatible version is found, and then bisecting the last step. This is faster than
+/// a binary search if the MSRV is recent.
+pub(crate) mod gallop;
 /// Find the MSRV by stepping through the most-recent to least-recent version, one-by-one. This is
 /// not very efficient, but is useful as a baseline, or if you're certain the MSRV is very close to
 /// the head.
 pub(crate) mod linear;
+/// Rust versions which are likely to be close to the MSRV, such as the MSRV declared in the Cargo
+/// manifest, which are checked before the search space is bisected.
+pub(crate) mod prior;
 
-pub trait FindMinimalSupportedRustVersion {
+/// A strategy to search for the MSRV, i.e. which Rust versions of the search space are checked,
+/// and in which order.
+pub trait SearchStrategy {
     /// Method to find the minimum capable toolchain.
     ///
     /// The search space must be ordered from most to least recent.
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use bisector::{Bisector, ConvergeTo, Indices, Step};
 use rust_releases::Release;
 
@@ -5,27 +7,42 @@ use crate::check::Check;
 use crate::error::NoToolchainsToTryError;
 use crate::msrv::MinimumSupportedRustVersion;
 use crate::outcome::{FailureOutcome, Outcome, SuccessOutcome};
-use crate::reporter::event::{FindMsrv, Progress};
+use crate::reporter::event::{FindMsrv, Progress, SearchProbe};
 use crate::reporter::Reporter;
-use crate::search_method::FindMinimalSupportedRustVersion;
+use crate::search_method::{Heuristic, Prior, SearchStrategy};
 use crate::toolchain::{OwnedToolchainSpec, ToolchainSpec};
 use crate::{Config, TResult};
 
 pub struct Bisect<'runner, R: Check> {
     runner: &'runner R,
+    priors: Vec<Prior>,
 }
 
 impl<'runner, R: Check> Bisect<'runner, R> {
     pub fn new(runner: &'runner R) -> Self {
-        Self { runner }
+        Self {
+            runner,
+            priors: Vec::new(),
+        }
+    }
+
+    /// Check the releases chosen by the given priors, in order, before the remaining search space
+    /// is bisected. When a prior is compatible, the release preceding it is checked as well, since
+    /// the prior is then likely to be the MSRV.
+    pub fn with_priors(mut self, priors: Vec<Prior>) -> Self {
+        self.priors = priors;
+        self
     }
 
     fn run_check(
         runner: &R,
         release: &Release,
         config: &Config,
-        _reporter: &impl Reporter,
+        reporter: &impl Reporter,
+        heuristic: Heuristic,
     ) -> TResult<ConvergeTo<FailureOutcome, SuccessOutcome>> {
+        reporter.report_event(SearchProbe::new(release.version().clone(), heuristic))?;
+
         let toolchain = ToolchainSpec::new(release.version(), config.target());
         match runner.check(config, &toolchain) {
             Ok(outcome) => match outcome {
@@ -39,12 +56,12 @@ impl<'runner, R: Check> Bisect<'runner, R> {
     fn show_progress(
         iteration: u64,
         total: u64,
-        indices: Indices,
+        current: usize,
         reporter: &impl Reporter,
     ) -> TResult<()> {
-        let current = indices.middle() as u64;
+        let remaining = max_steps(total).saturating_sub(iteration);
 
-        reporter.report_event(Progress::new(current, total, iteration))?;
+        reporter.report_event(Progress::new(current as u64, total, iteration, remaining))?;
 
         Ok(())
     }
@@ -61,87 +78,233 @@ impl<'runner, R: Check> Bisect<'runner, R> {
             },
         )
     }
-}
 
-impl<'runner, R: Check> FindMinimalSupportedRustVersion for Bisect<'runner, R> {
-    fn find_toolchain(
+    /// Check the releases chosen by the priors, and narrow the part of the search space which
+    /// remains to be bisected accordingly.
+    fn probe_priors(
         &self,
         search_space: &[Release],
+        iteration: &mut u64,
         config: &Config,
         reporter: &impl Reporter,
-    ) -> TResult<MinimumSupportedRustVersion> {
-        reporter.run_scoped_event(FindMsrv::new(config.search_method()), || {
-            let searcher = Bisector::new(search_space);
+    ) -> TResult<Bounds> {
+        let mut bounds = Bounds::default();
 
-            let total = search_space.len() as u64;
-            let mut iteration = 0_u64;
-            let mut indices =
-                Indices::try_from_bisector(&searcher).map_err(|_| NoToolchainsToTryError {
-                    min: config.minimum_version().map(Clone::clone),
-                    max: config.maximum_version().map(Clone::clone),
-                    search_space: search_space.to_vec(),
-                })?;
+        for prior in &self.priors {
+            let index = match prior.index_in(search_space) {
+                Some(index) => index,
+                None => continue,
+            };
 
-            let mut last_compatible_index = None;
+            info!(version = %prior.version(), heuristic = %prior.heuristic(), index, "probing prior");
 
-            info!(?search_space);
+            self.probe(
+                search_space,
+                index,
+                prior.heuristic(),
+                &mut bounds,
+                iteration,
+                config,
+                reporter,
+            )?;
 
-            while let Step {
-                indices: next_indices,
-                result: Some(step),
-            } = searcher.try_bisect(
-                |release| Self::run_check(self.runner, release, config, reporter),
-                indices,
-            )? {
-                iteration += 1;
+            if bounds.compatible == Some(index) {
+                self.probe(
+                    search_space,
+                    index + 1,
+                    Heuristic::Neighbour,
+                    &mut bounds,
+                    iteration,
+                    config,
+                    reporter,
+                )?;
+            }
+        }
 
-                info!(?indices, ?next_indices);
+        Ok(bounds)
+    }
 
-                Self::show_progress(iteration, total, indices, reporter)?;
+    /// Check a single release, unless its compatibility already follows from the bounds.
+    #[allow(clippy::too_many_arguments)]
+    fn probe(
+        &self,
+        search_space: &[Release],
+        index: usize,
+        heuristic: Heuristic,
+        bounds: &mut Bounds,
+        iteration: &mut u64,
+        config: &Config,
+        reporter: &impl Reporter,
+    ) -> TResult<()> {
+        if !bounds.unknown(search_space.len()).contains(&index) {
+            return Ok(());
+        }
 
-                match step {
-                    ConvergeTo::Left(_outcome) => {}
-                    ConvergeTo::Right(_outcome) => {
-                        last_compatible_index = Some(indices);
-                    }
-                }
+        match Self::run_check(
+            self.runner,
+            &search_space[index],
+            config,
+            reporter,
+            heuristic,
+        )? {
+            ConvergeTo::Left(_outcome) => bounds.incompatible = Some(index),
+            ConvergeTo::Right(_outcome) => bounds.compatible = Some(index),
+        }
 
-                indices = next_indices;
+        *iteration += 1;
+        Self::show_progress(*iteration, search_space.len() as u64, index, reporter)?;
+
+        Ok(())
+    }
+
+    /// Bisect the given part of the search space, and return the index of the least recent
+    /// compatible release within it, if any.
+    fn bisect(
+        &self,
+        search_space: &[Release],
+        range: Range<usize>,
+        iteration: &mut u64,
+        config: &Config,
+        reporter: &impl Reporter,
+    ) -> TResult<Option<usize>> {
+        let total = search_space.len() as u64;
+        let offset = range.start;
+        let search_space = &search_space[range];
+
+        let searcher = Bisector::new(search_space);
+
+        let mut indices =
+            Indices::try_from_bisector(&searcher).map_err(|_| NoToolchainsToTryError {
+                min: config.minimum_version().map(Clone::clone),
+                max: config.maximum_version().map(Clone::clone),
+                search_space: search_space.to_vec(),
+            })?;
+
+        let mut last_compatible_index = None;
+
+        info!(?search_space);
+
+        while let Step {
+            indices: next_indices,
+            result: Some(step),
+        } = searcher.try_bisect(
+            |release| Self::run_check(self.runner, release, config, reporter, Heuristic::Bisection),
+            indices,
+        )? {
+            *iteration += 1;
+
+            info!(?indices, ?next_indices);
+
+            Self::show_progress(*iteration, total, offset + indices.middle(), reporter)?;
+
+            match step {
+                ConvergeTo::Left(_outcome) => {}
+                ConvergeTo::Right(_outcome) => {
+                    last_compatible_index = Some(indices.middle());
+                }
             }
 
-            let converged_to_release = &search_space[indices.middle()];
+            indices = next_indices;
+        }
+
+        let converged_to_release = &search_space[indices.middle()];
 
-            // Work-around for regression:
-            // https://github.com/foresterre/cargo-msrv/issues/288
-            let msrv = if indices.middle() == search_space.len() - 1 {
-                Self::show_progress(iteration + 1, total, indices, reporter)?;
+        // Work-around for regression:
+        // https://github.com/foresterre/cargo-msrv/issues/288
+        let msrv = if indices.middle() == search_space.len() - 1 {
+            Self::show_progress(*iteration + 1, total, offset + indices.middle(), reporter)?;
 
-                match Self::run_check(self.runner, converged_to_release, config, reporter)? {
-                    ConvergeTo::Left(_outcome) => {
-                        last_compatible_index.map(|i| &search_space[i.middle()])
-                    }
-                    ConvergeTo::Right(_outcome) => Some(converged_to_release),
+            match Self::run_check(
+                self.runner,
+                converged_to_release,
+                config,
+                reporter,
+                Heuristic::Bisection,
+            )? {
+                ConvergeTo::Left(_outcome) => last_compatible_index,
+                ConvergeTo::Right(_outcome) => Some(indices.middle()),
+            }
+        } else {
+            last_compatible_index
+        };
+
+        Ok(msrv.map(|index| offset + index))
+    }
+}
+
+impl<'runner, R: Check> SearchStrategy for Bisect<'runner, R> {
+    fn find_toolchain(
+        &self,
+        search_space: &[Release],
+        config: &Config,
+        reporter: &impl Reporter,
+    ) -> TResult<MinimumSupportedRustVersion> {
+        reporter.run_scoped_event(FindMsrv::new(config.search_method(), search_space), || {
+            if search_space.is_empty() {
+                return Err(NoToolchainsToTryError {
+                    min: config.minimum_version().map(Clone::clone),
+                    max: config.maximum_version().map(Clone::clone),
+                    search_space: search_space.to_vec(),
                 }
+                .into());
+            }
+
+            let mut iteration = 0_u64;
+
+            let bounds = self.probe_priors(search_space, &mut iteration, config, reporter)?;
+            let unknown = bounds.unknown(search_space.len());
+
+            // The releases which were not ruled out by the priors are bisected. If none of them
+            // is compatible, the least recent compatible prior, if any, is the MSRV.
+            let msrv = if unknown.is_empty() {
+                bounds.compatible
             } else {
-                last_compatible_index.map(|i| &search_space[i.middle()])
+                self.bisect(search_space, unknown, &mut iteration, config, reporter)?
+                    .or(bounds.compatible)
             };
 
-            Ok(Self::minimum_capable(msrv, config))
+            Ok(Self::minimum_capable(
+                msrv.map(|index| &search_space[index]),
+                config,
+            ))
         })
     }
 }
 
+/// What is known about the compatibility of the search space, which is ordered from most to
+/// least recent: the releases up to and including `compatible` are compatible, and the releases
+/// from `incompatible` onwards are not.
+#[derive(Clone, Copy, Debug, Default)]
+struct Bounds {
+    compatible: Option<usize>,
+    incompatible: Option<usize>,
+}
+
+impl Bounds {
+    /// The indices of the releases whose compatibility is not yet known.
+    fn unknown(&self, len: usize) -> Range<usize> {
+        self.compatible.map_or(0, |index| index + 1)..self.incompatible.unwrap_or(len)
+    }
+}
+
+/// The maximum number of steps a binary search over `len` items takes.
+pub(crate) fn max_steps(len: u64) -> u64 {
+    u64::from(u64::BITS - len.leading_zeros())
+}
+
 #[cfg(test)]
 mod tests {
     use rust_releases::Release;
 
     use crate::check::TestRunner;
+    use crate::msrv::MinimumSupportedRustVersion;
+    use crate::reporter::event::{Message, SearchProbe};
     use crate::reporter::TestReporter;
-    use crate::search_method::FindMinimalSupportedRustVersion;
+    use crate::search_method::{Heuristic, Prior, SearchStrategy};
     use crate::semver::Version;
     use crate::{semver, Action, Config};
 
-    use super::Bisect;
+    use super::{max_steps, Bisect};
 
     fn fake_config() -> Config<'static> {
         Config::new(Action::Find, "".to_string())
@@ -349,4 +512,103 @@ mod tests {
 
         assert_eq!(result.unwrap_version(), expected_msrv);
     }
+
+    fn search_space(len: u64) -> Vec<Release> {
+        (0..len)
+            .rev()
+            .map(|minor| Release::new_stable(semver::Version::new(1, minor, 0)))
+            .collect()
+    }
+
+    #[yare::parameterized(
+        prior_is_msrv = { 10, 4, &[4] },
+        prior_is_too_old = { 10, 4, &[1] },
+        prior_is_too_new = { 10, 4, &[8] },
+        prior_is_newer_than_search_space = { 10, 4, &[20] },
+        priors_surround_msrv = { 10, 4, &[7, 2] },
+        priors_contradict = { 10, 4, &[2, 7] },
+        msrv_is_least_recent = { 10, 0, &[3] },
+        msrv_is_most_recent = { 10, 9, &[3] },
+        no_msrv = { 10, 10, &[3] },
+        one_option = { 1, 0, &[0] },
+    )]
+    fn find_toolchain_with_priors(len: u64, msrv_minor: u64, prior_minors: &[u64]) {
+        let search_space = search_space(len);
+        let accept = search_space
+            .iter()
+            .map(Release::version)
+            .filter(|version| version.minor >= msrv_minor);
+
+        let runner = TestRunner::with_ok(accept);
+        let priors = prior_minors
+            .iter()
+            .map(|&minor| Prior::new(semver::Version::new(1, minor, 0), Heuristic::DeclaredMsrv))
+            .collect();
+        let bisect = Bisect::new(&runner).with_priors(priors);
+
+        let reporter = TestReporter::default();
+
+        let result = bisect
+            .find_toolchain(&search_space, &fake_config(), reporter.reporter())
+            .unwrap();
+
+        if msrv_minor < len {
+            assert_eq!(result.unwrap_version(), Version::new(1, msrv_minor, 0));
+        } else {
+            assert_eq!(result, MinimumSupportedRustVersion::NoCompatibleToolchain);
+        }
+    }
+
+    #[test]
+    fn prior_which_is_msrv_is_confirmed_by_its_neighbour() {
+        let search_space = search_space(50);
+        let accept = search_space
+            .iter()
+            .map(Release::version)
+            .filter(|version| version.minor >= 31);
+
+        let runner = TestRunner::with_ok(accept);
+        let priors = vec![
+            Prior::new(Version::new(1, 31, 0), Heuristic::DeclaredMsrv),
+            Prior::new(Version::new(1, 20, 0), Heuristic::Edition),
+        ];
+        let bisect = Bisect::new(&runner).with_priors(priors);
+
+        let reporter = TestReporter::default();
+
+        let result = bisect
+            .find_toolchain(&search_space, &fake_config(), reporter.reporter())
+            .unwrap();
+
+        assert_eq!(result.unwrap_version(), Version::new(1, 31, 0));
+
+        let probes = reporter
+            .wait_for_events()
+            .into_iter()
+            .filter_map(|event| match event.message() {
+                Message::SearchProbe(probe) => Some(probe.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            probes,
+            vec![
+                SearchProbe::new(Version::new(1, 31, 0), Heuristic::DeclaredMsrv),
+                SearchProbe::new(Version::new(1, 30, 0), Heuristic::Neighbour),
+            ]
+        );
+    }
+
+    #[yare::parameterized(
+        empty = { 0, 0 },
+        one = { 1, 1 },
+        two = { 2, 2 },
+        three = { 3, 2 },
+        four = { 4, 3 },
+        fifty = { 50, 6 },
+    )]
+    fn max_steps_for_len(len: u64, expected: u64) {
+        assert_eq!(max_steps(len), expected);
+    }
 }
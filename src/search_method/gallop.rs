@@ -0,0 +1,200 @@
+use rust_releases::Release;
+
+use crate::check::Check;
+use crate::error::NoToolchainsToTryError;
+use crate::msrv::MinimumSupportedRustVersion;
+use crate::outcome::Outcome;
+use crate::reporter::event::{FindMsrv, Progress};
+use crate::reporter::Reporter;
+use crate::search_method::{bisect, SearchStrategy};
+use crate::toolchain::{OwnedToolchainSpec, ToolchainSpec};
+use crate::{Config, TResult};
+
+pub struct Gallop<'runner, R: Check> {
+    runner: &'runner R,
+}
+
+impl<'runner, R: Check> Gallop<'runner, R> {
+    pub fn new(runner: &'runner R) -> Self {
+        Self { runner }
+    }
+
+    fn is_compatible(&self, release: &Release, config: &Config) -> TResult<bool> {
+        let toolchain = ToolchainSpec::new(release.version(), config.target());
+
+        Ok(matches!(
+            self.runner.check(config, &toolchain)?,
+            Outcome::Success(_)
+        ))
+    }
+
+    fn minimum_capable(msrv: Option<&Release>, config: &Config) -> MinimumSupportedRustVersion {
+        msrv.map_or(
+            MinimumSupportedRustVersion::NoCompatibleToolchain,
+            |release| MinimumSupportedRustVersion::Toolchain {
+                toolchain: OwnedToolchainSpec::new(release.version(), config.target()),
+            },
+        )
+    }
+}
+
+impl<'runner, R: Check> SearchStrategy for Gallop<'runner, R> {
+    fn find_toolchain(
+        &self,
+        search_space: &[Release],
+        config: &Config,
+        reporter: &impl Reporter,
+    ) -> TResult<MinimumSupportedRustVersion> {
+        reporter.run_scoped_event(FindMsrv::new(config.search_method(), search_space), || {
+            if search_space.is_empty() {
+                return Err(NoToolchainsToTryError {
+                    min: config.minimum_version().map(Clone::clone),
+                    max: config.maximum_version().map(Clone::clone),
+                    search_space: search_space.to_vec(),
+                }
+                .into());
+            }
+
+            let total = search_space.len() as u64;
+            let mut iteration = 0_u64;
+
+            let mut check = |index: usize| -> TResult<bool> {
+                iteration += 1;
+                let remaining = max_steps(total).saturating_sub(iteration);
+                reporter.report_event(Progress::new(index as u64, total, iteration, remaining))?;
+
+                self.is_compatible(&search_space[index], config)
+            };
+
+            // Gallop from the most recent release towards the least recent release, until an
+            // incompatible release is found. The MSRV is then the least recent compatible release
+            // between the last compatible, and the first incompatible release.
+            let mut last_compatible = None;
+            let mut first_incompatible = None;
+            let mut index = 0;
+            let mut step = 1;
+
+            loop {
+                if check(index)? {
+                    last_compatible = Some(index);
+                } else {
+                    first_incompatible = Some(index);
+                    break;
+                }
+
+                if index == search_space.len() - 1 {
+                    break;
+                }
+
+                index = (index + step).min(search_space.len() - 1);
+                step *= 2;
+            }
+
+            let (mut compatible, mut incompatible) = match (last_compatible, first_incompatible) {
+                (Some(compatible), Some(incompatible)) => (compatible, incompatible),
+                // The least recent release is compatible
+                (compatible, None) => {
+                    let msrv = compatible.map(|i| &search_space[i]);
+                    return Ok(Self::minimum_capable(msrv, config));
+                }
+                // The most recent release is incompatible
+                (None, Some(_)) => {
+                    return Ok(MinimumSupportedRustVersion::NoCompatibleToolchain);
+                }
+            };
+
+            while incompatible - compatible > 1 {
+                let middle = compatible + (incompatible - compatible) / 2;
+
+                if check(middle)? {
+                    compatible = middle;
+                } else {
+                    incompatible = middle;
+                }
+            }
+
+            Ok(Self::minimum_capable(
+                Some(&search_space[compatible]),
+                config,
+            ))
+        })
+    }
+}
+
+/// The maximum number of steps a galloping search over `len` items takes: the steps to find an
+/// incompatible release, and the steps of the binary search thereafter.
+pub(crate) fn max_steps(len: u64) -> u64 {
+    2 * bisect::max_steps(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_releases::Release;
+
+    use crate::check::TestRunner;
+    use crate::reporter::TestReporter;
+    use crate::search_method::SearchStrategy;
+    use crate::{semver, Action, Config};
+
+    use super::Gallop;
+
+    fn search_space(len: u64) -> Vec<Release> {
+        (0..len)
+            .rev()
+            .map(|minor| Release::new_stable(semver::Version::new(1, minor, 0)))
+            .collect()
+    }
+
+    #[yare::parameterized(
+        one_all_succeed = { 1, 0 },
+        two_all_succeed = { 2, 0 },
+        two_most_recent_succeeds = { 2, 1 },
+        five_all_succeed = { 5, 0 },
+        five_most_recent_two_succeed = { 5, 3 },
+        ten_most_recent_succeeds = { 10, 9 },
+        ten_most_recent_three_succeed = { 10, 7 },
+        ten_most_recent_seven_succeed = { 10, 3 },
+        fifty_all_succeed = { 50, 0 },
+        fifty_most_recent_eleven_succeed = { 50, 39 },
+    )]
+    fn find_toolchain_with_gallop(len: u64, msrv_minor: u64) {
+        let search_space = search_space(len);
+        let accept = search_space
+            .iter()
+            .map(Release::version)
+            .filter(|version| version.minor >= msrv_minor);
+
+        let runner = TestRunner::with_ok(accept);
+        let gallop = Gallop::new(&runner);
+        let reporter = TestReporter::default();
+        let config = Config::new(Action::Find, "".to_string());
+
+        let result = gallop
+            .find_toolchain(&search_space, &config, reporter.reporter())
+            .unwrap();
+
+        assert_eq!(
+            result.unwrap_version(),
+            semver::Version::new(1, msrv_minor, 0)
+        );
+    }
+
+    #[test]
+    fn none_supported() {
+        let search_space = search_space(5);
+
+        let runner = TestRunner::with_ok(std::iter::empty());
+        let gallop = Gallop::new(&runner);
+        let reporter = TestReporter::default();
+        let config = Config::new(Action::Find, "".to_string());
+
+        let result = gallop
+            .find_toolchain(&search_space, &config, reporter.reporter())
+            .unwrap();
+
+        assert_eq!(
+            result,
+            crate::msrv::MinimumSupportedRustVersion::NoCompatibleToolchain
+        );
+    }
+}
@@ -5,7 +5,7 @@ use crate::msrv::MinimumSupportedRustVersion;
 use crate::outcome::Outcome;
 use crate::reporter::event::FindMsrv;
 use crate::reporter::Reporter;
-use crate::search_method::FindMinimalSupportedRustVersion;
+use crate::search_method::SearchStrategy;
 use crate::toolchain::{OwnedToolchainSpec, ToolchainSpec};
 use crate::{Config, TResult};
 
@@ -18,16 +18,6 @@ impl<'runner, R: Check> Linear<'runner, R> {
         Self { runner }
     }
 
-    fn run_check(
-        runner: &R,
-        release: &Release,
-        config: &Config,
-        _reporter: &impl Reporter,
-    ) -> TResult<Outcome> {
-        let toolchain = ToolchainSpec::new(release.version(), config.target());
-        runner.check(config, &toolchain)
-    }
-
     fn minimum_capable(
         releases: &[Release],
         index_of_msrv: Option<usize>,
@@ -43,27 +33,38 @@ impl<'runner, R: Check> Linear<'runner, R> {
     }
 }
 
-impl<'runner, R: Check> FindMinimalSupportedRustVersion for Linear<'runner, R> {
+impl<'runner, R: Check> SearchStrategy for Linear<'runner, R> {
     fn find_toolchain<'spec>(
         &self,
         search_space: &'spec [Release],
         config: &'spec Config,
         reporter: &impl Reporter,
     ) -> TResult<MinimumSupportedRustVersion> {
-        reporter.run_scoped_event(FindMsrv::new(config.search_method()), || {
+        reporter.run_scoped_event(FindMsrv::new(config.search_method(), search_space), || {
             let mut last_compatible_index = None;
 
-            for (i, release) in search_space.iter().enumerate() {
-                let outcome = Self::run_check(self.runner, release, config, reporter)?;
+            // Toolchains are checked in batches of `jobs` toolchains, which may be checked
+            // concurrently by the runner
+            let jobs = config.jobs().max(1);
+
+            'search: for (n, batch) in search_space.chunks(jobs).enumerate() {
+                let toolchains = batch
+                    .iter()
+                    .map(|release| ToolchainSpec::new(release.version(), config.target()))
+                    .collect::<Vec<_>>();
+
+                let outcomes = self.runner.check_many(config, &toolchains)?;
 
-                match outcome {
-                    Outcome::Failure(_outcome) => {
-                        break;
+                for (i, outcome) in outcomes.iter().enumerate() {
+                    match outcome {
+                        Outcome::Failure(_outcome) => {
+                            break 'search;
+                        }
+                        Outcome::Success(_outcome) => {}
                     }
-                    Outcome::Success(_outcome) => {}
-                }
 
-                last_compatible_index = Some(i);
+                    last_compatible_index = Some(n * jobs + i);
+                }
             }
 
             Ok(Self::minimum_capable(
@@ -79,6 +80,7 @@ impl<'runner, R: Check> FindMinimalSupportedRustVersion for Linear<'runner, R> {
 mod tests {
     use super::*;
     use crate::check::TestRunner;
+    use crate::config::ConfigBuilder;
     use crate::reporter::TestReporter;
     use crate::{semver, Action, Config, ReleaseIndex};
     use rust_releases::Release;
@@ -130,6 +132,42 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn all_supported_with_jobs() {
+        let config = ConfigBuilder::new(Action::Find, "my-test-target")
+            .jobs(2)
+            .build();
+        let reporter = TestReporter::default();
+
+        let supported_releases = vec![
+            Release::new_stable(semver::Version::new(1, 56, 0)),
+            Release::new_stable(semver::Version::new(1, 55, 0)),
+            Release::new_stable(semver::Version::new(1, 54, 0)),
+        ];
+
+        let index_of_releases = vec![
+            Release::new_stable(semver::Version::new(1, 56, 0)),
+            Release::new_stable(semver::Version::new(1, 55, 0)),
+            Release::new_stable(semver::Version::new(1, 54, 0)),
+            Release::new_stable(semver::Version::new(1, 53, 0)),
+            Release::new_stable(semver::Version::new(1, 52, 0)),
+        ];
+
+        let runner = TestRunner::with_ok(supported_releases.iter().map(Release::version));
+        let index = ReleaseIndex::from_iter(index_of_releases);
+
+        let linear_search = Linear::new(&runner);
+        let actual = linear_search
+            .find_toolchain(index.releases(), &config, reporter.reporter())
+            .unwrap();
+
+        let expected = MinimumSupportedRustVersion::Toolchain {
+            toolchain: OwnedToolchainSpec::new(&semver::Version::new(1, 54, 0), "my-test-target"),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn most_recent_only() {
         let config = Config::new(Action::Find, "my-test-target".to_string());
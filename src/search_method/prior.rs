@@ -0,0 +1,102 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use rust_releases::Release;
+
+use crate::semver;
+
+/// A Rust version which is likely to be close to the MSRV of the crate, and which is therefore
+/// checked before the search space is bisected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Prior {
+    version: semver::Version,
+    heuristic: Heuristic,
+}
+
+impl Prior {
+    pub fn new(version: semver::Version, heuristic: Heuristic) -> Self {
+        Self { version, heuristic }
+    }
+
+    pub fn version(&self) -> &semver::Version {
+        &self.version
+    }
+
+    pub fn heuristic(&self) -> Heuristic {
+        self.heuristic
+    }
+
+    /// The index of the least recent release of the search space which is at least as recent as
+    /// the version of this prior, i.e. the release which would be the MSRV if the prior is right.
+    ///
+    /// The search space must be ordered from most to least recent. Returns `None` if the prior is
+    /// more recent than each release of the search space.
+    pub(crate) fn index_in(&self, search_space: &[Release]) -> Option<usize> {
+        search_space
+            .iter()
+            .rposition(|release| release.version() >= &self.version)
+    }
+}
+
+/// The heuristic which chose the Rust version to check next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Heuristic {
+    /// The MSRV which was previously recorded in the Cargo manifest.
+    DeclaredMsrv,
+    /// The greatest MSRV declared by the dependencies of the crate.
+    DependencyMsrv,
+    /// The first Rust version which supports the edition of the crate.
+    Edition,
+    /// The release preceding a compatible prior, to confirm it is the MSRV.
+    Neighbour,
+    /// The middle of the remaining search space.
+    Bisection,
+    /// A patch release preceding the MSRV found at minor granularity, within the same minor
+    /// release.
+    PatchRefinement,
+}
+
+impl fmt::Display for Heuristic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::DeclaredMsrv => "declared msrv",
+            Self::DependencyMsrv => "dependency msrv",
+            Self::Edition => "edition",
+            Self::Neighbour => "neighbour of compatible prior",
+            Self::Bisection => "bisection",
+            Self::PatchRefinement => "patch refinement",
+        };
+
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_releases::Release;
+
+    use crate::semver;
+
+    use super::{Heuristic, Prior};
+
+    fn search_space() -> Vec<Release> {
+        (54..=58)
+            .rev()
+            .map(|minor| Release::new_stable(semver::Version::new(1, minor, 0)))
+            .collect()
+    }
+
+    #[yare::parameterized(
+        exact = { semver::Version::new(1, 56, 0), Some(2) },
+        between = { semver::Version::new(1, 55, 1), Some(2) },
+        most_recent = { semver::Version::new(1, 58, 0), Some(0) },
+        older_than_search_space = { semver::Version::new(1, 40, 0), Some(4) },
+        newer_than_search_space = { semver::Version::new(1, 60, 0), None },
+    )]
+    fn index_in(version: semver::Version, expected: Option<usize>) {
+        let prior = Prior::new(version, Heuristic::DeclaredMsrv);
+
+        assert_eq!(prior.index_in(&search_space()), expected);
+    }
+}
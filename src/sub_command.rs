@@ -3,16 +3,29 @@
 /// Use case:
 ///
 /// * Run `cargo msrv verify` on the CI, to verify the crates MSRV is acceptable.
-pub use {find::Find, list::List, set::Set, show::Show, verify::Verify};
+pub use {
+    diff::Diff, doctor::Doctor, dry_run::DryRun, find::Find, list::List, pin_lockfile::PinLockfile,
+    policy::Policy, schema::Schema, set::Set, show::Show, upgrade::Upgrade, verify::Verify,
+    verify_deps::VerifyDeps, watch::Watch,
+};
 
 use crate::reporter::Reporter;
 use crate::{Config, TResult};
 
+pub(crate) mod diff;
+pub(crate) mod doctor;
+pub(crate) mod dry_run;
 pub(crate) mod find;
 pub(crate) mod list;
+pub(crate) mod pin_lockfile;
+pub(crate) mod policy;
+pub(crate) mod schema;
 pub(crate) mod set;
 pub(crate) mod show;
+pub(crate) mod upgrade;
 pub(crate) mod verify;
+pub(crate) mod verify_deps;
+pub(crate) mod watch;
 
 /// A sub-command of `cargo-msrv`.
 ///
@@ -0,0 +1,204 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use rust_releases::ReleaseIndex;
+
+use crate::check::Check;
+use crate::config::{Config, ConfigBuilder};
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::reporter::event::{DiffStatus, MsrvDiff};
+use crate::reporter::Reporter;
+use crate::sub_command::find::find;
+use crate::{semver, SubCommand};
+
+/// Compares the MSRV of the crate at two git revisions.
+///
+/// Fails if the MSRV of the head revision is greater than the MSRV of the base revision.
+pub struct Diff<'index, C: Check> {
+    release_index: &'index ReleaseIndex,
+    runner: C,
+}
+
+impl<'index, C: Check> Diff<'index, C> {
+    pub fn new(release_index: &'index ReleaseIndex, runner: C) -> Self {
+        Self {
+            release_index,
+            runner,
+        }
+    }
+}
+
+impl<'index, C: Check> SubCommand for Diff<'index, C> {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        let cmd = config.sub_command_config().diff();
+        let crate_root = config.context().crate_root_path()?;
+        let prefix = repository_prefix(crate_root)?;
+
+        let base_msrv = {
+            let worktree = Worktree::add(crate_root, &cmd.base, "base")?;
+            let crate_path = worktree.path().join(&prefix);
+
+            revision_msrv(
+                config,
+                reporter,
+                self.release_index,
+                &self.runner,
+                &crate_path,
+            )?
+        };
+
+        let head_msrv = match &cmd.head {
+            Some(head) => {
+                let worktree = Worktree::add(crate_root, head, "head")?;
+                let crate_path = worktree.path().join(&prefix);
+
+                revision_msrv(
+                    config,
+                    reporter,
+                    self.release_index,
+                    &self.runner,
+                    &crate_path,
+                )?
+            }
+            None => revision_msrv(
+                config,
+                reporter,
+                self.release_index,
+                &self.runner,
+                crate_root,
+            )?,
+        };
+
+        let diff = MsrvDiff::new(cmd.base.as_str(), base_msrv, cmd.head.clone(), head_msrv);
+        let status = diff.status();
+
+        reporter.report_event(diff)?;
+
+        if status == DiffStatus::Increased {
+            return Err(Error::MsrvIncreased {
+                base: cmd.base.clone(),
+                head: cmd
+                    .head
+                    .clone()
+                    .unwrap_or_else(|| "the working tree".to_string()),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Find the MSRV of the crate at the given path, or `None` if no Rust version is compatible.
+///
+/// Nothing is written to the checked out revision.
+fn revision_msrv(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+    crate_path: &Path,
+) -> TResult<Option<semver::Version>> {
+    info!(path = %crate_path.display(), "finding msrv of revision");
+
+    let revision_config = ConfigBuilder::from_config(config)
+        .crate_path(Some(crate_path))
+        .manifest_path(Option::<&Path>::None)
+        .output_toolchain_file(false)
+        .write_msrv(false)
+        .write_badge(Option::<&Path>::None)
+        .build();
+
+    match find(&revision_config, reporter, release_index, runner) {
+        Ok(version) => Ok(Some(version)),
+        Err(CargoMSRVError::UnableToFindAnyGoodVersion { .. }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// The path of the crate root, relative to the root of the git repository which contains it.
+fn repository_prefix(crate_root: &Path) -> TResult<PathBuf> {
+    let output = git(crate_root, &["rev-parse", "--show-prefix"])?;
+    let prefix = String::from_utf8_lossy(&output.stdout);
+
+    Ok(PathBuf::from(prefix.trim()))
+}
+
+/// A temporary git worktree, in which a revision is checked out. The worktree is removed when
+/// dropped.
+struct Worktree {
+    repository: PathBuf,
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn add(repository: &Path, revision: &str, name: &str) -> TResult<Self> {
+        let path =
+            std::env::temp_dir().join(format!("cargo-msrv-diff-{}-{}", std::process::id(), name));
+
+        debug!(%revision, path = %path.display(), "adding git worktree");
+
+        let worktree = path.to_string_lossy();
+        git(
+            repository,
+            &["worktree", "add", "--detach", &worktree, revision],
+        )?;
+
+        Ok(Self {
+            repository: repository.to_path_buf(),
+            path,
+        })
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let worktree = self.path.to_string_lossy();
+
+        if let Err(error) = git(
+            &self.repository,
+            &["worktree", "remove", "--force", &worktree],
+        ) {
+            warn!(%error, path = %self.path.display(), "unable to remove git worktree");
+        }
+    }
+}
+
+/// Run `git -C <dir> <args>`, and fail if git exits with a non-zero exit code.
+fn git(dir: &Path, args: &[&str]) -> TResult<Output> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::SpawnProcess(OsString::from("git")),
+        })?;
+
+    if !output.status.success() {
+        return Err(Error::GitFailed {
+            command: format!("git {}", args.join(" ")),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }
+        .into());
+    }
+
+    Ok(output)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Unable to run `{command}`: {stderr}")]
+    GitFailed { command: String, stderr: String },
+
+    #[error("The MSRV of '{head}' is greater than the MSRV of '{base}'")]
+    MsrvIncreased { base: String, head: String },
+}
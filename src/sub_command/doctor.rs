@@ -0,0 +1,272 @@
+use std::convert::TryFrom;
+use std::ffi::OsStr;
+
+use toml_edit::Document;
+
+use crate::command::RustupCommand;
+use crate::config::Config;
+use crate::error::TResult;
+use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
+use crate::reporter::event::{DoctorCheck, DoctorReport, Finding};
+use crate::reporter::Reporter;
+use crate::SubCommand;
+
+/// Diagnose the environment in which cargo-msrv runs, and report actionable findings.
+#[derive(Default)]
+pub struct Doctor;
+
+impl SubCommand for Doctor {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        let findings = vec![
+            check_rustup(),
+            check_installed_toolchains(),
+            check_release_index(config, reporter),
+        ]
+        .into_iter()
+        .chain(check_manifest(config))
+        .collect();
+
+        let report = DoctorReport::new(findings);
+        let problems = report.problems().count();
+
+        reporter.report_event(report)?;
+
+        if problems > 0 {
+            return Err(Error::ProblemsFound(problems).into());
+        }
+
+        Ok(())
+    }
+}
+
+fn check_rustup() -> Finding {
+    let output = RustupCommand::new()
+        .with_stdout()
+        .execute(OsStr::new("--version"));
+
+    match output {
+        Ok(output) if output.exit_status().success() => Finding::ok(
+            DoctorCheck::Rustup,
+            output.stdout().lines().next().unwrap_or("rustup").trim(),
+        ),
+        _ => Finding::problem(
+            DoctorCheck::Rustup,
+            "rustup is not available",
+            "cargo-msrv uses rustup to install and run Rust toolchains. Install rustup from https://rustup.rs, and make sure it can be found on the PATH",
+        ),
+    }
+}
+
+fn check_installed_toolchains() -> Finding {
+    let output = RustupCommand::new()
+        .with_args(["list"])
+        .with_stdout()
+        .execute(OsStr::new("toolchain"));
+
+    let output = match output {
+        Ok(output) if output.exit_status().success() => output,
+        _ => {
+            return Finding::problem(
+                DoctorCheck::InstalledToolchains,
+                "Unable to list the installed toolchains",
+                "Run `rustup toolchain list` to find out why",
+            )
+        }
+    };
+
+    let toolchains = installed_toolchains(output.stdout());
+
+    if toolchains == 0 {
+        Finding::warning(
+            DoctorCheck::InstalledToolchains,
+            "No toolchains are installed",
+            "cargo-msrv will install toolchains as needed, but a default toolchain is required to run Cargo. Run `rustup default stable` to install one",
+        )
+    } else {
+        Finding::ok(
+            DoctorCheck::InstalledToolchains,
+            format!("{} toolchain(s) installed", toolchains),
+        )
+    }
+}
+
+/// Count the toolchains listed by `rustup toolchain list`.
+fn installed_toolchains(output: &str) -> usize {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("no installed toolchains"))
+        .count()
+}
+
+fn check_release_index(config: &Config, reporter: &impl Reporter) -> Finding {
+    match crate::fetch_index(config, reporter) {
+        Ok(index) => Finding::ok(
+            DoctorCheck::ReleaseIndex,
+            format!(
+                "Fetched {} releases from the '{}' release source",
+                index.releases().len(),
+                config.release_source()
+            ),
+        ),
+        Err(err) => Finding::problem(
+            DoctorCheck::ReleaseIndex,
+            format!(
+                "Unable to fetch the release index from the '{}' release source: {}",
+                config.release_source(),
+                err
+            ),
+            "Check your network connection and proxy settings, or try another release source with `--release-source`",
+        ),
+    }
+}
+
+/// Check whether the Cargo manifest can be found and parsed, and whether it specifies an MSRV.
+fn check_manifest(config: &Config) -> Vec<Finding> {
+    let manifest_path = match config.context().manifest_path() {
+        Ok(path) => path,
+        Err(err) => {
+            return vec![Finding::problem(
+                DoctorCheck::Manifest,
+                format!("Unable to locate the Cargo manifest: {}", err),
+                "Run cargo-msrv from the crate root, or use `--path` or `--manifest-path` to point to the crate",
+            )]
+        }
+    };
+
+    let manifest = std::fs::read_to_string(manifest_path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| {
+            CargoManifestParser::default()
+                .parse::<Document>(&contents)
+                .map_err(|err| err.to_string())
+        })
+        .and_then(|document| CargoManifest::try_from(document).map_err(|err| err.to_string()));
+
+    let manifest = match manifest {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            return vec![Finding::problem(
+                DoctorCheck::Manifest,
+                format!(
+                    "Unable to read the Cargo manifest at '{}': {}",
+                    manifest_path.display(),
+                    err
+                ),
+                "Make sure the Cargo manifest is valid, for example by running `cargo metadata`",
+            )]
+        }
+    };
+
+    let rust_version = match manifest.minimum_rust_version() {
+        Some(version) => Finding::ok(
+            DoctorCheck::RustVersion,
+            format!("The MSRV is specified as Rust {}", version),
+        ),
+        None => Finding::warning(
+            DoctorCheck::RustVersion,
+            "The Cargo manifest does not specify an MSRV",
+            "Run `cargo msrv --write-msrv` to find the MSRV and write it to the Cargo manifest, or set it with `cargo msrv set`",
+        ),
+    };
+
+    vec![
+        Finding::ok(
+            DoctorCheck::Manifest,
+            format!("Found Cargo manifest at '{}'", manifest_path.display()),
+        ),
+        rust_version,
+    ]
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Found {0} problem(s) which may prevent cargo-msrv from working properly")]
+    ProblemsFound(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use crate::reporter::event::FindingStatus;
+    use crate::Action;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    fn statuses(findings: &[Finding]) -> Vec<(DoctorCheck, FindingStatus)> {
+        findings
+            .iter()
+            .map(|finding| (finding.check(), finding.status()))
+            .collect()
+    }
+
+    #[test]
+    fn manifest_with_msrv() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+        std::fs::write(
+            tmp.path("Cargo.toml"),
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\nrust-version = \"1.56\"\n",
+        )
+        .unwrap();
+
+        let config = ConfigBuilder::new(Action::Doctor, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        assert_eq!(
+            statuses(&check_manifest(&config)),
+            vec![
+                (DoctorCheck::Manifest, FindingStatus::Ok),
+                (DoctorCheck::RustVersion, FindingStatus::Ok),
+            ]
+        );
+    }
+
+    #[test]
+    fn manifest_without_msrv() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+        std::fs::write(
+            tmp.path("Cargo.toml"),
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let config = ConfigBuilder::new(Action::Doctor, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        assert_eq!(
+            statuses(&check_manifest(&config)),
+            vec![
+                (DoctorCheck::Manifest, FindingStatus::Ok),
+                (DoctorCheck::RustVersion, FindingStatus::Warning),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_manifest() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+        std::fs::write(tmp.path("Cargo.toml"), "[package").unwrap();
+
+        let config = ConfigBuilder::new(Action::Doctor, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        assert_eq!(
+            statuses(&check_manifest(&config)),
+            vec![(DoctorCheck::Manifest, FindingStatus::Problem)]
+        );
+    }
+
+    #[yare::parameterized(
+        none = { "no installed toolchains\n", 0 },
+        one = { "stable-x86_64-unknown-linux-gnu (default)\n", 1 },
+        two = { "stable-x86_64-unknown-linux-gnu (default)\n1.56.0-x86_64-unknown-linux-gnu\n", 2 },
+    )]
+    fn count_installed_toolchains(output: &str, expected: usize) {
+        assert_eq!(installed_toolchains(output), expected);
+    }
+}
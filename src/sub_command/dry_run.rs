@@ -0,0 +1,152 @@
+use rust_releases::{Release, ReleaseIndex};
+
+use crate::check::RustupToolchainCheck;
+use crate::config::{Action, Config};
+use crate::error::TResult;
+use crate::reporter::event::{
+    AuxiliaryOutput, AuxiliaryOutputItem, Destination, MsrvKind, PlannedChecks,
+};
+use crate::reporter::Reporter;
+use crate::sub_command::{find, verify, SubCommand};
+use crate::toolchain::ToolchainSpec;
+use crate::writer::clippy_config::planned_clippy_config;
+use crate::writer::env_file::planned_env_files;
+use crate::writer::toolchain_file::planned_toolchain_file;
+
+/// Reports the checks which `find` or `verify` would run, without installing toolchains or
+/// running the checks, and the files which `find` would write once the MSRV is found.
+pub struct DryRun<'index, 'reporter, R: Reporter> {
+    release_index: &'index ReleaseIndex,
+    runner: RustupToolchainCheck<'reporter, R>,
+}
+
+impl<'index, 'reporter, R: Reporter> DryRun<'index, 'reporter, R> {
+    pub fn new(
+        release_index: &'index ReleaseIndex,
+        runner: RustupToolchainCheck<'reporter, R>,
+    ) -> Self {
+        Self {
+            release_index,
+            runner,
+        }
+    }
+}
+
+impl<'index, 'reporter, R: Reporter> SubCommand for DryRun<'index, 'reporter, R> {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        let versions = match config.action() {
+            Action::Verify => verify::rust_versions(config, self.release_index)?,
+            _ => find::search_space(config, reporter, self.release_index)?
+                .iter()
+                .map(Release::version)
+                .cloned()
+                .collect(),
+        };
+
+        let mut checks = Vec::with_capacity(versions.len());
+
+        for version in &versions {
+            let toolchain = ToolchainSpec::new(version, config.target());
+            checks.extend(self.runner.planned_checks(config, &toolchain)?);
+        }
+
+        reporter.report_event(PlannedChecks::new(checks))?;
+
+        if config.action() == Action::Find {
+            for output in planned_outputs(config)? {
+                reporter.report_event(output)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The side effects which `find` would have after finding the MSRV, in the order in which they
+/// would happen.
+fn planned_outputs(config: &Config) -> TResult<Vec<AuxiliaryOutput>> {
+    let mut outputs = Vec::new();
+
+    if config.output_toolchain_file() {
+        outputs.push(planned_toolchain_file(config)?);
+    }
+
+    if config.write_msrv() {
+        outputs.push(AuxiliaryOutput::planned(
+            Destination::Manifest(config.context().manifest_path()?.to_path_buf()),
+            AuxiliaryOutputItem::msrv(MsrvKind::RustVersion),
+        ));
+
+        if config.sync_clippy() {
+            outputs.push(planned_clippy_config(config)?);
+        }
+    }
+
+    if config.certify() {
+        outputs.push(AuxiliaryOutput::planned(
+            Destination::Manifest(config.context().manifest_path()?.to_path_buf()),
+            AuxiliaryOutputItem::certification(),
+        ));
+    }
+
+    if let Some(path) = config.write_badge() {
+        outputs.push(AuxiliaryOutput::planned(
+            Destination::File(path.to_path_buf()),
+            AuxiliaryOutputItem::badge(),
+        ));
+    }
+
+    if let Some(write_env) = config.write_env() {
+        outputs.extend(planned_env_files(write_env)?);
+    }
+
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigBuilder;
+    use crate::reporter::event::ToolchainFileKind;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[test]
+    fn no_planned_outputs_by_default() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+        let config = ConfigBuilder::new(Action::Find, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        assert!(planned_outputs(&config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn planned_outputs_in_order_of_writing() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+        let config = ConfigBuilder::new(Action::Find, "")
+            .crate_path(Some(tmp.root()))
+            .output_toolchain_file(true)
+            .write_msrv(true)
+            .write_badge(Some(tmp.path("badge.json")))
+            .build();
+
+        let expected = vec![
+            AuxiliaryOutput::planned(
+                Destination::ToolchainFile(tmp.path("rust-toolchain")),
+                AuxiliaryOutputItem::planned_toolchain_file(ToolchainFileKind::Toml),
+            ),
+            AuxiliaryOutput::planned(
+                Destination::Manifest(tmp.path("Cargo.toml")),
+                AuxiliaryOutputItem::msrv(MsrvKind::RustVersion),
+            ),
+            AuxiliaryOutput::planned(
+                Destination::File(tmp.path("badge.json")),
+                AuxiliaryOutputItem::badge(),
+            ),
+        ];
+
+        assert_eq!(planned_outputs(&config).unwrap(), expected);
+    }
+}
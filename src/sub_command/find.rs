@@ -1,14 +1,33 @@
+use std::convert::TryFrom;
+use std::path::Path;
+
 use rust_releases::{Release, ReleaseIndex};
+use toml_edit::Document;
 
 use crate::check::Check;
-use crate::config::{Config, SearchMethod};
-use crate::error::{CargoMSRVError, TResult};
-use crate::filter_releases::filter_releases;
+use crate::cli::rust_releases_opts::Edition;
+use crate::config::features::Features;
+use crate::config::{Config, ConfigBuilder, Granularity, SearchMethod};
+use crate::dependency_graph::resolver::{CargoMetadataResolver, DependencyResolver};
+use crate::diagnosis::unsupported_features;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::filter_releases::{filter_releases, validate_search_space};
 use crate::manifest::bare_version::BareVersion;
+use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
 use crate::msrv::MinimumSupportedRustVersion;
-use crate::reporter::event::MsrvResult;
+use crate::outcome::Outcome;
+use crate::reporter::event::{
+    FeatureMatrixResult, FeatureMsrv, ManifestMsrvMismatch, MsrvCause, MsrvCauses,
+    MsrvClampedToEdition, MsrvResult, PackageMsrv, SearchProbe, SearchSpacePruned,
+    WorkspaceMsrvResult,
+};
 use crate::reporter::Reporter;
-use crate::search_method::{Bisect, FindMinimalSupportedRustVersion, Linear};
+use crate::search_method::{Bisect, Gallop, Heuristic, Linear, Prior, SearchStrategy};
+use crate::toolchain::{OwnedToolchainSpec, ToolchainSpec};
+use crate::workspace::{selected_members, WorkspaceMember};
+use crate::writer::badge::write_badge;
+use crate::writer::certification::write_certification;
+use crate::writer::env_file::write_env_file;
 use crate::writer::toolchain_file::write_toolchain_file;
 use crate::writer::write_msrv::write_msrv;
 use crate::{semver, SubCommand};
@@ -31,8 +50,165 @@ impl<'index, C: Check> SubCommand for Find<'index, C> {
     type Output = semver::Version;
 
     fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
-        find_msrv(config, reporter, self.release_index, &self.runner)
+        let msrv = find(config, reporter, self.release_index, &self.runner)?;
+
+        if let Some(path) = config.write_badge() {
+            write_badge(path, reporter, &msrv)?;
+        }
+
+        if let Some(write_env) = config.write_env() {
+            write_env_file(write_env, reporter, &msrv)?;
+        }
+
+        Ok(msrv)
+    }
+}
+
+/// Find the MSRV of the crate, the workspace, or of each feature combination, depending on the
+/// config.
+pub(crate) fn find(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+) -> TResult<semver::Version> {
+    if let Err(invalid) = validate_search_space(config, release_index.releases()) {
+        reporter.report_event(invalid.clone())?;
+        return Err(CargoMSRVError::InvalidSearchSpace(invalid));
+    }
+
+    if let Some(members) = selected_members(config)? {
+        find_workspace_msrv(config, reporter, release_index, runner, members)
+    } else if config.feature_matrix() {
+        find_feature_matrix_msrv(config, reporter, release_index, runner)
+    } else {
+        find_msrv(config, reporter, release_index, runner)
+    }
+}
+
+/// Find the MSRV of each workspace member separately.
+///
+/// The returned version is the MSRV of the workspace as a whole, i.e. the greatest MSRV of its
+/// members.
+fn find_workspace_msrv(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+    members: Vec<WorkspaceMember>,
+) -> TResult<semver::Version> {
+    let mut packages = Vec::with_capacity(members.len());
+
+    for member in members {
+        info!(package = member.name(), "finding msrv of workspace member");
+
+        let member_config = ConfigBuilder::from_config(config)
+            .crate_path(Option::<&Path>::None)
+            .manifest_path(Some(member.manifest_path()))
+            .workspace(false)
+            .build();
+
+        let msrv = match find_msrv(&member_config, reporter, release_index, runner) {
+            Ok(version) => Some(version),
+            Err(CargoMSRVError::UnableToFindAnyGoodVersion { .. }) => None,
+            Err(err) => return Err(err),
+        };
+
+        let rust_version = declared_msrv(member.manifest_path());
+        packages.push(PackageMsrv::new(member.name(), rust_version, msrv));
+    }
+
+    let result = WorkspaceMsrvResult::new(packages);
+    let workspace_msrv = result.workspace_msrv().cloned();
+
+    reporter.report_event(result)?;
+
+    workspace_msrv.ok_or_else(|| CargoMSRVError::UnableToFindAnyGoodVersion {
+        command: config.check_command_string(),
+    })
+}
+
+/// Find the MSRV of each combination of the features of the crate, with its default features
+/// disabled.
+///
+/// The returned version is the greatest MSRV of the feature combinations. If requested, the
+/// MSRV and toolchain file are written for this version only.
+fn find_feature_matrix_msrv(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+) -> TResult<semver::Version> {
+    let features = manifest_features(config.context().manifest_path()?)?;
+    let mut combinations = Vec::new();
+
+    for combination in powerset(&features) {
+        info!(features = ?combination, "finding msrv of feature combination");
+
+        let combination_config = ConfigBuilder::from_config(config)
+            .features(Features::new(&combination, false, true))
+            .feature_matrix(false)
+            .write_msrv(false)
+            .output_toolchain_file(false)
+            .certify(false)
+            .build();
+
+        let msrv = match find_msrv(&combination_config, reporter, release_index, runner) {
+            Ok(version) => Some(version),
+            Err(CargoMSRVError::UnableToFindAnyGoodVersion { .. }) => None,
+            Err(err) => return Err(err),
+        };
+
+        combinations.push(FeatureMsrv::new(combination, msrv));
     }
+
+    let result = FeatureMatrixResult::new(combinations);
+    let msrv = result.msrv().cloned();
+
+    reporter.report_event(result)?;
+
+    let msrv = msrv.ok_or_else(|| CargoMSRVError::UnableToFindAnyGoodVersion {
+        command: config.check_command_string(),
+    })?;
+
+    if config.output_toolchain_file() {
+        write_toolchain_file(config, reporter, &msrv)?;
+    }
+
+    if config.write_msrv() {
+        write_msrv(config, reporter, &msrv)?;
+    }
+
+    Ok(msrv)
+}
+
+/// The features declared by the Cargo manifest at the given path.
+fn manifest_features(manifest_path: &Path) -> TResult<Vec<String>> {
+    let contents = std::fs::read_to_string(manifest_path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(manifest_path.to_path_buf()),
+    })?;
+    let document = CargoManifestParser::default().parse::<Document>(&contents)?;
+    let manifest = CargoManifest::try_from(document)?;
+
+    Ok(manifest.features().to_vec())
+}
+
+/// Every combination of the given features, starting with the empty combination.
+fn powerset(features: &[String]) -> Vec<Vec<String>> {
+    features.iter().fold(vec![vec![]], |mut sets, feature| {
+        let extended = sets
+            .iter()
+            .map(|set| {
+                let mut set = set.clone();
+                set.push(feature.clone());
+                set
+            })
+            .collect::<Vec<_>>();
+
+        sets.extend(extended);
+        sets
+    })
 }
 
 fn find_msrv(
@@ -41,6 +217,7 @@ fn find_msrv(
     release_index: &ReleaseIndex,
     runner: &impl Check,
 ) -> TResult<semver::Version> {
+    let config = &with_dependency_msrv_lower_bound(config, reporter, release_index)?;
     let search_result = search(config, reporter, release_index, runner)?;
 
     match &search_result {
@@ -57,12 +234,22 @@ fn find_msrv(
                 "found minimal-compatible toolchain"
             );
 
+            if config.explain() {
+                explain_msrv(config, reporter, release_index, runner, toolchain.version())?;
+            }
+
             if config.output_toolchain_file() {
                 write_toolchain_file(config, reporter, toolchain.version())?;
             }
 
             if config.write_msrv() {
                 write_msrv(config, reporter, toolchain.version())?;
+            } else {
+                report_manifest_msrv_mismatch(config, reporter, toolchain.version())?;
+            }
+
+            if config.certify() {
+                write_certification(config, reporter, toolchain.version())?;
             }
 
             Ok(toolchain.version().clone())
@@ -70,6 +257,155 @@ fn find_msrv(
     }
 }
 
+/// Check the crate against the release preceding the MSRV, and report which features it
+/// rejects.
+///
+/// Nothing is reported if the MSRV is the least recent release of the search space.
+fn explain_msrv(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+    msrv: &semver::Version,
+) -> TResult<()> {
+    let releases = filter_releases(config, release_index.releases());
+    let previous = releases
+        .iter()
+        .map(Release::version)
+        .skip_while(|version| *version != msrv)
+        .nth(1);
+
+    let previous = match previous {
+        Some(version) => version,
+        None => {
+            info!(%msrv, "no release precedes the msrv, unable to explain it");
+            return Ok(());
+        }
+    };
+
+    let toolchain = ToolchainSpec::new(previous, config.target());
+
+    let causes = match runner.check(config, &toolchain)? {
+        Outcome::Failure(failure) => unsupported_features(&failure.error_message)
+            .into_iter()
+            .map(MsrvCause::from)
+            .collect(),
+        Outcome::Success(_) => Vec::new(),
+    };
+
+    reporter.report_event(MsrvCauses::new(msrv.clone(), previous.clone(), causes))?;
+
+    Ok(())
+}
+
+/// The releases which would be searched for the MSRV of the crate.
+pub(crate) fn search_space(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+) -> TResult<Vec<Release>> {
+    let config = with_dependency_msrv_lower_bound(config, reporter, release_index)?;
+
+    Ok(filter_releases(&config, release_index.releases()))
+}
+
+/// Raise the least recent version of the search space to the greatest MSRV declared by the
+/// dependencies of the crate, since Cargo refuses to build the crate with older toolchains.
+///
+/// The amount of releases which are removed from the search space is reported. If the
+/// dependencies can't be resolved, the search space is left as is.
+fn with_dependency_msrv_lower_bound<'c>(
+    config: &'c Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+) -> TResult<Config<'c>> {
+    match max_dependency_msrv(config) {
+        Some((package, msrv)) if raises_minimum_version(config.minimum_version(), &msrv) => {
+            info!(
+                %package,
+                %msrv,
+                "using msrv of dependency as least recent version"
+            );
+
+            let bounded = ConfigBuilder::from_config(config)
+                .minimum_version(BareVersion::from(&msrv))
+                .build();
+
+            let pruned = filter_releases(config, release_index.releases())
+                .len()
+                .saturating_sub(filter_releases(&bounded, release_index.releases()).len());
+
+            if pruned > 0 {
+                reporter.report_event(SearchSpacePruned::new(package.as_str(), msrv, pruned))?;
+            }
+
+            Ok(bounded)
+        }
+        _ => Ok(config.clone()),
+    }
+}
+
+/// The greatest MSRV declared by the dependencies of the crate, and the name of the dependency
+/// which declares it.
+///
+/// Returns `None` if reading the MSRV of dependencies is disabled, or if the dependencies can't
+/// be resolved.
+fn max_dependency_msrv(config: &Config) -> Option<(String, semver::Version)> {
+    if !config.read_dependency_msrv() {
+        return None;
+    }
+
+    let graph = match CargoMetadataResolver::try_from_config(config)
+        .and_then(|resolver| resolver.resolve())
+    {
+        Ok(graph) => graph,
+        Err(error) => {
+            warn!(%error, "unable to resolve dependencies to determine their msrv");
+            return None;
+        }
+    };
+
+    graph
+        .max_dependency_msrv(config.msrv_resolver(), config.ignored_dependencies())
+        .map(|(package, msrv)| (package.name.clone(), msrv))
+}
+
+fn raises_minimum_version(minimum: Option<&BareVersion>, msrv: &semver::Version) -> bool {
+    minimum.map_or(true, |minimum| minimum.to_semver_version() < *msrv)
+}
+
+/// Report when the MSRV declared in the Cargo manifest, if any, does not match the found MSRV.
+fn report_manifest_msrv_mismatch(
+    config: &Config,
+    reporter: &impl Reporter,
+    version: &semver::Version,
+) -> TResult<()> {
+    let manifest_path = config.context().manifest_path()?;
+
+    if let Some(declared) = declared_msrv(manifest_path) {
+        if !declared.to_comparator().matches(version) {
+            reporter.report_event(ManifestMsrvMismatch::new(
+                version.clone(),
+                declared,
+                manifest_path.to_path_buf(),
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The MSRV declared in the Cargo manifest, if it can be read.
+fn declared_msrv(manifest_path: &Path) -> Option<BareVersion> {
+    let contents = std::fs::read_to_string(manifest_path).ok()?;
+    let document = CargoManifestParser::default()
+        .parse::<Document>(&contents)
+        .ok()?;
+    let manifest = CargoManifest::try_from(document).ok()?;
+
+    manifest.minimum_rust_version().cloned()
+}
+
 fn search(
     config: &Config,
     reporter: &impl Reporter,
@@ -79,7 +415,18 @@ fn search(
     let releases = index.releases();
     let included_releases = filter_releases(config, releases);
 
-    run_with_search_method(config, &included_releases, reporter, runner)
+    let minimum_capable = run_with_search_method(config, &included_releases, reporter, runner)?;
+    let minimum_capable = match config.granularity() {
+        Granularity::Minor => minimum_capable,
+        Granularity::Patch => {
+            refine_to_patch_release(minimum_capable, releases, config, reporter, runner)?
+        }
+    };
+    let minimum_capable = clamp_to_edition(minimum_capable, config, reporter)?;
+
+    report_outcome(&minimum_capable, &included_releases, config, reporter)?;
+
+    Ok(minimum_capable)
 }
 
 fn run_with_search_method(
@@ -91,30 +438,158 @@ fn run_with_search_method(
     let search_method = config.search_method();
     info!(?search_method);
 
-    // Run a linear or binary search depending on the configuration
+    let search_space = included_releases
+        .iter()
+        .map(|release| release.version().clone())
+        .collect::<Vec<_>>();
+    runner.start_search(config, &search_space)?;
+
+    // Run a linear, binary or galloping search depending on the configuration
     match search_method {
         SearchMethod::Linear => {
-            run_searcher(&Linear::new(runner), included_releases, config, reporter)
+            Linear::new(runner).find_toolchain(included_releases, config, reporter)
         }
-        SearchMethod::Bisect => {
-            run_searcher(&Bisect::new(runner), included_releases, config, reporter)
+        SearchMethod::Bisect => Bisect::new(runner)
+            .with_priors(search_priors(config))
+            .find_toolchain(included_releases, config, reporter),
+        SearchMethod::Gallop => {
+            Gallop::new(runner).find_toolchain(included_releases, config, reporter)
         }
     }
 }
 
-fn run_searcher(
-    method: &impl FindMinimalSupportedRustVersion,
+/// Check the patch releases which precede the MSRV found at minor granularity, within the same
+/// minor release, from most to least recent, and lower the MSRV to each which is compatible, up
+/// until the first incompatible patch release.
+fn refine_to_patch_release(
+    minimum_capable: MinimumSupportedRustVersion,
     releases: &[Release],
     config: &Config,
     reporter: &impl Reporter,
+    runner: &impl Check,
 ) -> TResult<MinimumSupportedRustVersion> {
-    let minimum_capable = method.find_toolchain(releases, config, reporter)?;
+    let found = match &minimum_capable {
+        MinimumSupportedRustVersion::Toolchain { toolchain } => toolchain.version().clone(),
+        MinimumSupportedRustVersion::NoCompatibleToolchain => return Ok(minimum_capable),
+    };
+
+    let all_patch_releases = ConfigBuilder::from_config(config)
+        .include_all_patch_releases(true)
+        .build();
+    let candidates = filter_releases(&all_patch_releases, releases)
+        .into_iter()
+        .filter(|release| {
+            let version = release.version();
+            version.major == found.major && version.minor == found.minor && *version < found
+        })
+        .collect::<Vec<_>>();
+
+    let mut minimum_capable = minimum_capable;
 
-    report_outcome(&minimum_capable, releases, config, reporter)?;
+    for release in &candidates {
+        let version = release.version();
+        reporter.report_event(SearchProbe::new(
+            version.clone(),
+            Heuristic::PatchRefinement,
+        ))?;
+
+        let toolchain = ToolchainSpec::new(version, config.target());
+
+        match runner.check(config, &toolchain)? {
+            Outcome::Success(_) => {
+                minimum_capable = MinimumSupportedRustVersion::Toolchain {
+                    toolchain: OwnedToolchainSpec::new(version, config.target()),
+                };
+            }
+            Outcome::Failure(_) => break,
+        }
+    }
 
     Ok(minimum_capable)
 }
 
+/// Rust versions which are likely to be close to the MSRV, in the order in which they are checked
+/// before the search space is bisected: the MSRV previously recorded in the Cargo manifest, the
+/// greatest MSRV of the dependencies, and the first Rust version which supports the edition.
+fn search_priors(config: &Config) -> Vec<Prior> {
+    let manifest_path = match config.context().manifest_path() {
+        Ok(path) => path,
+        Err(_) => return Vec::new(),
+    };
+
+    let declared = declared_msrv(manifest_path)
+        .map(|msrv| Prior::new(msrv.to_semver_version(), Heuristic::DeclaredMsrv));
+    let dependency =
+        max_dependency_msrv(config).map(|(_, msrv)| Prior::new(msrv, Heuristic::DependencyMsrv));
+    let edition = manifest_edition(manifest_path).map(|edition| {
+        Prior::new(
+            edition.as_bare_version().to_semver_version(),
+            Heuristic::Edition,
+        )
+    });
+
+    declared
+        .into_iter()
+        .chain(dependency)
+        .chain(edition)
+        .collect()
+}
+
+/// Raise the MSRV to the least recent Rust version which supports the edition of the crate, if
+/// the MSRV which was found is older, since Cargo can't build the crate with such toolchains.
+///
+/// This can only happen when the search space extends below the edition, for example when
+/// `--min` or `--no-read-min-edition` is given.
+fn clamp_to_edition(
+    minimum_capable: MinimumSupportedRustVersion,
+    config: &Config,
+    reporter: &impl Reporter,
+) -> TResult<MinimumSupportedRustVersion> {
+    let found = match &minimum_capable {
+        MinimumSupportedRustVersion::Toolchain { toolchain } => toolchain.version().clone(),
+        MinimumSupportedRustVersion::NoCompatibleToolchain => return Ok(minimum_capable),
+    };
+
+    let edition = match config
+        .context()
+        .manifest_path()
+        .ok()
+        .and_then(manifest_edition)
+    {
+        Some(edition) => edition,
+        None => return Ok(minimum_capable),
+    };
+
+    let clamped = MsrvClampedToEdition::new(found, edition);
+
+    if clamped.found() >= clamped.msrv() {
+        return Ok(minimum_capable);
+    }
+
+    info!(
+        found = %clamped.found(),
+        %edition,
+        msrv = %clamped.msrv(),
+        "clamping msrv to the first version which supports the edition"
+    );
+
+    let toolchain = OwnedToolchainSpec::new(clamped.msrv(), config.target());
+    reporter.report_event(clamped)?;
+
+    Ok(MinimumSupportedRustVersion::Toolchain { toolchain })
+}
+
+/// The edition specified in the Cargo manifest, if it can be read.
+fn manifest_edition(manifest_path: &Path) -> Option<Edition> {
+    let contents = std::fs::read_to_string(manifest_path).ok()?;
+    let document = CargoManifestParser::default()
+        .parse::<Document>(&contents)
+        .ok()?;
+    let manifest = CargoManifest::try_from(document).ok()?;
+
+    manifest.edition()
+}
+
 fn report_outcome(
     minimum_capable: &MinimumSupportedRustVersion,
     releases: &[Release],
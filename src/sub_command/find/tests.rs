@@ -2,6 +2,7 @@ use super::*;
 use crate::check::TestRunner;
 use crate::config::ConfigBuilder;
 use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::InvalidSearchSpace;
 use crate::reporter::TestReporter;
 use crate::{Action, Event};
 use rust_releases::semver;
@@ -146,22 +147,155 @@ fn no_releases_available() {
     let cmd = Find::new(&index, runner);
     let result = cmd.run(&config, reporter.reporter());
     let err = result.unwrap_err();
-    assert!(matches!(err, CargoMSRVError::NoToolchainsToTry(_)));
+
+    let expected = InvalidSearchSpace::MinAboveMax {
+        min: min.clone(),
+        max: max.clone(),
+    };
+    assert!(matches!(&err, CargoMSRVError::InvalidSearchSpace(invalid) if invalid == &expected));
+
+    let events = reporter.wait_for_events();
+
+    let expected_event: Event = expected.into();
+    let unexpected_event: Event = MsrvResult::none(&config, min, max).into();
+
+    assert!(events.contains(&expected_event));
+    assert!(!events.contains(&unexpected_event));
+}
+
+#[test]
+fn no_releases_between_min_and_max() {
+    let releases = vec![
+        Release::new_stable(semver::Version::new(1, 46, 0)),
+        Release::new_stable(semver::Version::new(1, 55, 0)),
+        Release::new_stable(semver::Version::new(1, 56, 0)),
+    ];
+
+    let index = ReleaseIndex::from_iter(releases);
+
+    let min = BareVersion::TwoComponents(1, 47);
+    let max = BareVersion::ThreeComponents(1, 54, 0);
+
+    let config = ConfigBuilder::new(Action::Find, "")
+        .minimum_version(min.clone())
+        .maximum_version(max.clone())
+        .build();
+
+    let reporter = TestReporter::default();
+    let runner = TestRunner::with_ok(&[]);
+
+    let cmd = Find::new(&index, runner);
+    let err = cmd.run(&config, reporter.reporter()).unwrap_err();
 
     if let CargoMSRVError::NoToolchainsToTry(inner_err) = err {
         assert_eq!(inner_err.min.as_ref(), Some(&min));
         assert_eq!(inner_err.max.as_ref(), Some(&max));
         assert_eq!(&inner_err.search_space, &[]);
+    } else {
+        panic!("expected NoToolchainsToTry, got: {:?}", err);
     }
+}
 
-    let events = reporter.wait_for_events();
+#[yare::parameterized(
+    min_above_latest = { Some(BareVersion::TwoComponents(1, 60)), None, "min_above_latest" },
+    max_below_earliest = { None, Some(BareVersion::TwoComponents(1, 45)), "max_below_earliest" },
+)]
+fn search_space_outside_of_releases(
+    min: Option<BareVersion>,
+    max: Option<BareVersion>,
+    reason: &str,
+) {
+    let index = ReleaseIndex::from_iter(vec![
+        Release::new_stable(semver::Version::new(1, 46, 0)),
+        Release::new_stable(semver::Version::new(1, 59, 0)),
+    ]);
 
-    let unexpected_event: Event = MsrvResult::none(
-        &config,
-        BareVersion::TwoComponents(1, 56),
-        BareVersion::ThreeComponents(1, 54, 0),
-    )
-    .into();
+    let mut builder = ConfigBuilder::new(Action::Find, "");
+    if let Some(min) = min {
+        builder = builder.minimum_version(min);
+    }
+    if let Some(max) = max {
+        builder = builder.maximum_version(max);
+    }
+    let config = builder.build();
 
-    assert!(!events.contains(&unexpected_event));
+    let reporter = TestReporter::default();
+    let cmd = Find::new(&index, TestRunner::with_ok(&[]));
+
+    match cmd.run(&config, reporter.reporter()).unwrap_err() {
+        CargoMSRVError::InvalidSearchSpace(invalid) => {
+            let serialized = serde_json::to_value(&invalid).unwrap();
+            assert_eq!(serialized["reason"], reason);
+        }
+        err => panic!("expected InvalidSearchSpace, got: {:?}", err),
+    }
+}
+
+#[yare::parameterized(
+    no_minimum = { None, true },
+    older_minimum = { Some(BareVersion::TwoComponents(1, 56)), true },
+    same_minimum = { Some(BareVersion::ThreeComponents(1, 60, 0)), false },
+    newer_minimum = { Some(BareVersion::TwoComponents(1, 61)), false },
+)]
+fn dependency_msrv_raises_minimum_version(minimum: Option<BareVersion>, expected: bool) {
+    let msrv = semver::Version::new(1, 60, 0);
+
+    assert_eq!(raises_minimum_version(minimum.as_ref(), &msrv), expected);
+}
+
+#[yare::parameterized(
+    minor = { Granularity::Minor, &[semver::Version::new(1, 56, 2), semver::Version::new(1, 56, 1)], semver::Version::new(1, 56, 2) },
+    patch = { Granularity::Patch, &[semver::Version::new(1, 56, 2), semver::Version::new(1, 56, 1)], semver::Version::new(1, 56, 1) },
+    patch_all_compatible = { Granularity::Patch, &[semver::Version::new(1, 56, 2), semver::Version::new(1, 56, 1), semver::Version::new(1, 56, 0)], semver::Version::new(1, 56, 0) },
+    patch_none_older_compatible = { Granularity::Patch, &[semver::Version::new(1, 56, 2)], semver::Version::new(1, 56, 2) },
+)]
+fn find_with_granularity(
+    granularity: Granularity,
+    accept: &[semver::Version],
+    expected: semver::Version,
+) {
+    let index = ReleaseIndex::from_iter(vec![
+        Release::new_stable(semver::Version::new(1, 57, 0)),
+        Release::new_stable(semver::Version::new(1, 56, 2)),
+        Release::new_stable(semver::Version::new(1, 56, 1)),
+        Release::new_stable(semver::Version::new(1, 56, 0)),
+        Release::new_stable(semver::Version::new(1, 55, 0)),
+    ]);
+
+    let config = ConfigBuilder::new(Action::Find, "")
+        .granularity(granularity)
+        .build();
+    let reporter = TestReporter::default();
+    let runner = TestRunner::with_ok(
+        accept
+            .iter()
+            .chain(std::iter::once(&semver::Version::new(1, 57, 0))),
+    );
+
+    let cmd = Find::new(&index, runner);
+    let found = cmd.run(&config, reporter.reporter()).unwrap();
+
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn powerset_of_features() {
+    let features = vec!["a".to_string(), "b".to_string()];
+
+    let sets = powerset(&features);
+
+    assert_eq!(
+        sets,
+        vec![
+            vec![],
+            vec!["a".to_string()],
+            vec!["b".to_string()],
+            vec!["a".to_string(), "b".to_string()],
+        ]
+    );
+}
+
+#[test]
+fn powerset_of_no_features() {
+    assert_eq!(powerset(&[]), vec![Vec::<String>::new()]);
 }
@@ -17,11 +17,29 @@ impl SubCommand for List {
 }
 
 fn list_msrv(config: &Config, reporter: &impl Reporter) -> TResult<()> {
-    let resolver = CargoMetadataResolver::try_from_config(config)?;
-    let graph = resolver.resolve()?;
-    let variant = config.sub_command_config().list().variant;
+    let list_config = config.sub_command_config().list();
+    let resolver = CargoMetadataResolver::try_from_config(config)?
+        .with_dependency_kinds(list_config.dependency_kinds.clone());
 
-    reporter.report_event(ListDep::new(variant, graph))?;
+    let graph = match list_config.depth {
+        Some(depth) => resolver.resolve()?.with_max_depth(depth),
+        None => resolver.resolve()?,
+    };
+    let variant = list_config.variant;
+    let min_msrv = list_config
+        .min_msrv
+        .as_ref()
+        .map(|version| version.to_semver_version());
+
+    let list = ListDep::new(variant, graph)
+        .sorted_by(list_config.sort)
+        .with_min_msrv(min_msrv)
+        .resolved_with(config.msrv_resolver().clone())
+        .ignoring(config.ignored_dependencies().to_vec());
+    let coverage = list.coverage();
+
+    reporter.report_event(list)?;
+    reporter.report_event(coverage)?;
 
     Ok(())
 }
@@ -0,0 +1,359 @@
+use std::convert::TryFrom;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use cargo_metadata::MetadataCommand;
+use toml_edit::Document;
+
+use crate::config::Config;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::lockfile::CARGO_LOCK;
+use crate::manifest::bare_version::BareVersion;
+use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
+use crate::registry_index::{cached_versions, IndexEntry};
+use crate::reporter::event::{
+    AuxiliaryOutput, AuxiliaryOutputItem, Destination, LockfilePinned, PinnedDependency,
+};
+use crate::reporter::Reporter;
+use crate::semver;
+use crate::SubCommand;
+
+/// Downgrade the dependencies locked in the `Cargo.lock` lockfile which require a newer Rust
+/// version than the MSRV, to the newest version which still supports the MSRV.
+///
+/// The downgrades are performed with `cargo update --precise`, so Cargo verifies that each
+/// downgraded version is still accepted by the version requirements of its dependents.
+#[derive(Default)]
+pub struct PinLockfile;
+
+impl SubCommand for PinLockfile {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        pin_lockfile(config, reporter)
+    }
+}
+
+fn pin_lockfile(config: &Config, reporter: &impl Reporter) -> TResult<()> {
+    let manifest_path = config.context().manifest_path()?;
+    let msrv = msrv(config, manifest_path)?;
+
+    let metadata = MetadataCommand::new()
+        .manifest_path(manifest_path)
+        .no_deps()
+        .exec()?;
+
+    let lockfile_path = metadata.workspace_root.as_std_path().join(CARGO_LOCK);
+    let contents = std::fs::read_to_string(&lockfile_path)
+        .map_err(|_| Error::NoLockfile(lockfile_path.clone()))?;
+
+    let semver_msrv = msrv.to_semver_version();
+
+    let mut pinned = Vec::new();
+
+    for package in locked_packages(&contents)? {
+        let entries = cached_versions(&package.name);
+
+        if !requires_newer_rust(&package.version, &entries, &semver_msrv) {
+            continue;
+        }
+
+        let accepted = downgrade_candidates(&package.version, &entries, &semver_msrv)
+            .into_iter()
+            .find_map(
+                |candidate| match cargo_update_precise(manifest_path, &package, candidate) {
+                    Ok(true) => Some(Ok(candidate.clone())),
+                    Ok(false) => None,
+                    Err(err) => Some(Err(err)),
+                },
+            )
+            .transpose()?;
+
+        pinned.push(PinnedDependency::new(
+            package.name,
+            package.version,
+            accepted,
+        ));
+    }
+
+    if pinned.iter().any(|dependency| dependency.to().is_some()) {
+        reporter.report_event(AuxiliaryOutput::new(
+            Destination::File(lockfile_path),
+            AuxiliaryOutputItem::lockfile(),
+        ))?;
+    }
+
+    reporter.report_event(LockfilePinned::new(msrv, pinned))?;
+
+    Ok(())
+}
+
+/// The MSRV given as argument, or else the MSRV declared in the Cargo manifest.
+fn msrv(config: &Config, manifest_path: &Path) -> TResult<BareVersion> {
+    if let Some(msrv) = &config.sub_command_config().pin_lockfile().msrv {
+        return Ok(msrv.clone());
+    }
+
+    let contents = std::fs::read_to_string(manifest_path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(manifest_path.to_path_buf()),
+    })?;
+
+    let document = CargoManifestParser::default().parse::<Document>(&contents)?;
+    let manifest = CargoManifest::try_from(document)?;
+
+    manifest
+        .minimum_rust_version()
+        .cloned()
+        .ok_or_else(|| Error::NoMsrv(manifest_path.to_path_buf()).into())
+}
+
+/// A package locked in the lockfile, which was resolved from a registry.
+#[derive(Debug, PartialEq)]
+struct LockedPackage {
+    name: String,
+    version: semver::Version,
+}
+
+/// The registry packages listed in the `package` array of a `Cargo.lock` lockfile.
+///
+/// Workspace members, path and git dependencies are skipped, since they can't be downgraded
+/// with the registry index.
+fn locked_packages(contents: &str) -> TResult<Vec<LockedPackage>> {
+    let document = contents
+        .parse::<Document>()
+        .map_err(|_| Error::InvalidLockfile)?;
+
+    let packages = match document
+        .get("package")
+        .and_then(|item| item.as_array_of_tables())
+    {
+        Some(packages) => packages,
+        None => return Ok(Vec::new()),
+    };
+
+    let locked = packages
+        .iter()
+        .filter(|package| {
+            package
+                .get("source")
+                .and_then(|source| source.as_str())
+                .map_or(false, |source| {
+                    source.starts_with("registry+") || source.starts_with("sparse+")
+                })
+        })
+        .filter_map(|package| {
+            let name = package.get("name")?.as_str()?;
+            let version = package.get("version")?.as_str()?.parse().ok()?;
+
+            Some(LockedPackage {
+                name: name.to_string(),
+                version,
+            })
+        })
+        .collect();
+
+    Ok(locked)
+}
+
+/// Whether the given version of a crate declares a `rust-version` which is newer than the MSRV.
+fn requires_newer_rust(
+    version: &semver::Version,
+    entries: &[IndexEntry],
+    msrv: &semver::Version,
+) -> bool {
+    entries
+        .iter()
+        .find(|entry| entry.version() == version)
+        .and_then(IndexEntry::rust_version)
+        .map_or(false, |rust_version| {
+            rust_version.to_semver_version() > *msrv
+        })
+}
+
+/// The versions older than the locked version, which are semver compatible with it, and support
+/// the MSRV. The newest version comes first.
+///
+/// Yanked versions and pre-releases are never returned. Versions which don't declare a
+/// `rust-version` are assumed to support the MSRV.
+fn downgrade_candidates<'e>(
+    locked: &semver::Version,
+    entries: &'e [IndexEntry],
+    msrv: &semver::Version,
+) -> Vec<&'e semver::Version> {
+    let mut candidates = entries
+        .iter()
+        .filter(|entry| !entry.is_yanked() && entry.version().pre.is_empty())
+        .filter(|entry| entry.version() < locked && is_compatible(entry.version(), locked))
+        .filter(|entry| {
+            entry.rust_version().map_or(true, |rust_version| {
+                rust_version.to_semver_version() <= *msrv
+            })
+        })
+        .map(IndexEntry::version)
+        .collect::<Vec<_>>();
+
+    candidates.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    candidates
+}
+
+/// Whether two versions are semver compatible, in the same way as Cargo treats caret
+/// requirements: the left-most non-zero component must be equal.
+fn is_compatible(lhs: &semver::Version, rhs: &semver::Version) -> bool {
+    match (lhs.major, lhs.minor) {
+        (0, 0) => rhs.major == 0 && rhs.minor == 0 && lhs.patch == rhs.patch,
+        (0, minor) => rhs.major == 0 && rhs.minor == minor,
+        (major, _) => rhs.major == major,
+    }
+}
+
+/// Run `cargo update -p <name>@<version> --precise <candidate>`. Returns whether Cargo accepted
+/// the downgrade.
+fn cargo_update_precise(
+    manifest_path: &Path,
+    package: &LockedPackage,
+    candidate: &semver::Version,
+) -> TResult<bool> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let spec = format!("{}@{}", package.name, package.version);
+
+    debug!(package = %spec, precise = %candidate, "downgrading locked dependency");
+
+    let status = Command::new(&cargo)
+        .arg("update")
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .arg("--package")
+        .arg(&spec)
+        .arg("--precise")
+        .arg(candidate.to_string())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::SpawnProcess(cargo.clone()),
+        })?;
+
+    Ok(status.success())
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("No MSRV was given, and no MSRV was specified in the Cargo manifest at '{}'", .0.display())]
+    NoMsrv(PathBuf),
+
+    #[error("Unable to read the lockfile at '{}', run `cargo generate-lockfile` to create it", .0.display())]
+    NoLockfile(PathBuf),
+
+    #[error("Unable to parse the lockfile")]
+    InvalidLockfile,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_packages_are_locked() {
+        let contents = r#"version = 3
+
+[[package]]
+name = "my-crate"
+version = "0.1.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.140"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "fc855a42c7967b7c369eb5860f7164ef1f6f81c20c7cc1141f2a604e18723b03"
+
+[[package]]
+name = "local"
+version = "0.2.0"
+source = "git+https://github.com/foo/local#2f3a4b"
+"#;
+
+        let packages = locked_packages(contents).unwrap();
+
+        assert_eq!(
+            packages,
+            vec![LockedPackage {
+                name: "serde".to_string(),
+                version: semver::Version::new(1, 0, 140),
+            }]
+        );
+    }
+
+    fn entries() -> Vec<IndexEntry> {
+        vec![
+            IndexEntry::new(semver::Version::new(0, 9, 0), None, false),
+            IndexEntry::new(semver::Version::new(1, 0, 0), None, false),
+            IndexEntry::new(
+                semver::Version::new(1, 1, 0),
+                Some(BareVersion::TwoComponents(1, 56)),
+                false,
+            ),
+            IndexEntry::new(
+                semver::Version::new(1, 2, 0),
+                Some(BareVersion::TwoComponents(1, 56)),
+                true,
+            ),
+            IndexEntry::new(
+                semver::Version::new(1, 3, 0),
+                Some(BareVersion::TwoComponents(1, 60)),
+                false,
+            ),
+        ]
+    }
+
+    #[test]
+    fn locked_version_requires_newer_rust() {
+        let msrv = semver::Version::new(1, 58, 0);
+
+        assert!(requires_newer_rust(
+            &semver::Version::new(1, 3, 0),
+            &entries(),
+            &msrv
+        ));
+        assert!(!requires_newer_rust(
+            &semver::Version::new(1, 1, 0),
+            &entries(),
+            &msrv
+        ));
+    }
+
+    #[test]
+    fn candidates_are_compatible_and_support_msrv() {
+        let entries = entries();
+        let candidates = downgrade_candidates(
+            &semver::Version::new(1, 3, 0),
+            &entries,
+            &semver::Version::new(1, 58, 0),
+        );
+
+        assert_eq!(
+            candidates,
+            vec![
+                &semver::Version::new(1, 1, 0),
+                &semver::Version::new(1, 0, 0)
+            ]
+        );
+    }
+
+    #[yare::parameterized(
+        major = { semver::Version::new(1, 0, 0), semver::Version::new(1, 4, 2), true },
+        different_major = { semver::Version::new(1, 0, 0), semver::Version::new(2, 0, 0), false },
+        minor = { semver::Version::new(0, 3, 0), semver::Version::new(0, 3, 9), true },
+        different_minor = { semver::Version::new(0, 3, 0), semver::Version::new(0, 4, 0), false },
+        patch = { semver::Version::new(0, 0, 3), semver::Version::new(0, 0, 3), true },
+        different_patch = { semver::Version::new(0, 0, 3), semver::Version::new(0, 0, 4), false },
+    )]
+    fn compatible_versions(lhs: semver::Version, rhs: semver::Version, expected: bool) {
+        assert_eq!(is_compatible(&lhs, &rhs), expected);
+    }
+}
@@ -0,0 +1,195 @@
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use rust_releases::ReleaseIndex;
+use toml_edit::Document;
+
+use crate::config::policy::MsrvPolicy;
+use crate::config::Config;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::manifest::bare_version::BareVersion;
+use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
+use crate::reporter::event::{PolicyResult, PolicyRule, PolicyRuleKind};
+use crate::reporter::Reporter;
+use crate::{semver, SubCommand};
+
+/// Checks whether the MSRV complies with the MSRV policy, relative to the most recent stable
+/// release in the release index.
+///
+/// Fails if the MSRV violates any of the rules of the policy.
+pub struct Policy<'index> {
+    release_index: &'index ReleaseIndex,
+}
+
+impl<'index> Policy<'index> {
+    pub fn new(release_index: &'index ReleaseIndex) -> Self {
+        Self { release_index }
+    }
+}
+
+impl<'index> SubCommand for Policy<'index> {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        let policy = config.msrv_policy();
+
+        if policy.is_empty() {
+            return Err(Error::NoPolicy.into());
+        }
+
+        let msrv = msrv(config)?.to_semver_version();
+        let stable = self
+            .release_index
+            .releases()
+            .first()
+            .map(|release| release.version().clone())
+            .ok_or(Error::NoStableRelease)?;
+
+        let result = PolicyResult::new(msrv.clone(), stable.clone(), rules(policy, &msrv, &stable));
+        let compliant = result.is_compliant();
+
+        reporter.report_event(result)?;
+
+        if !compliant {
+            return Err(Error::Violated { msrv }.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// The MSRV given as argument, or else the MSRV declared in the Cargo manifest.
+fn msrv(config: &Config) -> TResult<BareVersion> {
+    if let Some(msrv) = &config.sub_command_config().policy().msrv {
+        return Ok(msrv.clone());
+    }
+
+    let manifest_path = config.context().manifest_path()?;
+    let contents = std::fs::read_to_string(manifest_path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(manifest_path.to_path_buf()),
+    })?;
+
+    let document = CargoManifestParser::default().parse::<Document>(&contents)?;
+    let manifest = CargoManifest::try_from(document)?;
+
+    config
+        .msrv_resolver()
+        .manifest_msrv(&manifest)
+        .cloned()
+        .ok_or_else(|| Error::NoMsrv(manifest_path.to_path_buf()).into())
+}
+
+/// Evaluate each rule of the policy for the given MSRV.
+fn rules(policy: &MsrvPolicy, msrv: &semver::Version, stable: &semver::Version) -> Vec<PolicyRule> {
+    let sliding_window = policy.sliding_window.map(|releases| {
+        let newest_allowed =
+            BareVersion::TwoComponents(stable.major, stable.minor.saturating_sub(releases));
+
+        (PolicyRuleKind::SlidingWindow { releases }, newest_allowed)
+    });
+
+    let minimum = policy
+        .minimum
+        .clone()
+        .map(|version| (PolicyRuleKind::Minimum, version));
+
+    sliding_window
+        .into_iter()
+        .chain(minimum)
+        .map(|(kind, newest_allowed)| {
+            PolicyRule::new(
+                kind,
+                newest_allowed.to_semver_version(),
+                complies(msrv, &newest_allowed),
+            )
+        })
+        .collect()
+}
+
+/// Whether the MSRV is not newer than the given version. Only the given components are compared,
+/// so an MSRV of `1.65.1` complies with `1.65`, but not with `1.65.0`.
+fn complies(msrv: &semver::Version, newest_allowed: &BareVersion) -> bool {
+    match *newest_allowed {
+        BareVersion::TwoComponents(major, minor) => (msrv.major, msrv.minor) <= (major, minor),
+        BareVersion::ThreeComponents(major, minor, patch) => {
+            (msrv.major, msrv.minor, msrv.patch) <= (major, minor, patch)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("No MSRV was given, and no MSRV was specified in the Cargo manifest at '{}'", .0.display())]
+    NoMsrv(PathBuf),
+
+    #[error("No MSRV policy was given: add a 'policy' table to the configuration file, or use --sliding-window or --minimum")]
+    NoPolicy,
+
+    #[error("The release index contains no stable release to apply the MSRV policy to")]
+    NoStableRelease,
+
+    #[error("The MSRV {msrv} violates the MSRV policy")]
+    Violated { msrv: semver::Version },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        older = { semver::Version::new(1, 60, 0), true },
+        at_the_edge_of_the_window = { semver::Version::new(1, 66, 3), true },
+        within_the_window = { semver::Version::new(1, 67, 0), false },
+    )]
+    fn sliding_window(msrv: semver::Version, expected: bool) {
+        let policy = MsrvPolicy {
+            sliding_window: Some(4),
+            minimum: None,
+        };
+        let stable = semver::Version::new(1, 70, 0);
+
+        let rules = rules(&policy, &msrv, &stable);
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(
+            rules[0].kind(),
+            &PolicyRuleKind::SlidingWindow { releases: 4 }
+        );
+        assert_eq!(
+            rules[0].newest_allowed_msrv(),
+            &semver::Version::new(1, 66, 0)
+        );
+        assert_eq!(rules[0].is_compliant(), expected);
+    }
+
+    #[yare::parameterized(
+        older = { semver::Version::new(1, 60, 0), BareVersion::TwoComponents(1, 65), true },
+        patch_of_two_components = { semver::Version::new(1, 65, 1), BareVersion::TwoComponents(1, 65), true },
+        patch_of_three_components = { semver::Version::new(1, 65, 1), BareVersion::ThreeComponents(1, 65, 0), false },
+        newer = { semver::Version::new(1, 66, 0), BareVersion::TwoComponents(1, 65), false },
+    )]
+    fn minimum(msrv: semver::Version, minimum: BareVersion, expected: bool) {
+        assert_eq!(complies(&msrv, &minimum), expected);
+    }
+
+    #[test]
+    fn all_rules_are_evaluated() {
+        let policy = MsrvPolicy {
+            sliding_window: Some(2),
+            minimum: Some(BareVersion::TwoComponents(1, 65)),
+        };
+        let msrv = semver::Version::new(1, 66, 0);
+        let stable = semver::Version::new(1, 70, 0);
+
+        let rules = rules(&policy, &msrv, &stable);
+
+        assert_eq!(
+            rules
+                .iter()
+                .map(PolicyRule::is_compliant)
+                .collect::<Vec<_>>(),
+            vec![true, false]
+        );
+    }
+}
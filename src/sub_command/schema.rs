@@ -0,0 +1,34 @@
+use std::io::Write;
+
+use crate::config::Config;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::reporter::event::{AuxiliaryOutput, AuxiliaryOutputItem, Destination};
+use crate::reporter::schema::event_schema;
+use crate::reporter::Reporter;
+use crate::SubCommand;
+
+/// Print the JSON Schema which describes the events reported by the `json` and `json-lines`
+/// output formats to stdout.
+#[derive(Default)]
+pub struct Schema;
+
+impl SubCommand for Schema {
+    type Output = ();
+
+    fn run(&self, _config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        let schema =
+            serde_json::to_string_pretty(&event_schema()).expect("Unable to serialize schema");
+
+        writeln!(std::io::stdout().lock(), "{}", schema).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::WriteStdout,
+        })?;
+
+        reporter.report_event(AuxiliaryOutput::new(
+            Destination::Stdout,
+            AuxiliaryOutputItem::schema(),
+        ))?;
+
+        Ok(())
+    }
+}
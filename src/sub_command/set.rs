@@ -1,8 +1,9 @@
 use std::io::Write;
+use std::path::Path;
 
 use rust_releases::semver;
 
-use toml_edit::{table, value, Document, Item, Value};
+use toml_edit::{table, value, Document, InlineTable, Item, Value};
 
 use crate::error::{IoErrorSource, SetMsrvError};
 use crate::manifest::bare_version::BareVersion;
@@ -11,6 +12,8 @@ use crate::reporter::event::{
     AuxiliaryOutput, AuxiliaryOutputItem, Destination, MsrvKind, SetOutputMessage,
 };
 use crate::reporter::Reporter;
+use crate::workspace::{is_virtual, workspace_members, workspace_root_manifest};
+use crate::writer::clippy_config::write_clippy_msrv;
 use crate::{CargoMSRVError, Config, SubCommand, TResult};
 
 const RUST_VERSION_SUPPORTED_SINCE: semver::Version = semver::Version::new(1, 56, 0);
@@ -28,77 +31,244 @@ impl SubCommand for Set {
 
 fn set_msrv(config: &Config, reporter: &impl Reporter) -> TResult<()> {
     let cargo_toml = config.context().manifest_path()?;
+    let set_config = config.sub_command_config().set();
+    let msrv = &set_config.msrv;
 
-    // Read the Cargo manifest to a String
-    let contents = std::fs::read_to_string(&cargo_toml).map_err(|error| CargoMSRVError::Io {
+    // Parse the Cargo manifest contents, in particular the MSRV value
+    let mut manifest = read_manifest(cargo_toml)?;
+
+    // When the crate inherits its MSRV from the workspace, or the MSRV is propagated to the
+    // members of the workspace, the MSRV is set in the workspace root instead
+    let written_to = if set_config.propagate || inherits_rust_version(&manifest) {
+        let root = workspace_root_manifest(cargo_toml)?;
+        set_workspace_msrv(&root, msrv, reporter)?;
+
+        root
+    } else if is_virtual(&manifest) {
+        if !has_workspace_package_table(&manifest) {
+            return Err(CargoMSRVError::WorkspaceFound);
+        }
+
+        set_workspace_msrv(cargo_toml, msrv, reporter)?;
+
+        cargo_toml.to_path_buf()
+    } else {
+        set_or_override_msrv(&mut manifest, msrv)?;
+        write_manifest(cargo_toml, &manifest)?;
+
+        reporter.report_event(AuxiliaryOutput::new(
+            Destination::Manifest(cargo_toml.to_path_buf()),
+            AuxiliaryOutputItem::msrv(MsrvKind::RustVersion),
+        ))?;
+
+        cargo_toml.to_path_buf()
+    };
+
+    if set_config.propagate {
+        for member in workspace_members(config)? {
+            inherit_workspace_msrv(member.manifest_path(), reporter)?;
+        }
+    }
+
+    if config.sync_clippy() {
+        write_clippy_msrv(config, reporter, msrv)?;
+    }
+
+    // Report that the MSRV was set
+    reporter.report_event(SetOutputMessage::new(msrv.clone(), written_to))?;
+
+    Ok(())
+}
+
+/// Set the `workspace.package.rust-version` in the Cargo manifest of the workspace root, which
+/// members inherit with `rust-version.workspace = true`.
+fn set_workspace_msrv(path: &Path, msrv: &BareVersion, reporter: &impl Reporter) -> TResult<()> {
+    let mut manifest = read_manifest(path)?;
+
+    if manifest.as_table().get("workspace").is_none() {
+        return Err(CargoMSRVError::SetMsrv(SetMsrvError::NoWorkspace(
+            path.to_path_buf(),
+        )));
+    }
+
+    insert_workspace_msrv(&mut manifest, msrv)?;
+    write_manifest(path, &manifest)?;
+
+    reporter.report_event(AuxiliaryOutput::new(
+        Destination::Manifest(path.to_path_buf()),
+        AuxiliaryOutputItem::msrv(MsrvKind::WorkspaceRustVersion),
+    ))?;
+
+    Ok(())
+}
+
+/// Switch the crate over to inherit its MSRV from the workspace.
+fn inherit_workspace_msrv(path: &Path, reporter: &impl Reporter) -> TResult<()> {
+    let mut manifest = read_manifest(path)?;
+
+    if inherits_rust_version(&manifest) {
+        return Ok(());
+    }
+
+    discard_current_msrv(&mut manifest);
+    insert_inherited_msrv(&mut manifest);
+    write_manifest(path, &manifest)?;
+
+    reporter.report_event(AuxiliaryOutput::new(
+        Destination::Manifest(path.to_path_buf()),
+        AuxiliaryOutputItem::msrv(MsrvKind::InheritedRustVersion),
+    ))?;
+
+    Ok(())
+}
+
+fn read_manifest(path: &Path) -> TResult<Document> {
+    let contents = std::fs::read_to_string(path).map_err(|error| CargoMSRVError::Io {
         error,
-        source: IoErrorSource::ReadFile(cargo_toml.to_path_buf()),
+        source: IoErrorSource::ReadFile(path.to_path_buf()),
     })?;
 
-    // Parse the Cargo manifest contents, in particular the MSRV value
-    let mut manifest = CargoManifestParser::default().parse::<Document>(&contents)?;
-    check_workspace(&manifest)?;
-    let msrv = &config.sub_command_config().set().msrv;
-
-    // Set the MSRV
-    set_or_override_msrv(&mut manifest, msrv)?;
+    Ok(CargoManifestParser::default().parse::<Document>(&contents)?)
+}
 
+fn write_manifest(path: &Path, manifest: &Document) -> TResult<()> {
     // Open the Cargo manifest file with write permissions and truncate the current its contents
     let mut file = std::fs::OpenOptions::new()
         .write(true)
         .truncate(true)
-        .open(&cargo_toml)
+        .open(path)
         .map_err(|error| CargoMSRVError::Io {
             error,
-            source: IoErrorSource::OpenFile(cargo_toml.to_path_buf()),
+            source: IoErrorSource::OpenFile(path.to_path_buf()),
         })?;
 
     // Write the new manifest contents with the newly set MSRV value
     write!(&mut file, "{}", manifest).map_err(|error| CargoMSRVError::Io {
         error,
-        source: IoErrorSource::WriteFile(cargo_toml.to_path_buf()),
-    })?;
+        source: IoErrorSource::WriteFile(path.to_path_buf()),
+    })
+}
 
-    reporter.report_event(AuxiliaryOutput::new(
-        Destination::File(cargo_toml.to_path_buf()),
-        AuxiliaryOutputItem::msrv(MsrvKind::RustVersion),
-    ))?;
+fn has_workspace_package_table(manifest: &Document) -> bool {
+    manifest
+        .as_table()
+        .get("workspace")
+        .and_then(|workspace| workspace.get("package"))
+        .and_then(Item::as_table_like)
+        .is_some()
+}
 
-    // Report that the MSRV was set
-    reporter.report_event(SetOutputMessage::new(
-        msrv.clone(),
-        cargo_toml.to_path_buf(),
-    ))?;
+/// Whether the crate inherits its MSRV from the workspace, i.e. `rust-version.workspace = true`.
+fn inherits_rust_version(manifest: &Document) -> bool {
+    manifest
+        .as_table()
+        .get("package")
+        .and_then(|package| package.get("rust-version"))
+        .and_then(|rust_version| rust_version.get("workspace"))
+        .and_then(Item::as_bool)
+        .unwrap_or(false)
+}
+
+/// Set the `workspace.package.rust-version`.
+///
+/// Members can only inherit the `rust-version`, which is supported by Cargo since Rust 1.56, and
+/// not the `package.metadata.msrv` fallback.
+fn insert_workspace_msrv(manifest: &mut Document, msrv: &BareVersion) -> TResult<()> {
+    if msrv.to_semver_version() < RUST_VERSION_SUPPORTED_SINCE {
+        return Err(CargoMSRVError::SetMsrv(
+            SetMsrvError::WorkspaceMsrvUnsupported(msrv.clone()),
+        ));
+    }
+
+    let rust_version = manifest
+        .get_mut("workspace")
+        .and_then(|workspace| workspace.get_mut("package"))
+        .and_then(|package| package.get_mut("rust-version"));
+
+    if replace_version(rust_version, msrv) {
+        return Ok(());
+    }
+
+    let package = &mut manifest["workspace"]["package"];
+
+    match package {
+        Item::None => {
+            // Explicitly create the table, otherwise it would default to an inline table instead
+            *package = table();
+            package["rust-version"] = value(msrv.to_string());
+        }
+        Item::Value(Value::InlineTable(package)) => {
+            package.insert("rust-version", msrv.to_string().into());
+        }
+        Item::Table(package) => {
+            package.insert("rust-version", value(msrv.to_string()));
+        }
+        _ => return Err(CargoMSRVError::SetMsrv(SetMsrvError::NotATable)),
+    }
 
     Ok(())
 }
 
-fn check_workspace(manifest: &Document) -> TResult<()> {
-    if manifest.as_table().get("package").is_none()
-        && manifest.as_table().get("workspace").is_some()
-    {
-        Err(CargoMSRVError::WorkspaceFound)
-    } else {
-        Ok(())
-    }
+/// Set `rust-version = { workspace = true }`, so the crate inherits the MSRV of the workspace.
+fn insert_inherited_msrv(manifest: &mut Document) {
+    let mut inherited = InlineTable::new();
+    inherited.insert("workspace", true.into());
+
+    manifest["package"]["rust-version"] = Item::Value(Value::InlineTable(inherited));
 }
 
 /// Override MSRV if it is already set, otherwise, simply set it
+///
+/// An MSRV which is already set in the same field is edited in place, so only its value changes,
+/// and its position and the comments around it are kept.
 fn set_or_override_msrv(manifest: &mut Document, msrv: &BareVersion) -> TResult<()> {
+    if msrv.to_semver_version() >= RUST_VERSION_SUPPORTED_SINCE {
+        let rust_version = manifest
+            .get_mut("package")
+            .and_then(|package| package.get_mut("rust-version"));
+
+        if replace_version(rust_version, msrv) {
+            remove_metadata_msrv(manifest);
+            return Ok(());
+        }
+    } else {
+        let metadata_msrv = manifest
+            .get_mut("package")
+            .and_then(|package| package.get_mut("metadata"))
+            .and_then(|metadata| metadata.get_mut("msrv"));
+
+        if replace_version(metadata_msrv, msrv) {
+            remove_rust_version(manifest);
+            return Ok(());
+        }
+    }
+
     // NB: As a consequence of scrubbing the current MSRV, if the MSRV is the only value in the
     //     [package.metadata] table, and the table is an inline table, then the inline table will
     //     be removed and replaced with a regular table (normally we try to keep the same table type
     //     if a table already existed).
-    //
-    //     In a future refactor, we may want to handle all cases of modifying the manifest instead
-    //     of discarding the current MSRV, considering the following cases:
-    //     * MSRV is not set yet, or is
-    //     * if set: is set as package.rust-version, as package.metadata.msrv or both
-    //     * new MSRV is below package.rust-version Cargo support threshold, or above
     discard_current_msrv(manifest);
     insert_new_msrv(manifest, msrv)
 }
 
+/// Replace the version of the given item, if it is set, while keeping the whitespace and comments
+/// around it.
+///
+/// Returns whether the version was replaced.
+fn replace_version(item: Option<&mut Item>, msrv: &BareVersion) -> bool {
+    match item.and_then(Item::as_value_mut) {
+        Some(version) if version.is_str() => {
+            let decor = version.decor().clone();
+
+            *version = Value::from(msrv.to_string());
+            *version.decor_mut() = decor;
+
+            true
+        }
+        _ => false,
+    }
+}
+
 fn insert_new_msrv(manifest: &mut Document, msrv: &BareVersion) -> TResult<()> {
     fn insert_rust_version(manifest: &mut Document, msrv: &BareVersion) -> TResult<()> {
         manifest["package"]["rust-version"] = value(msrv.to_string());
@@ -136,50 +306,50 @@ fn insert_new_msrv(manifest: &mut Document, msrv: &BareVersion) -> TResult<()> {
 
 /// Removes the minimum supported Rust version (MSRV) from `Cargo.toml` manifest, if it exists
 fn discard_current_msrv(document: &mut Document) {
-    fn get_package(document: &mut Document) -> Option<&mut Item> {
-        document.as_table_mut().get_mut("package")
-    }
+    // First remove the rust-version
+    remove_rust_version(document);
 
-    fn get_metadata(document: &Document) -> Option<&Item> {
-        document
-            .as_table()
-            .get("package")
-            .and_then(|package| package.get("metadata"))
-    }
+    // Then remove the metadata.msrv, if it exists
+    remove_metadata_msrv(document);
+}
 
-    /// Removes the `MSRV` as supported by Cargo since Rust 1.56.0
-    ///
-    /// [`Cargo`]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-rust-version-field
-    fn remove_rust_version(document: &mut Document) {
-        get_package(document)
-            .and_then(Item::as_table_like_mut)
-            .and_then(|package| package.remove("rust-version"));
-    }
+fn get_package(document: &mut Document) -> Option<&mut Item> {
+    document.as_table_mut().get_mut("package")
+}
+
+fn get_metadata(document: &Document) -> Option<&Item> {
+    document
+        .as_table()
+        .get("package")
+        .and_then(|package| package.get("metadata"))
+}
 
-    /// Removes the MSRV as supported by `cargo-msrv`, since prior to the release of Rust
-    /// 1.56.0
-    fn remove_metadata_msrv(document: &mut Document) {
+/// Removes the `MSRV` as supported by Cargo since Rust 1.56.0
+///
+/// [`Cargo`]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-rust-version-field
+fn remove_rust_version(document: &mut Document) {
+    get_package(document)
+        .and_then(Item::as_table_like_mut)
+        .and_then(|package| package.remove("rust-version"));
+}
+
+/// Removes the MSRV as supported by `cargo-msrv`, since prior to the release of Rust
+/// 1.56.0
+fn remove_metadata_msrv(document: &mut Document) {
+    get_package(document)
+        .and_then(|package| package.get_mut("metadata"))
+        .and_then(Item::as_table_like_mut)
+        .and_then(|metadata| metadata.remove("msrv"));
+
+    // remove residual metadata table if now empty
+    if let Some(true) = get_metadata(document)
+        .and_then(Item::as_table_like)
+        .map(|metadata| metadata.is_empty())
+    {
         get_package(document)
-            .and_then(|package| package.get_mut("metadata"))
             .and_then(Item::as_table_like_mut)
-            .and_then(|metadata| metadata.remove("msrv"));
-
-        // remove residual metadata table if now empty
-        if let Some(true) = get_metadata(document)
-            .and_then(Item::as_table_like)
-            .map(|metadata| metadata.is_empty())
-        {
-            get_package(document)
-                .and_then(Item::as_table_like_mut)
-                .map(|package| package.remove("metadata"));
-        }
+            .map(|package| package.remove("metadata"));
     }
-
-    // First remove the rust-version
-    remove_rust_version(document);
-
-    // Then remove the metadata.msrv, if it exists
-    remove_metadata_msrv(document);
 }
 
 #[cfg(test)]
@@ -924,3 +1094,277 @@ edition = "2021"
         assert_eq!(new_manifest.minimum_rust_version().unwrap(), &METADATA_MSRV)
     }
 }
+
+#[cfg(test)]
+mod workspace_msrv_tests {
+    use toml_edit::{Document, Item};
+
+    use crate::error::{CargoMSRVError, SetMsrvError};
+    use crate::manifest::bare_version::BareVersion;
+    use crate::manifest::{CargoManifestParser, TomlParser};
+    use crate::sub_command::set::{
+        discard_current_msrv, inherits_rust_version, insert_inherited_msrv, insert_workspace_msrv,
+    };
+
+    #[test]
+    fn insert_workspace_msrv_without_package_table() {
+        let input = r#"[workspace]
+members = ["a", "b"]
+"#;
+
+        let mut manifest = CargoManifestParser::default()
+            .parse::<Document>(input)
+            .unwrap();
+
+        insert_workspace_msrv(&mut manifest, &BareVersion::TwoComponents(1, 64)).unwrap();
+
+        assert!(matches!(manifest["workspace"]["package"], Item::Table(_)));
+        assert_eq!(
+            manifest["workspace"]["package"]["rust-version"]
+                .as_str()
+                .unwrap(),
+            "1.64"
+        );
+    }
+
+    #[test]
+    fn insert_workspace_msrv_with_existing() {
+        let input = r#"[workspace]
+members = ["a", "b"]
+
+[workspace.package]
+version = "0.1.0"
+rust-version = "1.60"
+"#;
+
+        let mut manifest = CargoManifestParser::default()
+            .parse::<Document>(input)
+            .unwrap();
+
+        insert_workspace_msrv(&mut manifest, &BareVersion::ThreeComponents(1, 64, 1)).unwrap();
+
+        assert_eq!(
+            manifest["workspace"]["package"]["rust-version"]
+                .as_str()
+                .unwrap(),
+            "1.64.1"
+        );
+        assert_eq!(
+            manifest["workspace"]["package"]["version"]
+                .as_str()
+                .unwrap(),
+            "0.1.0"
+        );
+    }
+
+    #[test]
+    fn insert_workspace_msrv_prior_to_rust_version() {
+        let input = r#"[workspace]
+members = ["a", "b"]
+"#;
+
+        let mut manifest = CargoManifestParser::default()
+            .parse::<Document>(input)
+            .unwrap();
+
+        let result = insert_workspace_msrv(&mut manifest, &BareVersion::TwoComponents(1, 40));
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::SetMsrv(
+                SetMsrvError::WorkspaceMsrvUnsupported(_)
+            ))
+        ));
+    }
+
+    #[yare::parameterized(
+        dotted = { "[package]\nrust-version.workspace = true\n", true },
+        inline = { "[package]\nrust-version = { workspace = true }\n", true },
+        explicit = { "[package]\nrust-version = \"1.56\"\n", false },
+        absent = { "[package]\nname = \"a\"\n", false },
+    )]
+    fn inherits(input: &str, expected: bool) {
+        let manifest = CargoManifestParser::default()
+            .parse::<Document>(input)
+            .unwrap();
+
+        assert_eq!(inherits_rust_version(&manifest), expected);
+    }
+
+    #[test]
+    fn switch_to_inherited_msrv() {
+        let input = r#"[package]
+name = "a"
+version = "0.1.0"
+rust-version = "1.58"
+
+[package.metadata]
+msrv = "1.58"
+"#;
+
+        let mut manifest = CargoManifestParser::default()
+            .parse::<Document>(input)
+            .unwrap();
+
+        discard_current_msrv(&mut manifest);
+        insert_inherited_msrv(&mut manifest);
+
+        assert!(inherits_rust_version(&manifest));
+        assert!(manifest["package"].get("metadata").is_none());
+    }
+}
+
+#[cfg(test)]
+mod preserve_formatting_tests {
+    use toml_edit::Document;
+
+    use crate::manifest::bare_version::BareVersion;
+    use crate::manifest::{CargoManifestParser, TomlParser};
+    use crate::sub_command::set::{insert_workspace_msrv, set_or_override_msrv};
+
+    fn set(input: &str, msrv: BareVersion) -> String {
+        let mut manifest = CargoManifestParser::default()
+            .parse::<Document>(input)
+            .unwrap();
+
+        set_or_override_msrv(&mut manifest, &msrv).unwrap();
+
+        manifest.to_string()
+    }
+
+    #[test]
+    fn keeps_comments() {
+        let input = r#"# The package
+[package]
+name = "package_name" # the name
+# The MSRV, checked on CI
+rust-version = "1.58"   # keep in sync with the README
+edition = "2021"
+
+[dependencies]
+# none yet
+"#;
+
+        let expected = r#"# The package
+[package]
+name = "package_name" # the name
+# The MSRV, checked on CI
+rust-version = "1.60"   # keep in sync with the README
+edition = "2021"
+
+[dependencies]
+# none yet
+"#;
+
+        assert_eq!(set(input, BareVersion::TwoComponents(1, 60)), expected);
+    }
+
+    #[test]
+    fn keeps_key_order() {
+        let input = r#"[package]
+rust-version = "1.58"
+name = "package_name"
+version = "0.1.0"
+"#;
+
+        let expected = r#"[package]
+rust-version = "1.61.0"
+name = "package_name"
+version = "0.1.0"
+"#;
+
+        assert_eq!(set(input, BareVersion::ThreeComponents(1, 61, 0)), expected);
+    }
+
+    #[test]
+    fn keeps_table_order() {
+        let input = r#"[dependencies]
+serde = "1"
+
+[package]
+name = "package_name"
+rust-version = "1.58"
+
+[features]
+default = []
+"#;
+
+        let expected = r#"[dependencies]
+serde = "1"
+
+[package]
+name = "package_name"
+rust-version = "1.60"
+
+[features]
+default = []
+"#;
+
+        assert_eq!(set(input, BareVersion::TwoComponents(1, 60)), expected);
+    }
+
+    #[test]
+    fn keeps_inline_metadata_table() {
+        let input = r#"[package]
+name = "package_name"
+metadata = { msrv = "1.15", other = 1 }
+"#;
+
+        let expected = r#"[package]
+name = "package_name"
+metadata = { msrv = "1.17", other = 1 }
+"#;
+
+        assert_eq!(set(input, BareVersion::TwoComponents(1, 17)), expected);
+    }
+
+    #[test]
+    fn keeps_metadata_table() {
+        let input = r#"[package]
+name = "package_name"
+
+[package.metadata]
+# fallback for older toolchains
+msrv = "1.15"
+other = 1
+"#;
+
+        let expected = r#"[package]
+name = "package_name"
+
+[package.metadata]
+# fallback for older toolchains
+msrv = "1.17"
+other = 1
+"#;
+
+        assert_eq!(set(input, BareVersion::TwoComponents(1, 17)), expected);
+    }
+
+    #[test]
+    fn keeps_workspace_comments() {
+        let input = r#"[workspace]
+members = ["a"]
+
+[workspace.package]
+rust-version = "1.64" # inherited by all members
+version = "0.1.0"
+"#;
+
+        let expected = r#"[workspace]
+members = ["a"]
+
+[workspace.package]
+rust-version = "1.65" # inherited by all members
+version = "0.1.0"
+"#;
+
+        let mut manifest = CargoManifestParser::default()
+            .parse::<Document>(input)
+            .unwrap();
+
+        insert_workspace_msrv(&mut manifest, &BareVersion::TwoComponents(1, 65)).unwrap();
+
+        assert_eq!(manifest.to_string(), expected);
+    }
+}
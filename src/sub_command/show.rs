@@ -1,14 +1,18 @@
 use std::convert::TryFrom;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use toml_edit::Document;
 
-use crate::config::Config;
+use crate::config::{Config, SubCommandConfig};
 use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::manifest::bare_version::BareVersion;
 
 use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
-use crate::reporter::event::ShowOutputMessage;
+use crate::reporter::event::{
+    DeclaredMsrv, EditionFloor, MsrvSource, MsrvSources, ShowOutputMessage,
+};
 use crate::reporter::Reporter;
+use crate::toolchain_file::ToolchainFile;
 use crate::SubCommand;
 
 #[derive(Default)]
@@ -33,8 +37,22 @@ fn show_msrv(config: &Config, reporter: &impl Reporter) -> TResult<()> {
     let manifest = CargoManifestParser::default().parse::<Document>(&contents)?;
     let manifest = CargoManifest::try_from(manifest)?;
 
-    let msrv = manifest
-        .minimum_rust_version()
+    if show_edition(config) {
+        reporter.report_event(EditionFloor::new(
+            manifest.edition(),
+            cargo_toml.to_path_buf(),
+        ))?;
+
+        return Ok(());
+    }
+
+    let toolchain_file = ToolchainFile::try_from_dir(config.context().crate_root_path()?)?;
+
+    reporter.report_event(msrv_sources(&manifest, cargo_toml, toolchain_file.as_ref()))?;
+
+    let msrv = config
+        .msrv_resolver()
+        .manifest_msrv(&manifest)
         .ok_or_else(|| Error::NoMSRVInCargoManifest(cargo_toml.to_path_buf()))?;
 
     reporter.report_event(ShowOutputMessage::new(
@@ -45,6 +63,45 @@ fn show_msrv(config: &Config, reporter: &impl Reporter) -> TResult<()> {
     Ok(())
 }
 
+/// Whether the edition imposed lower bound of the MSRV should be shown, instead of the MSRV.
+fn show_edition(config: &Config) -> bool {
+    matches!(config.sub_command_config(), SubCommandConfig::ShowConfig(cmd) if cmd.edition)
+}
+
+/// Collect the MSRV from each source which declares one.
+fn msrv_sources(
+    manifest: &CargoManifest,
+    manifest_path: &Path,
+    toolchain_file: Option<&ToolchainFile>,
+) -> MsrvSources {
+    let from_manifest = |source, version: Option<&BareVersion>| {
+        version.map(|version| {
+            DeclaredMsrv::new(source, manifest_path.to_path_buf(), version.to_string())
+        })
+    };
+
+    let from_toolchain_file = toolchain_file.and_then(|file| {
+        file.channel().map(|channel| {
+            DeclaredMsrv::new(
+                MsrvSource::ToolchainFile,
+                file.path().to_path_buf(),
+                channel,
+            )
+        })
+    });
+
+    let sources = from_manifest(MsrvSource::RustVersion, manifest.rust_version())
+        .into_iter()
+        .chain(from_manifest(
+            MsrvSource::MetadataMsrv,
+            manifest.metadata_msrv(),
+        ))
+        .chain(from_toolchain_file)
+        .collect();
+
+    MsrvSources::new(sources)
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("MSRV was not specified in Cargo manifest at '{}'", .0.display())]
@@ -0,0 +1,285 @@
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+
+use crate::semver;
+use cargo_metadata::{Dependency, DependencyKind, MetadataCommand};
+use toml_edit::{Document, Item, Value};
+
+use crate::config::Config;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::manifest::bare_version::BareVersion;
+use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
+use crate::registry_index::{cached_versions, IndexEntry};
+use crate::reporter::event::{
+    AuxiliaryOutput, AuxiliaryOutputItem, DependencyUpgrade, Destination, UpgradeSuggestions,
+};
+use crate::reporter::Reporter;
+use crate::SubCommand;
+
+/// Suggest the newest version of each direct dependency which is compatible with its current
+/// version requirement, and which still supports the given MSRV.
+///
+/// The available versions of the dependencies are read from the local cache of the registry
+/// index, so dependencies which Cargo has not resolved before can't be upgraded.
+#[derive(Default)]
+pub struct Upgrade;
+
+impl SubCommand for Upgrade {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        upgrade(config, reporter)
+    }
+}
+
+fn upgrade(config: &Config, reporter: &impl Reporter) -> TResult<()> {
+    let manifest_path = config.context().manifest_path()?;
+    let msrv = msrv(config, manifest_path)?;
+
+    let metadata = MetadataCommand::new()
+        .manifest_path(manifest_path)
+        .no_deps()
+        .exec()?;
+
+    // Cargo metadata reports canonical paths
+    let canonical_path = manifest_path
+        .canonicalize()
+        .unwrap_or_else(|_| manifest_path.to_path_buf());
+
+    let package = metadata
+        .packages
+        .iter()
+        .find(|package| package.manifest_path.as_std_path() == canonical_path)
+        .ok_or_else(|| Error::NoPackage(manifest_path.to_path_buf()))?;
+
+    let dependencies = package
+        .dependencies
+        .iter()
+        .filter(|dependency| is_registry_dependency(dependency))
+        .map(|dependency| {
+            let entries = cached_versions(&dependency.name);
+            let (suggested, latest) = suggest(&dependency.req, &entries, &msrv.to_semver_version());
+
+            DependencyUpgrade::new(
+                dependency.rename.as_deref().unwrap_or(&dependency.name),
+                table_name(dependency.kind),
+                &dependency.req,
+                suggested,
+                latest,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let suggestions = UpgradeSuggestions::new(msrv, dependencies);
+
+    if config.sub_command_config().upgrade().write {
+        write_upgrades(manifest_path, &suggestions)?;
+
+        reporter.report_event(AuxiliaryOutput::new(
+            Destination::Manifest(manifest_path.to_path_buf()),
+            AuxiliaryOutputItem::dependencies(),
+        ))?;
+    }
+
+    reporter.report_event(suggestions)?;
+
+    Ok(())
+}
+
+/// The MSRV given as argument, or else the MSRV declared in the Cargo manifest.
+fn msrv(config: &Config, manifest_path: &Path) -> TResult<BareVersion> {
+    if let Some(msrv) = &config.sub_command_config().upgrade().msrv {
+        return Ok(msrv.clone());
+    }
+
+    let contents = read_manifest(manifest_path)?;
+    let document = CargoManifestParser::default().parse::<Document>(&contents)?;
+    let manifest = CargoManifest::try_from(document)?;
+
+    manifest
+        .minimum_rust_version()
+        .cloned()
+        .ok_or_else(|| Error::NoMsrv(manifest_path.to_path_buf()).into())
+}
+
+/// The newest version which matches the requirement and supports the MSRV, and the newest
+/// version which matches the requirement regardless of its MSRV.
+///
+/// Yanked versions and pre-releases are never suggested. Versions which don't declare a
+/// `rust-version` are assumed to support the MSRV.
+fn suggest(
+    requirement: &semver::VersionReq,
+    entries: &[IndexEntry],
+    msrv: &semver::Version,
+) -> (Option<semver::Version>, Option<semver::Version>) {
+    let candidates = entries.iter().filter(|entry| {
+        !entry.is_yanked() && entry.version().pre.is_empty() && requirement.matches(entry.version())
+    });
+
+    let latest = candidates.clone().map(IndexEntry::version).max().cloned();
+
+    let suggested = candidates
+        .filter(|entry| {
+            entry.rust_version().map_or(true, |rust_version| {
+                rust_version.to_semver_version() <= *msrv
+            })
+        })
+        .map(IndexEntry::version)
+        .max()
+        .cloned();
+
+    (suggested, latest)
+}
+
+fn is_registry_dependency(dependency: &Dependency) -> bool {
+    dependency.source.as_deref().map_or(false, |source| {
+        source.starts_with("registry+") || source.starts_with("sparse+")
+    })
+}
+
+fn table_name(kind: DependencyKind) -> &'static str {
+    match kind {
+        DependencyKind::Development => "dev-dependencies",
+        DependencyKind::Build => "build-dependencies",
+        _ => "dependencies",
+    }
+}
+
+/// Update the version requirement of each dependency with a suggested upgrade in the Cargo
+/// manifest, keeping the rest of the manifest intact.
+fn write_upgrades(manifest_path: &Path, suggestions: &UpgradeSuggestions) -> TResult<()> {
+    let contents = read_manifest(manifest_path)?;
+    let mut document = CargoManifestParser::default().parse::<Document>(&contents)?;
+
+    for dependency in suggestions.upgrades() {
+        if let Some(version) = dependency.suggested() {
+            set_requirement(
+                &mut document,
+                dependency.table(),
+                dependency.name(),
+                &version.to_string(),
+            );
+        }
+    }
+
+    std::fs::write(manifest_path, document.to_string()).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(manifest_path.to_path_buf()),
+    })
+}
+
+/// Set the version requirement of a dependency in the given table, and in the same table of each
+/// platform specific `target` table.
+fn set_requirement(document: &mut Document, table: &str, name: &str, requirement: &str) {
+    if let Some(dependency) = document
+        .get_mut(table)
+        .and_then(|table| table.get_mut(name))
+    {
+        set_version(dependency, requirement);
+    }
+
+    if let Some(targets) = document.get_mut("target").and_then(Item::as_table_like_mut) {
+        for (_, target) in targets.iter_mut() {
+            if let Some(dependency) = target.get_mut(table).and_then(|table| table.get_mut(name)) {
+                set_version(dependency, requirement);
+            }
+        }
+    }
+}
+
+/// Set the version of a dependency, given either as version string, or as table with a `version`
+/// key. The formatting of the existing value is preserved.
+fn set_version(dependency: &mut Item, requirement: &str) {
+    let version = if dependency.is_str() {
+        dependency
+    } else {
+        match dependency.get_mut("version") {
+            Some(version) => version,
+            None => return,
+        }
+    };
+
+    if let Some(current) = version.as_value_mut() {
+        let decor = current.decor().clone();
+        *current = Value::from(requirement);
+        *current.decor_mut() = decor;
+    }
+}
+
+fn read_manifest(manifest_path: &Path) -> TResult<String> {
+    std::fs::read_to_string(manifest_path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(manifest_path.to_path_buf()),
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("No MSRV was given, and no MSRV was specified in the Cargo manifest at '{}'", .0.display())]
+    NoMsrv(PathBuf),
+
+    #[error("No package was found for the Cargo manifest at '{}'", .0.display())]
+    NoPackage(PathBuf),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<IndexEntry> {
+        vec![
+            IndexEntry::new(semver::Version::new(1, 0, 0), None, false),
+            IndexEntry::new(
+                semver::Version::new(1, 1, 0),
+                Some(BareVersion::TwoComponents(1, 56)),
+                false,
+            ),
+            IndexEntry::new(
+                semver::Version::new(1, 2, 0),
+                Some(BareVersion::TwoComponents(1, 60)),
+                false,
+            ),
+            IndexEntry::new(semver::Version::new(1, 3, 0), None, true),
+            IndexEntry::new(semver::Version::new(2, 0, 0), None, false),
+        ]
+    }
+
+    #[yare::parameterized(
+        msrv_supported_by_all = { semver::Version::new(1, 64, 0), Some(semver::Version::new(1, 2, 0)) },
+        msrv_raised_by_newest = { semver::Version::new(1, 58, 0), Some(semver::Version::new(1, 1, 0)) },
+        msrv_raised_by_all_but_oldest = { semver::Version::new(1, 50, 0), Some(semver::Version::new(1, 0, 0)) },
+    )]
+    fn suggested_version(msrv: semver::Version, expected: Option<semver::Version>) {
+        let requirement = semver::VersionReq::parse("1.0").unwrap();
+
+        let (suggested, latest) = suggest(&requirement, &entries(), &msrv);
+
+        assert_eq!(suggested, expected);
+        assert_eq!(latest, Some(semver::Version::new(1, 2, 0)));
+    }
+
+    #[test]
+    fn set_requirement_preserves_formatting() {
+        let contents = r#"[dependencies]
+a = "1.0" # comment
+b = { version = "0.3", features = ["x"] }
+
+[target.'cfg(unix)'.dependencies]
+a = "1.0"
+"#;
+
+        let mut document = contents.parse::<Document>().unwrap();
+        set_requirement(&mut document, "dependencies", "a", "1.2.0");
+        set_requirement(&mut document, "dependencies", "b", "0.3.5");
+
+        let expected = r#"[dependencies]
+a = "1.2.0" # comment
+b = { version = "0.3.5", features = ["x"] }
+
+[target.'cfg(unix)'.dependencies]
+a = "1.2.0"
+"#;
+
+        assert_eq!(document.to_string(), expected);
+    }
+}
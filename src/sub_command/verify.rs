@@ -5,15 +5,28 @@ use rust_releases::{Release, ReleaseIndex};
 
 use toml_edit::Document;
 
+use crate::certification::Certification;
 use crate::check::Check;
-use crate::config::Config;
+use crate::config::{Config, ConfigBuilder, SearchMethod};
+use crate::diagnosis::{diagnose, Diagnosis};
 use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::filter_releases::filter_releases;
 use crate::manifest::bare_version::BareVersion;
 use crate::manifest::{CargoManifest, CargoManifestParser, TomlParser};
+use crate::msrv::MinimumSupportedRustVersion;
 use crate::outcome::Outcome;
+use crate::reporter::event::{
+    CertificationOutdated, LowerMsrvAvailable, PackageVerifyResult, VerifyResult, VerifyStatus,
+    WorkspaceVerifyResult,
+};
 use crate::reporter::Reporter;
+use crate::search_method::{Bisect, Gallop, Linear, SearchStrategy};
+use crate::semver;
 use crate::sub_command::SubCommand;
 use crate::toolchain::ToolchainSpec;
+use crate::workspace::{selected_members, WorkspaceMember};
+
+mod published;
 
 /// Verifier which determines whether a given Rust version is deemed compatible or not.
 pub struct Verify<'index, C: Check> {
@@ -37,15 +50,147 @@ impl<'index, C: Check> SubCommand for Verify<'index, C> {
     type Output = ();
 
     /// Run the verifier against a Rust version which is obtained from the config.
-    fn run(&self, config: &Config, _reporter: &impl Reporter) -> TResult<Self::Output> {
-        let rust_version = RustVersion::try_from_config(config)?;
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        verify(config, reporter, self.release_index, &self.runner)
+    }
+}
 
-        verify_msrv(config, self.release_index, rust_version, &self.runner)?;
+/// Verify the crate, or each member of the workspace, depending on the config.
+pub(crate) fn verify(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+) -> TResult<()> {
+    if let Some(version) = &config.sub_command_config().verify().published {
+        return verify_published(config, reporter, release_index, runner, version);
+    }
+
+    if let Some(members) = selected_members(config)? {
+        return verify_workspace(config, reporter, release_index, runner, members);
+    }
+
+    let rust_version = RustVersion::try_from_config(config)?;
+
+    verify_msrv(config, reporter, release_index, rust_version, runner)
+}
 
+/// Verify each member of the workspace against its own MSRV.
+///
+/// Members which do not specify an MSRV are skipped. Fails if any of the members fails
+/// verification, according to the fail-on policy.
+fn verify_workspace(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+    members: Vec<WorkspaceMember>,
+) -> TResult<()> {
+    let mut packages = Vec::with_capacity(members.len());
+
+    for member in members {
+        info!(
+            package = member.name(),
+            "verifying msrv of workspace member"
+        );
+
+        let member_config = ConfigBuilder::from_config(config)
+            .crate_path(Option::<&Path>::None)
+            .manifest_path(Some(member.manifest_path()))
+            .workspace(false)
+            .build();
+
+        let rust_version = match RustVersion::try_from_config(&member_config) {
+            Ok(rust_version) => rust_version,
+            Err(CargoMSRVError::NoMSRVKeyInCargoToml(_)) => {
+                packages.push(PackageVerifyResult::without_rust_version(member.name()));
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        let verdict = check_rust_version(
+            &member_config,
+            reporter,
+            release_index,
+            &rust_version,
+            runner,
+        )?;
+
+        packages.push(PackageVerifyResult::new(
+            member.name(),
+            rust_version.version().clone(),
+            verdict.status(),
+        ));
+    }
+
+    let fail_on = config.sub_command_config().verify().fail_on;
+    let result = WorkspaceVerifyResult::new(packages, fail_on);
+
+    let failed_with = |status: VerifyStatus| {
+        result
+            .packages()
+            .iter()
+            .filter(|package| package.status() == status && fail_on.fails_on(status))
+            .filter_map(|package| {
+                package
+                    .rust_version()
+                    .map(|version| (package.name().to_string(), version.clone()))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let failed = WorkspaceVerifyFailed {
+        incompatible: failed_with(VerifyStatus::Incompatible),
+        too_conservative: failed_with(VerifyStatus::TooConservative),
+    };
+
+    reporter.report_event(result)?;
+
+    if failed.incompatible.is_empty() && failed.too_conservative.is_empty() {
         Ok(())
+    } else {
+        Err(CargoMSRVError::SubCommandVerify(
+            Error::WorkspaceVerifyFailed(failed),
+        ))
     }
 }
 
+/// Verify the sources of the given version of the crate, as published to crates.io, rather than
+/// the local sources.
+///
+/// The published crate is verified against the MSRV declared in its own Cargo manifest, unless a
+/// Rust version is given.
+fn verify_published(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+    version: &semver::Version,
+) -> TResult<()> {
+    let name = published::package_name(config.context().manifest_path()?)?;
+
+    info!(package = %name, %version, "verifying msrv of published crate");
+
+    let published = published::PublishedCrate::download(&name, version)?;
+
+    let published_config = ConfigBuilder::from_config(config)
+        .crate_path(Option::<&Path>::None)
+        .manifest_path(Some(published.manifest_path()))
+        .workspace(false)
+        .build();
+
+    let rust_version = RustVersion::try_from_config(&published_config)?;
+
+    verify_msrv(
+        &published_config,
+        reporter,
+        release_index,
+        rust_version,
+        runner,
+    )
+}
+
 /// Parse the cargo manifest from the given path.
 fn parse_manifest(path: &Path) -> TResult<CargoManifest> {
     let contents = std::fs::read_to_string(path).map_err(|error| CargoMSRVError::Io {
@@ -59,33 +204,289 @@ fn parse_manifest(path: &Path) -> TResult<CargoManifest> {
 
 /// Verify whether a Cargo project is compatible with a `rustup run` command,
 /// for the (given or specified) `rust_version`.
+///
+/// Whether the verification fails is decided by the fail-on policy.
 fn verify_msrv(
     config: &Config,
+    reporter: &impl Reporter,
     release_index: &ReleaseIndex,
     rust_version: RustVersion,
     runner: &impl Check,
 ) -> TResult<()> {
+    let cmd = config.sub_command_config().verify();
+    let fail_on = cmd.fail_on;
+
+    let verdict = check_rust_version(config, reporter, release_index, &rust_version, runner)?;
+
+    if cmd.find_lower {
+        report_lower_msrv(
+            config,
+            reporter,
+            release_index,
+            &rust_version,
+            &verdict,
+            runner,
+        )?;
+    }
+
+    match verdict {
+        Verdict::Incompatible(diagnosis) if fail_on.fails_on_lower() => {
+            Err(CargoMSRVError::SubCommandVerify(Error::VerifyFailed(
+                VerifyFailed::new(rust_version, diagnosis),
+            )))
+        }
+        Verdict::TooConservative(preceding_release) if fail_on.fails_on_higher() => Err(
+            CargoMSRVError::SubCommandVerify(Error::MsrvTooConservative(MsrvTooConservative::new(
+                rust_version,
+                preceding_release,
+            ))),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// The outcome of verifying a crate against a Rust version.
+#[derive(Debug)]
+enum Verdict {
+    Compatible,
+    /// The crate is not compatible with the Rust version, possibly for a diagnosed reason.
+    Incompatible(Option<Diagnosis>),
+    /// The crate is compatible with the Rust version, but also with the given release which
+    /// precedes it.
+    TooConservative(semver::Version),
+}
+
+impl Verdict {
+    fn status(&self) -> VerifyStatus {
+        match self {
+            Self::Compatible => VerifyStatus::Compatible,
+            Self::Incompatible(_) => VerifyStatus::Incompatible,
+            Self::TooConservative(_) => VerifyStatus::TooConservative,
+        }
+    }
+}
+
+/// Check the crate against the Rust version, and report the result.
+///
+/// If the fail-on policy fails on a declared MSRV which is higher than necessary, and the crate is
+/// compatible, the release preceding the Rust version is checked as well.
+fn check_rust_version(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    rust_version: &RustVersion,
+    runner: &impl Check,
+) -> TResult<Verdict> {
     let bare_version = rust_version.version();
-    let version =
-        bare_version.try_to_semver(release_index.releases().iter().map(Release::version))?;
+    let version = to_semver(bare_version, release_index)?;
+
+    let toolchain = ToolchainSpec::new(&version, config.target());
+
+    let manifest_path = rust_version.source.manifest_path().map(Path::to_path_buf);
+
+    if let Some(path) = &manifest_path {
+        report_outdated_certification(config, reporter, path, &version)?;
+    }
+
+    let verdict = match runner.check(config, &toolchain)? {
+        Outcome::Success(_) => {
+            let fail_on = config.sub_command_config().verify().fail_on;
+
+            match preceding_release(&version, release_index) {
+                Some(preceding) if fail_on.fails_on_higher() => {
+                    let toolchain = ToolchainSpec::new(&preceding, config.target());
+
+                    match runner.check(config, &toolchain)? {
+                        Outcome::Success(_) => Verdict::TooConservative(preceding),
+                        Outcome::Failure(_) => Verdict::Compatible,
+                    }
+                }
+                _ => Verdict::Compatible,
+            }
+        }
+        Outcome::Failure(failure) => {
+            let diagnosis = diagnose(&failure.error_message);
+            info!(?diagnosis, "diagnosed verify failure");
+
+            Verdict::Incompatible(diagnosis)
+        }
+    };
+
+    let result = match &verdict {
+        Verdict::Compatible => VerifyResult::compatible(bare_version.clone(), manifest_path),
+        Verdict::Incompatible(diagnosis) => VerifyResult::incompatible(
+            bare_version.clone(),
+            manifest_path,
+            diagnosis.as_ref().map(ToString::to_string),
+        ),
+        Verdict::TooConservative(preceding) => {
+            VerifyResult::too_conservative(bare_version.clone(), manifest_path, preceding.clone())
+        }
+    };
+
+    reporter.report_event(result.with_host(config.host()))?;
+
+    Ok(verdict)
+}
+
+/// Search for the MSRV among the releases which precede the verified Rust version, and report it
+/// if the crate is compatible with any of them.
+///
+/// Nothing is searched if the crate is not compatible with the verified Rust version, or if the
+/// preceding release was already found to be incompatible.
+fn report_lower_msrv(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    rust_version: &RustVersion,
+    verdict: &Verdict,
+    runner: &impl Check,
+) -> TResult<()> {
+    let fail_on = config.sub_command_config().verify().fail_on;
+
+    match verdict {
+        Verdict::Compatible if !fail_on.fails_on_higher() => {}
+        Verdict::TooConservative(_) => {}
+        _ => return Ok(()),
+    }
+
+    let version = to_semver(rust_version.version(), release_index)?;
+    let releases = filter_releases(config, release_index.releases())
+        .into_iter()
+        .filter(|release| release.version() < &version)
+        .collect::<Vec<_>>();
+
+    if releases.is_empty() {
+        return Ok(());
+    }
+
+    let msrv = match config.search_method() {
+        SearchMethod::Linear => Linear::new(runner).find_toolchain(&releases, config, reporter)?,
+        SearchMethod::Bisect => Bisect::new(runner).find_toolchain(&releases, config, reporter)?,
+        SearchMethod::Gallop => Gallop::new(runner).find_toolchain(&releases, config, reporter)?,
+    };
+
+    if let MinimumSupportedRustVersion::Toolchain { toolchain } = msrv {
+        reporter.report_event(LowerMsrvAvailable::new(
+            rust_version.version().clone(),
+            rust_version.source.manifest_path().map(Path::to_path_buf),
+            toolchain.version().clone(),
+        ))?;
+    }
+
+    Ok(())
+}
 
-    let toolchain = ToolchainSpec::new(version, config.target());
+/// Report whether the inputs of the checks changed since the MSRV was certified by
+/// `find --certify`, if it was certified at all.
+fn report_outdated_certification(
+    config: &Config,
+    reporter: &impl Reporter,
+    manifest_path: &Path,
+    version: &semver::Version,
+) -> TResult<()> {
+    let contents = std::fs::read_to_string(manifest_path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(manifest_path.to_path_buf()),
+    })?;
+    let manifest = CargoManifestParser::default().parse::<Document>(&contents)?;
+
+    let certified = match Certification::read(&manifest) {
+        Some(certified) => certified,
+        None => return Ok(()),
+    };
+
+    let changed = certified.changed_inputs(&Certification::new(config, version)?);
 
-    match runner.check(config, &toolchain)? {
-        Outcome::Success(_) => Ok(()),
-        Outcome::Failure(_) => Err(CargoMSRVError::SubCommandVerify(Error::VerifyFailed(
-            VerifyFailed::from(rust_version),
-        ))),
+    if !changed.is_empty() {
+        info!(?changed, "inputs changed since the msrv was certified");
+
+        reporter.report_event(CertificationOutdated::new(
+            manifest_path,
+            certified.toolchain(),
+            certified.date(),
+            changed,
+        ))?;
     }
+
+    Ok(())
+}
+
+/// The Rust versions which would be checked: the given rust-version, or else the MSRV of the
+/// crate, followed by the release preceding it if the fail-on policy fails on a declared MSRV
+/// which is higher than necessary.
+pub(crate) fn rust_versions(
+    config: &Config,
+    release_index: &ReleaseIndex,
+) -> TResult<Vec<semver::Version>> {
+    let rust_version = RustVersion::try_from_config(config)?;
+    let version = to_semver(rust_version.version(), release_index)?;
+
+    let preceding = if config
+        .sub_command_config()
+        .verify()
+        .fail_on
+        .fails_on_higher()
+    {
+        preceding_release(&version, release_index)
+    } else {
+        None
+    };
+
+    Ok(std::iter::once(version).chain(preceding).collect())
+}
+
+/// The most recent release in the index which precedes the given version, if any.
+fn preceding_release(
+    version: &semver::Version,
+    release_index: &ReleaseIndex,
+) -> Option<semver::Version> {
+    release_index
+        .releases()
+        .iter()
+        .map(Release::version)
+        .filter(|release| *release < version)
+        .max()
+        .cloned()
+}
+
+/// The release which matches a bare (two- or three component) version.
+fn to_semver(version: &BareVersion, release_index: &ReleaseIndex) -> TResult<semver::Version> {
+    let version = version.try_to_semver(release_index.releases().iter().map(Release::version))?;
+
+    Ok(version.clone())
 }
 
 /// Error which can be returned if the verifier deemed the tested Rust version incompatible.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error(
-        "Crate source was found to be incompatible with Rust version '{}' specified {}", .0.rust_version, .0.source
-    )]
+    #[error("{0}")]
     VerifyFailed(VerifyFailed),
+
+    #[error("{0}")]
+    MsrvTooConservative(MsrvTooConservative),
+
+    #[error("{0}")]
+    WorkspaceVerifyFailed(WorkspaceVerifyFailed),
+
+    #[error("Unable to download '{url}': {reason}")]
+    DownloadFailed { url: String, reason: String },
+
+    #[error("Version {version} of crate '{name}' was not found in the registry index")]
+    NotPublished {
+        name: String,
+        version: semver::Version,
+    },
+
+    #[error("The checksum of the downloaded crate '{package}' is '{actual}', but the registry index lists '{expected}'")]
+    ChecksumMismatch {
+        package: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("No package name was found in the Cargo manifest at '{}'", .0.display())]
+    NoPackageName(PathBuf),
 }
 
 /// Data structure which contains information about which version failed to verify, and where
@@ -93,18 +494,110 @@ pub enum Error {
 ///
 /// It is combination of the Rust version which was tested for compatibility and the source which was
 /// used to find this tested Rust version.
+///
+/// If the cause of the failure could be determined from the output of the check command, it is
+/// included as the diagnosis.
 #[derive(Debug)]
 pub struct VerifyFailed {
     rust_version: BareVersion,
     source: RustVersionSource,
+    diagnosis: Option<Diagnosis>,
 }
 
-impl From<RustVersion> for VerifyFailed {
-    fn from(value: RustVersion) -> Self {
+impl VerifyFailed {
+    fn new(rust_version: RustVersion, diagnosis: Option<Diagnosis>) -> Self {
         VerifyFailed {
-            rust_version: value.rust_version,
-            source: value.source,
+            rust_version: rust_version.rust_version,
+            source: rust_version.source,
+            diagnosis,
+        }
+    }
+}
+
+impl std::fmt::Display for VerifyFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Crate source was found to be incompatible with Rust version '{}' specified {}",
+            self.rust_version, self.source
+        )?;
+
+        if let Some(diagnosis) = &self.diagnosis {
+            write!(f, ", because {}", diagnosis)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Data structure which contains information about which version was verified, and the release
+/// preceding it, with which the crate was found to be compatible as well.
+///
+/// Only returned if the fail-on policy fails on a declared MSRV which is higher than necessary.
+#[derive(Debug)]
+pub struct MsrvTooConservative {
+    rust_version: BareVersion,
+    source: RustVersionSource,
+    preceding_release: semver::Version,
+}
+
+impl MsrvTooConservative {
+    fn new(rust_version: RustVersion, preceding_release: semver::Version) -> Self {
+        MsrvTooConservative {
+            rust_version: rust_version.rust_version,
+            source: rust_version.source,
+            preceding_release,
+        }
+    }
+}
+
+impl std::fmt::Display for MsrvTooConservative {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Crate source was found to be compatible with Rust version '{}', which precedes Rust version '{}' specified {}",
+            self.preceding_release, self.rust_version, self.source
+        )
+    }
+}
+
+/// Data structure which contains the workspace members which failed to verify, and the Rust
+/// version they were tested against.
+#[derive(Debug)]
+pub struct WorkspaceVerifyFailed {
+    /// Members which are not compatible with their MSRV
+    incompatible: Vec<(String, BareVersion)>,
+    /// Members which are also compatible with the release preceding their MSRV
+    too_conservative: Vec<(String, BareVersion)>,
+}
+
+impl std::fmt::Display for WorkspaceVerifyFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn list(packages: &[(String, BareVersion)]) -> String {
+            packages
+                .iter()
+                .map(|(name, version)| format!("'{}' (Rust {})", name, version))
+                .collect::<Vec<_>>()
+                .join(", ")
         }
+
+        let mut reasons = Vec::with_capacity(2);
+
+        if !self.incompatible.is_empty() {
+            reasons.push(format!(
+                "Workspace members were found to be incompatible with the MSRV specified in their Cargo manifest: {}",
+                list(&self.incompatible)
+            ));
+        }
+
+        if !self.too_conservative.is_empty() {
+            reasons.push(format!(
+                "Workspace members were found to be compatible with the Rust release preceding the MSRV specified in their Cargo manifest: {}",
+                list(&self.too_conservative)
+            ));
+        }
+
+        write!(f, "{}", reasons.join("; "))
     }
 }
 
@@ -129,8 +622,9 @@ impl RustVersion {
                 let path = config.context().manifest_path()?;
                 let manifest = parse_manifest(path)?;
 
-                manifest
-                    .minimum_rust_version()
+                config
+                    .msrv_resolver()
+                    .manifest_msrv(&manifest)
                     .ok_or_else(|| CargoMSRVError::NoMSRVKeyInCargoToml(path.to_path_buf()))
                     .map(|v| (v.clone(), RustVersionSource::Manifest(path.to_path_buf())))
             }
@@ -151,9 +645,179 @@ impl RustVersion {
 /// Source used to obtain a Rust version for the verifier.
 #[derive(Debug, thiserror::Error)]
 enum RustVersionSource {
-    #[error("as --rust-version argument")]
+    #[error("as --rust-version (or --against) argument")]
     Arg,
 
     #[error("as MSRV in the Cargo manifest located at '{0}'")]
     Manifest(PathBuf),
 }
+
+impl RustVersionSource {
+    /// The Cargo manifest which declares the Rust version, if any.
+    fn manifest_path(&self) -> Option<&Path> {
+        match self {
+            Self::Arg => None,
+            Self::Manifest(path) => Some(path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod fail_on_tests {
+    use super::*;
+    use crate::check::TestRunner;
+    use crate::config::verify::{FailOn, VerifyCmdConfig};
+    use crate::config::SubCommandConfig;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::Action;
+    use std::iter::FromIterator;
+    use yare::parameterized;
+
+    fn index() -> ReleaseIndex {
+        ReleaseIndex::from_iter(vec![
+            Release::new_stable(semver::Version::new(1, 58, 0)),
+            Release::new_stable(semver::Version::new(1, 57, 0)),
+            Release::new_stable(semver::Version::new(1, 56, 1)),
+            Release::new_stable(semver::Version::new(1, 56, 0)),
+        ])
+    }
+
+    fn config(fail_on: FailOn) -> Config<'static> {
+        config_finding_lower(fail_on, false)
+    }
+
+    fn config_finding_lower(fail_on: FailOn, find_lower: bool) -> Config<'static> {
+        ConfigBuilder::new(Action::Verify, "")
+            .sub_command_config(SubCommandConfig::VerifyConfig(VerifyCmdConfig {
+                rust_version: Some(BareVersion::ThreeComponents(1, 57, 0)),
+                fail_on,
+                find_lower,
+                published: None,
+            }))
+            .build()
+    }
+
+    #[parameterized(
+        incompatible_lower = { &[], FailOn::Lower, false, VerifyStatus::Incompatible },
+        incompatible_higher = { &[], FailOn::Higher, true, VerifyStatus::Incompatible },
+        incompatible_both = { &[], FailOn::Both, false, VerifyStatus::Incompatible },
+        incompatible_never = { &[], FailOn::Never, true, VerifyStatus::Incompatible },
+        compatible_lower = { &[(1, 57, 0)], FailOn::Lower, true, VerifyStatus::Compatible },
+        compatible_higher = { &[(1, 57, 0)], FailOn::Higher, true, VerifyStatus::Compatible },
+        compatible_both = { &[(1, 57, 0)], FailOn::Both, true, VerifyStatus::Compatible },
+        compatible_never = { &[(1, 57, 0)], FailOn::Never, true, VerifyStatus::Compatible },
+        // the preceding release is only checked if the policy fails on a too conservative MSRV
+        preceding_lower = { &[(1, 57, 0), (1, 56, 1)], FailOn::Lower, true, VerifyStatus::Compatible },
+        preceding_higher = { &[(1, 57, 0), (1, 56, 1)], FailOn::Higher, false, VerifyStatus::TooConservative },
+        preceding_both = { &[(1, 57, 0), (1, 56, 1)], FailOn::Both, false, VerifyStatus::TooConservative },
+        preceding_never = { &[(1, 57, 0), (1, 56, 1)], FailOn::Never, true, VerifyStatus::Compatible },
+    )]
+    fn verify_with_policy(
+        accept: &[(u64, u64, u64)],
+        fail_on: FailOn,
+        expect_success: bool,
+        expected_status: VerifyStatus,
+    ) {
+        let index = index();
+        let accept = accept
+            .iter()
+            .map(|&(major, minor, patch)| semver::Version::new(major, minor, patch))
+            .collect::<Vec<_>>();
+
+        let reporter = TestReporter::default();
+        let cmd = Verify::new(&index, TestRunner::with_ok(&accept));
+
+        let result = cmd.run(&config(fail_on), reporter.reporter());
+        assert_eq!(result.is_ok(), expect_success);
+
+        let events = reporter.wait_for_events();
+        let status = events
+            .iter()
+            .find_map(|event| match event.message() {
+                Message::VerifyResult(result) => Some(result.status()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(status, expected_status);
+    }
+
+    #[test]
+    fn too_conservative_reports_preceding_release() {
+        let index = index();
+        let accept = [
+            semver::Version::new(1, 57, 0),
+            semver::Version::new(1, 56, 1),
+        ];
+
+        let reporter = TestReporter::default();
+        let cmd = Verify::new(&index, TestRunner::with_ok(&accept));
+
+        let result = cmd.run(&config(FailOn::Higher), reporter.reporter());
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::SubCommandVerify(
+                Error::MsrvTooConservative(_)
+            ))
+        ));
+
+        let events = reporter.wait_for_events();
+        let expected = VerifyResult::too_conservative(
+            BareVersion::ThreeComponents(1, 57, 0),
+            None,
+            semver::Version::new(1, 56, 1),
+        );
+
+        assert!(events
+            .iter()
+            .any(|event| event.message() == &Message::VerifyResult(expected.clone())));
+    }
+
+    #[parameterized(
+        lower_compatible = { &[(1, 57, 0), (1, 56, 1)], FailOn::Lower, Some((1, 56, 1)) },
+        lower_incompatible = { &[(1, 57, 0)], FailOn::Lower, None },
+        too_conservative = { &[(1, 57, 0), (1, 56, 1)], FailOn::Never, Some((1, 56, 1)) },
+        incompatible = { &[(1, 56, 1)], FailOn::Never, None },
+    )]
+    fn find_lower(accept: &[(u64, u64, u64)], fail_on: FailOn, expected: Option<(u64, u64, u64)>) {
+        let index = index();
+        let accept = accept
+            .iter()
+            .map(|&(major, minor, patch)| semver::Version::new(major, minor, patch))
+            .collect::<Vec<_>>();
+
+        let reporter = TestReporter::default();
+        let cmd = Verify::new(&index, TestRunner::with_ok(&accept));
+
+        let result = cmd.run(&config_finding_lower(fail_on, true), reporter.reporter());
+        // finding a lower MSRV is advisory only
+        assert!(result.is_ok());
+
+        let lower = reporter
+            .wait_for_events()
+            .iter()
+            .find_map(|event| match event.message() {
+                Message::LowerMsrvAvailable(lower) => Some(lower.lower_msrv().clone()),
+                _ => None,
+            });
+
+        assert_eq!(
+            lower,
+            expected.map(|(major, minor, patch)| semver::Version::new(major, minor, patch))
+        );
+    }
+
+    #[parameterized(
+        none = { (1, 56, 0), None },
+        patch = { (1, 57, 0), Some((1, 56, 1)) },
+        unknown = { (1, 59, 0), Some((1, 58, 0)) },
+    )]
+    fn preceding_release_in_index(version: (u64, u64, u64), expected: Option<(u64, u64, u64)>) {
+        let version = semver::Version::new(version.0, version.1, version.2);
+        let expected =
+            expected.map(|(major, minor, patch)| semver::Version::new(major, minor, patch));
+
+        assert_eq!(preceding_release(&version, &index()), expected);
+    }
+}
@@ -0,0 +1,299 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::semver;
+use crate::sub_command::verify::Error;
+
+mod archive;
+
+/// The sparse index of crates.io, used when Cargo's `CARGO_REGISTRIES_CRATES_IO_INDEX` doesn't
+/// point to another sparse index.
+const CRATES_IO_INDEX: &str = "https://index.crates.io/";
+
+/// The sources of a published version of a crate, as downloaded from crates.io and unpacked into
+/// a temporary directory. The directory is removed when dropped.
+pub(super) struct PublishedCrate {
+    dir: PathBuf,
+    name: String,
+    version: semver::Version,
+}
+
+impl PublishedCrate {
+    /// Download the `.crate` archive of the given version of the crate, and unpack it.
+    pub(super) fn download(name: &str, version: &semver::Version) -> TResult<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "cargo-msrv-published-{}-{}-{}",
+            std::process::id(),
+            name,
+            version
+        ));
+
+        fs::create_dir_all(&dir).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::WriteFile(dir.clone()),
+        })?;
+
+        // Create the guard first, so a partial download is removed as well
+        let published = Self {
+            dir,
+            name: name.to_string(),
+            version: version.clone(),
+        };
+
+        let index = index_url();
+        let entry = index_entry(&index, name, version)?;
+        let url = download_url(&index, name, version)?;
+
+        debug!(%url, dir = %published.dir.display(), "downloading published crate");
+
+        let archive = fetch(&url)?;
+        let checksum = sha256(&archive);
+
+        if checksum != entry.cksum {
+            return Err(Error::ChecksumMismatch {
+                package: published.package(),
+                expected: entry.cksum,
+                actual: checksum,
+            }
+            .into());
+        }
+
+        archive::unpack(&archive, &published.dir).map_err(|error| Error::DownloadFailed {
+            url,
+            reason: format!("unable to unpack the archive: {}", error),
+        })?;
+
+        Ok(published)
+    }
+
+    /// The Cargo manifest of the published crate, as it was normalized by `cargo publish`.
+    pub(super) fn manifest_path(&self) -> PathBuf {
+        self.dir.join(self.package()).join("Cargo.toml")
+    }
+
+    /// The name of the directory which the archive unpacks into, i.e. `<name>-<version>`.
+    fn package(&self) -> String {
+        format!("{}-{}", self.name, self.version)
+    }
+}
+
+impl Drop for PublishedCrate {
+    fn drop(&mut self) {
+        if let Err(error) = fs::remove_dir_all(&self.dir) {
+            warn!(%error, path = %self.dir.display(), "unable to remove published crate");
+        }
+    }
+}
+
+/// The base URL of the sparse registry index from which the crate is downloaded, which is
+/// crates.io, unless Cargo is configured to use another sparse index for crates.io.
+fn index_url() -> String {
+    let index = std::env::var("CARGO_REGISTRIES_CRATES_IO_INDEX")
+        .ok()
+        .and_then(|index| index.strip_prefix("sparse+").map(ToString::to_string))
+        .unwrap_or_else(|| CRATES_IO_INDEX.to_string());
+
+    if index.ends_with('/') {
+        index
+    } else {
+        format!("{}/", index)
+    }
+}
+
+/// The path of the index file of a crate, relative to the root of the index, e.g. `3/s/syn`.
+fn index_path(name: &str) -> String {
+    let name = name.to_lowercase();
+
+    match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    }
+}
+
+/// A published version of a crate, as listed in the registry index.
+#[derive(Debug, serde::Deserialize)]
+struct IndexEntry {
+    vers: String,
+    /// The SHA-256 checksum of the `.crate` archive, as lowercase hexadecimal string.
+    cksum: String,
+}
+
+/// The entry of the given version of the crate in the registry index.
+fn index_entry(index: &str, name: &str, version: &semver::Version) -> TResult<IndexEntry> {
+    let contents = fetch(&format!("{}{}", index, index_path(name)))?;
+
+    find_index_entry(&String::from_utf8_lossy(&contents), version).ok_or_else(|| {
+        Error::NotPublished {
+            name: name.to_string(),
+            version: version.clone(),
+        }
+        .into()
+    })
+}
+
+/// Each line of the index file of a crate is the JSON object of one of its versions.
+fn find_index_entry(contents: &str, version: &semver::Version) -> Option<IndexEntry> {
+    let version = version.to_string();
+
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<IndexEntry>(line).ok())
+        .find(|entry| entry.vers == version)
+}
+
+/// The URL which serves the `.crate` archive, as given by the `dl` template of the index.
+fn download_url(index: &str, name: &str, version: &semver::Version) -> TResult<String> {
+    #[derive(serde::Deserialize)]
+    struct IndexConfig {
+        dl: String,
+    }
+
+    let url = format!("{}config.json", index);
+    let config = serde_json::from_slice::<IndexConfig>(&fetch(&url)?).map_err(|error| {
+        Error::DownloadFailed {
+            url,
+            reason: error.to_string(),
+        }
+    })?;
+
+    Ok(expand_download_template(&config.dl, name, version))
+}
+
+/// Expand the markers of the `dl` template of a registry. Without markers, the template is the
+/// base URL, to which `/{crate}/{version}/download` is appended.
+fn expand_download_template(template: &str, name: &str, version: &semver::Version) -> String {
+    const MARKERS: [&str; 4] = ["{crate}", "{version}", "{prefix}", "{lowerprefix}"];
+
+    if !MARKERS.iter().any(|marker| template.contains(marker)) {
+        return format!(
+            "{}/{}/{}/download",
+            template.trim_end_matches('/'),
+            name,
+            version
+        );
+    }
+
+    let prefix = index_path(name);
+    let prefix = prefix.rsplit_once('/').map_or("", |(prefix, _)| prefix);
+
+    template
+        .replace("{crate}", name)
+        .replace("{version}", &version.to_string())
+        .replace("{lowerprefix}", &prefix.to_lowercase())
+        .replace("{prefix}", prefix)
+}
+
+/// Fetch the given URL with the HTTP client which is also used to fetch the release index, which
+/// reads the proxy given by `--proxy` from the environment.
+fn fetch(url: &str) -> TResult<Vec<u8>> {
+    let failed = |reason: String| Error::DownloadFailed {
+        url: url.to_string(),
+        reason,
+    };
+
+    let response = attohttpc::get(url)
+        .send()
+        .map_err(|error| failed(error.to_string()))?;
+
+    if !response.is_success() {
+        return Err(failed(format!("the server responded with {}", response.status())).into());
+    }
+
+    Ok(response
+        .bytes()
+        .map_err(|error| failed(error.to_string()))?)
+}
+
+/// The SHA-256 checksum of the given bytes, as lowercase hexadecimal string.
+fn sha256(bytes: &[u8]) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, bytes);
+
+    digest
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// The name of the package defined by the Cargo manifest at the given path.
+pub(super) fn package_name(manifest_path: &Path) -> TResult<String> {
+    let contents = fs::read_to_string(manifest_path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(manifest_path.to_path_buf()),
+    })?;
+
+    package_name_of(&contents)
+        .ok_or_else(|| Error::NoPackageName(manifest_path.to_path_buf()).into())
+}
+
+fn package_name_of(contents: &str) -> Option<String> {
+    let document = contents.parse::<toml_edit::Document>().ok()?;
+
+    document
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        one = { "a", "1/a" },
+        two = { "ab", "2/ab" },
+        three = { "abc", "3/a/abc" },
+        more = { "cargo-msrv", "ca/rg/cargo-msrv" },
+        uppercase = { "Inflector", "in/fl/inflector" },
+    )]
+    fn path_in_index(name: &str, expected: &str) {
+        assert_eq!(index_path(name), expected);
+    }
+
+    #[yare::parameterized(
+        crates_io = { "https://static.crates.io/crates", "https://static.crates.io/crates/cargo-msrv/0.15.1/download" },
+        markers = { "https://mirror.example/{prefix}/{crate}/{crate}-{version}.crate", "https://mirror.example/ca/rg/cargo-msrv/cargo-msrv-0.15.1.crate" },
+    )]
+    fn url_of_crate_archive(template: &str, expected: &str) {
+        assert_eq!(
+            expand_download_template(template, "cargo-msrv", &semver::Version::new(0, 15, 1)),
+            expected
+        );
+    }
+
+    #[test]
+    fn entry_of_version_in_index() {
+        let contents = concat!(
+            r#"{"name":"a","vers":"0.1.0","deps":[],"cksum":"aaaa","features":{},"yanked":false}"#,
+            "\n",
+            r#"{"name":"a","vers":"0.2.0","deps":[],"cksum":"bbbb","features":{},"yanked":false}"#,
+            "\n",
+        );
+
+        let entry = find_index_entry(contents, &semver::Version::new(0, 2, 0)).unwrap();
+        assert_eq!(entry.cksum, "bbbb");
+
+        assert!(find_index_entry(contents, &semver::Version::new(0, 3, 0)).is_none());
+    }
+
+    #[test]
+    fn checksum_of_bytes() {
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[yare::parameterized(
+        package = { "[package]\nname = \"a\"\nversion = \"0.1.0\"\n", Some("a") },
+        virtual_manifest = { "[workspace]\nmembers = [\"a\"]\n", None },
+        invalid = { "[package", None },
+    )]
+    fn name_of_package(contents: &str, expected: Option<&str>) {
+        assert_eq!(package_name_of(contents).as_deref(), expected);
+    }
+}
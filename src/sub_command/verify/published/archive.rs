@@ -0,0 +1,275 @@
+//! Unpacks the `.crate` archive of a published crate, which is a gzip compressed tar archive.
+//!
+//! Only the entries which `cargo package` writes are supported: regular files and directories,
+//! with long paths given by GNU long name or PAX extended headers. Other entries, such as links,
+//! are skipped.
+
+use flate2::read::GzDecoder;
+use std::convert::TryFrom;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+
+const BLOCK_SIZE: usize = 512;
+
+/// Unpack the gzip compressed tar archive into the given directory.
+pub(super) fn unpack(archive: &[u8], dir: &Path) -> io::Result<()> {
+    let mut decoder = GzDecoder::new(archive);
+    let mut long_path: Option<String> = None;
+
+    loop {
+        let mut header = [0; BLOCK_SIZE];
+        decoder.read_exact(&mut header)?;
+
+        // The archive ends with zero filled blocks
+        if header.iter().all(|&byte| byte == 0) {
+            return Ok(());
+        }
+
+        let size = entry_size(&header)?;
+        let mut data = vec![0; size];
+        decoder.read_exact(&mut data)?;
+        skip_padding(&mut decoder, size)?;
+
+        match header[156] {
+            // GNU long name, which is the path of the next entry
+            b'L' => long_path = Some(null_terminated(&data)),
+            // PAX extended header, which may hold the path of the next entry
+            b'x' => long_path = pax_path(&data).or(long_path),
+            entry_type @ (b'0' | b'\0' | b'5') => {
+                let path = long_path.take().unwrap_or_else(|| header_path(&header));
+                let path = dir.join(relative_path(&path)?);
+
+                if entry_type == b'5' {
+                    fs::create_dir_all(&path)?;
+                } else {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+
+                    fs::write(&path, &data)?;
+                }
+            }
+            _ => {
+                long_path = None;
+            }
+        }
+    }
+}
+
+/// The size of the data of the entry, which is octal, or base-256 for large entries.
+fn entry_size(header: &[u8; BLOCK_SIZE]) -> io::Result<usize> {
+    let field = &header[124..136];
+
+    let size = if field[0] & 0x80 != 0 {
+        field[1..]
+            .iter()
+            .fold(u64::from(field[0] & 0x7f), |size, &byte| {
+                (size << 8) | u64::from(byte)
+            })
+    } else {
+        let octal = null_terminated(field);
+        u64::from_str_radix(octal.trim(), 8)
+            .map_err(|_| invalid(format!("invalid entry size '{}'", octal)))?
+    };
+
+    usize::try_from(size).map_err(|_| invalid(format!("entry size {} is too large", size)))
+}
+
+/// The path of the entry, as given by the name field, prefixed by the prefix field of ustar
+/// archives.
+fn header_path(header: &[u8; BLOCK_SIZE]) -> String {
+    let name = null_terminated(&header[..100]);
+
+    // The prefix field only exists in POSIX ustar archives; GNU archives use it for other fields
+    if &header[257..263] == b"ustar\0" {
+        let prefix = null_terminated(&header[345..500]);
+
+        if !prefix.is_empty() {
+            return format!("{}/{}", prefix, name);
+        }
+    }
+
+    name
+}
+
+/// The `path` record of a PAX extended header, which consists of `<length> <key>=<value>\n`
+/// records.
+fn pax_path(data: &[u8]) -> Option<String> {
+    let mut rest = data;
+
+    while !rest.is_empty() {
+        let space = rest.iter().position(|&byte| byte == b' ')?;
+        let length = std::str::from_utf8(&rest[..space])
+            .ok()?
+            .parse::<usize>()
+            .ok()?;
+
+        if length <= space || length > rest.len() {
+            return None;
+        }
+
+        let record = &rest[space + 1..length];
+        let record = record.strip_suffix(b"\n").unwrap_or(record);
+
+        if let Some(path) = record.strip_prefix(b"path=") {
+            return Some(String::from_utf8_lossy(path).into_owned());
+        }
+
+        rest = &rest[length..];
+    }
+
+    None
+}
+
+/// The path relative to the directory into which the archive is unpacked. Paths which would
+/// escape this directory are rejected.
+fn relative_path(path: &str) -> io::Result<PathBuf> {
+    let path = Path::new(path);
+
+    if path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        Ok(path.to_path_buf())
+    } else {
+        Err(invalid(format!(
+            "entry '{}' is outside of the archive",
+            path.display()
+        )))
+    }
+}
+
+/// The data of each entry is padded to a multiple of the block size.
+fn skip_padding(reader: &mut impl Read, size: usize) -> io::Result<()> {
+    let padding = (BLOCK_SIZE - size % BLOCK_SIZE) % BLOCK_SIZE;
+    let mut buffer = [0; BLOCK_SIZE];
+
+    reader.read_exact(&mut buffer[..padding])
+}
+
+fn null_terminated(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(bytes.len());
+
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+fn invalid(reason: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+    use test_dir::{DirBuilder, TestDir};
+
+    fn header(path: &str, size: usize, entry_type: u8) -> Vec<u8> {
+        let mut header = vec![0; BLOCK_SIZE];
+        header[..path.len()].copy_from_slice(path.as_bytes());
+        let size = format!("{:011o}\0", size);
+        header[124..136].copy_from_slice(size.as_bytes());
+        header[156] = entry_type;
+        header[257..263].copy_from_slice(b"ustar\0");
+        header
+    }
+
+    fn entry(archive: &mut Vec<u8>, path: &str, entry_type: u8, data: &[u8]) {
+        archive.extend(header(path, data.len(), entry_type));
+        archive.extend(data);
+        archive.resize(
+            archive.len() + (BLOCK_SIZE - data.len() % BLOCK_SIZE) % BLOCK_SIZE,
+            0,
+        );
+    }
+
+    fn gzip(mut archive: Vec<u8>) -> Vec<u8> {
+        archive.resize(archive.len() + 2 * BLOCK_SIZE, 0);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&archive).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn unpacks_files_and_directories() {
+        let mut archive = Vec::new();
+        entry(&mut archive, "a-0.1.0/src", b'5', b"");
+        entry(&mut archive, "a-0.1.0/Cargo.toml", b'0', b"[package]\n");
+        entry(&mut archive, "a-0.1.0/src/lib.rs", b'0', b"");
+        entry(&mut archive, "a-0.1.0/link", b'2', b"");
+
+        let dir = TestDir::temp();
+        unpack(&gzip(archive), dir.root()).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.root().join("a-0.1.0/Cargo.toml")).unwrap(),
+            "[package]\n"
+        );
+        assert!(dir.root().join("a-0.1.0/src/lib.rs").is_file());
+        assert!(!dir.root().join("a-0.1.0/link").exists());
+    }
+
+    #[test]
+    fn unpacks_long_paths() {
+        let long = format!("a-0.1.0/{}.rs", "x".repeat(120));
+        let mut archive = Vec::new();
+        entry(
+            &mut archive,
+            "././@LongLink",
+            b'L',
+            format!("{}\0", long).as_bytes(),
+        );
+        entry(&mut archive, "a-0.1.0/truncated", b'0', b"gnu");
+
+        let record = format!("path=a-0.1.0/{}.rs\n", "y".repeat(120));
+        let record = format!("{} {}", record.len() + 4, record);
+        entry(&mut archive, "PaxHeader", b'x', record.as_bytes());
+        entry(&mut archive, "a-0.1.0/truncated", b'0', b"pax");
+
+        let dir = TestDir::temp();
+        unpack(&gzip(archive), dir.root()).unwrap();
+
+        assert_eq!(fs::read_to_string(dir.root().join(long)).unwrap(), "gnu");
+        assert_eq!(
+            fs::read_to_string(dir.root().join(format!("a-0.1.0/{}.rs", "y".repeat(120)))).unwrap(),
+            "pax"
+        );
+        assert!(!dir.root().join("a-0.1.0/truncated").exists());
+    }
+
+    #[test]
+    fn rejects_paths_outside_of_archive() {
+        let mut archive = Vec::new();
+        entry(&mut archive, "../escaped", b'0', b"");
+
+        let dir = TestDir::temp();
+        let error = unpack(&gzip(archive), dir.root()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[yare::parameterized(
+        octal = { b"00000001750\0", 1000 },
+        base_256 = { &[0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x03, 0xe8], 1000 },
+    )]
+    fn size_of_entry(field: &[u8], expected: usize) {
+        let mut header = [0; BLOCK_SIZE];
+        header[124..136].copy_from_slice(field);
+
+        assert_eq!(entry_size(&header).unwrap(), expected);
+    }
+
+    #[test]
+    fn path_of_pax_header() {
+        let data = b"20 ctime=1234567890\n21 path=a-0.1.0/b.rs\n";
+
+        assert_eq!(pax_path(data).as_deref(), Some("a-0.1.0/b.rs"));
+        assert_eq!(pax_path(b"20 ctime=1234567890\n"), None);
+    }
+}
@@ -0,0 +1,191 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::Package;
+use rust_releases::ReleaseIndex;
+
+use crate::check::Check;
+use crate::config::{Config, ConfigBuilder};
+use crate::dependency_graph::resolver::{CargoMetadataResolver, DependencyResolver};
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::reporter::event::DependencyMsrvVerified;
+use crate::reporter::Reporter;
+use crate::sub_command::find::find;
+use crate::{semver, SubCommand};
+
+/// Finds the MSRV of the direct dependencies of the crate, by compiling each dependency in
+/// isolation.
+///
+/// Useful since many crates don't declare their MSRV at all. Only dependencies from a registry or
+/// git repository are compiled; path dependencies are usually part of the same workspace.
+pub struct VerifyDeps<'index, C: Check> {
+    release_index: &'index ReleaseIndex,
+    runner: C,
+}
+
+impl<'index, C: Check> VerifyDeps<'index, C> {
+    pub fn new(release_index: &'index ReleaseIndex, runner: C) -> Self {
+        Self {
+            release_index,
+            runner,
+        }
+    }
+}
+
+impl<'index, C: Check> SubCommand for VerifyDeps<'index, C> {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        let graph = CargoMetadataResolver::try_from_config(config)?.resolve()?;
+        let root_index = graph.index()[graph.root_crate()].into();
+
+        let dependencies = graph
+            .packages()
+            .neighbors(root_index)
+            .map(|node| &graph.packages()[node])
+            .filter(|package| package.source.is_some())
+            .filter(|package| !config.ignored_dependencies().contains(&package.name))
+            .collect::<Vec<_>>();
+
+        for package in dependencies {
+            let verified_msrv =
+                dependency_msrv(config, reporter, self.release_index, &self.runner, package)?;
+
+            reporter.report_event(DependencyMsrvVerified::new(
+                package.name.as_str(),
+                package.version.clone(),
+                config.msrv_resolver().package_msrv(package),
+                verified_msrv,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Find the MSRV of a dependency, or `None` if no Rust version is compatible.
+///
+/// The dependency is compiled from a temporary copy, so nothing is written to the sources in the
+/// Cargo home directory.
+fn dependency_msrv(
+    config: &Config,
+    reporter: &impl Reporter,
+    release_index: &ReleaseIndex,
+    runner: &impl Check,
+    package: &Package,
+) -> TResult<Option<semver::Version>> {
+    info!(name = %package.name, version = %package.version, "finding msrv of dependency");
+
+    let source = package
+        .manifest_path
+        .parent()
+        .map(|dir| dir.as_std_path())
+        .ok_or(CargoMSRVError::NoCrateRootFound)?;
+    let copy = TemporaryCopy::of(source, &package.name)?;
+
+    let dependency_config = ConfigBuilder::from_config(config)
+        .crate_path(Some(copy.path()))
+        .manifest_path(Option::<&Path>::None)
+        .output_toolchain_file(false)
+        .write_msrv(false)
+        .write_badge(Option::<&Path>::None)
+        .build();
+
+    match find(&dependency_config, reporter, release_index, runner) {
+        Ok(version) => Ok(Some(version)),
+        Err(CargoMSRVError::UnableToFindAnyGoodVersion { .. }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// A temporary copy of the sources of a dependency. The copy is removed when dropped.
+struct TemporaryCopy {
+    path: PathBuf,
+}
+
+impl TemporaryCopy {
+    fn of(source: &Path, name: &str) -> TResult<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "cargo-msrv-verify-deps-{}-{}",
+            std::process::id(),
+            name
+        ));
+
+        debug!(source = %source.display(), path = %path.display(), "copying dependency");
+
+        // Create the guard first, so a partial copy is removed as well
+        let copy = Self { path };
+        copy_dir(source, &copy.path)?;
+
+        Ok(copy)
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TemporaryCopy {
+    fn drop(&mut self) {
+        if let Err(error) = fs::remove_dir_all(&self.path) {
+            warn!(%error, path = %self.path.display(), "unable to remove copy of dependency");
+        }
+    }
+}
+
+/// Recursively copy the contents of the `from` directory to the `to` directory, except for build
+/// output in `target` directories.
+fn copy_dir(from: &Path, to: &Path) -> TResult<()> {
+    fs::create_dir_all(to).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(to.to_path_buf()),
+    })?;
+
+    let entries = fs::read_dir(from).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(from.to_path_buf()),
+    })?;
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let destination = to.join(entry.file_name());
+
+        if path.is_dir() {
+            if entry.file_name() != "target" {
+                copy_dir(&path, &destination)?;
+            }
+        } else {
+            fs::copy(&path, &destination).map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::WriteFile(destination.clone()),
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[test]
+    fn copies_sources_but_not_build_output() {
+        let source = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("src", FileType::Dir)
+            .create("src/lib.rs", FileType::EmptyFile)
+            .create("target", FileType::Dir)
+            .create("target/debug", FileType::EmptyFile);
+
+        let destination = TestDir::temp();
+        let to = destination.path("copy");
+
+        copy_dir(source.root(), &to).unwrap();
+
+        assert!(to.join("Cargo.toml").is_file());
+        assert!(to.join("src").join("lib.rs").is_file());
+        assert!(!to.join("target").exists());
+    }
+}
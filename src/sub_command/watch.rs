@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use rust_releases::ReleaseIndex;
+
+use crate::check::Check;
+use crate::config::Config;
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::lockfile::find_lockfile;
+use crate::reporter::event::{WatchIteration, WatchIterationFailed};
+use crate::reporter::Reporter;
+use crate::sub_command::verify::verify;
+use crate::sub_command::SubCommand;
+
+/// How often the files of the crate are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Verify the MSRV of the crate, and verify it again whenever its Cargo manifest, lockfile or
+/// sources change, until the process is stopped.
+///
+/// The files are polled for changes, so no platform specific file system notifications are
+/// required. A failed verification is reported, but does not stop the watch.
+pub struct Watch<'index, C: Check> {
+    release_index: &'index ReleaseIndex,
+    runner: C,
+}
+
+impl<'index, C: Check> Watch<'index, C> {
+    pub fn new(release_index: &'index ReleaseIndex, runner: C) -> Self {
+        Self {
+            release_index,
+            runner,
+        }
+    }
+
+    /// Verify the MSRV, and report errors which are not caused by an incompatible MSRV.
+    fn verify(&self, config: &Config, reporter: &impl Reporter, iteration: u32) -> TResult<()> {
+        match verify(config, reporter, self.release_index, &self.runner) {
+            // The verify result has been reported already
+            Ok(()) | Err(CargoMSRVError::SubCommandVerify(_)) => Ok(()),
+            Err(CargoMSRVError::Storyteller) => Err(CargoMSRVError::Storyteller),
+            Err(err) => {
+                info!(iteration, error = %err, "verify failed while watching");
+                reporter.report_event(WatchIterationFailed::new(iteration, err.to_string()))?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'index, C: Check> SubCommand for Watch<'index, C> {
+    type Output = ();
+
+    fn run(&self, config: &Config, reporter: &impl Reporter) -> TResult<Self::Output> {
+        let root = config.context().crate_root_path()?;
+        let mut snapshot = Snapshot::take(root)?;
+
+        reporter.report_event(WatchIteration::initial(root))?;
+        self.verify(config, reporter, 0)?;
+
+        for iteration in 1.. {
+            let changed = wait_for_changes(root, &mut snapshot)?;
+
+            info!(iteration, ?changed, "files changed, verifying again");
+
+            reporter.report_event(WatchIteration::new(iteration, root, changed))?;
+            self.verify(config, reporter, iteration)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Block until any of the watched files changed, and return the changed files.
+fn wait_for_changes(root: &Path, snapshot: &mut Snapshot) -> TResult<Vec<PathBuf>> {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = Snapshot::take(root)?;
+        let changed = snapshot.changed_files(&current);
+
+        if !changed.is_empty() {
+            *snapshot = current;
+            return Ok(changed);
+        }
+    }
+}
+
+/// The modification time and size of each watched file.
+#[derive(Debug, Default)]
+struct Snapshot {
+    files: BTreeMap<PathBuf, (Option<SystemTime>, u64)>,
+}
+
+impl Snapshot {
+    /// Record the state of the files in the crate root, and of the lockfile, which may be
+    /// located in the root of a workspace.
+    ///
+    /// The `target` folder and hidden files and folders (such as `.git`) are skipped.
+    fn take(root: &Path) -> TResult<Self> {
+        let mut snapshot = Self::default();
+        snapshot.record_dir(root, root)?;
+
+        if let Some(lockfile) = find_lockfile(root) {
+            snapshot.record_file(&lockfile);
+        }
+
+        Ok(snapshot)
+    }
+
+    fn record_dir(&mut self, root: &Path, dir: &Path) -> TResult<()> {
+        let read_dir = fs::read_dir(dir).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(dir.to_path_buf()),
+        })?;
+
+        // Entries may be removed while they're being read, which is picked up by the next snapshot
+        for path in read_dir.filter_map(Result::ok).map(|entry| entry.path()) {
+            let skip = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.starts_with('.') || (dir == root && name == "target"))
+                .unwrap_or(false);
+
+            if skip {
+                continue;
+            }
+
+            if path.is_dir() {
+                self.record_dir(root, &path)?;
+            } else if path.is_file() {
+                self.record_file(&path);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn record_file(&mut self, path: &Path) {
+        if let Ok(metadata) = fs::metadata(path) {
+            self.files.insert(
+                path.to_path_buf(),
+                (metadata.modified().ok(), metadata.len()),
+            );
+        }
+    }
+
+    /// The files which were added, removed or modified in the `current` snapshot.
+    fn changed_files(&self, current: &Self) -> Vec<PathBuf> {
+        let added_or_modified = current
+            .files
+            .iter()
+            .filter(|(path, state)| self.files.get(*path) != Some(state))
+            .map(|(path, _)| path.clone());
+
+        let removed = self
+            .files
+            .keys()
+            .filter(|path| !current.files.contains_key(*path))
+            .cloned();
+
+        added_or_modified.chain(removed).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[test]
+    fn unchanged() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("src", FileType::Dir);
+
+        let before = Snapshot::take(tmp.root()).unwrap();
+        let after = Snapshot::take(tmp.root()).unwrap();
+
+        assert!(before.changed_files(&after).is_empty());
+    }
+
+    #[test]
+    fn added_and_removed_files() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("src", FileType::Dir)
+            .create("src/main.rs", FileType::EmptyFile);
+
+        let before = Snapshot::take(tmp.root()).unwrap();
+
+        fs::remove_file(tmp.path("src/main.rs")).unwrap();
+        fs::write(tmp.path("src/lib.rs"), "pub fn lib() {}").unwrap();
+
+        let after = Snapshot::take(tmp.root()).unwrap();
+
+        assert_eq!(
+            before.changed_files(&after),
+            vec![tmp.path("src/lib.rs"), tmp.path("src/main.rs")]
+        );
+    }
+
+    #[test]
+    fn modified_file() {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+
+        let before = Snapshot::take(tmp.root()).unwrap();
+
+        fs::write(tmp.path("Cargo.toml"), "[package]").unwrap();
+
+        let after = Snapshot::take(tmp.root()).unwrap();
+
+        assert_eq!(before.changed_files(&after), vec![tmp.path("Cargo.toml")]);
+    }
+
+    #[test]
+    fn target_and_hidden_files_are_ignored() {
+        let tmp = TestDir::temp()
+            .create("Cargo.toml", FileType::EmptyFile)
+            .create("target", FileType::Dir)
+            .create(".git", FileType::Dir);
+
+        let before = Snapshot::take(tmp.root()).unwrap();
+
+        fs::write(tmp.path("target/out"), "artifact").unwrap();
+        fs::write(tmp.path(".git/HEAD"), "ref: refs/heads/main").unwrap();
+
+        let after = Snapshot::take(tmp.root()).unwrap();
+
+        assert!(before.changed_files(&after).is_empty());
+    }
+}
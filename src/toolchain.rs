@@ -26,6 +26,12 @@ impl<'spec> ToolchainSpec<'spec> {
         self.version
     }
 
+    /// Whether this is a locally built toolchain, which was linked with rustup instead of
+    /// installed.
+    pub fn is_linked(&self) -> bool {
+        crate::linked_toolchain::linked_toolchain_name(self.version).is_some()
+    }
+
     pub fn to_owned(&self) -> OwnedToolchainSpec {
         OwnedToolchainSpec {
             version: self.version.clone(),
@@ -73,6 +79,47 @@ impl std::fmt::Display for OwnedToolchainSpec {
     }
 }
 
+/// Pre-release versions refer to the toolchain of the release channel named by their pre-release
+/// identifier, e.g. `1.64.0-beta` refers to the `beta` channel.
+///
+/// Linked toolchains are referred to by the name under which they were linked, see
+/// `linked_toolchain`.
 fn make_toolchain_spec(version: &semver::Version, target: &str) -> String {
-    format!("{}-{}", version, target)
+    if let Some(name) = crate::linked_toolchain::linked_toolchain_name(version) {
+        name
+    } else if version.pre.is_empty() {
+        format!("{}-{}", version, target)
+    } else {
+        format!("{}-{}", version.pre, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_toolchain_spec() {
+        let version = semver::Version::new(1, 63, 0);
+        let spec = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        assert_eq!(spec.spec(), "1.63.0-x86_64-unknown-linux-gnu");
+    }
+
+    #[test]
+    fn prerelease_toolchain_spec() {
+        let version = semver::Version::parse("1.64.0-beta").unwrap();
+        let spec = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        assert_eq!(spec.spec(), "beta-x86_64-unknown-linux-gnu");
+    }
+
+    #[test]
+    fn linked_toolchain_spec() {
+        let version = semver::Version::parse("1.75.0-linked.stage1").unwrap();
+        let spec = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        assert_eq!(spec.spec(), "cargo-msrv-stage1");
+        assert!(spec.is_linked());
+    }
 }
@@ -0,0 +1,105 @@
+//! Uninstall the toolchains which were installed for a run, see `--uninstall-after`.
+//!
+//! Finding the MSRV may install a dozen toolchains, which take up a lot of disk space. The
+//! toolchains which were installed before the run are recorded, so they are left alone.
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+
+use crate::command::RustupCommand;
+use crate::config::{Action, Config};
+use crate::error::TResult;
+use crate::installed_toolchains::{installed_toolchain_names, installs_toolchains};
+use crate::reporter::event::ToolchainCleanup as ToolchainCleanupEvent;
+use crate::reporter::Reporter;
+
+pub(crate) struct ToolchainCleanup {
+    preexisting: BTreeSet<String>,
+}
+
+impl ToolchainCleanup {
+    /// Record the toolchains which are installed before the run, if toolchains will be
+    /// uninstalled afterwards.
+    pub(crate) fn prepare(config: &Config) -> TResult<Option<Self>> {
+        let checks_toolchains = matches!(
+            config.action(),
+            Action::Find | Action::Verify | Action::Diff | Action::Watch
+        );
+
+        if !config.uninstall_after() || !checks_toolchains || !installs_toolchains(config) {
+            return Ok(None);
+        }
+
+        let preexisting = installed_toolchain_names()?.into_iter().collect();
+
+        Ok(Some(Self { preexisting }))
+    }
+
+    /// Uninstall each toolchain which was installed since the cleanup was prepared.
+    ///
+    /// A toolchain which can't be uninstalled is reported, but does not fail the run.
+    pub(crate) fn run(self, reporter: &impl Reporter) -> TResult<()> {
+        let installed = installed_toolchain_names()?;
+
+        for toolchain in new_toolchains(&self.preexisting, installed) {
+            info!(toolchain = toolchain.as_str(), "uninstalling toolchain");
+
+            let event = match uninstall(&toolchain, reporter) {
+                Ok(()) => ToolchainCleanupEvent::uninstalled(toolchain),
+                Err(reason) => ToolchainCleanupEvent::failed(toolchain, reason),
+            };
+
+            reporter.report_event(event)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn new_toolchains(preexisting: &BTreeSet<String>, installed: Vec<String>) -> Vec<String> {
+    installed
+        .into_iter()
+        .filter(|toolchain| !preexisting.contains(toolchain))
+        .collect()
+}
+
+fn uninstall(toolchain: &str, reporter: &impl Reporter) -> Result<(), String> {
+    let output = RustupCommand::new()
+        .with_args(["uninstall", toolchain])
+        .with_stderr()
+        .execute_reported(OsStr::new("toolchain"), reporter)
+        .map_err(|err| err.to_string())?;
+
+    if output.exit_status().success() {
+        Ok(())
+    } else {
+        Err(output.stderr().trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_new_toolchains_are_uninstalled() {
+        let preexisting = [
+            "stable-x86_64-unknown-linux-gnu",
+            "1.56.0-x86_64-unknown-linux-gnu",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect::<BTreeSet<_>>();
+
+        let installed = vec![
+            "stable-x86_64-unknown-linux-gnu".to_string(),
+            "1.56.0-x86_64-unknown-linux-gnu".to_string(),
+            "1.58.1-x86_64-unknown-linux-gnu".to_string(),
+        ];
+
+        assert_eq!(
+            new_toolchains(&preexisting, installed),
+            vec!["1.58.1-x86_64-unknown-linux-gnu".to_string()]
+        );
+    }
+}
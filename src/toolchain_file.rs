@@ -0,0 +1,140 @@
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use std::path::{Path, PathBuf};
+use toml_edit::{Document, Item};
+
+pub(crate) const TOOLCHAIN_FILE: &str = "rust-toolchain";
+pub(crate) const TOOLCHAIN_FILE_TOML: &str = "rust-toolchain.toml";
+
+/// A `rust-toolchain` or `rust-toolchain.toml` file, which overrides the toolchain used by rustup.
+#[derive(Debug)]
+pub struct ToolchainFile {
+    path: PathBuf,
+    channel: Option<String>,
+}
+
+impl ToolchainFile {
+    /// Read the toolchain file in the given directory, if it has one.
+    ///
+    /// The file without extension takes precedence over the one with a `.toml` extension, like
+    /// [rustup](https://rust-lang.github.io/rustup/overrides.html#the-toolchain-file) does.
+    pub fn try_from_dir(dir: &Path) -> TResult<Option<Self>> {
+        let path = match find_toolchain_file(dir) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let contents = std::fs::read_to_string(&path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.clone()),
+        })?;
+
+        let channel = parse_channel(&contents);
+
+        Ok(Some(Self { path, channel }))
+    }
+
+    /// Read the toolchain file which applies to the given directory, which is the toolchain file
+    /// in the directory itself, or else the one in the nearest parent directory which has one.
+    pub fn try_from_dir_or_parents(dir: &Path) -> TResult<Option<Self>> {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+
+        match dir
+            .ancestors()
+            .find(|ancestor| find_toolchain_file(ancestor).is_some())
+        {
+            Some(ancestor) => Self::try_from_dir(ancestor),
+            None => Ok(None),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The toolchain channel, e.g. `1.56.0` or `stable`, if the file specifies one.
+    pub fn channel(&self) -> Option<&str> {
+        self.channel.as_deref()
+    }
+}
+
+/// The path of the existing toolchain file in the given directory, if any.
+pub(crate) fn find_toolchain_file(dir: &Path) -> Option<PathBuf> {
+    [TOOLCHAIN_FILE, TOOLCHAIN_FILE_TOML]
+        .iter()
+        .map(|file| dir.join(file))
+        .find(|path| path.exists())
+}
+
+/// Parse the channel from either the TOML format, or the legacy format, where the file only
+/// contains the name of the toolchain.
+fn parse_channel(contents: &str) -> Option<String> {
+    match contents.parse::<Document>() {
+        Ok(document) => document
+            .as_table()
+            .get("toolchain")
+            .and_then(Item::as_table_like)
+            .and_then(|toolchain| toolchain.get("channel"))
+            .and_then(Item::as_str)
+            .map(String::from),
+        Err(_) => {
+            let channel = contents.trim();
+            (!channel.is_empty() && !channel.contains(char::is_whitespace))
+                .then(|| channel.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[yare::parameterized(
+        toml = { "[toolchain]\nchannel = \"1.56.0\"\n", Some("1.56.0") },
+        toml_without_channel = { "[toolchain]\ncomponents = [\"clippy\"]\n", None },
+        legacy = { "1.56.0\n", Some("1.56.0") },
+        legacy_named = { "stable", Some("stable") },
+        empty = { "", None },
+    )]
+    fn channel(contents: &str, expected: Option<&str>) {
+        assert_eq!(parse_channel(contents).as_deref(), expected);
+    }
+
+    #[test]
+    fn without_extension_has_precedence() {
+        let tmp = TestDir::temp()
+            .create("rust-toolchain", FileType::EmptyFile)
+            .create("rust-toolchain.toml", FileType::EmptyFile);
+
+        let file = ToolchainFile::try_from_dir(tmp.root()).unwrap().unwrap();
+
+        assert_eq!(file.path(), tmp.path("rust-toolchain"));
+        assert!(file.channel().is_none());
+    }
+
+    #[test]
+    fn toolchain_file_in_parent() {
+        let tmp = TestDir::temp()
+            .create("rust-toolchain.toml", FileType::EmptyFile)
+            .create("member", FileType::Dir);
+
+        let file = ToolchainFile::try_from_dir_or_parents(&tmp.path("member"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            file.path(),
+            tmp.root()
+                .canonicalize()
+                .unwrap()
+                .join("rust-toolchain.toml")
+        );
+    }
+
+    #[test]
+    fn no_toolchain_file() {
+        let tmp = TestDir::temp();
+
+        assert!(ToolchainFile::try_from_dir(tmp.root()).unwrap().is_none());
+    }
+}
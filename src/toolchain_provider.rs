@@ -0,0 +1,409 @@
+//! Provides the toolchains which are checked, and runs programs with them.
+//!
+//! Toolchains are installed and run with rustup by default. Toolchains which are installed
+//! otherwise, for example by Nix or a distribution package manager, can be used by pointing
+//! cargo-msrv to the directory which contains them. Checks may also be run in Docker containers
+//! instead, to isolate them from the host.
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use crate::command::RustupCommand;
+use crate::download::{DownloadToolchain, RetryPolicy, ToolchainDownloader};
+use crate::error::{CargoMSRVError, IoErrorSource, TResult};
+use crate::reporter::event::SetupToolchain;
+use crate::toolchain::ToolchainSpec;
+use crate::Reporter;
+
+pub trait ToolchainProvider {
+    /// Make sure the toolchain, and the standard library of each of the given compilation
+    /// targets, are available.
+    fn provide(&self, toolchain: &ToolchainSpec, targets: &[String]) -> TResult<()>;
+
+    /// Make sure the given components, such as `clippy`, are available for the toolchain.
+    ///
+    /// Returns [`CargoMSRVError::ComponentUnavailable`] if the toolchain has no such component.
+    /// Toolchains which are not installed by rustup are used as they are, including their
+    /// components.
+    fn provide_components(
+        &self,
+        _toolchain: &ToolchainSpec,
+        _components: &[String],
+    ) -> TResult<()> {
+        Ok(())
+    }
+
+    /// A command which runs the given program, e.g. `cargo check`, with the toolchain.
+    fn command(&self, toolchain: &ToolchainSpec, program: &[&str]) -> TResult<RustupCommand>;
+
+    /// Like [`ToolchainProvider::command`], with the given environment variables set for the
+    /// program.
+    fn command_with_env(
+        &self,
+        toolchain: &ToolchainSpec,
+        program: &[&str],
+        env: &[(String, String)],
+    ) -> TResult<RustupCommand> {
+        Ok(env.iter().fold(
+            self.command(toolchain, program)?,
+            |command, (key, value)| command.with_env(key, value),
+        ))
+    }
+
+    /// The environment variables which select the toolchain explicitly for the programs it runs,
+    /// so it is used even if a rust-toolchain file pins another toolchain for the crate.
+    fn toolchain_env(&self, toolchain: &ToolchainSpec) -> TResult<Vec<(String, String)>> {
+        Ok(vec![(
+            "RUSTUP_TOOLCHAIN".to_string(),
+            toolchain.spec().to_string(),
+        )])
+    }
+}
+
+/// Installs toolchains with `rustup install`, and runs programs with `rustup run`.
+pub struct RustupProvider<'reporter, R: Reporter> {
+    reporter: &'reporter R,
+    offline: bool,
+    retry_policy: RetryPolicy,
+}
+
+impl<'reporter, R: Reporter> RustupProvider<'reporter, R> {
+    /// When `offline`, toolchains are not installed, and only installed toolchains can be used.
+    /// Failed installations are retried according to the `retry_policy`.
+    pub fn new(reporter: &'reporter R, offline: bool, retry_policy: RetryPolicy) -> Self {
+        Self {
+            reporter,
+            offline,
+            retry_policy,
+        }
+    }
+}
+
+impl<'reporter, R: Reporter> ToolchainProvider for RustupProvider<'reporter, R> {
+    fn provide(&self, toolchain: &ToolchainSpec, targets: &[String]) -> TResult<()> {
+        // A linked toolchain is used as it was built
+        if self.offline || toolchain.is_linked() {
+            return Ok(());
+        }
+
+        let downloader = ToolchainDownloader::new(self.reporter, self.retry_policy);
+        downloader.download(toolchain)?;
+
+        for target in targets {
+            downloader.add_target(toolchain, target)?;
+        }
+
+        Ok(())
+    }
+
+    fn provide_components(&self, toolchain: &ToolchainSpec, components: &[String]) -> TResult<()> {
+        if self.offline || toolchain.is_linked() {
+            return Ok(());
+        }
+
+        let downloader = ToolchainDownloader::new(self.reporter, self.retry_policy);
+
+        for component in components {
+            downloader.add_component(toolchain, component)?;
+        }
+
+        Ok(())
+    }
+
+    fn command(&self, toolchain: &ToolchainSpec, program: &[&str]) -> TResult<RustupCommand> {
+        let mut args = vec![toolchain.spec()];
+        args.extend_from_slice(program);
+
+        Ok(RustupCommand::new().with_args(args))
+    }
+}
+
+/// Uses the toolchains which are installed in a directory, without rustup.
+///
+/// Each toolchain is a subdirectory, named after its version, with or without the target, e.g.
+/// `1.60.0` or `1.60.0-x86_64-unknown-linux-gnu`, like in the `toolchains` folder of rustup.
+/// Programs are run from the `bin` folder of the toolchain.
+pub struct DirectoryProvider<'dir> {
+    dir: &'dir Path,
+}
+
+impl<'dir> DirectoryProvider<'dir> {
+    pub fn new(dir: &'dir Path) -> Self {
+        Self { dir }
+    }
+
+    /// The path of the given toolchain, preferring the directory which includes the target.
+    fn toolchain_path(&self, toolchain: &ToolchainSpec) -> TResult<PathBuf> {
+        [
+            toolchain.spec().to_string(),
+            toolchain.version().to_string(),
+        ]
+        .iter()
+        .map(|name| self.dir.join(name))
+        .find(|path| path.is_dir())
+        .ok_or_else(|| CargoMSRVError::ToolchainNotInDirectory {
+            toolchain: toolchain.spec().to_string(),
+            dir: self.dir.to_path_buf(),
+        })
+    }
+}
+
+impl<'dir> ToolchainProvider for DirectoryProvider<'dir> {
+    fn provide(&self, toolchain: &ToolchainSpec, targets: &[String]) -> TResult<()> {
+        let path = self.toolchain_path(toolchain)?;
+
+        let missing = targets
+            .iter()
+            .find(|target| !path.join("lib").join("rustlib").join(target).is_dir());
+
+        if let Some(target) = missing {
+            return Err(CargoMSRVError::TargetNotInToolchainDirectory {
+                toolchain: toolchain.spec().to_string(),
+                target: target.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn command(&self, toolchain: &ToolchainSpec, program: &[&str]) -> TResult<RustupCommand> {
+        let bin_dir = self.toolchain_path(toolchain)?.join("bin");
+
+        match program.split_first() {
+            Some((program, args)) => {
+                Ok(RustupCommand::toolchain_program(&bin_dir, program).with_args(args.iter()))
+            }
+            None => Err(CargoMSRVError::UnableToRunCheck),
+        }
+    }
+
+    // The toolchain is not managed by rustup, so Cargo is pointed to its compiler instead
+    fn toolchain_env(&self, toolchain: &ToolchainSpec) -> TResult<Vec<(String, String)>> {
+        let bin_dir = self.toolchain_path(toolchain)?.join("bin");
+
+        Ok(vec![
+            (
+                "RUSTC".to_string(),
+                bin_dir.join("rustc").display().to_string(),
+            ),
+            (
+                "RUSTDOC".to_string(),
+                bin_dir.join("rustdoc").display().to_string(),
+            ),
+        ])
+    }
+}
+
+/// Runs programs in the official `rust:<version>` Docker container of the toolchain.
+///
+/// The crate is mounted read-only at `/project`. Build artifacts are written to a volume per
+/// toolchain, and the Cargo registry is shared between the containers in a separate volume, so
+/// dependencies are only downloaded once.
+pub struct DockerProvider<'reporter, R: Reporter> {
+    reporter: &'reporter R,
+    crate_root: PathBuf,
+}
+
+impl<'reporter, R: Reporter> DockerProvider<'reporter, R> {
+    pub fn new(reporter: &'reporter R, crate_root: &Path) -> TResult<Self> {
+        // Docker requires absolute paths for bind mounts
+        let crate_root = crate_root
+            .canonicalize()
+            .map_err(|error| CargoMSRVError::Io {
+                error,
+                source: IoErrorSource::CurrentDir,
+            })?;
+
+        Ok(Self {
+            reporter,
+            crate_root,
+        })
+    }
+
+    /// Run the program in the container, with the given environment variables.
+    fn docker_command(
+        &self,
+        toolchain: &ToolchainSpec,
+        program: &[&str],
+        env: &[(String, String)],
+    ) -> RustupCommand {
+        let version = toolchain.version();
+
+        let mut args = vec![
+            "run".to_string(),
+            "--rm".to_string(),
+            "--volume".to_string(),
+            format!("{}:/project:ro", self.crate_root.display()),
+            "--volume".to_string(),
+            format!("cargo-msrv-target-{}:/target", version),
+            "--volume".to_string(),
+            "cargo-msrv-registry:/usr/local/cargo/registry".to_string(),
+            "--env".to_string(),
+            "CARGO_TARGET_DIR=/target".to_string(),
+        ];
+
+        for (key, value) in env {
+            args.push("--env".to_string());
+            args.push(format!("{}={}", key, value));
+        }
+
+        args.extend([
+            "--workdir".to_string(),
+            "/project".to_string(),
+            docker_image(toolchain),
+        ]);
+
+        RustupCommand::program("docker")
+            .with_args(args)
+            .with_args(program.iter())
+    }
+}
+
+impl<'reporter, R: Reporter> ToolchainProvider for DockerProvider<'reporter, R> {
+    fn provide(&self, toolchain: &ToolchainSpec, targets: &[String]) -> TResult<()> {
+        if !targets.is_empty() {
+            return Err(CargoMSRVError::DockerRunnerTargets);
+        }
+
+        let image = docker_image(toolchain);
+
+        self.reporter
+            .run_scoped_event(SetupToolchain::new(toolchain.to_owned()), || {
+                info!(%image, "pulling docker image");
+
+                let output = RustupCommand::program("docker")
+                    .with_args(["pull", "--quiet", image.as_str()])
+                    .with_stderr()
+                    .execute_reported(OsStr::new("pull"), self.reporter)?;
+
+                if !output.exit_status().success() {
+                    error!(%image, stderr = output.stderr(), "unable to pull docker image");
+
+                    return Err(CargoMSRVError::DockerPullFailed(image.clone()));
+                }
+
+                Ok(())
+            })
+    }
+
+    fn command(&self, toolchain: &ToolchainSpec, program: &[&str]) -> TResult<RustupCommand> {
+        Ok(self.docker_command(toolchain, program, &[]))
+    }
+
+    // The environment of the docker command is not passed on to the container
+    fn command_with_env(
+        &self,
+        toolchain: &ToolchainSpec,
+        program: &[&str],
+        env: &[(String, String)],
+    ) -> TResult<RustupCommand> {
+        Ok(self.docker_command(toolchain, program, env))
+    }
+
+    // The container has a single toolchain, which rustup knows by its version
+    fn toolchain_env(&self, toolchain: &ToolchainSpec) -> TResult<Vec<(String, String)>> {
+        Ok(vec![(
+            "RUSTUP_TOOLCHAIN".to_string(),
+            toolchain.version().to_string(),
+        )])
+    }
+}
+
+/// The official Docker image of the toolchain.
+fn docker_image(toolchain: &ToolchainSpec) -> String {
+    format!("rust:{}", toolchain.version())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::FakeTestReporter;
+    use crate::semver;
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[test]
+    fn toolchain_with_target_is_preferred() {
+        let dir = TestDir::temp()
+            .create("1.60.0", FileType::Dir)
+            .create("1.60.0-x86_64-unknown-linux-gnu", FileType::Dir);
+
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let path = DirectoryProvider::new(dir.root())
+            .toolchain_path(&toolchain)
+            .unwrap();
+
+        assert_eq!(path, dir.path("1.60.0-x86_64-unknown-linux-gnu"));
+    }
+
+    #[test]
+    fn toolchain_without_target() {
+        let dir = TestDir::temp().create("1.60.0", FileType::Dir);
+
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let path = DirectoryProvider::new(dir.root())
+            .toolchain_path(&toolchain)
+            .unwrap();
+
+        assert_eq!(path, dir.path("1.60.0"));
+    }
+
+    #[test]
+    fn missing_toolchain() {
+        let dir = TestDir::temp().create("1.60.0", FileType::Dir);
+
+        let version = semver::Version::new(1, 59, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let result = DirectoryProvider::new(dir.root()).provide(&toolchain, &[]);
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::ToolchainNotInDirectory { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_target() {
+        let dir =
+            TestDir::temp().create("1.60.0/lib/rustlib/x86_64-unknown-linux-gnu", FileType::Dir);
+
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+        let provider = DirectoryProvider::new(dir.root());
+
+        assert!(provider
+            .provide(&toolchain, &["x86_64-unknown-linux-gnu".to_string()])
+            .is_ok());
+        assert!(matches!(
+            provider.provide(&toolchain, &["wasm32-unknown-unknown".to_string()]),
+            Err(CargoMSRVError::TargetNotInToolchainDirectory { .. })
+        ));
+    }
+
+    #[test]
+    fn docker_image_of_toolchain() {
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        assert_eq!(docker_image(&toolchain), "rust:1.60.0");
+    }
+
+    #[test]
+    fn docker_rejects_targets() {
+        let dir = TestDir::temp();
+        let reporter = FakeTestReporter::default();
+
+        let version = semver::Version::new(1, 60, 0);
+        let toolchain = ToolchainSpec::new(&version, "x86_64-unknown-linux-gnu");
+
+        let result = DockerProvider::new(&reporter, dir.root())
+            .unwrap()
+            .provide(&toolchain, &["wasm32-unknown-unknown".to_string()]);
+
+        assert!(matches!(result, Err(CargoMSRVError::DockerRunnerTargets)));
+    }
+}
@@ -0,0 +1,308 @@
+use crate::config::Config;
+use crate::error::{CargoMSRVError, TResult};
+use crate::manifest::{CargoManifestParser, TomlParser};
+use cargo_metadata::MetadataCommand;
+use std::path::{Component, Path, PathBuf};
+use toml_edit::Document;
+
+/// A package which is a member of a Cargo workspace.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkspaceMember {
+    name: String,
+    manifest_path: PathBuf,
+}
+
+impl WorkspaceMember {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn manifest_path(&self) -> &Path {
+        &self.manifest_path
+    }
+}
+
+/// Collect the members of the workspace to which the Cargo manifest, given by the config, belongs.
+///
+/// The manifest may either be the manifest of a workspace member, or the (possibly virtual)
+/// manifest of the workspace root.
+pub fn workspace_members(config: &Config) -> TResult<Vec<WorkspaceMember>> {
+    let manifest_path = config.context().manifest_path()?;
+
+    let metadata = MetadataCommand::new()
+        .manifest_path(manifest_path)
+        .no_deps()
+        .exec()?;
+
+    let workspace_members = metadata.workspace_members;
+
+    let members = metadata
+        .packages
+        .into_iter()
+        .filter(|package| workspace_members.contains(&package.id))
+        .map(|package| WorkspaceMember {
+            name: package.name,
+            manifest_path: package.manifest_path.into_std_path_buf(),
+        })
+        .collect();
+
+    select_members(
+        members,
+        config.workspace_packages(),
+        config.workspace_excludes(),
+    )
+}
+
+/// The workspace members to run for, or `None` if the run is for a single crate.
+///
+/// Like Cargo, a run from the root of a workspace with a virtual manifest is a run for the
+/// workspace, even if `--workspace` is not given: for the `default-members` of the workspace if
+/// specified, and for each member otherwise.
+///
+/// Returns an error if no members remain after selecting and excluding members.
+pub fn selected_members(config: &Config) -> TResult<Option<Vec<WorkspaceMember>>> {
+    let members = if config.workspace() {
+        workspace_members(config)?
+    } else {
+        // If the manifest can't be located, it's left to the single crate run to report so
+        let manifest_path = match config.context().manifest_path() {
+            Ok(path) => path,
+            Err(_) => return Ok(None),
+        };
+
+        let document = match read_manifest(manifest_path) {
+            Some(document) if is_virtual(&document) => document,
+            _ => return Ok(None),
+        };
+
+        info!(
+            manifest = %manifest_path.display(),
+            "running for workspace members of virtual manifest"
+        );
+
+        let members = workspace_members(config)?;
+
+        match default_members(&document) {
+            Some(paths) if config.workspace_packages().is_empty() => {
+                let root = manifest_path.parent().unwrap_or_else(|| Path::new("."));
+                select_default_members(members, root, &paths)
+            }
+            _ => members,
+        }
+    };
+
+    if members.is_empty() {
+        let manifest_path = config.context().manifest_path()?;
+
+        return Err(CargoMSRVError::NoWorkspaceMembersSelected(
+            manifest_path.to_path_buf(),
+        ));
+    }
+
+    Ok(Some(members))
+}
+
+/// Whether the Cargo manifest at the given path is the manifest of a workspace without a root
+/// package.
+///
+/// Returns `false` if the manifest can't be read.
+pub fn is_virtual_manifest(manifest_path: &Path) -> bool {
+    read_manifest(manifest_path).map_or(false, |document| is_virtual(&document))
+}
+
+/// Whether the manifest is the manifest of a workspace without a root package.
+pub fn is_virtual(manifest: &Document) -> bool {
+    manifest.as_table().get("package").is_none() && manifest.as_table().get("workspace").is_some()
+}
+
+fn read_manifest(manifest_path: &Path) -> Option<Document> {
+    let contents = std::fs::read_to_string(manifest_path).ok()?;
+
+    CargoManifestParser::default()
+        .parse::<Document>(&contents)
+        .ok()
+}
+
+/// The paths of the `workspace.default-members`, relative to the root of the workspace, if
+/// specified.
+fn default_members(manifest: &Document) -> Option<Vec<String>> {
+    let paths = manifest
+        .as_table()
+        .get("workspace")?
+        .get("default-members")?
+        .as_array()?;
+
+    Some(
+        paths
+            .iter()
+            .filter_map(|path| path.as_str().map(str::to_string))
+            .collect(),
+    )
+}
+
+/// Keep only the members whose package is located at one of the given paths, relative to the
+/// root of the workspace.
+fn select_default_members(
+    members: Vec<WorkspaceMember>,
+    root: &Path,
+    paths: &[String],
+) -> Vec<WorkspaceMember> {
+    // Compare canonical paths, or without `.` components if the path can't be canonicalized
+    let normalize = |path: &Path| {
+        std::fs::canonicalize(path).unwrap_or_else(|_| {
+            path.components()
+                .filter(|component| *component != Component::CurDir)
+                .collect()
+        })
+    };
+
+    let defaults = paths
+        .iter()
+        .map(|path| normalize(&root.join(path)))
+        .collect::<Vec<_>>();
+
+    members
+        .into_iter()
+        .filter(|member| {
+            member
+                .manifest_path()
+                .parent()
+                .map_or(false, |dir| defaults.contains(&normalize(dir)))
+        })
+        .collect()
+}
+
+/// The Cargo manifest of the root of the workspace to which the Cargo manifest at the given path
+/// belongs.
+pub fn workspace_root_manifest(manifest_path: &Path) -> TResult<PathBuf> {
+    let metadata = MetadataCommand::new()
+        .manifest_path(manifest_path)
+        .no_deps()
+        .exec()?;
+
+    Ok(metadata
+        .workspace_root
+        .join("Cargo.toml")
+        .into_std_path_buf())
+}
+
+/// Keep only the given packages, if any are given, and drop the excluded packages.
+///
+/// Like Cargo, it's an error to select a package which is not a member of the workspace, while
+/// excluding such a package is not.
+fn select_members(
+    members: Vec<WorkspaceMember>,
+    packages: &[String],
+    excludes: &[String],
+) -> TResult<Vec<WorkspaceMember>> {
+    if let Some(unknown) = packages
+        .iter()
+        .find(|package| !members.iter().any(|member| member.name() == *package))
+    {
+        return Err(CargoMSRVError::UnknownWorkspaceMember(unknown.clone()));
+    }
+
+    for exclude in excludes {
+        if !members.iter().any(|member| member.name() == exclude) {
+            warn!(
+                package = exclude.as_str(),
+                "excluded package is not a workspace member"
+            );
+        }
+    }
+
+    Ok(members
+        .into_iter()
+        .filter(|member| packages.is_empty() || packages.iter().any(|p| p == member.name()))
+        .filter(|member| !excludes.iter().any(|p| p == member.name()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members() -> Vec<WorkspaceMember> {
+        ["core", "cli", "fuzz"]
+            .iter()
+            .map(|name| WorkspaceMember {
+                name: name.to_string(),
+                manifest_path: PathBuf::from(name).join("Cargo.toml"),
+            })
+            .collect()
+    }
+
+    fn names(members: &[WorkspaceMember]) -> Vec<&str> {
+        members.iter().map(WorkspaceMember::name).collect()
+    }
+
+    #[test]
+    fn virtual_manifest() {
+        let manifest = "[workspace]\nmembers = [\"core\"]\n"
+            .parse::<Document>()
+            .unwrap();
+
+        assert!(is_virtual(&manifest));
+    }
+
+    #[yare::parameterized(
+        package = { "[package]\nname = \"a\"\n" },
+        package_with_workspace = { "[package]\nname = \"a\"\n\n[workspace]\n" },
+    )]
+    fn not_a_virtual_manifest(contents: &str) {
+        let manifest = contents.parse::<Document>().unwrap();
+
+        assert!(!is_virtual(&manifest));
+    }
+
+    #[test]
+    fn default_members_of_manifest() {
+        let manifest = "[workspace]\nmembers = [\"core\", \"cli\"]\ndefault-members = [\"cli\"]\n"
+            .parse::<Document>()
+            .unwrap();
+
+        assert_eq!(default_members(&manifest), Some(vec!["cli".to_string()]));
+    }
+
+    #[test]
+    fn select_default_members_by_path() {
+        let paths = vec!["cli".to_string(), "./fuzz".to_string()];
+        let selected = select_default_members(members(), Path::new(""), &paths);
+
+        assert_eq!(names(&selected), vec!["cli", "fuzz"]);
+    }
+
+    #[test]
+    fn select_all_members() {
+        let selected = select_members(members(), &[], &[]).unwrap();
+
+        assert_eq!(names(&selected), vec!["core", "cli", "fuzz"]);
+    }
+
+    #[test]
+    fn select_packages() {
+        let packages = vec!["fuzz".to_string(), "core".to_string()];
+        let selected = select_members(members(), &packages, &[]).unwrap();
+
+        assert_eq!(names(&selected), vec!["core", "fuzz"]);
+    }
+
+    #[test]
+    fn exclude_packages() {
+        let excludes = vec!["fuzz".to_string(), "not-a-member".to_string()];
+        let selected = select_members(members(), &[], &excludes).unwrap();
+
+        assert_eq!(names(&selected), vec!["core", "cli"]);
+    }
+
+    #[test]
+    fn select_unknown_package() {
+        let packages = vec!["not-a-member".to_string()];
+        let result = select_members(members(), &packages, &[]);
+
+        assert!(matches!(
+            result,
+            Err(CargoMSRVError::UnknownWorkspaceMember(name)) if name == "not-a-member"
+        ));
+    }
+}
@@ -1,2 +1,6 @@
+pub mod badge;
+pub mod certification;
+pub mod clippy_config;
+pub mod env_file;
 pub mod toolchain_file;
 pub mod write_msrv;
@@ -0,0 +1,95 @@
+use crate::error::IoErrorSource;
+use crate::reporter::event::{AuxiliaryOutput, AuxiliaryOutputItem, Destination};
+use crate::reporter::Reporter;
+use crate::{semver, CargoMSRVError, TResult};
+use std::path::Path;
+
+/// The version of the shields.io endpoint badge schema.
+///
+/// See https://shields.io/endpoint
+const SCHEMA_VERSION: u32 = 1;
+
+/// Write a shields.io endpoint badge, which displays the given version as MSRV, to the given path.
+///
+/// The badge can be displayed by passing the URL of the published file to
+/// `https://img.shields.io/endpoint?url=<url>`.
+pub fn write_badge(path: &Path, reporter: &impl Reporter, msrv: &semver::Version) -> TResult<()> {
+    std::fs::write(path, badge_json(msrv)).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(path.to_path_buf()),
+    })?;
+
+    reporter.report_event(AuxiliaryOutput::new(
+        Destination::File(path.to_path_buf()),
+        AuxiliaryOutputItem::badge(),
+    ))?;
+
+    Ok(())
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Badge {
+    schema_version: u32,
+    label: &'static str,
+    message: String,
+    color: &'static str,
+}
+
+fn badge_json(msrv: &semver::Version) -> String {
+    let badge = Badge {
+        schema_version: SCHEMA_VERSION,
+        label: "msrv",
+        message: msrv.to_string(),
+        color: "blue",
+    };
+
+    let mut json = serde_json::to_string_pretty(&badge).expect("Unable to serialize badge");
+    json.push('\n');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::TestReporter;
+    use crate::Event;
+    use test_dir::{DirBuilder, TestDir};
+
+    #[test]
+    fn badge_is_shields_endpoint_json() {
+        let json = badge_json(&semver::Version::new(1, 56, 1));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "schemaVersion": 1,
+                "label": "msrv",
+                "message": "1.56.1",
+                "color": "blue",
+            })
+        );
+    }
+
+    #[test]
+    fn write_badge_to_file() {
+        let tmp = TestDir::temp();
+        let path = tmp.path("msrv-badge.json");
+        let reporter = TestReporter::default();
+
+        write_badge(&path, reporter.reporter(), &semver::Version::new(1, 60, 0)).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            badge_json(&semver::Version::new(1, 60, 0))
+        );
+
+        let expected: Vec<Event> =
+            vec![
+                AuxiliaryOutput::new(Destination::File(path), AuxiliaryOutputItem::badge()).into(),
+            ];
+
+        phenomenon::contains_at_least_ordered(reporter.wait_for_events(), expected).assert_this();
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use toml_edit::{table, Document, Item, Value};
+
+use crate::certification::{Certification, CERTIFICATION_KEY};
+use crate::error::IoErrorSource;
+use crate::manifest::{CargoManifestParser, TomlParser};
+use crate::reporter::event::{AuxiliaryOutput, AuxiliaryOutputItem, Destination};
+use crate::reporter::Reporter;
+use crate::{semver, CargoMSRVError, Config, TResult};
+
+/// Record the inputs of the checks by which the given toolchain was found to be the MSRV, in the
+/// `package.metadata.msrv-verified` table of the Cargo manifest.
+///
+/// An existing certification is replaced, while the rest of the manifest is kept intact.
+pub fn write_certification(
+    config: &Config,
+    reporter: &impl Reporter,
+    toolchain: &semver::Version,
+) -> TResult<()> {
+    let path = config.context().manifest_path()?;
+    let certification = Certification::new(config, toolchain)?;
+
+    let contents = std::fs::read_to_string(path).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::ReadFile(path.to_path_buf()),
+    })?;
+
+    let contents = set_certification(&contents, &certification, path)?;
+
+    std::fs::write(path, contents).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(path.to_path_buf()),
+    })?;
+
+    reporter.report_event(AuxiliaryOutput::new(
+        Destination::Manifest(path.to_path_buf()),
+        AuxiliaryOutputItem::certification(),
+    ))?;
+
+    Ok(())
+}
+
+fn set_certification(
+    contents: &str,
+    certification: &Certification,
+    path: &Path,
+) -> TResult<String> {
+    let mut document = CargoManifestParser::default().parse::<Document>(contents)?;
+    let metadata = &mut document["package"]["metadata"];
+
+    match metadata {
+        Item::None => {
+            // Only the [package.metadata.msrv-verified] header is written, and not an empty
+            // [package.metadata] table
+            *metadata = table();
+            if let Some(metadata) = metadata.as_table_mut() {
+                metadata.set_implicit(true);
+            }
+            metadata[CERTIFICATION_KEY] = Item::Table(certification.to_table());
+        }
+        Item::Value(Value::InlineTable(metadata)) => {
+            metadata.insert(
+                CERTIFICATION_KEY,
+                Value::InlineTable(certification.to_table().into_inline_table()),
+            );
+        }
+        Item::Table(metadata) => {
+            metadata.insert(CERTIFICATION_KEY, Item::Table(certification.to_table()));
+        }
+        _ => return Err(CargoMSRVError::CertificationNotATable(path.to_path_buf())),
+    }
+
+    Ok(document.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_certification;
+    use crate::certification::Certification;
+    use crate::config::ConfigBuilder;
+    use crate::manifest::{CargoManifestParser, TomlParser};
+    use crate::reporter::FakeTestReporter;
+    use crate::{semver, Action};
+    use test_dir::{DirBuilder, FileType, TestDir};
+    use toml_edit::Document;
+
+    #[yare::parameterized(
+        no_metadata = { "[package]\nname = \"a\"\n" },
+        metadata_table = { "[package]\nname = \"a\"\n\n[package.metadata]\nmsrv = \"1.50\"\n" },
+        inline_metadata = { "[package]\nname = \"a\"\nmetadata = { msrv = \"1.50\" }\n" },
+    )]
+    fn certify(manifest: &str) {
+        let tmp = TestDir::temp().create("Cargo.toml", FileType::EmptyFile);
+        let path = tmp.path("Cargo.toml");
+
+        std::fs::write(&path, manifest).unwrap();
+
+        let config = ConfigBuilder::new(Action::Find, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        let version = semver::Version::new(1, 56, 1);
+
+        write_certification(&config, &FakeTestReporter::default(), &version).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let document = CargoManifestParser::default()
+            .parse::<Document>(&content)
+            .unwrap();
+        let certification = Certification::read(&document).unwrap();
+
+        assert_eq!(certification.toolchain(), "1.56.1");
+        assert!(!content.contains("[package.metadata]\n[package.metadata.msrv-verified]"));
+    }
+}
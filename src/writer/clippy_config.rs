@@ -0,0 +1,164 @@
+use std::path::{Path, PathBuf};
+
+use toml_edit::{value, Document};
+
+use crate::combinators::ThenSome;
+use crate::error::IoErrorSource;
+use crate::manifest::bare_version::BareVersion;
+use crate::reporter::event::{AuxiliaryOutput, AuxiliaryOutputItem, Destination};
+use crate::reporter::Reporter;
+use crate::{CargoMSRVError, Config, TResult};
+
+const CLIPPY_CONFIG_FILE: &str = "clippy.toml";
+const CLIPPY_CONFIG_FILE_HIDDEN: &str = ".clippy.toml";
+
+/// Write the MSRV to the `msrv` key of the Clippy configuration file, so Clippy's MSRV aware
+/// lints use the same MSRV as the Cargo manifest.
+///
+/// An existing configuration file is updated in place, keeping its other keys and formatting
+/// intact. If no configuration file exists yet, a `clippy.toml` file is created.
+pub fn write_clippy_msrv(
+    config: &Config,
+    reporter: &impl Reporter,
+    msrv: &BareVersion,
+) -> TResult<()> {
+    let path_prefix = config.context().crate_root_path()?;
+    let path = clippy_config_file(path_prefix);
+
+    let contents = if path.exists() {
+        std::fs::read_to_string(&path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.clone()),
+        })?
+    } else {
+        String::new()
+    };
+
+    let contents = set_msrv(&contents, msrv)?;
+
+    std::fs::write(&path, contents).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(path.clone()),
+    })?;
+
+    reporter.report_event(AuxiliaryOutput::new(
+        Destination::File(path),
+        AuxiliaryOutputItem::clippy_config(),
+    ))?;
+
+    Ok(())
+}
+
+/// The Clippy configuration file which [`write_clippy_msrv`] would write, without writing it.
+pub fn planned_clippy_config(config: &Config) -> TResult<AuxiliaryOutput> {
+    let path = clippy_config_file(config.context().crate_root_path()?);
+
+    Ok(AuxiliaryOutput::planned(
+        Destination::File(path),
+        AuxiliaryOutputItem::clippy_config(),
+    ))
+}
+
+/// Determine which Clippy configuration file should be written to.
+///
+/// Clippy prefers `.clippy.toml` over `clippy.toml` when both exist.
+fn clippy_config_file(path: &Path) -> PathBuf {
+    fn hidden(path: &Path) -> Option<PathBuf> {
+        let file = path.join(CLIPPY_CONFIG_FILE_HIDDEN);
+        ThenSome::then_some(file.exists(), file)
+    }
+
+    fn regular(path: &Path) -> Option<PathBuf> {
+        let file = path.join(CLIPPY_CONFIG_FILE);
+        ThenSome::then_some(file.exists(), file)
+    }
+
+    hidden(path)
+        .or_else(|| regular(path))
+        .unwrap_or_else(|| path.join(CLIPPY_CONFIG_FILE))
+}
+
+fn set_msrv(contents: &str, msrv: &BareVersion) -> TResult<String> {
+    let mut document = contents.parse::<Document>()?;
+    document["msrv"] = value(msrv.to_string());
+
+    Ok(document.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::ConfigBuilder;
+    use crate::manifest::bare_version::BareVersion;
+    use crate::reporter::event::{AuxiliaryOutput, AuxiliaryOutputItem, Destination};
+    use crate::reporter::{FakeTestReporter, TestReporter};
+    use crate::writer::clippy_config::write_clippy_msrv;
+    use crate::{Action, Event};
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    #[test]
+    fn no_clippy_config_yet() {
+        let tmp = TestDir::temp();
+        let config = ConfigBuilder::new(Action::Set, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        let msrv = BareVersion::TwoComponents(1, 60);
+        write_clippy_msrv(&config, &FakeTestReporter::default(), &msrv).unwrap();
+
+        let contents = std::fs::read_to_string(tmp.path("clippy.toml")).unwrap();
+        assert_eq!(contents, "msrv = \"1.60\"\n");
+        assert!(!tmp.path(".clippy.toml").exists());
+    }
+
+    #[test]
+    fn update_existing_config() {
+        let tmp = TestDir::temp().create("clippy.toml", FileType::EmptyFile);
+        std::fs::write(
+            tmp.path("clippy.toml"),
+            "# lints\ncognitive-complexity-threshold = 30\nmsrv = \"1.40.0\"\n",
+        )
+        .unwrap();
+
+        let config = ConfigBuilder::new(Action::Set, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        let msrv = BareVersion::ThreeComponents(1, 56, 1);
+        write_clippy_msrv(&config, &FakeTestReporter::default(), &msrv).unwrap();
+
+        let contents = std::fs::read_to_string(tmp.path("clippy.toml")).unwrap();
+        assert_eq!(
+            contents,
+            "# lints\ncognitive-complexity-threshold = 30\nmsrv = \"1.56.1\"\n"
+        );
+    }
+
+    #[test]
+    fn hidden_config_takes_precedence() {
+        let tmp = TestDir::temp()
+            .create("clippy.toml", FileType::EmptyFile)
+            .create(".clippy.toml", FileType::EmptyFile);
+
+        let config = ConfigBuilder::new(Action::Set, "")
+            .crate_path(Some(tmp.root()))
+            .build();
+
+        let reporter = TestReporter::default();
+        let msrv = BareVersion::TwoComponents(1, 60);
+        write_clippy_msrv(&config, reporter.reporter(), &msrv).unwrap();
+
+        let contents = std::fs::read_to_string(tmp.path(".clippy.toml")).unwrap();
+        assert_eq!(contents, "msrv = \"1.60\"\n");
+
+        let metadata = std::fs::metadata(tmp.path("clippy.toml")).unwrap();
+        assert_eq!(metadata.len(), 0);
+
+        let expected: Vec<Event> = vec![AuxiliaryOutput::new(
+            Destination::File(tmp.path(".clippy.toml")),
+            AuxiliaryOutputItem::clippy_config(),
+        )
+        .into()];
+
+        phenomenon::contains_at_least_ordered(reporter.wait_for_events(), expected).assert_this();
+    }
+}
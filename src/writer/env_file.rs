@@ -0,0 +1,153 @@
+use std::path::{Path, PathBuf};
+
+use crate::config::WriteEnv;
+use crate::error::IoErrorSource;
+use crate::reporter::event::{AuxiliaryOutput, AuxiliaryOutputItem, Destination, MsrvKind};
+use crate::reporter::Reporter;
+use crate::{semver, CargoMSRVError, TResult};
+
+const ENV_VARIABLE: &str = "MSRV";
+const STEP_OUTPUT: &str = "msrv";
+
+const GITHUB_ENV: &str = "GITHUB_ENV";
+const GITHUB_OUTPUT: &str = "GITHUB_OUTPUT";
+
+/// Write the MSRV as `MSRV=<version>` to an environment file, so later steps of a CI workflow can
+/// install the toolchain of the MSRV.
+pub fn write_env_file(
+    write_env: &WriteEnv,
+    reporter: &impl Reporter,
+    msrv: &semver::Version,
+) -> TResult<()> {
+    for (path, name) in env_files(write_env)? {
+        write_variable(&path, name, &msrv.to_string())?;
+
+        reporter.report_event(AuxiliaryOutput::new(
+            Destination::Env(path),
+            AuxiliaryOutputItem::msrv(MsrvKind::EnvVariable),
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// The environment files which [`write_env_file`] would write, without writing them.
+pub fn planned_env_files(write_env: &WriteEnv) -> TResult<Vec<AuxiliaryOutput>> {
+    let outputs = env_files(write_env)?
+        .into_iter()
+        .map(|(path, _)| {
+            AuxiliaryOutput::planned(
+                Destination::Env(path),
+                AuxiliaryOutputItem::msrv(MsrvKind::EnvVariable),
+            )
+        })
+        .collect();
+
+    Ok(outputs)
+}
+
+/// The environment files to write to, and the name of the variable in each file.
+fn env_files(write_env: &WriteEnv) -> TResult<Vec<(PathBuf, &'static str)>> {
+    match write_env {
+        WriteEnv::File(path) => Ok(vec![(path.clone(), ENV_VARIABLE)]),
+        WriteEnv::GitHubActions => {
+            let github_file = |variable: &str| {
+                std::env::var_os(variable)
+                    .filter(|path| !path.is_empty())
+                    .map(PathBuf::from)
+            };
+
+            let files = github_file(GITHUB_ENV)
+                .map(|path| (path, ENV_VARIABLE))
+                .into_iter()
+                .chain(github_file(GITHUB_OUTPUT).map(|path| (path, STEP_OUTPUT)))
+                .collect::<Vec<_>>();
+
+            if files.is_empty() {
+                return Err(CargoMSRVError::InvalidConfig(format!(
+                    "No path was given to --write-env, and neither ${} nor ${} is set. Give the path of the environment file instead, e.g. --write-env msrv.env",
+                    GITHUB_ENV, GITHUB_OUTPUT
+                )));
+            }
+
+            Ok(files)
+        }
+    }
+}
+
+/// Set the variable in the environment file, keeping its other variables.
+///
+/// A previous value of the variable is replaced, instead of adding the variable again.
+fn write_variable(path: &Path, name: &str, value: &str) -> TResult<()> {
+    let contents = if path.exists() {
+        std::fs::read_to_string(path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.to_path_buf()),
+        })?
+    } else {
+        String::new()
+    };
+
+    std::fs::write(path, set_variable(&contents, name, value)).map_err(|error| CargoMSRVError::Io {
+        error,
+        source: IoErrorSource::WriteFile(path.to_path_buf()),
+    })
+}
+
+fn set_variable(contents: &str, name: &str, value: &str) -> String {
+    let prefix = format!("{}=", name);
+
+    let mut lines = contents
+        .lines()
+        .filter(|line| !line.starts_with(&prefix))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+
+    lines.push(format!("{}{}", prefix, value));
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporter::event::Message;
+    use crate::reporter::TestReporter;
+    use crate::Event;
+    use test_dir::{DirBuilder, TestDir};
+
+    #[yare::parameterized(
+        empty = { "", "MSRV=1.56.1\n" },
+        other_variables = { "RUST_LOG=info\n", "RUST_LOG=info\nMSRV=1.56.1\n" },
+        replaces_previous = { "MSRV=1.50.0\nRUST_LOG=info\n", "RUST_LOG=info\nMSRV=1.56.1\n" },
+        similar_name = { "MSRV_CHECKED=1\n", "MSRV_CHECKED=1\nMSRV=1.56.1\n" },
+    )]
+    fn set_msrv_variable(contents: &str, expected: &str) {
+        assert_eq!(set_variable(contents, "MSRV", "1.56.1"), expected);
+    }
+
+    #[test]
+    fn write_to_given_file() {
+        let tmp = TestDir::temp();
+        let path = tmp.path("msrv.env");
+        let reporter = TestReporter::default();
+
+        write_env_file(
+            &WriteEnv::File(path.clone()),
+            reporter.reporter(),
+            &semver::Version::new(1, 56, 1),
+        )
+        .unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "MSRV=1.56.1\n");
+        assert_eq!(
+            reporter.wait_for_events(),
+            vec![Event::new(Message::AuxiliaryOutput(AuxiliaryOutput::new(
+                Destination::Env(path),
+                AuxiliaryOutputItem::msrv(MsrvKind::EnvVariable),
+            )))]
+        );
+    }
+}
@@ -4,17 +4,21 @@ use crate::reporter::event::{
     AuxiliaryOutput, AuxiliaryOutputItem, Destination, ToolchainFileKind,
 };
 use crate::reporter::Reporter;
+use crate::toolchain_file::{TOOLCHAIN_FILE, TOOLCHAIN_FILE_TOML};
 use crate::{semver, CargoMSRVError, Config, TResult};
 use std::fmt;
 use std::path::{Path, PathBuf};
+use toml_edit::{value, Array, Document, Item, Table};
 
-const TOOLCHAIN_FILE: &str = "rust-toolchain";
-const TOOLCHAIN_FILE_TOML: &str = "rust-toolchain.toml";
-
-// - consider: replace toolchain file with 'best' rust-toolchain(.toml) format variant available
-// - consider: support old toolchain-file format
-// - consider: do not simply override, also support components, targets, profile
+// - consider: also support profile
 //     - in reverse: use the values from rust-toolchain file to auto configure config
+/// Write the given version as the channel of the rust-toolchain file, pinned as precisely as
+/// configured by [`Config::toolchain_file_pin`].
+///
+/// An existing TOML toolchain file is updated in place, so its `components`, `targets` and
+/// other keys are preserved. The configured components and targets are added to it. An existing
+/// legacy toolchain file, which only contains the name of the toolchain, is kept in the legacy
+/// format, unless components or targets have to be added.
 pub fn write_toolchain_file(
     config: &Config,
     reporter: &impl Reporter,
@@ -22,7 +26,24 @@ pub fn write_toolchain_file(
 ) -> TResult<()> {
     let path_prefix = config.context().crate_root_path()?;
     let path = toolchain_file(path_prefix);
-    let content = format_toolchain_file(stable_version);
+
+    let existing = if path.is_file() {
+        std::fs::read_to_string(&path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.clone()),
+        })?
+    } else {
+        String::new()
+    };
+
+    let channel = config.toolchain_file_pin().channel(stable_version);
+
+    let (kind, content) = toolchain_file_contents(
+        &existing,
+        &channel,
+        config.toolchain_file_components(),
+        config.toolchain_file_targets(),
+    )?;
 
     std::fs::write(&path, content).map_err(|error| CargoMSRVError::Io {
         error,
@@ -30,13 +51,115 @@ pub fn write_toolchain_file(
     })?;
 
     reporter.report_event(AuxiliaryOutput::new(
-        Destination::File(path),
-        AuxiliaryOutputItem::toolchain_file(ToolchainFileKind::Toml),
+        Destination::ToolchainFile(path),
+        AuxiliaryOutputItem::toolchain_file(kind, channel),
     ))?;
 
     Ok(())
 }
 
+/// The toolchain file which [`write_toolchain_file`] would write, without writing it.
+pub fn planned_toolchain_file(config: &Config) -> TResult<AuxiliaryOutput> {
+    let path = toolchain_file(config.context().crate_root_path()?);
+
+    let existing = if path.is_file() {
+        std::fs::read_to_string(&path).map_err(|error| CargoMSRVError::Io {
+            error,
+            source: IoErrorSource::ReadFile(path.clone()),
+        })?
+    } else {
+        String::new()
+    };
+
+    let kind = toolchain_file_kind(
+        &existing,
+        config.toolchain_file_components(),
+        config.toolchain_file_targets(),
+    );
+
+    Ok(AuxiliaryOutput::planned(
+        Destination::ToolchainFile(path),
+        AuxiliaryOutputItem::planned_toolchain_file(kind),
+    ))
+}
+
+/// A legacy toolchain file stays a legacy toolchain file, unless components or targets have to be
+/// added to it.
+fn toolchain_file_kind(
+    existing: &str,
+    components: &[String],
+    targets: &[String],
+) -> ToolchainFileKind {
+    let is_legacy = !existing.trim().is_empty() && existing.parse::<Document>().is_err();
+
+    if is_legacy && components.is_empty() && targets.is_empty() {
+        ToolchainFileKind::Legacy
+    } else {
+        ToolchainFileKind::Toml
+    }
+}
+
+/// The new contents of the toolchain file, given the contents of the existing file, which are
+/// empty when no toolchain file exists yet.
+fn toolchain_file_contents(
+    existing: &str,
+    channel: &str,
+    components: &[String],
+    targets: &[String],
+) -> TResult<(ToolchainFileKind, String)> {
+    let is_empty = existing.trim().is_empty();
+    let is_legacy = !is_empty && existing.parse::<Document>().is_err();
+
+    if toolchain_file_kind(existing, components, targets) == ToolchainFileKind::Legacy {
+        return Ok((
+            ToolchainFileKind::Legacy,
+            format_legacy_toolchain_file(&channel),
+        ));
+    }
+
+    let contents = if is_empty || is_legacy {
+        format_toolchain_file(&channel)
+    } else {
+        existing.to_string()
+    };
+
+    let mut document = contents.parse::<Document>()?;
+
+    if !document.get("toolchain").map_or(false, Item::is_table_like) {
+        document["toolchain"] = Item::Table(Table::new());
+    }
+
+    let toolchain = &mut document["toolchain"];
+    toolchain["channel"] = value(channel);
+    extend_array(toolchain, "components", components);
+    extend_array(toolchain, "targets", targets);
+
+    Ok((ToolchainFileKind::Toml, document.to_string()))
+}
+
+/// Add the values which are not yet present to the array with the given key, creating the
+/// array if it does not exist yet.
+fn extend_array(table: &mut Item, key: &str, values: &[String]) {
+    if values.is_empty() {
+        return;
+    }
+
+    if table.get(key).and_then(Item::as_array).is_none() {
+        table[key] = value(Array::new());
+    }
+
+    if let Some(array) = table[key].as_array_mut() {
+        for value in values {
+            if !array
+                .iter()
+                .any(|existing| existing.as_str() == Some(value))
+            {
+                array.push(value.as_str());
+            }
+        }
+    }
+}
+
 /// Determine whether we should use a .toml extension or no extension for the rust-toolchain file.
 fn toolchain_file(path: &Path) -> PathBuf {
     fn without_extension(path: &Path) -> Option<PathBuf> {
@@ -68,8 +191,16 @@ channel = "{}"
     )
 }
 
+fn format_legacy_toolchain_file<D>(channel: &D) -> String
+where
+    D: fmt::Display,
+{
+    format!("{}\n", channel)
+}
+
 #[cfg(test)]
 mod write_toolchain_file_tests {
+    use crate::config::toolchain_file::ToolchainFilePin;
     use crate::config::ConfigBuilder;
     use crate::error::IoErrorSource;
     use crate::reporter::event::{
@@ -218,8 +349,50 @@ channel = "1.55.77"
 
         let events = test_reporter.wait_for_events();
         let expected: Vec<Event> = vec![AuxiliaryOutput::new(
-            Destination::File(tmp.path("rust-toolchain")),
-            AuxiliaryOutputItem::toolchain_file(ToolchainFileKind::Toml),
+            Destination::ToolchainFile(tmp.path("rust-toolchain")),
+            AuxiliaryOutputItem::toolchain_file(ToolchainFileKind::Toml, "2.0.5"),
+        )
+        .into()];
+
+        phenomenon::contains_at_least_ordered(events, expected).assert_this();
+    }
+
+    #[test]
+    fn pin_minor_preserves_existing_entries() {
+        let tmp = TestDir::temp();
+        std::fs::write(
+            tmp.path("rust-toolchain.toml"),
+            r#"[toolchain]
+channel = "1.50.0"
+profile = "minimal"
+components = ["clippy"]
+"#,
+        )
+        .unwrap();
+
+        let crate_path = tmp.root();
+        let config = ConfigBuilder::new(Action::Find, "")
+            .crate_path(Some(crate_path))
+            .toolchain_file_pin(ToolchainFilePin::Minor)
+            .build();
+
+        let test_reporter = TestReporter::default();
+        let version = semver::Version::new(1, 64, 3);
+
+        write_toolchain_file(&config, test_reporter.reporter(), &version).unwrap();
+
+        let contents = std::fs::read_to_string(tmp.path("rust-toolchain.toml")).unwrap();
+        let expected = r#"[toolchain]
+channel = "1.64"
+profile = "minimal"
+components = ["clippy"]
+"#;
+        assert_eq!(&contents, expected);
+
+        let events = test_reporter.wait_for_events();
+        let expected: Vec<Event> = vec![AuxiliaryOutput::new(
+            Destination::ToolchainFile(tmp.path("rust-toolchain.toml")),
+            AuxiliaryOutputItem::toolchain_file(ToolchainFileKind::Toml, "1.64"),
         )
         .into()];
 
@@ -262,6 +435,89 @@ channel = "1.55.77"
     }
 }
 
+#[cfg(test)]
+mod toolchain_file_contents_tests {
+    use crate::reporter::event::ToolchainFileKind;
+    use crate::writer::toolchain_file::toolchain_file_contents;
+
+    fn contents(
+        existing: &str,
+        components: &[&str],
+        targets: &[&str],
+    ) -> (ToolchainFileKind, String) {
+        let components = components
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let targets = targets.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        toolchain_file_contents(existing, "1.56.0", &components, &targets).unwrap()
+    }
+
+    #[test]
+    fn preserves_components_and_targets() {
+        let existing = r#"[toolchain]
+channel = "1.50.0"
+components = ["clippy"]
+targets = ["wasm32-unknown-unknown"]
+profile = "minimal"
+"#;
+
+        let (kind, content) = contents(existing, &[], &[]);
+
+        let expected = r#"[toolchain]
+channel = "1.56.0"
+components = ["clippy"]
+targets = ["wasm32-unknown-unknown"]
+profile = "minimal"
+"#;
+        assert_eq!(kind, ToolchainFileKind::Toml);
+        assert_eq!(content, expected);
+    }
+
+    #[test]
+    fn adds_missing_components_and_targets() {
+        let existing = r#"[toolchain]
+channel = "1.50.0"
+components = ["clippy"]
+"#;
+
+        let (kind, content) = contents(
+            existing,
+            &["clippy", "rustfmt"],
+            &["wasm32-unknown-unknown"],
+        );
+
+        let expected = r#"[toolchain]
+channel = "1.56.0"
+components = ["clippy", "rustfmt"]
+targets = ["wasm32-unknown-unknown"]
+"#;
+        assert_eq!(kind, ToolchainFileKind::Toml);
+        assert_eq!(content, expected);
+    }
+
+    #[test]
+    fn legacy_stays_legacy() {
+        let (kind, content) = contents("1.50.0\n", &[], &[]);
+
+        assert_eq!(kind, ToolchainFileKind::Legacy);
+        assert_eq!(content, "1.56.0\n");
+    }
+
+    #[test]
+    fn legacy_with_components_becomes_toml() {
+        let (kind, content) = contents("1.50.0\n", &["clippy"], &[]);
+
+        let expected = r#"[toolchain]
+channel = "1.56.0"
+components = ["clippy"]
+"#;
+        assert_eq!(kind, ToolchainFileKind::Toml);
+        assert_eq!(content, expected);
+    }
+}
+
 #[cfg(test)]
 mod toolchain_file_tests {
     use crate::writer::toolchain_file::toolchain_file;
@@ -15,6 +15,7 @@ pub fn write_msrv(
         .mode_intent(Action::Set)
         .sub_command_config(SubCommandConfig::SetConfig(SetCmdConfig {
             msrv: version.into(),
+            propagate: false,
         }))
         .build();
 